@@ -0,0 +1,69 @@
+use {
+    criterion::{Criterion, criterion_group, criterion_main},
+    ical::{Date, DateTime, RecurrenceFrequency, RecurrenceRule, Time, Weekday, WeekdayNum},
+    std::{fmt::Write, hint::black_box},
+};
+
+/// The rules to benchmark, together with a short name for each rule.
+fn rules() -> Vec<(&'static str, RecurrenceRule)> {
+    let mut until = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+    until.set_until(DateTime {
+        date: Date::new(2025, 12, 31),
+        time: Time::new_utc(23, 59, 59),
+    });
+
+    let mut by_day = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    by_day.set_count(10).set_by_day([
+        WeekdayNum::nth(2, Weekday::Sunday),
+        WeekdayNum::nth(-1, Weekday::Friday),
+    ]);
+
+    let mut by_month_day = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    by_month_day.set_interval(2).set_by_month_day([1, 15, -1]);
+
+    // The last workday of the month.
+    let mut by_set_pos = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    by_set_pos
+        .set_by_day([
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ])
+        .set_by_set_pos([-1]);
+
+    vec![
+        ("UNTIL", until),
+        ("BYDAY", by_day),
+        ("BYMONTHDAY", by_month_day),
+        ("BYSETPOS", by_set_pos),
+    ]
+}
+
+fn display(c: &mut Criterion) {
+    let rules = rules();
+
+    let mut group = c.benchmark_group("RecurrenceRule::to_string");
+    for (name, rule) in &rules {
+        group.bench_function(*name, |b| {
+            b.iter(|| black_box(rule).to_string());
+        });
+    }
+    group.finish();
+
+    let mut buffer = String::new();
+    let mut group = c.benchmark_group("RecurrenceRule::fmt");
+    for (name, rule) in &rules {
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                buffer.clear();
+                write!(buffer, "{}", black_box(rule)).expect("Writing to a String never fails");
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, display);
+criterion_main!(benches);
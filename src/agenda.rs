@@ -0,0 +1,149 @@
+use {
+    crate::{Calendar, Date, DateOrDateTime, Event, Weekday},
+    std::collections::BTreeMap,
+};
+
+/// An occurrence of an event, as listed by [`Calendar::occurrences_by_day`] and
+/// [`Calendar::occurrences_by_week`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Occurrence<'a> {
+    /// The event that occurs.
+    pub event: &'a Event,
+    /// The start of this occurrence.
+    pub start: DateOrDateTime,
+}
+
+impl Calendar {
+    /// Get the occurrences of all events that start in the range from `start` (inclusive) to
+    /// `end` (exclusive), grouped by the day on which they start.
+    ///
+    /// Only days with at least one occurrence are included. The occurrences of each day are in
+    /// chronological order. They are computed as described for [`Calendar::events_between`]. An
+    /// occurrence that lasts several days is only listed on the day on which it starts.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+    ///
+    /// let at = |day, hour| DateTime {
+    ///     date: Date::new(2024, 7, day),
+    ///     time: Time::new_utc(hour, 0, 0),
+    /// };
+    /// let mut standup = Event::with_uid("standup@example.com", at(1, 9).into(), at(1, 0));
+    /// standup.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+    /// let lunch = Event::with_uid("lunch@example.com", at(2, 12).into(), at(1, 0));
+    /// let calendar = Calendar::from_events([standup, lunch]);
+    ///
+    /// let agenda = calendar.occurrences_by_day(Date::new(2024, 7, 1), Date::new(2024, 7, 8));
+    /// assert_eq!(agenda.len(), 7);
+    /// let tuesday = &agenda[&Date::new(2024, 7, 2)];
+    /// assert_eq!(tuesday[0].event.uid(), "standup@example.com");
+    /// assert_eq!(tuesday[1].start, at(2, 12).into());
+    /// ```
+    #[must_use]
+    pub fn occurrences_by_day(
+        &self,
+        start: Date,
+        end: Date,
+    ) -> BTreeMap<Date, Vec<Occurrence<'_>>> {
+        self.group_occurrences(start, end, |date| date)
+    }
+
+    /// Get the occurrences of all events that start in the range from `start` (inclusive) to
+    /// `end` (exclusive), grouped by the week in which they start.
+    ///
+    /// Each week is identified by its first day, which is a `week_start`. Otherwise, this is the
+    /// same as [`Calendar::occurrences_by_day`].
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, Weekday};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(0, 0, 0),
+    /// };
+    /// // 2024-07-06 is a Saturday and 2024-07-07 is a Sunday.
+    /// let saturday = Event::with_uid("sat@example.com", Date::new(2024, 7, 6).into(), stamp);
+    /// let sunday = Event::with_uid("sun@example.com", Date::new(2024, 7, 7).into(), stamp);
+    /// let calendar = Calendar::from_events([saturday, sunday]);
+    ///
+    /// let start = Date::new(2024, 7, 1);
+    /// let end = Date::new(2024, 8, 1);
+    /// let weeks = calendar.occurrences_by_week(start, end, Weekday::Monday);
+    /// assert_eq!(weeks.keys().collect::<Vec<_>>(), [&Date::new(2024, 7, 1)]);
+    /// let weeks = calendar.occurrences_by_week(start, end, Weekday::Sunday);
+    /// assert_eq!(
+    ///     weeks.keys().collect::<Vec<_>>(),
+    ///     [&Date::new(2024, 6, 30), &Date::new(2024, 7, 7)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn occurrences_by_week(
+        &self,
+        start: Date,
+        end: Date,
+        week_start: Weekday,
+    ) -> BTreeMap<Date, Vec<Occurrence<'_>>> {
+        self.group_occurrences(start, end, |date| {
+            let days = (date.weekday().days_from_monday() + 7 - week_start.days_from_monday()) % 7;
+            // The first week of the year 0 starts before the first date that can be represented.
+            date.checked_add_days(-i64::from(days))
+                .unwrap_or(Date::new(0, 1, 1))
+        })
+    }
+
+    /// Group the occurrences that start in the range from `start` to `end` by the date returned
+    /// by `key` for the day on which they start.
+    fn group_occurrences<F>(
+        &self,
+        start: Date,
+        end: Date,
+        key: F,
+    ) -> BTreeMap<Date, Vec<Occurrence<'_>>>
+    where
+        F: Fn(Date) -> Date,
+    {
+        let mut groups: BTreeMap<Date, Vec<Occurrence<'_>>> = BTreeMap::new();
+        for (event, start) in self.events_between(start, end) {
+            groups
+                .entry(key(start.date()))
+                .or_default()
+                .push(Occurrence { event, start });
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time, Weekday,
+    };
+
+    #[test]
+    fn by_week() {
+        let start = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(9, 0, 0),
+        };
+        let mut event = Event::with_uid("daily", start.into(), start);
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        let calendar = Calendar::from_events([event]);
+        let weeks: Vec<_> = calendar
+            .occurrences_by_week(
+                Date::new(2024, 7, 3),
+                Date::new(2024, 7, 20),
+                Weekday::Wednesday,
+            )
+            .into_iter()
+            .map(|(week, occurrences)| (week, occurrences.len()))
+            .collect();
+        assert_eq!(
+            weeks,
+            [
+                (Date::new(2024, 7, 3), 7),
+                (Date::new(2024, 7, 10), 7),
+                (Date::new(2024, 7, 17), 3),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1213 @@
+use {
+    crate::{
+        Attendee, DateOrDateTime, DateTime, Duration, Event, Geo, InvalidValueError,
+        StructuredLocation, Time, param, value,
+    },
+    ical_vcard::{Contentline, Value},
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+#[cfg(all(feature = "clock", feature = "uuid"))]
+use {
+    std::{collections::HashMap, mem},
+    uuid::Uuid,
+};
+
+/// Represents a `VALARM` component as specified in
+/// [RFC 5545 section 3.6.6](https://tools.ietf.org/html/rfc5545#section-3.6.6).
+///
+/// An alarm reminds the user of an event at the time given by its [`Trigger`], by displaying a
+/// message, playing a sound or sending an email.
+///
+/// The extensions of [RFC 9074 - VALARM
+/// Extensions](https://tools.ietf.org/html/rfc9074) that allow clients to synchronize dismissed
+/// and snoozed alarms are supported, see [`Alarm::snooze`], as are alarms that are triggered
+/// when the user arrives at or leaves a location, see [`Alarm::set_proximity`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alarm {
+    action: AlarmAction,
+    trigger: Trigger,
+    description: Option<Value<String>>,
+    summary: Option<Value<String>>,
+    attendees: Vec<Attendee>,
+    attachments: Vec<Value<String>>,
+    uid: Option<Value<String>>,
+    acknowledged: Option<DateTime>,
+    snoozed_uid: Option<Value<String>>,
+    proximity: Option<Proximity>,
+    locations: Vec<StructuredLocation>,
+}
+
+/// The text of a `DISPLAY` alarm without a description whose event has no summary either.
+const DEFAULT_DESCRIPTION: &str = "Reminder";
+
+impl Alarm {
+    /// Create a new alarm that displays a message (`ACTION:DISPLAY`) at the time given by
+    /// `trigger`.
+    ///
+    /// The message is the description of the alarm, see [`Alarm::set_description`]. If the
+    /// alarm has no description, the summary of its event is displayed instead.
+    #[must_use]
+    pub fn display(trigger: Trigger) -> Self {
+        Self {
+            action: AlarmAction::Display,
+            trigger,
+            description: None,
+            summary: None,
+            attendees: Vec::new(),
+            attachments: Vec::new(),
+            uid: None,
+            acknowledged: None,
+            snoozed_uid: None,
+            proximity: None,
+            locations: Vec::new(),
+        }
+    }
+
+    /// Create a new alarm that does nothing (`ACTION:NONE`) at the time given by `trigger`.
+    ///
+    /// Such an alarm can be used to override a default alarm of a client, or to notify the user
+    /// in a way that is chosen by the client, for example when a proximity trigger fires, see
+    /// [`Alarm::set_proximity`].
+    ///
+    /// See [RFC 9074 section 3](https://tools.ietf.org/html/rfc9074#section-3).
+    #[must_use]
+    pub fn none(trigger: Trigger) -> Self {
+        let mut alarm = Self::display(trigger);
+        alarm.action = AlarmAction::None;
+        alarm
+    }
+
+    /// Create a new alarm that plays a sound (`ACTION:AUDIO`) at the time given by `trigger`.
+    ///
+    /// The sound is referenced by the URI `sound`. Without a sound, clients play a default
+    /// sound.
+    ///
+    /// ```
+    /// use ical::{Alarm, AlarmAction, Duration, Trigger};
+    ///
+    /// let trigger = Trigger::relative_to_start(-Duration::from_minutes(5));
+    /// let alarm = Alarm::audio(trigger, Some("https://example.com/chime.wav"));
+    /// assert_eq!(alarm.action(), AlarmAction::Audio);
+    /// assert!(alarm.attachments().eq(["https://example.com/chime.wav"]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sound` is not a valid [`Value`].
+    #[must_use]
+    pub fn audio(trigger: Trigger, sound: Option<&str>) -> Self {
+        let mut alarm = Self::display(trigger);
+        alarm.action = AlarmAction::Audio;
+        if let Some(sound) = sound {
+            alarm.add_attachment(sound);
+        }
+        alarm
+    }
+
+    /// Create a new alarm that sends an email (`ACTION:EMAIL`) to `attendee` at the time given
+    /// by `trigger`.
+    ///
+    /// The `summary` is the subject of the email and the `description` is its body. Further
+    /// recipients can be added with [`Alarm::add_attendee`] and attachments with
+    /// [`Alarm::add_attachment`].
+    ///
+    /// ```
+    /// use ical::{Alarm, Attendee, Duration, Trigger};
+    ///
+    /// let mut alarm = Alarm::email(
+    ///     Trigger::relative_to_start(-Duration::from_days(1)),
+    ///     "Board meeting tomorrow",
+    ///     "Please review the agenda before the meeting.",
+    ///     Attendee::new("mailto:alice@example.com"),
+    /// );
+    /// alarm
+    ///     .add_attendee(Attendee::new("mailto:bob@example.com"))
+    ///     .add_attachment("https://example.com/agenda.pdf");
+    /// assert_eq!(alarm.summary(), Some("Board meeting tomorrow"));
+    /// assert_eq!(alarm.attendees().len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `summary` or `description` is not a valid [`Value`].
+    #[must_use]
+    pub fn email<S, T>(trigger: Trigger, summary: S, description: T, attendee: Attendee) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        let mut alarm = Self::display(trigger);
+        alarm.action = AlarmAction::Email;
+        alarm
+            .set_summary(summary)
+            .set_description(description)
+            .add_attendee(attendee);
+        alarm
+    }
+
+    /// Get the action of the alarm (`ACTION`).
+    #[must_use]
+    pub fn action(&self) -> AlarmAction {
+        self.action
+    }
+
+    /// Get the trigger of the alarm (`TRIGGER`).
+    #[must_use]
+    pub fn trigger(&self) -> Trigger {
+        self.trigger
+    }
+
+    /// Get the time at which the alarm is triggered for the occurrence of `event` that starts at
+    /// `occurrence`, such as an occurrence returned by [`Event::occurrences_between`].
+    ///
+    /// An absolute trigger is returned as is. A relative trigger is resolved against the start
+    /// of the occurrence, or against its end, which is the start plus the time from `DTSTART` to
+    /// `DTEND` of the event. As specified in RFC 5545, an event without an end ends at the end
+    /// of its start date if it starts at a date, and at its start otherwise. A date refers to
+    /// midnight in local time.
+    ///
+    /// The time of a relative trigger is in the same time frame as the occurrence: in UTC time
+    /// for an occurrence in UTC time, and in the local time of the time zone of the event
+    /// otherwise, see [`ZonedDateTime`](crate::ZonedDateTime) for a conversion.
+    ///
+    /// ```
+    /// use ical::{Alarm, Date, DateTime, Duration, Event, Time, Trigger};
+    ///
+    /// let at = |day, hour, minute| DateTime {
+    ///     date: Date::new(2024, 7, day),
+    ///     time: Time::new_utc(hour, minute, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", at(5, 9, 0).into(), at(1, 12, 0));
+    /// event.set_end(at(5, 10, 0));
+    ///
+    /// let alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+    /// assert_eq!(alarm.trigger_time(&event, at(12, 9, 0)), Some(at(12, 8, 45)));
+    /// let alarm = Alarm::display(Trigger::relative_to_end(-Duration::from_minutes(5)));
+    /// assert_eq!(alarm.trigger_time(&event, at(12, 9, 0)), Some(at(12, 9, 55)));
+    /// ```
+    ///
+    /// Returns `None` if the time is out of range.
+    #[must_use]
+    pub fn trigger_time<T>(&self, event: &Event, occurrence: T) -> Option<DateTime>
+    where
+        T: Into<DateOrDateTime>,
+    {
+        let (offset, related) = match self.trigger.kind {
+            TriggerKind::Relative { offset, related } => (offset, related),
+            TriggerKind::Absolute(date_time) => return Some(date_time),
+        };
+        let start = midnight(occurrence.into());
+        let reference = match related {
+            TriggerRelation::Start => start,
+            TriggerRelation::End => start.checked_add(duration(event)?)?,
+        };
+        reference.checked_add(offset)
+    }
+
+    /// Set the description of the alarm (`DESCRIPTION`), which is the message of a `DISPLAY`
+    /// alarm and the body of an `EMAIL` alarm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `description` is not a valid [`Value`]. Use [`Alarm::try_set_description`] if
+    /// the description is not known to be valid.
+    pub fn set_description<S: Into<String>>(&mut self, description: S) -> &mut Self {
+        self.try_set_description(description)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the description of the alarm, or return an error if it is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::set_description`] panics.
+    pub fn try_set_description<S: Into<String>>(
+        &mut self,
+        description: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.description = Some(value("alarm description", description.into())?);
+        Ok(self)
+    }
+
+    /// Get the description of the alarm (`DESCRIPTION`), if any.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(Value::as_str)
+    }
+
+    /// Set the summary of the alarm (`SUMMARY`), which is the subject of an `EMAIL` alarm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `summary` is not a valid [`Value`]. Use [`Alarm::try_set_summary`] if the
+    /// summary is not known to be valid.
+    pub fn set_summary<S: Into<String>>(&mut self, summary: S) -> &mut Self {
+        self.try_set_summary(summary)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the summary of the alarm, or return an error if it is not a valid [`Value`].
+    ///
+    /// ```
+    /// use ical::{Alarm, Duration, Trigger};
+    ///
+    /// let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+    /// assert!(alarm.try_set_summary("Standup").is_ok());
+    /// let err = alarm.try_set_summary("Stand\u{0}up").unwrap_err();
+    /// assert_eq!(err.what(), "alarm summary");
+    /// assert_eq!(alarm.summary(), Some("Standup"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::set_summary`] panics.
+    pub fn try_set_summary<S: Into<String>>(
+        &mut self,
+        summary: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.summary = Some(value("alarm summary", summary.into())?);
+        Ok(self)
+    }
+
+    /// Get the summary of the alarm (`SUMMARY`), if any.
+    #[must_use]
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_ref().map(Value::as_str)
+    }
+
+    /// Add a recipient of an `EMAIL` alarm (`ATTENDEE`).
+    pub fn add_attendee(&mut self, attendee: Attendee) -> &mut Self {
+        self.attendees.push(attendee);
+        self
+    }
+
+    /// Get the recipients of an `EMAIL` alarm (`ATTENDEE`).
+    #[must_use]
+    pub fn attendees(&self) -> &[Attendee] {
+        &self.attendees
+    }
+
+    /// Add an attachment of an `EMAIL` alarm (`ATTACH`), referenced by `uri`.
+    ///
+    /// The attachment of an `AUDIO` alarm is its sound, see [`Alarm::audio`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uri` is not a valid [`Value`]. Use [`Alarm::try_add_attachment`] if the URI is
+    /// not known to be valid.
+    pub fn add_attachment<S: Into<String>>(&mut self, uri: S) -> &mut Self {
+        self.try_add_attachment(uri)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add an attachment of an `EMAIL` alarm, or return an error if `uri` is not a valid
+    /// [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::add_attachment`] panics.
+    pub fn try_add_attachment<S: Into<String>>(
+        &mut self,
+        uri: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.attachments
+            .push(value("alarm attachment", uri.into())?);
+        Ok(self)
+    }
+
+    /// Get the URIs of the attachments of the alarm (`ATTACH`).
+    pub fn attachments(&self) -> impl Iterator<Item = &str> {
+        self.attachments.iter().map(Value::as_str)
+    }
+
+    /// Set the unique identifier of the alarm (`UID`), which other alarms use to refer to it.
+    ///
+    /// See [RFC 9074 section 4](https://tools.ietf.org/html/rfc9074#section-4).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uid` is not a valid [`Value`]. Use [`Alarm::try_set_uid`] if the UID is not
+    /// known to be valid.
+    pub fn set_uid<S: Into<String>>(&mut self, uid: S) -> &mut Self {
+        self.try_set_uid(uid).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the unique identifier of the alarm, or return an error if it is not a valid
+    /// [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::set_uid`] panics.
+    pub fn try_set_uid<S: Into<String>>(&mut self, uid: S) -> Result<&mut Self, InvalidValueError> {
+        self.uid = Some(value("alarm UID", uid.into())?);
+        Ok(self)
+    }
+
+    /// Get the unique identifier of the alarm (`UID`), if any.
+    #[must_use]
+    pub fn uid(&self) -> Option<&str> {
+        self.uid.as_ref().map(Value::as_str)
+    }
+
+    /// Set the time at which the user last dismissed or snoozed the alarm (`ACKNOWLEDGED`).
+    ///
+    /// See [RFC 9074 section 6](https://tools.ietf.org/html/rfc9074#section-6).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date_time` is not in UTC time.
+    pub fn set_acknowledged(&mut self, date_time: DateTime) -> &mut Self {
+        assert!(
+            date_time.time.is_utc(),
+            "The acknowledgement time of an alarm must be in UTC time"
+        );
+        self.acknowledged = Some(date_time);
+        self
+    }
+
+    /// Get the time at which the user last dismissed or snoozed the alarm (`ACKNOWLEDGED`).
+    #[must_use]
+    pub fn acknowledged(&self) -> Option<DateTime> {
+        self.acknowledged
+    }
+
+    /// Set the `UID` of the alarm that this alarm snoozes (`RELATED-TO;RELTYPE=SNOOZE`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uid` is not a valid [`Value`]. Use [`Alarm::try_set_snoozed_alarm`] if the UID
+    /// is not known to be valid.
+    pub fn set_snoozed_alarm<S: Into<String>>(&mut self, uid: S) -> &mut Self {
+        self.try_set_snoozed_alarm(uid)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the `UID` of the alarm that this alarm snoozes, or return an error if it is not a
+    /// valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::set_snoozed_alarm`] panics.
+    pub fn try_set_snoozed_alarm<S: Into<String>>(
+        &mut self,
+        uid: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.snoozed_uid = Some(value("snoozed alarm UID", uid.into())?);
+        Ok(self)
+    }
+
+    /// Get the `UID` of the alarm that this alarm snoozes (`RELATED-TO;RELTYPE=SNOOZE`), if
+    /// any.
+    #[must_use]
+    pub fn snoozed_alarm(&self) -> Option<&str> {
+        self.snoozed_uid.as_ref().map(Value::as_str)
+    }
+
+    /// Snooze the alarm until `until`, as described in [RFC 9074 section
+    /// 7](https://tools.ietf.org/html/rfc9074#section-7).
+    ///
+    /// The alarm is acknowledged at `now`, and a copy of the alarm that is triggered at `until`
+    /// and refers to this alarm is returned. The copy has no `UID`, so that it can be given a
+    /// new one. Add the copy to the event with [`Event::add_alarm`].
+    ///
+    /// ```
+    /// use ical::{Alarm, Date, DateTime, Duration, Time, Trigger};
+    ///
+    /// let at = |hour, minute| DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_utc(hour, minute, 0),
+    /// };
+    /// let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+    /// alarm.set_uid("alarm-1@example.com");
+    ///
+    /// let mut snoozed = alarm.snooze(at(8, 45), at(8, 50));
+    /// snoozed.set_uid("alarm-2@example.com");
+    /// assert_eq!(alarm.acknowledged(), Some(at(8, 45)));
+    /// assert_eq!(snoozed.trigger(), Trigger::absolute(at(8, 50)));
+    /// assert_eq!(snoozed.snoozed_alarm(), Some("alarm-1@example.com"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alarm has no `UID`, or if `now` or `until` is not in UTC time.
+    #[must_use]
+    pub fn snooze(&mut self, now: DateTime, until: DateTime) -> Alarm {
+        let uid = self
+            .uid
+            .clone()
+            .expect("Only alarms with a UID can be snoozed");
+        self.set_acknowledged(now);
+        Alarm {
+            trigger: Trigger::absolute(until),
+            uid: None,
+            acknowledged: None,
+            snoozed_uid: Some(uid),
+            ..self.clone()
+        }
+    }
+
+    /// Trigger the alarm when the user arrives at or leaves one of the locations of the alarm
+    /// (`PROXIMITY`), see [`Alarm::add_location`].
+    ///
+    /// An alarm with a proximity trigger is usually given an absolute [`Trigger`] in the past,
+    /// so that it is not triggered at a specific time.
+    ///
+    /// ```
+    /// use ical::{Alarm, Date, DateTime, Geo, Proximity, Time, Trigger};
+    ///
+    /// // The date-time that RFC 9074 uses for alarms that are never triggered by time.
+    /// let never = DateTime {
+    ///     date: Date::new(1976, 4, 1),
+    ///     time: Time::new_utc(0, 55, 45),
+    /// };
+    /// let mut alarm = Alarm::display(Trigger::absolute(never));
+    /// alarm
+    ///     .set_description("Buy milk")
+    ///     .set_proximity(Proximity::Arrive)
+    ///     .add_location("Grocery store", None, Some(Geo::new(47.3769, 8.5417)), None);
+    /// assert_eq!(alarm.proximity(), Some(Proximity::Arrive));
+    /// assert_eq!(alarm.locations()[0].name(), "Grocery store");
+    /// ```
+    ///
+    /// See [RFC 9074 section 8](https://tools.ietf.org/html/rfc9074#section-8).
+    pub fn set_proximity(&mut self, proximity: Proximity) -> &mut Self {
+        self.proximity = Some(proximity);
+        self
+    }
+
+    /// Get the proximity trigger of the alarm (`PROXIMITY`), if any.
+    #[must_use]
+    pub fn proximity(&self) -> Option<Proximity> {
+        self.proximity
+    }
+
+    /// Add a location of a proximity trigger as a `VLOCATION` component, see
+    /// [`Alarm::set_proximity`].
+    ///
+    /// The arguments are the same as for
+    /// [`Event::set_location_structured`](crate::Event::set_location_structured).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name`, `address` or `uri` is not a valid [`Value`]. Use
+    /// [`Alarm::try_add_location`] if they are not known to be valid.
+    pub fn add_location<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: Option<&str>,
+        geo: Option<Geo>,
+        uri: Option<&str>,
+    ) -> &mut Self {
+        self.try_add_location(name, address, geo, uri)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add a location of a proximity trigger, or return an error if `name`, `address` or `uri`
+    /// is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Alarm::add_location`] panics.
+    pub fn try_add_location<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: Option<&str>,
+        geo: Option<Geo>,
+        uri: Option<&str>,
+    ) -> Result<&mut Self, InvalidValueError> {
+        let location = StructuredLocation::try_new(
+            self.uid().unwrap_or("alarm"),
+            self.locations.len(),
+            name.into(),
+            address,
+            geo,
+            uri,
+        )?;
+        self.locations.push(location);
+        Ok(self)
+    }
+
+    /// Add a location that was read from a `VLOCATION` component.
+    pub(crate) fn push_location(&mut self, location: StructuredLocation) {
+        self.locations.push(location);
+    }
+
+    pub(crate) fn locations_mut(&mut self) -> &mut [StructuredLocation] {
+        &mut self.locations
+    }
+
+    /// Replace the `UID` of the alarm, the `UID` of the alarm that it snoozes and the `UID`s of
+    /// its locations by `uid`, see [`Event::content_hash`](crate::Event::content_hash).
+    pub(crate) fn replace_uids(&mut self, uid: &Value<String>) {
+        for field in [&mut self.uid, &mut self.snoozed_uid].into_iter().flatten() {
+            *field = uid.clone();
+        }
+        for location in &mut self.locations {
+            location.set_uid(uid.clone());
+        }
+    }
+
+    /// Give the alarms of a copy of an event and the locations of the alarms new `UID`s, see
+    /// [`Event::clone_for`](crate::Event::clone_for).
+    ///
+    /// Alarms without a `UID` are left without one. Snoozed alarms are updated to refer to the
+    /// new `UID` of the alarm that they snooze.
+    #[cfg(all(feature = "clock", feature = "uuid"))]
+    pub(crate) fn regenerate_uids(alarms: &mut [Alarm]) {
+        let mut uids = HashMap::new();
+        for alarm in alarms.iter_mut() {
+            if let Some(uid) = &mut alarm.uid {
+                let new_uid =
+                    Value::new(Uuid::new_v4().to_string()).expect("UUIDs are valid values");
+                let old_uid = mem::replace(uid, new_uid.clone());
+                uids.insert(old_uid.as_str().to_owned(), new_uid);
+            }
+            let owner_uid = alarm.uid.as_ref().map_or("alarm", Value::as_str);
+            for (index, location) in alarm.locations.iter_mut().enumerate() {
+                location.regenerate_uid(owner_uid, index);
+            }
+        }
+        for alarm in alarms {
+            if let Some(new_uid) = alarm
+                .snoozed_uid
+                .as_ref()
+                .and_then(|uid| uids.get(uid.as_str()))
+            {
+                alarm.snoozed_uid = Some(new_uid.clone());
+            }
+        }
+    }
+
+    /// Get the locations of the proximity trigger of the alarm (`VLOCATION`).
+    #[must_use]
+    pub fn locations(&self) -> &[StructuredLocation] {
+        &self.locations
+    }
+
+    /// Write the alarm of the event with the summary `summary`.
+    pub(crate) fn write<W: Write>(
+        &self,
+        writer: &mut ical_vcard::Writer<W>,
+        summary: Option<&str>,
+    ) -> io::Result<()> {
+        writer.write(&Contentline::new("BEGIN", "VALARM"))?;
+        if let Some(uid) = &self.uid {
+            writer.write(&Contentline::new("UID", uid.as_str()))?;
+        }
+        writer.write(&Contentline::new("ACTION", self.action.to_string()))?;
+        self.trigger.write(writer)?;
+        // `DISPLAY` and `EMAIL` alarms require a description.
+        let description = match self.action {
+            AlarmAction::Display | AlarmAction::Email => Some(
+                self.description
+                    .as_ref()
+                    .map(Value::as_str)
+                    .or(summary)
+                    .unwrap_or(DEFAULT_DESCRIPTION),
+            ),
+            AlarmAction::Audio | AlarmAction::None => self.description.as_ref().map(Value::as_str),
+        };
+        if let Some(description) = description {
+            writer.write(&Contentline::new("DESCRIPTION", description))?;
+        }
+        if let Some(summary) = &self.summary {
+            writer.write(&Contentline::new("SUMMARY", summary.as_str()))?;
+        }
+        for attendee in &self.attendees {
+            attendee.write(writer)?;
+        }
+        for attachment in &self.attachments {
+            writer.write(&Contentline::new("ATTACH", attachment.as_str()))?;
+        }
+        if let Some(acknowledged) = self.acknowledged {
+            writer.write(&Contentline::new("ACKNOWLEDGED", acknowledged.to_string()))?;
+        }
+        if let Some(snoozed_uid) = &self.snoozed_uid {
+            let mut contentline = Contentline::new("RELATED-TO", snoozed_uid.as_str());
+            contentline.params.push(param("RELTYPE", "SNOOZE"));
+            writer.write(&contentline)?;
+        }
+        if let Some(proximity) = self.proximity {
+            writer.write(&Contentline::new("PROXIMITY", proximity.to_string()))?;
+        }
+        for location in &self.locations {
+            location.write(writer)?;
+        }
+        writer.write(&Contentline::new("END", "VALARM"))?;
+        Ok(())
+    }
+}
+
+/// The action of an alarm, corresponding to the `ACTION` property.
+///
+/// See [RFC 5545 section 3.8.6.1](https://tools.ietf.org/html/rfc5545#section-3.8.6.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlarmAction {
+    /// Play a sound.
+    Audio,
+    /// Display a message to the user.
+    Display,
+    /// Send an email to the attendees of the alarm.
+    Email,
+    /// Do nothing, see [`Alarm::none`].
+    None,
+}
+
+impl Display for AlarmAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AlarmAction::Audio => write!(f, "AUDIO"),
+            AlarmAction::Display => write!(f, "DISPLAY"),
+            AlarmAction::Email => write!(f, "EMAIL"),
+            AlarmAction::None => write!(f, "NONE"),
+        }
+    }
+}
+
+/// When an alarm with a proximity trigger is triggered, corresponding to the `PROXIMITY`
+/// property.
+///
+/// See [RFC 9074 section 8.1](https://tools.ietf.org/html/rfc9074#section-8.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Proximity {
+    /// When the user arrives at the location.
+    Arrive,
+    /// When the user leaves the location.
+    Depart,
+    /// When the user's device connects to the location, such as a car's Bluetooth system.
+    Connect,
+    /// When the user's device disconnects from the location.
+    Disconnect,
+}
+
+impl Display for Proximity {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Proximity::Arrive => write!(f, "ARRIVE"),
+            Proximity::Depart => write!(f, "DEPART"),
+            Proximity::Connect => write!(f, "CONNECT"),
+            Proximity::Disconnect => write!(f, "DISCONNECT"),
+        }
+    }
+}
+
+/// When an alarm is triggered, corresponding to the `TRIGGER` property.
+///
+/// An alarm is either triggered at an offset relative to the start or the end of its event, or
+/// at an absolute date and time.
+///
+/// ```
+/// use ical::{Date, DateTime, Duration, Time, Trigger, TriggerRelation};
+///
+/// // 10 minutes before the end of the event.
+/// let trigger = Trigger::relative_to_end(-Duration::from_minutes(10));
+/// assert_eq!(trigger.offset(), Some(-Duration::from_minutes(10)));
+/// assert_eq!(trigger.related(), Some(TriggerRelation::End));
+///
+/// let date_time = DateTime {
+///     date: Date::new(2024, 7, 5),
+///     time: Time::new_utc(8, 0, 0),
+/// };
+/// let trigger = Trigger::absolute(date_time);
+/// assert_eq!(trigger.offset(), None);
+/// assert_eq!(trigger.date_time(), Some(date_time));
+/// ```
+///
+/// See [RFC 5545 section 3.8.6.3](https://tools.ietf.org/html/rfc5545#section-3.8.6.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Trigger {
+    kind: TriggerKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TriggerKind {
+    Relative {
+        offset: Duration,
+        related: TriggerRelation,
+    },
+    Absolute(DateTime),
+}
+
+impl Trigger {
+    /// Trigger the alarm at `offset` relative to the start of the event, where a negative offset
+    /// is before the start.
+    #[must_use]
+    pub fn relative_to_start(offset: Duration) -> Self {
+        Self {
+            kind: TriggerKind::Relative {
+                offset,
+                related: TriggerRelation::Start,
+            },
+        }
+    }
+
+    /// Trigger the alarm at `offset` relative to the end of the event (`RELATED=END`), where a
+    /// negative offset is before the end.
+    ///
+    /// The end of an event is the end of its duration, which is computed from `DTEND`.
+    #[must_use]
+    pub fn relative_to_end(offset: Duration) -> Self {
+        Self {
+            kind: TriggerKind::Relative {
+                offset,
+                related: TriggerRelation::End,
+            },
+        }
+    }
+
+    /// Trigger the alarm at the absolute `date_time` (`VALUE=DATE-TIME`), independently of the
+    /// time of the event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date_time` is not in UTC time, which RFC 5545 requires for absolute triggers.
+    #[must_use]
+    pub fn absolute(date_time: DateTime) -> Self {
+        assert!(
+            date_time.time.is_utc(),
+            "An absolute trigger must be in UTC time"
+        );
+        Self {
+            kind: TriggerKind::Absolute(date_time),
+        }
+    }
+
+    /// Get the offset of a relative trigger, or `None` if the trigger is absolute.
+    #[must_use]
+    pub fn offset(&self) -> Option<Duration> {
+        match self.kind {
+            TriggerKind::Relative { offset, .. } => Some(offset),
+            TriggerKind::Absolute(_) => None,
+        }
+    }
+
+    /// Get whether a relative trigger is relative to the start or the end of the event, or
+    /// `None` if the trigger is absolute.
+    #[must_use]
+    pub fn related(&self) -> Option<TriggerRelation> {
+        match self.kind {
+            TriggerKind::Relative { related, .. } => Some(related),
+            TriggerKind::Absolute(_) => None,
+        }
+    }
+
+    /// Get the date and time of an absolute trigger, or `None` if the trigger is relative.
+    #[must_use]
+    pub fn date_time(&self) -> Option<DateTime> {
+        match self.kind {
+            TriggerKind::Relative { .. } => None,
+            TriggerKind::Absolute(date_time) => Some(date_time),
+        }
+    }
+
+    fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        let contentline = match self.kind {
+            TriggerKind::Relative { offset, related } => {
+                let mut contentline = Contentline::new("TRIGGER", offset.to_string());
+                if related == TriggerRelation::End {
+                    contentline.params.push(param("RELATED", "END"));
+                }
+                contentline
+            }
+            TriggerKind::Absolute(date_time) => {
+                let mut contentline = Contentline::new("TRIGGER", date_time.to_string());
+                contentline.params.push(param("VALUE", "DATE-TIME"));
+                contentline
+            }
+        };
+        writer.write(&contentline)
+    }
+}
+
+/// What a relative [`Trigger`] is relative to, corresponding to the `RELATED` parameter.
+///
+/// See [RFC 5545 section 3.2.14](https://tools.ietf.org/html/rfc5545#section-3.2.14).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriggerRelation {
+    /// The start of the event, which is the default.
+    Start,
+    /// The end of the event.
+    End,
+}
+
+impl Display for TriggerRelation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TriggerRelation::Start => write!(f, "START"),
+            TriggerRelation::End => write!(f, "END"),
+        }
+    }
+}
+
+impl Event {
+    /// Add an alarm to the event.
+    ///
+    /// Without the `uuid` feature, the locations of an alarm without a `UID` get `UID`s derived
+    /// from the `UID` of the event and the position of the alarm, see [`Alarm::add_location`].
+    pub fn add_alarm(&mut self, alarm: Alarm) -> &mut Self {
+        self.alarms.push(alarm);
+        #[cfg(not(feature = "uuid"))]
+        {
+            let index = self.alarms.len() - 1;
+            let owner_uid = alarm_uid(self.uid(), index);
+            let alarm = &mut self.alarms[index];
+            if alarm.uid.is_none() {
+                for (index, location) in alarm.locations.iter_mut().enumerate() {
+                    location.regenerate_uid(&owner_uid, index);
+                }
+            }
+        }
+        self
+    }
+
+    /// Get the alarms of the event (`VALARM`).
+    #[must_use]
+    pub fn alarms(&self) -> &[Alarm] {
+        &self.alarms
+    }
+
+    /// Add an alarm that displays the summary of the event `before` the start of the event.
+    ///
+    /// This is a shorthand for [`Alarm::display`] with [`Trigger::relative_to_start`]. The
+    /// summary is taken when the event is written, so it does not matter whether it is set
+    /// before or after the alarm is added.
+    ///
+    /// ```
+    /// use ical::{AlarmAction, Date, DateTime, Duration, Event, Time};
+    ///
+    /// let date_time = DateTime {
+    ///     date: Date::new(2024, 1, 1),
+    ///     time: Time::new_utc(9, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", date_time.into(), date_time);
+    /// event
+    ///     .add_display_reminder(Duration::from_minutes(15))
+    ///     .set_summary("Standup");
+    /// assert_eq!(event.alarms()[0].action(), AlarmAction::Display);
+    /// assert!(event.to_string().contains(
+    ///     "BEGIN:VALARM\r\n\
+    ///      ACTION:DISPLAY\r\n\
+    ///      TRIGGER:-PT15M\r\n\
+    ///      DESCRIPTION:Standup\r\n\
+    ///      END:VALARM\r\n"
+    /// ));
+    /// ```
+    pub fn add_display_reminder(&mut self, before: Duration) -> &mut Self {
+        self.add_alarm(Alarm::display(Trigger::relative_to_start(-before)))
+    }
+}
+
+/// The `UID` that identifies the alarm at the (0-based) position `index` of the event with the
+/// `UID` `event_uid` if the alarm has no `UID` of its own.
+///
+/// It is only used to derive the `UID`s of the locations of the alarm.
+pub(crate) fn alarm_uid(event_uid: &str, index: usize) -> String {
+    format!("{event_uid}-alarm-{}", index + 1)
+}
+
+/// The time from the start to the end of `event`.
+///
+/// Returns `None` if the duration is out of range.
+fn duration(event: &Event) -> Option<Duration> {
+    let start = event.start().value();
+    match event.end() {
+        Some(end) => midnight(end).duration_since(midnight(start)),
+        None if start.is_date() => Some(Duration::from_days(1)),
+        None => Some(Duration::from_seconds(0)),
+    }
+}
+
+/// Convert a date to a date-time at midnight in local time.
+fn midnight(value: DateOrDateTime) -> DateTime {
+    match value {
+        DateOrDateTime::Date(date) => DateTime {
+            date,
+            time: Time::new_local(0, 0, 0),
+        },
+        DateOrDateTime::DateTime(date_time) => date_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Alarm, Attendee, Calendar, Date, DateTime, Duration, Event, Geo, Proximity,
+        RecurrenceFrequency, RecurrenceRule, Time, Trigger,
+    };
+
+    fn event() -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp)
+    }
+
+    #[test]
+    fn description() {
+        let mut event = event();
+        event.add_display_reminder(Duration::from_days(1));
+        assert!(event.to_string().contains("DESCRIPTION:Reminder\r\n"));
+
+        let mut alarm = Alarm::display(Trigger::relative_to_start(Duration::default()));
+        alarm.set_description("It is starting");
+        event.set_summary("Offsite").add_alarm(alarm);
+        let text = event.to_string();
+        assert!(text.contains("TRIGGER:-P1D\r\nDESCRIPTION:Offsite\r\n"));
+        assert!(text.contains("TRIGGER:PT0S\r\nDESCRIPTION:It is starting\r\n"));
+    }
+
+    #[test]
+    fn read() {
+        let mut event = event();
+        let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_hours(1)));
+        alarm.set_description("Pack your bags");
+        event.add_alarm(alarm);
+        let calendar = Calendar::from_ics(Calendar::from_event(event.clone()).to_ics_string());
+        assert_eq!(calendar.unwrap().events().next(), Some(&event));
+
+        let mut alarm = Alarm::email(
+            Trigger::relative_to_start(-Duration::from_days(1)),
+            "Trip tomorrow",
+            "Don't forget your passport.",
+            Attendee::new("mailto:alice@example.com"),
+        );
+        alarm
+            .add_attendee(Attendee::new("mailto:bob@example.com"))
+            .add_attachment("https://example.com/tickets.pdf");
+        event.add_alarm(alarm);
+        let text = event.to_string();
+        assert!(text.contains(
+            "BEGIN:VALARM\r\n\
+             ACTION:EMAIL\r\n\
+             TRIGGER:-P1D\r\n\
+             DESCRIPTION:Don't forget your passport.\r\n\
+             SUMMARY:Trip tomorrow\r\n\
+             ATTENDEE:mailto:alice@example.com\r\n\
+             ATTENDEE:mailto:bob@example.com\r\n\
+             ATTACH:https://example.com/tickets.pdf\r\n\
+             END:VALARM\r\n"
+        ));
+        event.add_alarm(Alarm::audio(
+            Trigger::relative_to_start(Duration::default()),
+            Some("file:///usr/share/sounds/bell.oga"),
+        ));
+        assert!(event.to_string().contains(
+            "ACTION:AUDIO\r\n\
+             TRIGGER:PT0S\r\n\
+             ATTACH:file:///usr/share/sounds/bell.oga\r\n\
+             END:VALARM\r\n"
+        ));
+        let calendar = Calendar::from_ics(Calendar::from_event(event.clone()).to_ics_string());
+        assert_eq!(calendar.unwrap().events().next(), Some(&event));
+
+        // Alarms with unknown actions are ignored.
+        let input = "BEGIN:VCALENDAR\r\n\
+                     BEGIN:VEVENT\r\n\
+                     UID:1@example.com\r\n\
+                     DTSTAMP:20240701T120000Z\r\n\
+                     DTSTART:20240705T120000Z\r\n\
+                     BEGIN:VALARM\r\n\
+                     ACTION:X-VIBRATE\r\n\
+                     TRIGGER:-PT5M\r\n\
+                     END:VALARM\r\n\
+                     END:VEVENT\r\n\
+                     END:VCALENDAR\r\n";
+        let calendar = Calendar::from_ics(input).unwrap();
+        assert!(calendar.events().next().unwrap().alarms().is_empty());
+    }
+
+    #[test]
+    fn triggers() {
+        let mut talk = event();
+        talk.set_summary("Talk")
+            .add_display_reminder(Duration::from_minutes(5))
+            .add_alarm(Alarm::display(Trigger::relative_to_end(
+                -Duration::from_minutes(10),
+            )))
+            .add_alarm(Alarm::display(Trigger::absolute(DateTime {
+                date: Date::new(2024, 7, 4),
+                time: Time::new_utc(18, 0, 0),
+            })));
+        let text = talk.to_string();
+        assert!(text.contains("TRIGGER:-PT5M\r\n"));
+        assert!(text.contains("TRIGGER;RELATED=END:-PT10M\r\n"));
+        assert!(text.contains("TRIGGER;VALUE=DATE-TIME:20240704T180000Z\r\n"));
+        let calendar = Calendar::from_ics(Calendar::from_event(talk.clone()).to_ics_string());
+        let triggers = |event: &Event| {
+            event
+                .alarms()
+                .iter()
+                .map(Alarm::trigger)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            triggers(calendar.unwrap().events().next().unwrap()),
+            triggers(&talk)
+        );
+    }
+
+    #[test]
+    fn snooze() {
+        let at = |hour, minute| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, minute, 0),
+        };
+        let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+        alarm.set_uid("alarm-1@example.com").set_description("Talk");
+        let mut snoozed = alarm.snooze(at(8, 45), at(8, 50));
+        snoozed.set_uid("alarm-2@example.com");
+        let mut event = event();
+        event.add_alarm(alarm).add_alarm(snoozed);
+        let text = event.to_string();
+        assert!(text.contains(
+            "BEGIN:VALARM\r\n\
+             UID:alarm-1@example.com\r\n\
+             ACTION:DISPLAY\r\n\
+             TRIGGER:-PT15M\r\n\
+             DESCRIPTION:Talk\r\n\
+             ACKNOWLEDGED:20240705T084500Z\r\n\
+             END:VALARM\r\n"
+        ));
+        assert!(text.contains(
+            "BEGIN:VALARM\r\n\
+             UID:alarm-2@example.com\r\n\
+             ACTION:DISPLAY\r\n\
+             TRIGGER;VALUE=DATE-TIME:20240705T085000Z\r\n\
+             DESCRIPTION:Talk\r\n\
+             RELATED-TO;RELTYPE=SNOOZE:alarm-1@example.com\r\n\
+             END:VALARM\r\n"
+        ));
+        let calendar = Calendar::from_ics(Calendar::from_event(event.clone()).to_ics_string());
+        assert_eq!(calendar.unwrap().events().next(), Some(&event));
+    }
+
+    #[test]
+    fn proximity() {
+        let never = DateTime {
+            date: Date::new(1976, 4, 1),
+            time: Time::new_utc(0, 55, 45),
+        };
+        let mut alarm = Alarm::none(Trigger::absolute(never));
+        alarm
+            .set_uid("alarm-1@example.com")
+            .set_proximity(Proximity::Depart)
+            .add_location("Office", None, Some(Geo::new(47.3769, 8.5417)), None);
+        let mut event = event();
+        event.add_alarm(alarm);
+        let text = event.to_string();
+        assert!(text.contains(
+            "ACTION:NONE\r\n\
+             TRIGGER;VALUE=DATE-TIME:19760401T005545Z\r\n\
+             PROXIMITY:DEPART\r\n\
+             BEGIN:VLOCATION\r\n"
+        ));
+        assert!(!text.contains("DESCRIPTION"));
+        let calendar = Calendar::from_ics(Calendar::from_event(event.clone()).to_ics_string());
+        assert_eq!(calendar.unwrap().events().next(), Some(&event));
+    }
+
+    #[cfg(not(feature = "uuid"))]
+    #[test]
+    fn location_uids() {
+        let mut alarm = Alarm::display(Trigger::relative_to_start(Duration::default()));
+        alarm
+            .set_description("Reminder")
+            .add_location("Office", None, None, None)
+            .add_location("Home", None, None, None);
+        let mut event = event();
+        event.add_alarm(alarm.clone()).add_alarm(alarm);
+        let text = event.to_string();
+        for uid in [
+            "1@example.com-alarm-1-location",
+            "1@example.com-alarm-1-location-2",
+            "1@example.com-alarm-2-location",
+            "1@example.com-alarm-2-location-2",
+        ] {
+            assert!(text.contains(&format!("UID:{uid}\r\n")));
+        }
+        let calendar = Calendar::from_ics(Calendar::from_event(event.clone()).to_ics_string());
+        assert_eq!(calendar.unwrap().events().next(), Some(&event));
+    }
+
+    #[test]
+    fn trigger_time() {
+        let local = |day, hour| DateTime {
+            date: Date::new(2024, 7, day),
+            time: Time::new_local(hour, 0, 0),
+        };
+        let before_start = Alarm::display(Trigger::relative_to_start(-Duration::from_hours(1)));
+        let after_end = Alarm::display(Trigger::relative_to_end(Duration::from_hours(1)));
+
+        // An all-day event without an end lasts for one day.
+        let mut event = event();
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        let occurrence = event
+            .occurrences_between(Date::new(2024, 7, 7), Date::new(2024, 7, 8))
+            .next()
+            .unwrap();
+        assert_eq!(
+            before_start.trigger_time(&event, occurrence),
+            Some(local(6, 23))
+        );
+        assert_eq!(
+            after_end.trigger_time(&event, occurrence),
+            Some(local(8, 1))
+        );
+        event.set_end(Date::new(2024, 7, 7));
+        assert_eq!(
+            after_end.trigger_time(&event, occurrence),
+            Some(local(9, 1))
+        );
+
+        // An event at a date-time without an end ends at its start.
+        let event = Event::with_uid("2@example.com", local(5, 9).into(), local(1, 0));
+        assert_eq!(
+            after_end.trigger_time(&event, local(6, 9)),
+            Some(local(6, 10))
+        );
+
+        let utc = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(8, 0, 0),
+        };
+        let absolute = Alarm::display(Trigger::absolute(utc));
+        assert_eq!(absolute.trigger_time(&event, local(6, 9)), Some(utc));
+        assert_eq!(before_start.trigger_time(&event, Date::new(0, 1, 1)), None);
+    }
+
+    #[test]
+    fn try_setters_reject_control_characters() {
+        let mut alarm = Alarm::display(Trigger::relative_to_start(Duration::default()));
+        alarm
+            .set_description("It is starting")
+            .set_summary("Offsite")
+            .set_uid("alarm-1@example.com");
+        let before = alarm.clone();
+        let err = alarm
+            .try_set_description("It is\u{7} starting")
+            .unwrap_err();
+        assert_eq!(err.what(), "alarm description");
+        assert!(alarm.try_set_summary("Off\u{0}site").is_err());
+        assert!(alarm.try_set_uid("alarm-2\u{1b}@example.com").is_err());
+        assert!(
+            alarm
+                .try_add_attachment("https://example.com/\u{7}.txt")
+                .is_err()
+        );
+        assert!(
+            alarm
+                .try_add_location("Off\u{7}ice", None, None, None)
+                .is_err()
+        );
+        let err = alarm
+            .try_set_snoozed_alarm("alarm-0\u{7}@example.com")
+            .unwrap_err();
+        assert_eq!(err.what(), "snoozed alarm UID");
+        assert_eq!(alarm, before);
+    }
+}
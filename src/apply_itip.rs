@@ -0,0 +1,413 @@
+use {
+    crate::{Attendee, Calendar, DateOrDateTime, Event, Method, PartStat, shifted_end},
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+impl Calendar {
+    /// Update the events of this calendar according to the iTIP message `message`, as a
+    /// calendar application does when it receives an invitation or a reply.
+    ///
+    /// The events of the message are matched with the events of this calendar by their `UID`
+    /// and `RECURRENCE-ID`. An event of the message is stale, and therefore ignored, if the
+    /// matching event of this calendar has a higher sequence number (`SEQUENCE`), as described
+    /// in [RFC 5546 section 2.1.5 - Message
+    /// Sequencing](https://tools.ietf.org/html/rfc5546#section-2.1.5). Requests and replies are
+    /// also stale if they have the same sequence number and an earlier date-time stamp
+    /// (`DTSTAMP`).
+    ///
+    /// The supported methods are:
+    /// - [`Method::Request`]: The event is added, or replaces the matching event. If the
+    ///   sequence number did not change, the participation status (`PARTSTAT`) of the attendees
+    ///   is kept for attendees that have none in the request.
+    /// - [`Method::Cancel`]: The matching event is removed. If the whole event is cancelled,
+    ///   its modified instances are removed as well. If a single instance is cancelled, an
+    ///   exception date (`EXDATE`) is added to the recurring event.
+    /// - [`Method::Reply`]: The participation status of the replying attendees is copied to
+    ///   the matching event. A reply to a single instance of a recurring event that has no
+    ///   modified instance yet creates one, which is a copy of the recurring event that starts
+    ///   at the `RECURRENCE-ID`. Replies to instances that the recurring event does not have
+    ///   are ignored.
+    ///
+    /// Returns the number of events of the message that were applied.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Attendee, Calendar, Date, DateTime, Event, ItipReply, ItipRequest, Organizer,
+    ///     PartStat, Time,
+    /// };
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// let organizer = Organizer::new("mailto:alice@example.com");
+    /// let request =
+    ///     ItipRequest::new(event, organizer, [Attendee::new("mailto:bob@example.com")]).unwrap();
+    ///
+    /// // The organizer stores the event and receives a reply.
+    /// let mut calendar = request.calendar().clone();
+    /// calendar.clear_method();
+    /// let reply = ItipReply::from_request_at(
+    ///     request.calendar(),
+    ///     "mailto:bob@example.com",
+    ///     PartStat::Accepted,
+    ///     stamp,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(calendar.apply_itip(reply.calendar()), Ok(1));
+    /// let attendee = &calendar.events().next().unwrap().attendees()[0];
+    /// assert_eq!(attendee.participation_status(), Some(PartStat::Accepted));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message has no method or a method that is not supported. In
+    /// that case, the calendar is not changed.
+    pub fn apply_itip(&mut self, message: &Calendar) -> Result<usize, ApplyItipError> {
+        let method = message.method().ok_or(ApplyItipError::MissingMethod)?;
+        let apply = match method {
+            Method::Request => Calendar::apply_request,
+            Method::Cancel => Calendar::apply_cancel,
+            Method::Reply => Calendar::apply_reply,
+            method => return Err(ApplyItipError::UnsupportedMethod(method)),
+        };
+        Ok(message.events().filter(|event| apply(self, event)).count())
+    }
+
+    /// Add or replace the event `request`, and return `true` if it is not stale.
+    fn apply_request(&mut self, request: &Event) -> bool {
+        let Some(position) = self.position_of(request.uid(), request.recurrence_id) else {
+            self.add_component(request.clone());
+            return true;
+        };
+        let Some(current) = self.components[position].as_event() else {
+            return false;
+        };
+        if (request.sequence, request.date_time) <= (current.sequence, current.date_time) {
+            return false;
+        }
+        let mut updated = request.clone();
+        if updated.sequence == current.sequence {
+            for attendee in &mut updated.attendees {
+                if attendee.participation_status().is_none()
+                    && let Some(participation_status) = current
+                        .attendees
+                        .iter()
+                        .find(|known| known.address().eq_ignore_ascii_case(attendee.address()))
+                        .and_then(Attendee::participation_status)
+                {
+                    attendee.set_participation_status(participation_status);
+                }
+            }
+        }
+        self.components[position] = updated.into();
+        true
+    }
+
+    /// Remove the events that are cancelled by `cancel`, and return `true` if any event was
+    /// removed.
+    fn apply_cancel(&mut self, cancel: &Event) -> bool {
+        let uid = cancel.uid();
+        let is_cancelled = |event: &Event| {
+            event.uid() == uid
+                && cancel.sequence >= event.sequence
+                && (cancel.recurrence_id.is_none() || event.recurrence_id == cancel.recurrence_id)
+        };
+        let count = self.components.len();
+        self.components
+            .retain(|component| !component.as_event().is_some_and(is_cancelled));
+        let mut changed = self.components.len() != count;
+        if let Some(recurrence_id) = cancel.recurrence_id
+            && let Some(master) = self
+                .events_mut()
+                .find(|event| event.uid() == uid && event.recurrence_id.is_none())
+            && cancel.sequence >= master.sequence
+            && !master.exception_dates.contains(&recurrence_id)
+        {
+            master.add_exception_date(recurrence_id);
+            changed = true;
+        }
+        changed
+    }
+
+    /// Copy the participation status of the attendees of `reply` to the matching event, and
+    /// return `true` if the reply is not stale.
+    fn apply_reply(&mut self, reply: &Event) -> bool {
+        let mut instance = None;
+        let current = if let Some(position) = self.position_of(reply.uid(), reply.recurrence_id) {
+            let Some(current) = self.components[position].as_event_mut() else {
+                return false;
+            };
+            current
+        } else {
+            // A reply to an instance that was not modified yet.
+            instance = reply
+                .recurrence_id
+                .and_then(|recurrence_id| self.instance_of(reply.uid(), recurrence_id));
+            let Some(instance) = &mut instance else {
+                return false;
+            };
+            instance
+        };
+        if (reply.sequence, reply.date_time) < (current.sequence, current.date_time) {
+            return false;
+        }
+        for replying in &reply.attendees {
+            if let Some(attendee) = current
+                .attendees
+                .iter_mut()
+                .find(|attendee| attendee.address().eq_ignore_ascii_case(replying.address()))
+            {
+                attendee.set_participation_status(
+                    replying
+                        .participation_status()
+                        .unwrap_or(PartStat::NeedsAction),
+                );
+            }
+        }
+        if let Some(instance) = instance {
+            self.add_component(instance);
+        }
+        true
+    }
+
+    /// Create a modified instance of the recurring event with the given `UID` that starts at
+    /// `recurrence_id`, or return `None` if there is no such event or if it has no instance
+    /// at `recurrence_id`.
+    fn instance_of(&self, uid: &str, recurrence_id: DateOrDateTime) -> Option<Event> {
+        let master = self
+            .events()
+            .find(|event| event.uid() == uid && event.recurrence_id.is_none())?;
+        let next_day = DateOrDateTime::Date(recurrence_id.date().succ()?);
+        master
+            .occurrences_between(recurrence_id, next_day)
+            .next()
+            .filter(|&occurrence| occurrence == recurrence_id)?;
+        let mut instance = master.clone();
+        instance.start_date_time.value = recurrence_id;
+        instance.end = master
+            .end
+            .and_then(|end| shifted_end(master.start().value(), end, recurrence_id));
+        instance.recurrence_id = Some(recurrence_id);
+        instance.recurrence_rule = None;
+        instance.exception_rule = None;
+        instance.recurrence_dates.clear();
+        instance.exception_dates.clear();
+        Some(instance)
+    }
+
+    /// The index of the component that is the event with the given `UID` and `RECURRENCE-ID`.
+    fn position_of(&self, uid: &str, recurrence_id: Option<DateOrDateTime>) -> Option<usize> {
+        self.components.iter().position(|component| {
+            component
+                .as_event()
+                .is_some_and(|event| event.uid() == uid && event.recurrence_id == recurrence_id)
+        })
+    }
+}
+
+/// Error type for [`Calendar::apply_itip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyItipError {
+    /// The calendar has no method (`METHOD`), so it is not an iTIP message.
+    MissingMethod,
+    /// The method of the message is not supported.
+    UnsupportedMethod(Method),
+}
+
+impl Display for ApplyItipError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ApplyItipError::MissingMethod => write!(f, "The calendar is not an iTIP message"),
+            ApplyItipError::UnsupportedMethod(method) => {
+                write!(f, "Unsupported iTIP method: {method}")
+            }
+        }
+    }
+}
+
+impl Error for ApplyItipError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ApplyItipError, Attendee, Calendar, Date, DateOrDateTime, DateTime, Event, ItipCancel,
+        ItipReply, ItipRequest, Method, Organizer, PartStat, RecurrenceFrequency, RecurrenceRule,
+        Time,
+    };
+
+    fn stamp(hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    fn request(summary: &str, sequence: u32, hour: u8) -> Calendar {
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp(hour));
+        event
+            .set_summary(summary)
+            .set_sequence(sequence)
+            .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+        ItipRequest::new(
+            event,
+            Organizer::new("mailto:alice@example.com"),
+            [Attendee::new("mailto:bob@example.com")],
+        )
+        .unwrap()
+        .into_calendar()
+    }
+
+    fn summary(calendar: &Calendar) -> Option<&str> {
+        calendar.events().next().unwrap().summary()
+    }
+
+    #[test]
+    fn request_sequencing() {
+        let mut calendar = Calendar::new();
+        assert_eq!(calendar.apply_itip(&request("Lunch", 1, 9)), Ok(1));
+        calendar.events_mut().next().unwrap().attendees_mut()[0]
+            .set_participation_status(PartStat::Tentative);
+
+        // Stale requests are ignored.
+        assert_eq!(calendar.apply_itip(&request("Breakfast", 0, 10)), Ok(0));
+        assert_eq!(calendar.apply_itip(&request("Breakfast", 1, 8)), Ok(0));
+        assert_eq!(summary(&calendar), Some("Lunch"));
+
+        // The participation status is kept if the sequence number does not change.
+        assert_eq!(calendar.apply_itip(&request("Team lunch", 1, 10)), Ok(1));
+        assert_eq!(summary(&calendar), Some("Team lunch"));
+        let attendee = &calendar.events().next().unwrap().attendees()[0];
+        assert_eq!(attendee.participation_status(), Some(PartStat::Tentative));
+
+        assert_eq!(calendar.apply_itip(&request("Dinner", 2, 10)), Ok(1));
+        let attendee = &calendar.events().next().unwrap().attendees()[0];
+        assert_eq!(attendee.participation_status(), None);
+        assert_eq!(calendar.events().count(), 1);
+    }
+
+    /// The reply of Bob to `request`, optionally for a single instance.
+    fn reply(
+        request: &Calendar,
+        participation_status: PartStat,
+        hour: u8,
+        recurrence_id: Option<Date>,
+    ) -> Calendar {
+        let mut reply = ItipReply::from_request_at(
+            request,
+            "mailto:bob@example.com",
+            participation_status,
+            stamp(hour),
+        )
+        .unwrap()
+        .into_calendar();
+        if let Some(recurrence_id) = recurrence_id {
+            reply
+                .events_mut()
+                .next()
+                .unwrap()
+                .set_recurrence_id(recurrence_id);
+        }
+        reply
+    }
+
+    fn participation_status(event: &Event) -> Option<PartStat> {
+        event.attendees()[0].participation_status()
+    }
+
+    #[test]
+    fn reply_sequencing() {
+        let request = request("Lunch", 1, 9);
+        let mut calendar = request.clone();
+        calendar.clear_method();
+
+        assert_eq!(
+            calendar.apply_itip(&reply(&request, PartStat::Accepted, 10, None)),
+            Ok(1)
+        );
+        // A reply with an earlier date-time stamp than the event is stale.
+        assert_eq!(
+            calendar.apply_itip(&reply(&request, PartStat::Declined, 8, None)),
+            Ok(0)
+        );
+        let event = calendar.events().next().unwrap();
+        assert_eq!(participation_status(event), Some(PartStat::Accepted));
+
+        let mut stale = reply(&request, PartStat::Declined, 11, None);
+        stale.events_mut().next().unwrap().set_sequence(0);
+        assert_eq!(calendar.apply_itip(&stale), Ok(0));
+    }
+
+    #[test]
+    fn instance_reply() {
+        let request = request("Lunch", 1, 9);
+        let mut calendar = request.clone();
+        calendar.clear_method();
+
+        let instance = Date::new(2024, 7, 12);
+        let declined = reply(&request, PartStat::Declined, 10, Some(instance));
+        assert_eq!(calendar.apply_itip(&declined), Ok(1));
+        let events: Vec<_> = calendar.events().collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(participation_status(events[0]), None);
+        assert_eq!(events[1].recurrence_id(), Some(instance.into()));
+        assert_eq!(events[1].start().value(), DateOrDateTime::Date(instance));
+        assert_eq!(events[1].recurrence_rule, None);
+        assert_eq!(events[1].summary(), Some("Lunch"));
+        assert_eq!(participation_status(events[1]), Some(PartStat::Declined));
+
+        // The modified instance is updated by later replies.
+        let accepted = reply(&request, PartStat::Accepted, 11, Some(instance));
+        assert_eq!(calendar.apply_itip(&accepted), Ok(1));
+        assert_eq!(calendar.events().count(), 2);
+        let event = calendar.events().nth(1).unwrap();
+        assert_eq!(participation_status(event), Some(PartStat::Accepted));
+
+        // July 13 is not an instance of the weekly event.
+        let other = reply(
+            &request,
+            PartStat::Declined,
+            10,
+            Some(Date::new(2024, 7, 13)),
+        );
+        assert_eq!(calendar.apply_itip(&other), Ok(0));
+        assert_eq!(calendar.events().count(), 2);
+    }
+
+    #[test]
+    fn cancel() {
+        let mut calendar = Calendar::new();
+        calendar.apply_itip(&request("Lunch", 1, 9)).unwrap();
+        let master = calendar.events().next().unwrap().clone();
+        let mut moved = master.clone();
+        moved.set_recurrence_id(Date::new(2024, 7, 19));
+        calendar.add_component(moved);
+
+        let instance = ItipCancel::new_at(&master, Some(Date::new(2024, 7, 12).into()), stamp(10));
+        assert_eq!(calendar.apply_itip(instance.calendar()), Ok(1));
+        let master = calendar.events().next().unwrap();
+        assert_eq!(master.exception_dates, [Date::new(2024, 7, 12).into()]);
+
+        let series = ItipCancel::new_at(master, None, stamp(10));
+        assert_eq!(calendar.apply_itip(series.calendar()), Ok(1));
+        assert_eq!(calendar.events().count(), 0);
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut calendar = request("Lunch", 1, 9);
+        assert_eq!(
+            Calendar::new().apply_itip(&Calendar::new()),
+            Err(ApplyItipError::MissingMethod)
+        );
+        calendar.set_method(Method::Counter);
+        assert_eq!(
+            Calendar::new().apply_itip(&calendar),
+            Err(ApplyItipError::UnsupportedMethod(Method::Counter))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use {
+    crate::{Calendar, Event},
+    std::collections::{HashMap, HashSet},
+};
+
+/// The differences between the events of two calendars, as computed by [`Calendar::diff`].
+///
+/// Events are identified by their `UID` and `RECURRENCE-ID`, so a modified instance of a
+/// recurring event is compared with the modified instance of the other calendar, and not with
+/// the recurring event itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarDiff<'a> {
+    added: Vec<&'a Event>,
+    removed: Vec<&'a Event>,
+    modified: Vec<(&'a Event, &'a Event)>,
+}
+
+impl<'a> CalendarDiff<'a> {
+    /// Get the events of the other calendar that are not in this calendar, in the order in which
+    /// they appear in the other calendar.
+    #[must_use]
+    pub fn added(&self) -> &[&'a Event] {
+        &self.added
+    }
+
+    /// Get the events of this calendar that are not in the other calendar, in the order in which
+    /// they appear in this calendar.
+    #[must_use]
+    pub fn removed(&self) -> &[&'a Event] {
+        &self.removed
+    }
+
+    /// Get the events that are in both calendars but whose content differs, as pairs of the
+    /// event of this calendar and the event of the other calendar.
+    #[must_use]
+    pub fn modified(&self) -> &[(&'a Event, &'a Event)] {
+        &self.modified
+    }
+
+    /// Returns `true` if there are no added, removed or modified events.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl Calendar {
+    /// Compute the events that were added, removed or modified in `other` with respect to this
+    /// calendar.
+    ///
+    /// Events are identified by their `UID` and `RECURRENCE-ID`. Two events with the same
+    /// identity are considered modified if their [`Event::content_hash`] differs. If a
+    /// calendar contains several events with the same identity, only the first one is compared.
+    ///
+    /// This is useful to build incremental synchronization on top of this crate, for example to
+    /// only upload the events that changed.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = |uid: &str, summary: &str| {
+    ///     let mut event = Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+    ///     event.set_summary(summary);
+    ///     event
+    /// };
+    /// let mut old = Calendar::new();
+    /// old.add_component(event("1@example.com", "Lunch"))
+    ///     .add_component(event("2@example.com", "Dinner"));
+    /// let mut new = Calendar::new();
+    /// new.add_component(event("1@example.com", "Brunch"))
+    ///     .add_component(event("3@example.com", "Breakfast"));
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.added()[0].uid(), "3@example.com");
+    /// assert_eq!(diff.removed()[0].uid(), "2@example.com");
+    /// assert_eq!(diff.modified()[0].1.uid(), "1@example.com");
+    /// ```
+    #[must_use]
+    pub fn diff<'a>(&'a self, other: &'a Calendar) -> CalendarDiff<'a> {
+        let mut others = HashMap::new();
+        for event in other.events() {
+            others.entry(event.identity()).or_insert(event);
+        }
+        let mut diff = CalendarDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        };
+        let mut seen = HashSet::new();
+        for event in self.events() {
+            let identity = event.identity();
+            if !seen.insert(identity.clone()) {
+                continue;
+            }
+            match others.get(&identity) {
+                Some(&other) if event.content_hash() != other.content_hash() => {
+                    diff.modified.push((event, other));
+                }
+                Some(_) => {}
+                None => diff.removed.push(event),
+            }
+        }
+        for event in other.events() {
+            if seen.insert(event.identity()) {
+                diff.added.push(event);
+            }
+        }
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Calendar, Date, DateTime, Event, Time};
+
+    fn event(uid: &str, summary: &str, hour: u8) -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        let mut event = Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+        event.set_summary(summary);
+        event
+    }
+
+    fn uids(events: &[&Event]) -> Vec<String> {
+        events.iter().map(|event| event.uid().to_owned()).collect()
+    }
+
+    #[test]
+    fn diff() {
+        let mut modified = event("a", "Lunch", 8);
+        modified.set_recurrence_id(Date::new(2024, 7, 12));
+        let mut old = Calendar::new();
+        old.add_component(event("a", "Lunch", 8))
+            .add_component(event("b", "Dinner", 8))
+            .add_component(event("c", "Breakfast", 8))
+            .add_component(modified.clone());
+        let mut new = Calendar::new();
+        modified.set_summary("Brunch");
+        new.add_component(event("d", "Coffee", 9))
+            .add_component(modified)
+            .add_component(event("c", "Breakfast", 9))
+            .add_component(event("a", "Lunch", 9));
+
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert_eq!(uids(diff.added()), ["d"]);
+        assert_eq!(uids(diff.removed()), ["b"]);
+        assert_eq!(diff.modified().len(), 1);
+        assert_eq!(
+            diff.modified()[0].0.recurrence_id(),
+            Some(Date::new(2024, 7, 12).into())
+        );
+        assert!(diff.modified()[0].1.to_string().contains("SUMMARY:Brunch"));
+
+        assert!(old.diff(&old).is_empty());
+    }
+}
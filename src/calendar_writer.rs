@@ -0,0 +1,145 @@
+use {
+    crate::{Calendar, Event, write_footer},
+    std::{
+        fmt::{self, Debug, Formatter},
+        io::{self, Write},
+    },
+};
+
+/// Writes a calendar one event at a time.
+///
+/// This is useful for calendars with many events, because the events do not have to be held in
+/// memory all at once, as they would be with [`Calendar::add_component`].
+///
+/// ```
+/// use ical::{Calendar, CalendarWriter, Date, DateTime, Event, Time};
+///
+/// let mut output = Vec::new();
+/// let mut writer = CalendarWriter::new(&Calendar::new(), &mut output).unwrap();
+/// for day in 1..=28 {
+///     let start = DateTime {
+///         date: Date::new(2024, 2, day),
+///         time: Time::new_utc(9, 0, 0),
+///     };
+///     let event = Event::with_uid(format!("standup-{day}@example.com"), start.into(), start);
+///     writer.write_event(&event).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert_eq!(output.matches("BEGIN:VEVENT\r\n").count(), 28);
+/// assert!(output.ends_with("END:VCALENDAR\r\n"));
+/// ```
+///
+/// The output is incomplete unless [`CalendarWriter::finish`] is called.
+pub struct CalendarWriter<W: Write> {
+    writer: ical_vcard::Writer<W>,
+    strict: bool,
+}
+
+impl<W: Write> CalendarWriter<W> {
+    /// Create a new [`CalendarWriter`] and write the properties of `calendar` to `writer`.
+    ///
+    /// The components of `calendar`, if any, are written right away. If `calendar` is in
+    /// [strict mode](Calendar::set_strict), the events passed to
+    /// [`CalendarWriter::write_event`] are validated as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`].
+    pub fn new(calendar: &Calendar, writer: W) -> io::Result<Self> {
+        calendar.validate_before_write()?;
+        let mut writer = ical_vcard::Writer::new(writer);
+        calendar.write_header(&mut writer)?;
+        for component in &calendar.components {
+            component.write(&mut writer)?;
+        }
+        Ok(Self {
+            writer,
+            strict: calendar.strict,
+        })
+    }
+
+    /// Write an event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails. In strict mode, an error of kind
+    /// [`io::ErrorKind::InvalidData`] wrapping a [`RuleError`](crate::RuleError) is returned if
+    /// the event is invalid. Nothing is written in this case.
+    pub fn write_event(&mut self, event: &Event) -> io::Result<()> {
+        if self.strict {
+            event
+                .validate()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+        event.write(&mut self.writer)
+    }
+
+    /// Write the end of the calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    pub fn finish(mut self) -> io::Result<()> {
+        write_footer(&mut self.writer)
+    }
+}
+
+impl<W: Write> Debug for CalendarWriter<W> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CalendarWriter")
+            .field("strict", &self.strict)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::CalendarWriter,
+        crate::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time},
+        std::io::ErrorKind,
+    };
+
+    fn event(uid: &str) -> Event {
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(9, 0, 0),
+        };
+        Event::with_uid(uid, start.into(), start)
+    }
+
+    #[test]
+    fn same_as_write() {
+        let mut calendar = Calendar::new();
+        calendar.add_property("X-WR-CALNAME", "Work", &[]);
+        calendar.add_component(event("1@example.com"));
+        let mut output = Vec::new();
+        let mut writer = CalendarWriter::new(&calendar, &mut output).unwrap();
+        writer.write_event(&event("2@example.com")).unwrap();
+        writer.finish().unwrap();
+
+        calendar.add_component(event("2@example.com"));
+        assert_eq!(output, calendar.to_bytes());
+    }
+
+    #[test]
+    fn strict() {
+        let mut calendar = Calendar::new();
+        calendar.set_strict(true);
+        let mut output = Vec::new();
+        let mut writer = CalendarWriter::new(&calendar, &mut output).unwrap();
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        rule.set_until(DateTime {
+            date: Date::new(2024, 7, 10),
+            time: Time::new_local(9, 0, 0),
+        });
+        let mut event = event("1@example.com");
+        event.set_recurrence_rule(rule);
+        let err = writer.write_event(&event).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        writer.finish().unwrap();
+        assert!(!String::from_utf8(output).unwrap().contains("VEVENT"));
+    }
+}
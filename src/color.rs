@@ -0,0 +1,620 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The named colors of [CSS Color Module Level
+/// 3](https://www.w3.org/TR/css-color-3/#svg-color).
+///
+/// These are the values allowed in the `COLOR` property as specified in
+/// [RFC 7986 section 5.9](https://tools.ietf.org/html/rfc7986#section-5.9).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CssColorName {
+    /// `aliceblue`
+    AliceBlue,
+    /// `antiquewhite`
+    AntiqueWhite,
+    /// `aqua`
+    Aqua,
+    /// `aquamarine`
+    Aquamarine,
+    /// `azure`
+    Azure,
+    /// `beige`
+    Beige,
+    /// `bisque`
+    Bisque,
+    /// `black`
+    Black,
+    /// `blanchedalmond`
+    BlanchedAlmond,
+    /// `blue`
+    Blue,
+    /// `blueviolet`
+    BlueViolet,
+    /// `brown`
+    Brown,
+    /// `burlywood`
+    BurlyWood,
+    /// `cadetblue`
+    CadetBlue,
+    /// `chartreuse`
+    Chartreuse,
+    /// `chocolate`
+    Chocolate,
+    /// `coral`
+    Coral,
+    /// `cornflowerblue`
+    CornflowerBlue,
+    /// `cornsilk`
+    Cornsilk,
+    /// `crimson`
+    Crimson,
+    /// `cyan`
+    Cyan,
+    /// `darkblue`
+    DarkBlue,
+    /// `darkcyan`
+    DarkCyan,
+    /// `darkgoldenrod`
+    DarkGoldenrod,
+    /// `darkgray`
+    DarkGray,
+    /// `darkgreen`
+    DarkGreen,
+    /// `darkgrey`
+    DarkGrey,
+    /// `darkkhaki`
+    DarkKhaki,
+    /// `darkmagenta`
+    DarkMagenta,
+    /// `darkolivegreen`
+    DarkOliveGreen,
+    /// `darkorange`
+    DarkOrange,
+    /// `darkorchid`
+    DarkOrchid,
+    /// `darkred`
+    DarkRed,
+    /// `darksalmon`
+    DarkSalmon,
+    /// `darkseagreen`
+    DarkSeaGreen,
+    /// `darkslateblue`
+    DarkSlateBlue,
+    /// `darkslategray`
+    DarkSlateGray,
+    /// `darkslategrey`
+    DarkSlateGrey,
+    /// `darkturquoise`
+    DarkTurquoise,
+    /// `darkviolet`
+    DarkViolet,
+    /// `deeppink`
+    DeepPink,
+    /// `deepskyblue`
+    DeepSkyBlue,
+    /// `dimgray`
+    DimGray,
+    /// `dimgrey`
+    DimGrey,
+    /// `dodgerblue`
+    DodgerBlue,
+    /// `firebrick`
+    FireBrick,
+    /// `floralwhite`
+    FloralWhite,
+    /// `forestgreen`
+    ForestGreen,
+    /// `fuchsia`
+    Fuchsia,
+    /// `gainsboro`
+    Gainsboro,
+    /// `ghostwhite`
+    GhostWhite,
+    /// `gold`
+    Gold,
+    /// `goldenrod`
+    Goldenrod,
+    /// `gray`
+    Gray,
+    /// `green`
+    Green,
+    /// `greenyellow`
+    GreenYellow,
+    /// `grey`
+    Grey,
+    /// `honeydew`
+    Honeydew,
+    /// `hotpink`
+    HotPink,
+    /// `indianred`
+    IndianRed,
+    /// `indigo`
+    Indigo,
+    /// `ivory`
+    Ivory,
+    /// `khaki`
+    Khaki,
+    /// `lavender`
+    Lavender,
+    /// `lavenderblush`
+    LavenderBlush,
+    /// `lawngreen`
+    LawnGreen,
+    /// `lemonchiffon`
+    LemonChiffon,
+    /// `lightblue`
+    LightBlue,
+    /// `lightcoral`
+    LightCoral,
+    /// `lightcyan`
+    LightCyan,
+    /// `lightgoldenrodyellow`
+    LightGoldenrodYellow,
+    /// `lightgray`
+    LightGray,
+    /// `lightgreen`
+    LightGreen,
+    /// `lightgrey`
+    LightGrey,
+    /// `lightpink`
+    LightPink,
+    /// `lightsalmon`
+    LightSalmon,
+    /// `lightseagreen`
+    LightSeaGreen,
+    /// `lightskyblue`
+    LightSkyBlue,
+    /// `lightslategray`
+    LightSlateGray,
+    /// `lightslategrey`
+    LightSlateGrey,
+    /// `lightsteelblue`
+    LightSteelBlue,
+    /// `lightyellow`
+    LightYellow,
+    /// `lime`
+    Lime,
+    /// `limegreen`
+    LimeGreen,
+    /// `linen`
+    Linen,
+    /// `magenta`
+    Magenta,
+    /// `maroon`
+    Maroon,
+    /// `mediumaquamarine`
+    MediumAquamarine,
+    /// `mediumblue`
+    MediumBlue,
+    /// `mediumorchid`
+    MediumOrchid,
+    /// `mediumpurple`
+    MediumPurple,
+    /// `mediumseagreen`
+    MediumSeaGreen,
+    /// `mediumslateblue`
+    MediumSlateBlue,
+    /// `mediumspringgreen`
+    MediumSpringGreen,
+    /// `mediumturquoise`
+    MediumTurquoise,
+    /// `mediumvioletred`
+    MediumVioletRed,
+    /// `midnightblue`
+    MidnightBlue,
+    /// `mintcream`
+    MintCream,
+    /// `mistyrose`
+    MistyRose,
+    /// `moccasin`
+    Moccasin,
+    /// `navajowhite`
+    NavajoWhite,
+    /// `navy`
+    Navy,
+    /// `oldlace`
+    OldLace,
+    /// `olive`
+    Olive,
+    /// `olivedrab`
+    OliveDrab,
+    /// `orange`
+    Orange,
+    /// `orangered`
+    OrangeRed,
+    /// `orchid`
+    Orchid,
+    /// `palegoldenrod`
+    PaleGoldenrod,
+    /// `palegreen`
+    PaleGreen,
+    /// `paleturquoise`
+    PaleTurquoise,
+    /// `palevioletred`
+    PaleVioletRed,
+    /// `papayawhip`
+    PapayaWhip,
+    /// `peachpuff`
+    PeachPuff,
+    /// `peru`
+    Peru,
+    /// `pink`
+    Pink,
+    /// `plum`
+    Plum,
+    /// `powderblue`
+    PowderBlue,
+    /// `purple`
+    Purple,
+    /// `red`
+    Red,
+    /// `rosybrown`
+    RosyBrown,
+    /// `royalblue`
+    RoyalBlue,
+    /// `saddlebrown`
+    SaddleBrown,
+    /// `salmon`
+    Salmon,
+    /// `sandybrown`
+    SandyBrown,
+    /// `seagreen`
+    SeaGreen,
+    /// `seashell`
+    SeaShell,
+    /// `sienna`
+    Sienna,
+    /// `silver`
+    Silver,
+    /// `skyblue`
+    SkyBlue,
+    /// `slateblue`
+    SlateBlue,
+    /// `slategray`
+    SlateGray,
+    /// `slategrey`
+    SlateGrey,
+    /// `snow`
+    Snow,
+    /// `springgreen`
+    SpringGreen,
+    /// `steelblue`
+    SteelBlue,
+    /// `tan`
+    Tan,
+    /// `teal`
+    Teal,
+    /// `thistle`
+    Thistle,
+    /// `tomato`
+    Tomato,
+    /// `turquoise`
+    Turquoise,
+    /// `violet`
+    Violet,
+    /// `wheat`
+    Wheat,
+    /// `white`
+    White,
+    /// `whitesmoke`
+    WhiteSmoke,
+    /// `yellow`
+    Yellow,
+    /// `yellowgreen`
+    YellowGreen,
+}
+
+impl CssColorName {
+    /// Get the name of the color as used in CSS and in the `COLOR` property.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CssColorName::AliceBlue => "aliceblue",
+            CssColorName::AntiqueWhite => "antiquewhite",
+            CssColorName::Aqua => "aqua",
+            CssColorName::Aquamarine => "aquamarine",
+            CssColorName::Azure => "azure",
+            CssColorName::Beige => "beige",
+            CssColorName::Bisque => "bisque",
+            CssColorName::Black => "black",
+            CssColorName::BlanchedAlmond => "blanchedalmond",
+            CssColorName::Blue => "blue",
+            CssColorName::BlueViolet => "blueviolet",
+            CssColorName::Brown => "brown",
+            CssColorName::BurlyWood => "burlywood",
+            CssColorName::CadetBlue => "cadetblue",
+            CssColorName::Chartreuse => "chartreuse",
+            CssColorName::Chocolate => "chocolate",
+            CssColorName::Coral => "coral",
+            CssColorName::CornflowerBlue => "cornflowerblue",
+            CssColorName::Cornsilk => "cornsilk",
+            CssColorName::Crimson => "crimson",
+            CssColorName::Cyan => "cyan",
+            CssColorName::DarkBlue => "darkblue",
+            CssColorName::DarkCyan => "darkcyan",
+            CssColorName::DarkGoldenrod => "darkgoldenrod",
+            CssColorName::DarkGray => "darkgray",
+            CssColorName::DarkGreen => "darkgreen",
+            CssColorName::DarkGrey => "darkgrey",
+            CssColorName::DarkKhaki => "darkkhaki",
+            CssColorName::DarkMagenta => "darkmagenta",
+            CssColorName::DarkOliveGreen => "darkolivegreen",
+            CssColorName::DarkOrange => "darkorange",
+            CssColorName::DarkOrchid => "darkorchid",
+            CssColorName::DarkRed => "darkred",
+            CssColorName::DarkSalmon => "darksalmon",
+            CssColorName::DarkSeaGreen => "darkseagreen",
+            CssColorName::DarkSlateBlue => "darkslateblue",
+            CssColorName::DarkSlateGray => "darkslategray",
+            CssColorName::DarkSlateGrey => "darkslategrey",
+            CssColorName::DarkTurquoise => "darkturquoise",
+            CssColorName::DarkViolet => "darkviolet",
+            CssColorName::DeepPink => "deeppink",
+            CssColorName::DeepSkyBlue => "deepskyblue",
+            CssColorName::DimGray => "dimgray",
+            CssColorName::DimGrey => "dimgrey",
+            CssColorName::DodgerBlue => "dodgerblue",
+            CssColorName::FireBrick => "firebrick",
+            CssColorName::FloralWhite => "floralwhite",
+            CssColorName::ForestGreen => "forestgreen",
+            CssColorName::Fuchsia => "fuchsia",
+            CssColorName::Gainsboro => "gainsboro",
+            CssColorName::GhostWhite => "ghostwhite",
+            CssColorName::Gold => "gold",
+            CssColorName::Goldenrod => "goldenrod",
+            CssColorName::Gray => "gray",
+            CssColorName::Green => "green",
+            CssColorName::GreenYellow => "greenyellow",
+            CssColorName::Grey => "grey",
+            CssColorName::Honeydew => "honeydew",
+            CssColorName::HotPink => "hotpink",
+            CssColorName::IndianRed => "indianred",
+            CssColorName::Indigo => "indigo",
+            CssColorName::Ivory => "ivory",
+            CssColorName::Khaki => "khaki",
+            CssColorName::Lavender => "lavender",
+            CssColorName::LavenderBlush => "lavenderblush",
+            CssColorName::LawnGreen => "lawngreen",
+            CssColorName::LemonChiffon => "lemonchiffon",
+            CssColorName::LightBlue => "lightblue",
+            CssColorName::LightCoral => "lightcoral",
+            CssColorName::LightCyan => "lightcyan",
+            CssColorName::LightGoldenrodYellow => "lightgoldenrodyellow",
+            CssColorName::LightGray => "lightgray",
+            CssColorName::LightGreen => "lightgreen",
+            CssColorName::LightGrey => "lightgrey",
+            CssColorName::LightPink => "lightpink",
+            CssColorName::LightSalmon => "lightsalmon",
+            CssColorName::LightSeaGreen => "lightseagreen",
+            CssColorName::LightSkyBlue => "lightskyblue",
+            CssColorName::LightSlateGray => "lightslategray",
+            CssColorName::LightSlateGrey => "lightslategrey",
+            CssColorName::LightSteelBlue => "lightsteelblue",
+            CssColorName::LightYellow => "lightyellow",
+            CssColorName::Lime => "lime",
+            CssColorName::LimeGreen => "limegreen",
+            CssColorName::Linen => "linen",
+            CssColorName::Magenta => "magenta",
+            CssColorName::Maroon => "maroon",
+            CssColorName::MediumAquamarine => "mediumaquamarine",
+            CssColorName::MediumBlue => "mediumblue",
+            CssColorName::MediumOrchid => "mediumorchid",
+            CssColorName::MediumPurple => "mediumpurple",
+            CssColorName::MediumSeaGreen => "mediumseagreen",
+            CssColorName::MediumSlateBlue => "mediumslateblue",
+            CssColorName::MediumSpringGreen => "mediumspringgreen",
+            CssColorName::MediumTurquoise => "mediumturquoise",
+            CssColorName::MediumVioletRed => "mediumvioletred",
+            CssColorName::MidnightBlue => "midnightblue",
+            CssColorName::MintCream => "mintcream",
+            CssColorName::MistyRose => "mistyrose",
+            CssColorName::Moccasin => "moccasin",
+            CssColorName::NavajoWhite => "navajowhite",
+            CssColorName::Navy => "navy",
+            CssColorName::OldLace => "oldlace",
+            CssColorName::Olive => "olive",
+            CssColorName::OliveDrab => "olivedrab",
+            CssColorName::Orange => "orange",
+            CssColorName::OrangeRed => "orangered",
+            CssColorName::Orchid => "orchid",
+            CssColorName::PaleGoldenrod => "palegoldenrod",
+            CssColorName::PaleGreen => "palegreen",
+            CssColorName::PaleTurquoise => "paleturquoise",
+            CssColorName::PaleVioletRed => "palevioletred",
+            CssColorName::PapayaWhip => "papayawhip",
+            CssColorName::PeachPuff => "peachpuff",
+            CssColorName::Peru => "peru",
+            CssColorName::Pink => "pink",
+            CssColorName::Plum => "plum",
+            CssColorName::PowderBlue => "powderblue",
+            CssColorName::Purple => "purple",
+            CssColorName::Red => "red",
+            CssColorName::RosyBrown => "rosybrown",
+            CssColorName::RoyalBlue => "royalblue",
+            CssColorName::SaddleBrown => "saddlebrown",
+            CssColorName::Salmon => "salmon",
+            CssColorName::SandyBrown => "sandybrown",
+            CssColorName::SeaGreen => "seagreen",
+            CssColorName::SeaShell => "seashell",
+            CssColorName::Sienna => "sienna",
+            CssColorName::Silver => "silver",
+            CssColorName::SkyBlue => "skyblue",
+            CssColorName::SlateBlue => "slateblue",
+            CssColorName::SlateGray => "slategray",
+            CssColorName::SlateGrey => "slategrey",
+            CssColorName::Snow => "snow",
+            CssColorName::SpringGreen => "springgreen",
+            CssColorName::SteelBlue => "steelblue",
+            CssColorName::Tan => "tan",
+            CssColorName::Teal => "teal",
+            CssColorName::Thistle => "thistle",
+            CssColorName::Tomato => "tomato",
+            CssColorName::Turquoise => "turquoise",
+            CssColorName::Violet => "violet",
+            CssColorName::Wheat => "wheat",
+            CssColorName::White => "white",
+            CssColorName::WhiteSmoke => "whitesmoke",
+            CssColorName::Yellow => "yellow",
+            CssColorName::YellowGreen => "yellowgreen",
+        }
+    }
+
+    /// Get the color with the given name, ignoring case.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "aliceblue" => CssColorName::AliceBlue,
+            "antiquewhite" => CssColorName::AntiqueWhite,
+            "aqua" => CssColorName::Aqua,
+            "aquamarine" => CssColorName::Aquamarine,
+            "azure" => CssColorName::Azure,
+            "beige" => CssColorName::Beige,
+            "bisque" => CssColorName::Bisque,
+            "black" => CssColorName::Black,
+            "blanchedalmond" => CssColorName::BlanchedAlmond,
+            "blue" => CssColorName::Blue,
+            "blueviolet" => CssColorName::BlueViolet,
+            "brown" => CssColorName::Brown,
+            "burlywood" => CssColorName::BurlyWood,
+            "cadetblue" => CssColorName::CadetBlue,
+            "chartreuse" => CssColorName::Chartreuse,
+            "chocolate" => CssColorName::Chocolate,
+            "coral" => CssColorName::Coral,
+            "cornflowerblue" => CssColorName::CornflowerBlue,
+            "cornsilk" => CssColorName::Cornsilk,
+            "crimson" => CssColorName::Crimson,
+            "cyan" => CssColorName::Cyan,
+            "darkblue" => CssColorName::DarkBlue,
+            "darkcyan" => CssColorName::DarkCyan,
+            "darkgoldenrod" => CssColorName::DarkGoldenrod,
+            "darkgray" => CssColorName::DarkGray,
+            "darkgreen" => CssColorName::DarkGreen,
+            "darkgrey" => CssColorName::DarkGrey,
+            "darkkhaki" => CssColorName::DarkKhaki,
+            "darkmagenta" => CssColorName::DarkMagenta,
+            "darkolivegreen" => CssColorName::DarkOliveGreen,
+            "darkorange" => CssColorName::DarkOrange,
+            "darkorchid" => CssColorName::DarkOrchid,
+            "darkred" => CssColorName::DarkRed,
+            "darksalmon" => CssColorName::DarkSalmon,
+            "darkseagreen" => CssColorName::DarkSeaGreen,
+            "darkslateblue" => CssColorName::DarkSlateBlue,
+            "darkslategray" => CssColorName::DarkSlateGray,
+            "darkslategrey" => CssColorName::DarkSlateGrey,
+            "darkturquoise" => CssColorName::DarkTurquoise,
+            "darkviolet" => CssColorName::DarkViolet,
+            "deeppink" => CssColorName::DeepPink,
+            "deepskyblue" => CssColorName::DeepSkyBlue,
+            "dimgray" => CssColorName::DimGray,
+            "dimgrey" => CssColorName::DimGrey,
+            "dodgerblue" => CssColorName::DodgerBlue,
+            "firebrick" => CssColorName::FireBrick,
+            "floralwhite" => CssColorName::FloralWhite,
+            "forestgreen" => CssColorName::ForestGreen,
+            "fuchsia" => CssColorName::Fuchsia,
+            "gainsboro" => CssColorName::Gainsboro,
+            "ghostwhite" => CssColorName::GhostWhite,
+            "gold" => CssColorName::Gold,
+            "goldenrod" => CssColorName::Goldenrod,
+            "gray" => CssColorName::Gray,
+            "green" => CssColorName::Green,
+            "greenyellow" => CssColorName::GreenYellow,
+            "grey" => CssColorName::Grey,
+            "honeydew" => CssColorName::Honeydew,
+            "hotpink" => CssColorName::HotPink,
+            "indianred" => CssColorName::IndianRed,
+            "indigo" => CssColorName::Indigo,
+            "ivory" => CssColorName::Ivory,
+            "khaki" => CssColorName::Khaki,
+            "lavender" => CssColorName::Lavender,
+            "lavenderblush" => CssColorName::LavenderBlush,
+            "lawngreen" => CssColorName::LawnGreen,
+            "lemonchiffon" => CssColorName::LemonChiffon,
+            "lightblue" => CssColorName::LightBlue,
+            "lightcoral" => CssColorName::LightCoral,
+            "lightcyan" => CssColorName::LightCyan,
+            "lightgoldenrodyellow" => CssColorName::LightGoldenrodYellow,
+            "lightgray" => CssColorName::LightGray,
+            "lightgreen" => CssColorName::LightGreen,
+            "lightgrey" => CssColorName::LightGrey,
+            "lightpink" => CssColorName::LightPink,
+            "lightsalmon" => CssColorName::LightSalmon,
+            "lightseagreen" => CssColorName::LightSeaGreen,
+            "lightskyblue" => CssColorName::LightSkyBlue,
+            "lightslategray" => CssColorName::LightSlateGray,
+            "lightslategrey" => CssColorName::LightSlateGrey,
+            "lightsteelblue" => CssColorName::LightSteelBlue,
+            "lightyellow" => CssColorName::LightYellow,
+            "lime" => CssColorName::Lime,
+            "limegreen" => CssColorName::LimeGreen,
+            "linen" => CssColorName::Linen,
+            "magenta" => CssColorName::Magenta,
+            "maroon" => CssColorName::Maroon,
+            "mediumaquamarine" => CssColorName::MediumAquamarine,
+            "mediumblue" => CssColorName::MediumBlue,
+            "mediumorchid" => CssColorName::MediumOrchid,
+            "mediumpurple" => CssColorName::MediumPurple,
+            "mediumseagreen" => CssColorName::MediumSeaGreen,
+            "mediumslateblue" => CssColorName::MediumSlateBlue,
+            "mediumspringgreen" => CssColorName::MediumSpringGreen,
+            "mediumturquoise" => CssColorName::MediumTurquoise,
+            "mediumvioletred" => CssColorName::MediumVioletRed,
+            "midnightblue" => CssColorName::MidnightBlue,
+            "mintcream" => CssColorName::MintCream,
+            "mistyrose" => CssColorName::MistyRose,
+            "moccasin" => CssColorName::Moccasin,
+            "navajowhite" => CssColorName::NavajoWhite,
+            "navy" => CssColorName::Navy,
+            "oldlace" => CssColorName::OldLace,
+            "olive" => CssColorName::Olive,
+            "olivedrab" => CssColorName::OliveDrab,
+            "orange" => CssColorName::Orange,
+            "orangered" => CssColorName::OrangeRed,
+            "orchid" => CssColorName::Orchid,
+            "palegoldenrod" => CssColorName::PaleGoldenrod,
+            "palegreen" => CssColorName::PaleGreen,
+            "paleturquoise" => CssColorName::PaleTurquoise,
+            "palevioletred" => CssColorName::PaleVioletRed,
+            "papayawhip" => CssColorName::PapayaWhip,
+            "peachpuff" => CssColorName::PeachPuff,
+            "peru" => CssColorName::Peru,
+            "pink" => CssColorName::Pink,
+            "plum" => CssColorName::Plum,
+            "powderblue" => CssColorName::PowderBlue,
+            "purple" => CssColorName::Purple,
+            "red" => CssColorName::Red,
+            "rosybrown" => CssColorName::RosyBrown,
+            "royalblue" => CssColorName::RoyalBlue,
+            "saddlebrown" => CssColorName::SaddleBrown,
+            "salmon" => CssColorName::Salmon,
+            "sandybrown" => CssColorName::SandyBrown,
+            "seagreen" => CssColorName::SeaGreen,
+            "seashell" => CssColorName::SeaShell,
+            "sienna" => CssColorName::Sienna,
+            "silver" => CssColorName::Silver,
+            "skyblue" => CssColorName::SkyBlue,
+            "slateblue" => CssColorName::SlateBlue,
+            "slategray" => CssColorName::SlateGray,
+            "slategrey" => CssColorName::SlateGrey,
+            "snow" => CssColorName::Snow,
+            "springgreen" => CssColorName::SpringGreen,
+            "steelblue" => CssColorName::SteelBlue,
+            "tan" => CssColorName::Tan,
+            "teal" => CssColorName::Teal,
+            "thistle" => CssColorName::Thistle,
+            "tomato" => CssColorName::Tomato,
+            "turquoise" => CssColorName::Turquoise,
+            "violet" => CssColorName::Violet,
+            "wheat" => CssColorName::Wheat,
+            "white" => CssColorName::White,
+            "whitesmoke" => CssColorName::WhiteSmoke,
+            "yellow" => CssColorName::Yellow,
+            "yellowgreen" => CssColorName::YellowGreen,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for CssColorName {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,335 @@
+use {
+    crate::{Calendar, Component},
+    std::fmt::{self, Display, Formatter},
+};
+
+/// A target client profile describing which iCalendar extensions a recipient understands.
+///
+/// Used by [`Calendar::compat_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Clients that only implement [RFC 2445](https://tools.ietf.org/html/rfc2445), the
+    /// predecessor of RFC 5545. Unlike newer clients, they understand `EXRULE`.
+    Rfc2445,
+    /// Clients that only implement [RFC 5545](https://tools.ietf.org/html/rfc5545).
+    Rfc5545,
+    /// Clients that additionally implement the new properties of
+    /// [RFC 7986](https://tools.ietf.org/html/rfc7986), such as `IMAGE`, `CONFERENCE` and `COLOR`.
+    Rfc7986,
+    /// Clients that additionally implement the event publishing extensions of
+    /// [RFC 9073](https://tools.ietf.org/html/rfc9073), such as `VLOCATION`.
+    Rfc9073,
+    /// Clients that additionally implement the alarm extensions of
+    /// [RFC 9074](https://tools.ietf.org/html/rfc9074), such as `PROXIMITY` and `ACKNOWLEDGED`.
+    Rfc9074,
+}
+
+impl Profile {
+    fn supports(self, feature: CompatFeature) -> bool {
+        match feature {
+            CompatFeature::Image | CompatFeature::Conference | CompatFeature::Color => {
+                matches!(self, Profile::Rfc7986 | Profile::Rfc9073 | Profile::Rfc9074)
+            }
+            CompatFeature::StructuredLocation => {
+                matches!(self, Profile::Rfc9073 | Profile::Rfc9074)
+            }
+            CompatFeature::AlarmProximity
+            | CompatFeature::AlarmAcknowledged
+            | CompatFeature::SnoozedAlarm => self == Profile::Rfc9074,
+            // Removed in RFC 5545.
+            CompatFeature::ExceptionRule => self == Profile::Rfc2445,
+        }
+    }
+}
+
+/// A feature of the model that is not understood by every client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompatFeature {
+    /// The `IMAGE` property of calendars and events.
+    Image,
+    /// The `CONFERENCE` property of events.
+    Conference,
+    /// The `COLOR` property of events.
+    Color,
+    /// The `VLOCATION` component of events.
+    StructuredLocation,
+    /// The deprecated `EXRULE` property of events.
+    ExceptionRule,
+    /// The `PROXIMITY` property of alarms, see
+    /// [`Alarm::set_proximity`](crate::Alarm::set_proximity).
+    AlarmProximity,
+    /// The `ACKNOWLEDGED` property of alarms, see
+    /// [`Alarm::set_acknowledged`](crate::Alarm::set_acknowledged).
+    AlarmAcknowledged,
+    /// The `RELATED-TO` property of alarms that snooze another alarm, see
+    /// [`Alarm::snooze`](crate::Alarm::snooze).
+    SnoozedAlarm,
+}
+
+impl CompatFeature {
+    /// What happens to the feature in clients that do not support it.
+    fn impact(self) -> CompatImpact {
+        match self {
+            CompatFeature::Image
+            | CompatFeature::Conference
+            | CompatFeature::Color
+            | CompatFeature::ExceptionRule
+            | CompatFeature::AlarmProximity
+            | CompatFeature::AlarmAcknowledged => CompatImpact::Ignored,
+            // The plain `LOCATION` property is still understood, and a snoozed alarm is shown
+            // as an alarm of its own.
+            CompatFeature::StructuredLocation | CompatFeature::SnoozedAlarm => {
+                CompatImpact::Degraded
+            }
+        }
+    }
+}
+
+impl Display for CompatFeature {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CompatFeature::Image => write!(f, "IMAGE"),
+            CompatFeature::Conference => write!(f, "CONFERENCE"),
+            CompatFeature::Color => write!(f, "COLOR"),
+            CompatFeature::StructuredLocation => write!(f, "VLOCATION"),
+            CompatFeature::ExceptionRule => write!(f, "EXRULE"),
+            CompatFeature::AlarmProximity => write!(f, "PROXIMITY"),
+            CompatFeature::AlarmAcknowledged => write!(f, "ACKNOWLEDGED"),
+            CompatFeature::SnoozedAlarm => write!(f, "RELATED-TO;RELTYPE=SNOOZE"),
+        }
+    }
+}
+
+/// How a feature is presented by a client that does not support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompatImpact {
+    /// The feature is not shown at all.
+    Ignored,
+    /// A simplified fallback of the feature is shown.
+    Degraded,
+}
+
+/// A single entry of a [`CompatReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatEntry {
+    /// The affected feature.
+    pub feature: CompatFeature,
+    /// How the feature is presented to the recipient.
+    pub impact: CompatImpact,
+    /// How often the feature is used in the calendar.
+    pub occurrences: usize,
+}
+
+/// Lists the features of a calendar that will be ignored or degraded by a given [`Profile`].
+///
+/// Created by [`Calendar::compat_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatReport {
+    profile: Profile,
+    entries: Vec<CompatEntry>,
+}
+
+impl CompatReport {
+    /// Get the profile the report was created for.
+    #[must_use]
+    pub fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    /// Get the entries of the report.
+    ///
+    /// Every feature appears at most once.
+    #[must_use]
+    pub fn entries(&self) -> &[CompatEntry] {
+        &self.entries
+    }
+
+    /// Returns true if recipients will see the calendar exactly as modeled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn record(&mut self, feature: CompatFeature) {
+        if self.profile.supports(feature) {
+            return;
+        }
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.feature == feature)
+        {
+            Some(entry) => entry.occurrences += 1,
+            None => self.entries.push(CompatEntry {
+                feature,
+                impact: feature.impact(),
+                occurrences: 1,
+            }),
+        }
+    }
+}
+
+impl Calendar {
+    /// Create a [`CompatReport`] listing which features of this calendar will be ignored or
+    /// degraded by clients matching the given [`Profile`].
+    #[must_use]
+    pub fn compat_report(&self, profile: Profile) -> CompatReport {
+        let mut report = CompatReport {
+            profile,
+            entries: Vec::new(),
+        };
+        if self.image.is_some() {
+            report.record(CompatFeature::Image);
+        }
+        for component in &self.components {
+            match component {
+                Component::Event(event) => {
+                    if event.image.is_some() {
+                        report.record(CompatFeature::Image);
+                    }
+                    for _ in &event.conferences {
+                        report.record(CompatFeature::Conference);
+                    }
+                    if event.color.is_some() {
+                        report.record(CompatFeature::Color);
+                    }
+                    if event.structured_location.is_some() {
+                        report.record(CompatFeature::StructuredLocation);
+                    }
+                    if event.exception_rule.is_some() {
+                        report.record(CompatFeature::ExceptionRule);
+                    }
+                    for alarm in &event.alarms {
+                        if alarm.proximity().is_some() {
+                            report.record(CompatFeature::AlarmProximity);
+                        }
+                        if alarm.acknowledged().is_some() {
+                            report.record(CompatFeature::AlarmAcknowledged);
+                        }
+                        if alarm.snoozed_alarm().is_some() {
+                            report.record(CompatFeature::SnoozedAlarm);
+                        }
+                    }
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Alarm, Calendar, CompatEntry, CompatFeature, CompatImpact, Conference, CssColorName, Date,
+        DateTime, Duration, Event, Profile, Proximity, RecurrenceFrequency, RecurrenceRule, Time,
+        Trigger,
+    };
+
+    fn at(hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    fn event() -> Event {
+        Event::with_uid("1@example.com", at(9).into(), at(8))
+    }
+
+    /// The features reported for a calendar with the single event `event`.
+    fn features(event: Event, profile: Profile) -> Vec<(CompatFeature, CompatImpact, usize)> {
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+        calendar
+            .compat_report(profile)
+            .entries()
+            .iter()
+            .map(|entry| (entry.feature, entry.impact, entry.occurrences))
+            .collect()
+    }
+
+    #[test]
+    fn rfc7986() {
+        let mut event = event();
+        event.set_image("https://example.com/event.png", None);
+        event.add_conference(Conference::new("https://example.com/call"));
+        event.add_conference(Conference::new("tel:+1-555-0100"));
+        event.set_color(CssColorName::Red);
+        let mut calendar = Calendar::new();
+        calendar
+            .set_image("https://example.com/logo.png", None)
+            .add_component(event);
+        let report = calendar.compat_report(Profile::Rfc5545);
+        assert_eq!(report.profile(), Profile::Rfc5545);
+        assert_eq!(
+            report.entries(),
+            [
+                (CompatFeature::Image, 2),
+                (CompatFeature::Conference, 2),
+                (CompatFeature::Color, 1)
+            ]
+            .map(|(feature, occurrences)| CompatEntry {
+                feature,
+                impact: CompatImpact::Ignored,
+                occurrences,
+            })
+        );
+        for profile in [Profile::Rfc7986, Profile::Rfc9073, Profile::Rfc9074] {
+            assert!(calendar.compat_report(profile).is_empty());
+        }
+    }
+
+    #[test]
+    fn structured_location() {
+        let mut event = event();
+        event.set_location_structured("Zurich HB", None, None, None);
+        let degraded = [(CompatFeature::StructuredLocation, CompatImpact::Degraded, 1)];
+        assert_eq!(features(event.clone(), Profile::Rfc7986), degraded);
+        assert_eq!(features(event.clone(), Profile::Rfc9073), []);
+        assert_eq!(features(event, Profile::Rfc9074), []);
+    }
+
+    #[test]
+    fn exception_rule() {
+        let mut event = event();
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        event.set_exception_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+        assert_eq!(features(event.clone(), Profile::Rfc2445), []);
+        for profile in [Profile::Rfc5545, Profile::Rfc9074] {
+            assert_eq!(
+                features(event.clone(), profile),
+                [(CompatFeature::ExceptionRule, CompatImpact::Ignored, 1)]
+            );
+        }
+    }
+
+    #[test]
+    fn alarms() {
+        let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+        alarm.set_uid("alarm-1@example.com");
+        let snoozed = alarm.snooze(at(8), at(9));
+        let mut proximity = Alarm::display(Trigger::absolute(at(0)));
+        proximity
+            .set_proximity(Proximity::Depart)
+            .add_location("Office", None, None, None);
+        let mut event = event();
+        event
+            .add_alarm(alarm)
+            .add_alarm(snoozed)
+            .add_alarm(proximity)
+            .add_display_reminder(Duration::from_minutes(5));
+        assert_eq!(
+            features(event.clone(), Profile::Rfc9073),
+            [
+                (CompatFeature::AlarmAcknowledged, CompatImpact::Ignored, 1),
+                (CompatFeature::SnoozedAlarm, CompatImpact::Degraded, 1),
+                (CompatFeature::AlarmProximity, CompatImpact::Ignored, 1),
+            ]
+        );
+        assert_eq!(features(event, Profile::Rfc9074), []);
+        assert_eq!(
+            CompatFeature::SnoozedAlarm.to_string(),
+            "RELATED-TO;RELTYPE=SNOOZE"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use {
+    crate::{InvalidValueError, param, param_value, param_with_values, value},
+    ical_vcard::{Contentline, ParamValue, Value},
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+/// Represents a `CONFERENCE` property as specified in
+/// [RFC 7986 section 5.11](https://tools.ietf.org/html/rfc7986#section-5.11).
+///
+/// A conference describes how to join a video call, a phone conference, a chat room, etc.
+/// The conference is always referenced by URI, that is, it is written as
+/// `CONFERENCE;VALUE=URI`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conference {
+    uri: Value<String>,
+    features: Vec<ConferenceFeature>,
+    label: Option<ParamValue<String>>,
+}
+
+impl Conference {
+    /// Create a new [`Conference`] that can be accessed through the given URI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uri` is not a valid [`Value`]. Use [`Conference::try_new`] if the URI is not
+    /// known to be valid.
+    #[must_use]
+    pub fn new<S: Into<String>>(uri: S) -> Self {
+        Self::try_new(uri).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new [`Conference`], or return an error if `uri` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Conference::new`] panics.
+    pub fn try_new<S: Into<String>>(uri: S) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            uri: value("conference URI", uri.into())?,
+            features: Vec::new(),
+            label: None,
+        })
+    }
+
+    /// Get the URI of the conference.
+    #[must_use]
+    pub fn uri(&self) -> &str {
+        self.uri.as_str()
+    }
+
+    /// Add a feature of the conference.
+    ///
+    /// Adding a feature that is already present has no effect.
+    pub fn add_feature(&mut self, feature: ConferenceFeature) -> &mut Self {
+        if !self.features.contains(&feature) {
+            self.features.push(feature);
+        }
+        self
+    }
+
+    /// Get the features of the conference.
+    #[must_use]
+    pub fn features(&self) -> &[ConferenceFeature] {
+        &self.features
+    }
+
+    /// Set the label of the conference.
+    ///
+    /// The label is a short text that clients display instead of the URI, such as "Zoom" or
+    /// "Dial-in number".
+    ///
+    /// # Panics
+    ///
+    /// Panics if `label` is not a valid [`ParamValue`]. Use [`Conference::try_set_label`] if the
+    /// label is not known to be valid.
+    pub fn set_label<S: Into<String>>(&mut self, label: S) -> &mut Self {
+        self.try_set_label(label)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the label of the conference, or return an error if it is not a valid [`ParamValue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Conference::set_label`] panics.
+    pub fn try_set_label<S: Into<String>>(
+        &mut self,
+        label: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.label = Some(param_value("conference label", label.into())?);
+        Ok(self)
+    }
+
+    /// Get the label of the conference.
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(ParamValue::as_str)
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        let mut contentline = Contentline::new("CONFERENCE", self.uri.as_str());
+        contentline.params.push(param("VALUE", "URI"));
+        if !self.features.is_empty() {
+            contentline.params.push(param_with_values(
+                "FEATURE",
+                self.features.iter().map(ToString::to_string),
+            ));
+        }
+        if let Some(label) = &self.label {
+            contentline.params.push(param("LABEL", label.as_str()));
+        }
+        writer.write(&contentline)
+    }
+}
+
+/// A feature of a [`Conference`], corresponding to the values of the `FEATURE` parameter.
+///
+/// See [RFC 7986 section 6.3 - Feature](https://tools.ietf.org/html/rfc7986#section-6.3)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConferenceFeature {
+    /// Audio capability.
+    Audio,
+    /// Chat or instant messaging.
+    Chat,
+    /// Blog or Atom feed.
+    Feed,
+    /// Moderator dial-in code.
+    Moderator,
+    /// Phone conference.
+    Phone,
+    /// Screen sharing.
+    Screen,
+    /// Video capability.
+    Video,
+}
+
+impl Display for ConferenceFeature {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ConferenceFeature::Audio => write!(f, "AUDIO"),
+            ConferenceFeature::Chat => write!(f, "CHAT"),
+            ConferenceFeature::Feed => write!(f, "FEED"),
+            ConferenceFeature::Moderator => write!(f, "MODERATOR"),
+            ConferenceFeature::Phone => write!(f, "PHONE"),
+            ConferenceFeature::Screen => write!(f, "SCREEN"),
+            ConferenceFeature::Video => write!(f, "VIDEO"),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use {
+    crate::{Calendar, DateOrDateTime, Event, Transparency},
+    std::collections::HashSet,
+};
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+impl Calendar {
+    /// Find the pairs of events whose occurrences overlap, for example to reject double
+    /// bookings.
+    ///
+    /// Only the occurrences that overlap the range from `start` (inclusive) to `end`
+    /// (exclusive) are considered, including occurrences that started before `start` and are
+    /// still running, see [`Calendar::events_between`]. Each occurrence lasts as
+    /// long as its event, that is, from the start to the end (`DTEND`) of the event. An event
+    /// without an end lasts one day if it starts on a date and takes up no time otherwise, as
+    /// described in [RFC 5545 section 3.6.1 - Event
+    /// Component](https://tools.ietf.org/html/rfc5545#section-3.6.1). Occurrences overlap if one
+    /// starts before the other ends, so back-to-back events do not conflict. Time zones are not
+    /// resolved, that is, all times are compared as if they were in the same time zone.
+    ///
+    /// Every pair of conflicting events is reported once, in the order in which the conflicts
+    /// start. Within a pair, the event whose occurrence starts first comes first.
+    ///
+    /// Events that are marked as transparent (`TRANSP:TRANSPARENT`, see
+    /// [`Event::set_transparency`]) do not block time and therefore never conflict.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let at = |hour, minute| DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_utc(hour, minute, 0),
+    /// };
+    /// let meeting = |uid: &str, start: DateTime, end: DateTime| {
+    ///     let mut event = Event::with_uid(uid, start.into(), at(0, 0));
+    ///     event.set_end(end);
+    ///     event
+    /// };
+    /// let calendar = Calendar::from_events([
+    ///     meeting("a@example.com", at(9, 0), at(10, 0)),
+    ///     meeting("b@example.com", at(10, 0), at(11, 0)),
+    ///     meeting("c@example.com", at(10, 30), at(12, 0)),
+    /// ]);
+    /// let conflicts: Vec<_> = calendar
+    ///     .find_conflicts(Date::new(2024, 7, 5), Date::new(2024, 7, 6))
+    ///     .into_iter()
+    ///     .map(|(a, b)| (a.uid(), b.uid()))
+    ///     .collect();
+    /// assert_eq!(conflicts, [("b@example.com", "c@example.com")]);
+    /// ```
+    ///
+    /// Unbounded recurrence rules are only expanded up to a limit, see
+    /// [`Occurrences`](crate::Occurrences).
+    pub fn find_conflicts<S, E>(&self, start: S, end: E) -> Vec<(&Event, &Event)>
+    where
+        S: Into<DateOrDateTime>,
+        E: Into<DateOrDateTime>,
+    {
+        let start = start.into();
+        let durations: Vec<_> = self.events().map(duration).collect();
+        let longest = durations.iter().copied().max().unwrap_or(0);
+        // Occurrences that started up to `longest` seconds before `start` may still be running.
+        let lookback = (longest + SECONDS_PER_DAY - 1) / SECONDS_PER_DAY;
+        let earliest = match start {
+            DateOrDateTime::Date(date) => date.checked_add_days(-lookback).map(Into::into),
+            DateOrDateTime::DateTime(mut date_time) => {
+                date_time.date.checked_add_days(-lookback).map(|date| {
+                    date_time.date = date;
+                    date_time.into()
+                })
+            }
+        };
+        let range_start = seconds(start);
+        let mut occurrences = self.events_between(earliest.unwrap_or(start), end);
+        // The occurrences that have started but not ended, as `(end, index, event)`.
+        let mut ongoing: Vec<(i64, usize, &Event)> = Vec::new();
+        let mut reported = HashSet::new();
+        let mut conflicts = Vec::new();
+        while let Some((index, event, occurrence)) = occurrences.next_indexed() {
+            let start = seconds(occurrence);
+            let end = start + durations[index];
+            ongoing.retain(|&(ongoing_end, _, _)| ongoing_end > start);
+            if end == start || end <= range_start {
+                continue;
+            }
+            for &(_, other_index, other) in &ongoing {
+                if other_index != index
+                    && reported.insert((other_index.min(index), other_index.max(index)))
+                {
+                    conflicts.push((other, event));
+                }
+            }
+            ongoing.push((end, index, event));
+        }
+        conflicts
+    }
+}
+
+/// The number of seconds from the start to the end of `event`, which is 0 if the end is before
+/// the start or if the event is transparent.
+fn duration(event: &Event) -> i64 {
+    if event.transparency() == Some(Transparency::Transparent) {
+        return 0;
+    }
+    let start = event.start().value();
+    match event.end() {
+        Some(end) => (seconds(end) - seconds(start)).max(0),
+        None if start.is_date() => SECONDS_PER_DAY,
+        None => 0,
+    }
+}
+
+/// The number of seconds since 1970-01-01 at midnight, treating a date as the start of that day.
+fn seconds(value: DateOrDateTime) -> i64 {
+    match value {
+        DateOrDateTime::Date(date) => i64::from(date.days_since_epoch()) * SECONDS_PER_DAY,
+        DateOrDateTime::DateTime(date_time) => date_time.seconds_since_epoch(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time, Transparency,
+    };
+
+    fn at(day: u8, hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, day),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    fn uids(calendar: &Calendar) -> Vec<(&str, &str)> {
+        calendar
+            .find_conflicts(Date::new(2024, 7, 1), Date::new(2024, 8, 1))
+            .into_iter()
+            .map(|(a, b)| (a.uid(), b.uid()))
+            .collect()
+    }
+
+    #[test]
+    fn conflicts() {
+        let mut daily = Event::with_uid("daily", at(1, 9).into(), at(1, 0));
+        daily
+            .set_end(at(1, 10))
+            .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        let mut meeting = Event::with_uid("meeting", at(3, 8).into(), at(1, 0));
+        meeting.set_end(at(3, 12));
+        let holiday = Event::with_uid("holiday", Date::new(2024, 7, 3).into(), at(1, 0));
+        let reminder = Event::with_uid("reminder", at(4, 9).into(), at(1, 0));
+        let calendar = Calendar::from_events([daily, meeting, holiday, reminder]);
+        assert_eq!(
+            uids(&calendar),
+            [
+                ("holiday", "meeting"),
+                ("holiday", "daily"),
+                ("meeting", "daily"),
+            ]
+        );
+    }
+
+    #[test]
+    fn modified_instance() {
+        let mut daily = Event::with_uid("daily", at(1, 9).into(), at(1, 0));
+        daily
+            .set_end(at(1, 10))
+            .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        // The occurrence that conflicts with the meeting is moved away, so there is no conflict.
+        let mut moved = Event::with_uid("daily", at(3, 14).into(), at(1, 0));
+        moved.set_end(at(3, 15)).set_recurrence_id(at(3, 9));
+        let mut meeting = Event::with_uid("meeting", at(3, 9).into(), at(1, 0));
+        meeting.set_end(at(3, 10));
+        let calendar = Calendar::from_events([daily, moved, meeting]);
+        assert_eq!(uids(&calendar), []);
+    }
+
+    #[test]
+    fn started_before_range() {
+        let mut conference = Event::with_uid("conference", Date::new(2024, 6, 28).into(), at(1, 0));
+        conference.set_end(Date::new(2024, 7, 3));
+        let mut meeting = Event::with_uid("meeting", at(2, 9).into(), at(1, 0));
+        meeting.set_end(at(2, 10));
+        let june = |hour| DateTime {
+            date: Date::new(2024, 6, 28),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        // Overlaps with the conference only before the range starts, so it is not reported.
+        let mut travel = Event::with_uid("travel", june(9).into(), at(1, 0));
+        travel.set_end(june(10));
+        let calendar = Calendar::from_events([conference, meeting, travel]);
+        assert_eq!(uids(&calendar), [("conference", "meeting")]);
+    }
+
+    #[test]
+    fn transparent() {
+        let mut holiday = Event::with_uid("holiday", Date::new(2024, 7, 3).into(), at(1, 0));
+        holiday.set_transparency(Transparency::Transparent);
+        let mut meeting = Event::with_uid("meeting", at(3, 9).into(), at(1, 0));
+        meeting.set_end(at(3, 10));
+        let calendar = Calendar::from_events([holiday.clone(), meeting.clone()]);
+        assert_eq!(uids(&calendar), []);
+
+        holiday.set_transparency(Transparency::Opaque);
+        let calendar = Calendar::from_events([holiday, meeting]);
+        assert_eq!(uids(&calendar), [("holiday", "meeting")]);
+    }
+}
@@ -1,8 +1,24 @@
 mod date;
-pub use date::{Date, ParseDateError};
+pub use date::{Date, Days, InvalidDateError, ParseDateError};
+pub(crate) use date::{days_in_month, days_in_year};
 
 mod time;
-pub use time::{ParseTimeError, Time};
+pub use time::{InvalidTimeError, ParseTimeError, Time};
 
 mod date_time;
 pub use date_time::{DateTime, ParseDateTimeError};
+
+mod duration;
+pub use duration::{Duration, ParseDurationError};
+
+mod date_or_date_time;
+pub use date_or_date_time::DateOrDateTime;
+
+mod zoned_date_time;
+pub use zoned_date_time::ZonedDateTime;
+
+mod utc_offset;
+pub use utc_offset::{ParseUtcOffsetError, UtcOffset};
+
+mod weekday;
+pub use weekday::Weekday;
@@ -1,7 +1,11 @@
-use std::{
-    error::Error,
-    fmt::{self, Display, Formatter},
-    str::FromStr,
+use {
+    crate::Weekday,
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+        iter::FusedIterator,
+        str::FromStr,
+    },
 };
 
 /// Represents a date as specified in
@@ -14,8 +18,8 @@ pub struct Date {
 }
 
 /// Returns true if the given year is a leap year.
-fn is_leap_year(year: u16) -> bool {
-    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+pub(crate) fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
 }
 
 /// Returns the number of days in the given month of the given year.
@@ -23,7 +27,7 @@ fn is_leap_year(year: u16) -> bool {
 /// # Panics
 ///
 /// If month is not in the range 1-12.
-fn days_in_month(year: u16, month: u8) -> u8 {
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
     match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
@@ -38,6 +42,11 @@ fn days_in_month(year: u16, month: u8) -> u8 {
     }
 }
 
+/// Returns the number of days in the given year.
+pub(crate) fn days_in_year(year: u16) -> u16 {
+    if is_leap_year(year) { 366 } else { 365 }
+}
+
 impl Date {
     /// Create a new `Date`.
     ///
@@ -47,16 +56,43 @@ impl Date {
     /// - `year` is greater than 9999.
     /// - `month` is not in the range 1-12.
     /// - `day` is not in the valid range for the given month and year.
+    ///
+    /// Use [`Date::try_new`] if the values are not known to be valid, for example because they
+    /// come from user input.
     #[must_use]
     pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self::try_new(year, month, day).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `Date`, or return an error if the values do not form a valid date.
+    ///
+    /// ```
+    /// use ical::{Date, InvalidDateError};
+    ///
+    /// assert_eq!(Date::try_new(2024, 2, 29), Ok(Date::new(2024, 2, 29)));
+    /// assert_eq!(
+    ///     Date::try_new(2023, 2, 29),
+    ///     Err(InvalidDateError::Day {
+    ///         year: 2023,
+    ///         month: 2,
+    ///         day: 29
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Date::new`] panics.
+    pub fn try_new(year: u16, month: u8, day: u8) -> Result<Self, InvalidDateError> {
         if year > 9999 {
-            panic!("Year must be less than 10000");
+            Err(InvalidDateError::Year(year))
         } else if !(1..=12).contains(&month) {
-            panic!("Month must be between 1 and 12");
+            Err(InvalidDateError::Month(month))
         } else if day < 1 || day > days_in_month(year, month) {
-            panic!("Day ({day}) out of range for month ({month})");
+            Err(InvalidDateError::Day { year, month, day })
+        } else {
+            Ok(Self { year, month, day })
         }
-        Self { year, month, day }
     }
 
     /// Get the year of the date.
@@ -112,30 +148,370 @@ impl Date {
     }
 }
 
+impl Date {
+    /// The current date in UTC time, according to the system clock.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set to a time outside of the years 0-9999.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn today_utc() -> Self {
+        crate::DateTime::now_utc().date
+    }
+}
+
+/// Date arithmetic.
+///
+/// All operations return `None` if the result is not in the range of years 0-9999 supported by
+/// [`Date`].
+impl Date {
+    /// Add `days` days to the date. A negative number of days subtracts days.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// assert_eq!(
+    ///     Date::new(2024, 2, 28).checked_add_days(2),
+    ///     Some(Date::new(2024, 3, 1))
+    /// );
+    /// assert_eq!(
+    ///     Date::new(2024, 1, 1).checked_add_days(-1),
+    ///     Some(Date::new(2023, 12, 31))
+    /// );
+    /// assert_eq!(Date::new(9999, 12, 31).checked_add_days(1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let days = i64::from(self.days_since_epoch()).checked_add(days)?;
+        Self::from_days_since_epoch(i32::try_from(days).ok()?)
+    }
+
+    /// Add `months` months to the date. A negative number of months subtracts months.
+    ///
+    /// If the day does not exist in the resulting month, it is clamped to the last day of that
+    /// month. For example, one month after January 31 is the last day of February.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// assert_eq!(
+    ///     Date::new(2024, 1, 31).checked_add_months(1),
+    ///     Some(Date::new(2024, 2, 29))
+    /// );
+    /// assert_eq!(
+    ///     Date::new(2024, 3, 15).checked_add_months(-14),
+    ///     Some(Date::new(2023, 1, 15))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        let month_index =
+            (i32::from(self.year) * 12 + i32::from(self.month) - 1).checked_add(months)?;
+        let year = u16::try_from(month_index.div_euclid(12)).ok()?;
+        let month = u8::try_from(month_index.rem_euclid(12) + 1).ok()?;
+        Self::try_new(year, month, 1).ok()?;
+        let day = self.day.min(days_in_month(year, month));
+        Some(Self { year, month, day })
+    }
+
+    /// Add `years` years to the date. A negative number of years subtracts years.
+    ///
+    /// February 29 is clamped to February 28 if the resulting year is not a leap year.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// assert_eq!(
+    ///     Date::new(2024, 2, 29).checked_add_years(1),
+    ///     Some(Date::new(2025, 2, 28))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn checked_add_years(self, years: i32) -> Option<Self> {
+        self.checked_add_months(years.checked_mul(12)?)
+    }
+
+    /// The day after this date.
+    #[must_use]
+    pub fn succ(self) -> Option<Self> {
+        self.checked_add_days(1)
+    }
+
+    /// The day before this date.
+    #[must_use]
+    pub fn pred(self) -> Option<Self> {
+        self.checked_add_days(-1)
+    }
+
+    /// An iterator over the consecutive dates from this date up to and including `until`.
+    ///
+    /// The iterator is empty if `until` is before this date.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// let days: Vec<_> = Date::new(2024, 2, 28)
+    ///     .iter_days(Date::new(2024, 3, 1))
+    ///     .collect();
+    /// assert_eq!(
+    ///     days,
+    ///     [
+    ///         Date::new(2024, 2, 28),
+    ///         Date::new(2024, 2, 29),
+    ///         Date::new(2024, 3, 1)
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn iter_days(self, until: Self) -> Days {
+        Days {
+            next: self.days_since_epoch(),
+            last: until.days_since_epoch(),
+        }
+    }
+
+    /// The number of days from this date to `other`.
+    ///
+    /// The result is negative if `other` is before this date.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// let new_year = Date::new(2024, 1, 1);
+    /// assert_eq!(new_year.days_between(Date::new(2025, 1, 1)), 366);
+    /// assert_eq!(new_year.days_between(Date::new(2023, 12, 25)), -7);
+    /// ```
+    #[must_use]
+    pub fn days_between(self, other: Self) -> i32 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+}
+
+/// Calendar information.
+impl Date {
+    /// The day of the week of the date.
+    ///
+    /// ```
+    /// use ical::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::new(2024, 7, 5).weekday(), Weekday::Friday);
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::from_days_from_monday(
+            u8::try_from((self.days_since_epoch() + 3).rem_euclid(7))
+                .expect("remainder is in the range 0-6"),
+        )
+    }
+
+    /// The day of the year of the date, starting at 1 for January 1st.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// assert_eq!(Date::new(2024, 12, 31).day_of_year(), 366);
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn day_of_year(self) -> u16 {
+        u16::try_from(self.days_since_epoch() - self.first_of_year().days_since_epoch() + 1)
+            .expect("day of year is in the range 1-366")
+    }
+
+    /// The ISO 8601 week date of the date, that is, the week-numbering year and the week number.
+    ///
+    /// ISO weeks start on Monday, and week 1 is the week containing the first Thursday of the
+    /// year. Therefore, the first days of January may belong to the last week of the previous
+    /// year and the last days of December may belong to week 1 of the next year.
+    ///
+    /// The year is an `i32` because the first days of the year 0 belong to the year -1.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// assert_eq!(Date::new(2024, 7, 5).iso_week(), (2024, 27));
+    /// assert_eq!(Date::new(2021, 1, 3).iso_week(), (2020, 53));
+    /// assert_eq!(Date::new(2024, 12, 30).iso_week(), (2025, 1));
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn iso_week(self) -> (i32, u8) {
+        let year = i32::from(self.year);
+        let first = self.first_of_year().days_since_epoch();
+        // The week of a date is the week of the Thursday of the same week.
+        let thursday = self.days_since_epoch() + 3 - i32::from(self.weekday().days_from_monday());
+        let week = |first_of_year: i32| {
+            u8::try_from((thursday - first_of_year) / 7 + 1).expect("week is in the range 1-53")
+        };
+        if thursday < first {
+            let previous_year_length = match self.year.checked_sub(1) {
+                Some(previous) => days_in_year(previous),
+                // The year -1 is not a leap year.
+                None => 365,
+            };
+            (year - 1, week(first - i32::from(previous_year_length)))
+        } else if thursday >= first + i32::from(days_in_year(self.year)) {
+            (year + 1, 1)
+        } else {
+            (year, week(first))
+        }
+    }
+
+    /// January 1st of the year of the date.
+    fn first_of_year(self) -> Self {
+        Self {
+            year: self.year,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+// Internal calendar computations.
+//
+// The conversions between dates and day numbers use the algorithms described in
+// <https://howardhinnant.github.io/date_algorithms.html>.
+impl Date {
+    /// The number of days since 1970-01-01.
+    pub(crate) fn days_since_epoch(self) -> i32 {
+        let month = i32::from(self.month);
+        let year = i32::from(self.year) - i32::from(month <= 2);
+        // `year` is at least -1, so the era is either -1 or non-negative.
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i32::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date `days` days after 1970-01-01.
+    ///
+    /// Returns `None` if the resulting year is not in the range 0-9999.
+    pub(crate) fn from_days_since_epoch(days: i32) -> Option<Self> {
+        let days = days.checked_add(719_468)?;
+        let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i32::from(month <= 2);
+        Some(Self {
+            year: u16::try_from(year).ok().filter(|&year| year <= 9999)?,
+            month: u8::try_from(month).expect("month is in the range 1-12"),
+            day: u8::try_from(day).expect("day is in the range 1-31"),
+        })
+    }
+}
+
+/// An iterator over consecutive dates.
+///
+/// Created by [`Date::iter_days`].
+#[derive(Debug, Clone)]
+pub struct Days {
+    next: i32,
+    last: i32,
+}
+
+impl Iterator for Days {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        if self.next > self.last {
+            return None;
+        }
+        let date = Date::from_days_since_epoch(self.next);
+        self.next += 1;
+        date
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Days {
+    fn next_back(&mut self) -> Option<Date> {
+        if self.next > self.last {
+            return None;
+        }
+        let date = Date::from_days_since_epoch(self.last);
+        self.last -= 1;
+        date
+    }
+}
+
+impl ExactSizeIterator for Days {
+    fn len(&self) -> usize {
+        usize::try_from(self.last - self.next + 1).unwrap_or(0)
+    }
+}
+
+impl FusedIterator for Days {}
+
+/// ISO 8601 formatting.
+impl Date {
+    /// Parse a date in the ISO 8601 extended format `YYYY-MM-DD`, as used by RFC 3339 and HTML
+    /// date inputs.
+    ///
+    /// ```
+    /// use ical::Date;
+    ///
+    /// let date = Date::parse_iso8601("2024-07-05").unwrap();
+    /// assert_eq!(date, Date::new(2024, 7, 5));
+    /// assert_eq!(date.to_iso8601(), "2024-07-05");
+    /// assert!(Date::parse_iso8601("20240705").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in the format `YYYY-MM-DD` or is not a valid date.
+    pub fn parse_iso8601(s: &str) -> Result<Self, ParseDateError> {
+        if s.len() != 10 {
+            return Err(ParseDateError::Length(s.len()));
+        }
+        let bytes = s.as_bytes();
+        if bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(ParseDateError::Separator);
+        }
+        [&s[0..4], &s[5..7], &s[8..10]].concat().parse()
+    }
+
+    /// Format the date in the ISO 8601 extended format `YYYY-MM-DD`.
+    #[must_use]
+    pub fn to_iso8601(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
 impl FromStr for Date {
     type Err = ParseDateError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() != 8 {
-            return Err(ParseDateError {});
-        }
-        let bytes = s.as_bytes();
-        if !bytes.iter().all(|&b| b.is_ascii_digit()) {
-            return Err(ParseDateError {});
-        }
-        let year = s[0..4].parse().map_err(|_| ParseDateError {})?;
-        if year > 9999 {
-            return Err(ParseDateError {});
+            return Err(ParseDateError::Length(s.len()));
         }
-        let month = s[4..6].parse().map_err(|_| ParseDateError {})?;
-        if !(1..=12).contains(&month) {
-            return Err(ParseDateError {});
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseDateError::NotADigit);
         }
-        let day = s[6..8].parse().map_err(|_| ParseDateError {})?;
-        if day < 1 || day > days_in_month(year, month) {
-            return Err(ParseDateError {});
-        }
-        Ok(Self { year, month, day })
+        // All characters are ASCII digits, so the parts are valid numbers.
+        let year = s[0..4].parse().expect("4 digits fit in a u16");
+        let month = s[4..6].parse().expect("2 digits fit in a u8");
+        let day = s[6..8].parse().expect("2 digits fit in a u8");
+        Self::try_new(year, month, day).map_err(ParseDateError::Invalid)
     }
 }
 
@@ -146,20 +522,102 @@ impl Display for Date {
 }
 
 /// Error type for parsing a [`Date`].
-#[derive(Debug, Clone)]
-pub struct ParseDateError {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDateError {
+    /// The text does not have the length of the expected format, that is, 8 characters
+    /// (`YYYYMMDD`) or 10 characters for [`Date::parse_iso8601`] (`YYYY-MM-DD`). Contains the
+    /// actual length in bytes.
+    Length(usize),
+    /// The text does not contain the `-` separators expected by [`Date::parse_iso8601`].
+    Separator,
+    /// The text contains a character that is not an ASCII digit.
+    NotADigit,
+    /// The text has the right format, but does not describe a valid date, such as `20230229`.
+    Invalid(InvalidDateError),
+}
 
 impl Display for ParseDateError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Invalid date")
+        match self {
+            ParseDateError::Length(length) => {
+                write!(f, "Invalid date: unexpected length ({length} characters)")
+            }
+            ParseDateError::Separator => {
+                write!(f, "Invalid date: expected '-' between year, month and day")
+            }
+            ParseDateError::NotADigit => write!(f, "Invalid date: expected only digits"),
+            ParseDateError::Invalid(err) => write!(f, "Invalid date: {err}"),
+        }
+    }
+}
+
+impl Error for ParseDateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDateError::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Error type for creating a [`Date`] from values that do not form a valid date.
+///
+/// Returned by [`Date::try_new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDateError {
+    /// The year is greater than 9999.
+    Year(u16),
+    /// The month is not in the range 1-12.
+    Month(u8),
+    /// The day does not exist in the month.
+    Day {
+        /// The year.
+        year: u16,
+        /// The month.
+        month: u8,
+        /// The invalid day.
+        day: u8,
+    },
+}
+
+impl Display for InvalidDateError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            InvalidDateError::Year(_) => write!(f, "Year must be less than 10000"),
+            InvalidDateError::Month(_) => write!(f, "Month must be between 1 and 12"),
+            InvalidDateError::Day { month, day, .. } => {
+                write!(f, "Day ({day}) out of range for month ({month})")
+            }
+        }
     }
 }
 
-impl Error for ParseDateError {}
+impl Error for InvalidDateError {}
 
 #[cfg(test)]
 mod tests {
-    use super::Date;
+    use {
+        super::{Date, InvalidDateError, ParseDateError},
+        crate::Weekday,
+    };
+
+    #[test]
+    fn try_new() {
+        assert_eq!(Date::try_new(9999, 12, 31), Ok(Date::new(9999, 12, 31)));
+        assert_eq!(
+            Date::try_new(10000, 1, 1),
+            Err(InvalidDateError::Year(10000))
+        );
+        assert_eq!(Date::try_new(2024, 13, 1), Err(InvalidDateError::Month(13)));
+        assert_eq!(
+            Date::try_new(2024, 4, 31),
+            Err(InvalidDateError::Day {
+                year: 2024,
+                month: 4,
+                day: 31
+            })
+        );
+    }
 
     #[test]
     fn parse() {
@@ -172,12 +630,123 @@ mod tests {
         assert!("2021-01-01".parse::<Date>().is_err());
     }
 
+    #[test]
+    fn arithmetic() {
+        let date = Date::new(2024, 12, 31);
+        assert_eq!(date.succ(), Some(Date::new(2025, 1, 1)));
+        assert_eq!(date.pred(), Some(Date::new(2024, 12, 30)));
+        assert_eq!(date.checked_add_days(-366), Some(Date::new(2023, 12, 31)));
+        assert_eq!(date.checked_add_days(i64::MAX), None);
+        assert_eq!(Date::new(0, 1, 1).pred(), None);
+
+        assert_eq!(date.checked_add_months(2), Some(Date::new(2025, 2, 28)));
+        assert_eq!(date.checked_add_months(-10), Some(Date::new(2024, 2, 29)));
+        assert_eq!(date.checked_add_months(-12 * 2025), None);
+        assert_eq!(date.checked_add_months(12 * 7976), None);
+        assert_eq!(date.checked_add_years(-2024), Some(Date::new(0, 12, 31)));
+        assert_eq!(date.checked_add_years(i32::MAX), None);
+
+        assert_eq!(date.days_between(date), 0);
+        assert_eq!(
+            Date::new(0, 1, 1).days_between(Date::new(9999, 12, 31)),
+            3_652_424
+        );
+    }
+
+    #[test]
+    fn iter_days() {
+        let start = Date::new(2024, 12, 30);
+        let mut days = start.iter_days(Date::new(2025, 1, 2));
+        assert_eq!(days.len(), 4);
+        assert_eq!(days.next(), Some(start));
+        assert_eq!(days.next_back(), Some(Date::new(2025, 1, 2)));
+        assert_eq!(days.next_back(), Some(Date::new(2025, 1, 1)));
+        assert_eq!(days.next(), Some(Date::new(2024, 12, 31)));
+        assert_eq!(days.len(), 0);
+        assert_eq!(days.next(), None);
+        assert_eq!(days.next_back(), None);
+
+        assert_eq!(start.iter_days(start).count(), 1);
+        assert_eq!(start.iter_days(Date::new(2024, 12, 1)).count(), 0);
+        assert_eq!(
+            Date::new(0, 1, 1)
+                .iter_days(Date::new(9999, 12, 31))
+                .next_back(),
+            Some(Date::new(9999, 12, 31))
+        );
+    }
+
+    #[test]
+    fn iso_week() {
+        assert_eq!(Date::new(2020, 12, 31).iso_week(), (2020, 53));
+        assert_eq!(Date::new(2021, 1, 1).iso_week(), (2020, 53));
+        assert_eq!(Date::new(2021, 1, 4).iso_week(), (2021, 1));
+        assert_eq!(Date::new(2026, 1, 1).iso_week(), (2026, 1));
+        assert_eq!(Date::new(2027, 1, 1).iso_week(), (2026, 53));
+        // 0000-01-01 was a Saturday.
+        assert_eq!(Date::new(0, 1, 1).iso_week(), (-1, 52));
+        assert_eq!(Date::new(0, 1, 3).iso_week(), (0, 1));
+        // 9999-12-31 was a Friday.
+        assert_eq!(Date::new(9999, 12, 31).iso_week(), (9999, 52));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "2021-01-01".parse::<Date>(),
+            Err(ParseDateError::Length(10))
+        );
+        assert_eq!("2021O101".parse::<Date>(), Err(ParseDateError::NotADigit));
+        assert_eq!(
+            "20211301".parse::<Date>(),
+            Err(ParseDateError::Invalid(InvalidDateError::Month(13)))
+        );
+        let err = "20230230".parse::<Date>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseDateError::Invalid(InvalidDateError::Day {
+                year: 2023,
+                month: 2,
+                day: 30
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid date: Day (30) out of range for month (2)"
+        );
+    }
+
     #[test]
     fn display() {
         assert_eq!(format!("{}", Date::new(2021, 1, 1)), "20210101");
         assert_eq!(format!("{}", Date::new(0, 7, 7)), "00000707");
     }
 
+    #[test]
+    fn days_since_epoch() {
+        for (date, days) in [
+            (Date::new(1970, 1, 1), 0),
+            (Date::new(1969, 12, 31), -1),
+            (Date::new(2000, 3, 1), 11_017),
+            (Date::new(0, 1, 1), -719_528),
+            (Date::new(9999, 12, 31), 2_932_896),
+        ] {
+            assert_eq!(date.days_since_epoch(), days);
+            assert_eq!(Date::from_days_since_epoch(days), Some(date));
+        }
+        assert_eq!(Date::from_days_since_epoch(-719_529), None);
+        assert_eq!(Date::from_days_since_epoch(2_932_897), None);
+    }
+
+    #[test]
+    fn weekday_and_day_of_year() {
+        assert_eq!(Date::new(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(Date::new(2024, 7, 5).weekday(), Weekday::Friday);
+        assert_eq!(Date::new(1969, 12, 29).weekday(), Weekday::Monday);
+        assert_eq!(Date::new(2024, 12, 31).day_of_year(), 366);
+        assert_eq!(Date::new(2025, 3, 1).day_of_year(), 60);
+    }
+
     #[test]
     fn compare() {
         assert_eq!(Date::new(2021, 1, 1), Date::new(2021, 1, 1));
@@ -186,37 +755,37 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Year must be less than 10000")]
     fn invalid_year() {
         let _ = Date::new(10000, 2, 30);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Month must be between 1 and 12")]
     fn invalid_month_1() {
         let _ = Date::new(2021, 13, 1);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Month must be between 1 and 12")]
     fn invalid_month_2() {
         let _ = Date::new(2021, 0, 1);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Day (32) out of range for month (1)")]
     fn invalid_day_1() {
         let _ = Date::new(2021, 1, 32);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Day (29) out of range for month (2)")]
     fn invalid_day_2() {
         let _ = Date::new(2021, 2, 29);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Day (0) out of range for month (4)")]
     fn invalid_day_3() {
         let _ = Date::new(2021, 4, 0);
     }
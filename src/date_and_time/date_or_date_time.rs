@@ -0,0 +1,156 @@
+use {
+    crate::{Date, DateTime},
+    std::{
+        cmp::Ordering,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+/// Either a [`Date`] or a [`DateTime`].
+///
+/// Many properties, such as `DTSTART`, `DTEND`, `EXDATE`, `RDATE` and `RECURRENCE-ID`, as well as
+/// the `UNTIL` part of recurrence rules, may have either a `DATE` or a `DATE-TIME` value.
+///
+/// ```
+/// use ical::{Date, DateOrDateTime, DateTime, Time};
+///
+/// let all_day = DateOrDateTime::from(Date::new(2024, 7, 5));
+/// assert!(all_day.is_date());
+/// assert_eq!(all_day.date_time(), None);
+///
+/// let date_time = DateTime {
+///     date: Date::new(2024, 7, 5),
+///     time: Time::new_utc(9, 0, 0),
+/// };
+/// let timed = DateOrDateTime::from(date_time);
+/// assert_eq!(timed.date(), Date::new(2024, 7, 5));
+/// assert_eq!(timed.date_time(), Some(date_time));
+/// ```
+///
+/// Values are ordered chronologically, treating a date as the start of that day. A date is
+/// ordered before a date-time at midnight of the same day. UTC and local times are compared by
+/// their hour, minute and second only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateOrDateTime {
+    /// A date without a time.
+    Date(Date),
+    /// A date with a time.
+    DateTime(DateTime),
+}
+
+impl DateOrDateTime {
+    /// The key by which values are ordered chronologically.
+    ///
+    /// A date is treated as the start of that day. UTC and local times are not distinguished.
+    pub(crate) fn chronological_key(self) -> (Date, u32) {
+        match self {
+            DateOrDateTime::Date(date) => (date, 0),
+            DateOrDateTime::DateTime(date_time) => {
+                (date_time.date, date_time.time.seconds_of_day())
+            }
+        }
+    }
+
+    /// Returns `true` if this is a date without a time.
+    #[must_use]
+    pub fn is_date(self) -> bool {
+        matches!(self, DateOrDateTime::Date(_))
+    }
+
+    /// Get the date, that is, the value itself for a date and the date component of a
+    /// date-time.
+    #[must_use]
+    pub fn date(self) -> Date {
+        match self {
+            DateOrDateTime::Date(date) => date,
+            DateOrDateTime::DateTime(date_time) => date_time.date,
+        }
+    }
+
+    /// Get the date-time, or `None` if this is a date without a time.
+    #[must_use]
+    pub fn date_time(self) -> Option<DateTime> {
+        match self {
+            DateOrDateTime::Date(_) => None,
+            DateOrDateTime::DateTime(date_time) => Some(date_time),
+        }
+    }
+}
+
+impl Ord for DateOrDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chronological_key()
+            .cmp(&other.chronological_key())
+            // Ties are broken so that the order is consistent with `Eq`.
+            .then_with(|| match (self, other) {
+                (DateOrDateTime::Date(_), DateOrDateTime::Date(_)) => Ordering::Equal,
+                (DateOrDateTime::Date(_), DateOrDateTime::DateTime(_)) => Ordering::Less,
+                (DateOrDateTime::DateTime(_), DateOrDateTime::Date(_)) => Ordering::Greater,
+                (DateOrDateTime::DateTime(a), DateOrDateTime::DateTime(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for DateOrDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Date> for DateOrDateTime {
+    fn from(date: Date) -> Self {
+        DateOrDateTime::Date(date)
+    }
+}
+
+impl From<DateTime> for DateOrDateTime {
+    fn from(date_time: DateTime) -> Self {
+        DateOrDateTime::DateTime(date_time)
+    }
+}
+
+impl Display for DateOrDateTime {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DateOrDateTime::Date(date) => write!(f, "{date}"),
+            DateOrDateTime::DateTime(date_time) => write!(f, "{date_time}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::DateOrDateTime,
+        crate::{Date, DateTime, Time},
+    };
+
+    #[test]
+    fn order() {
+        let date = |day| DateOrDateTime::Date(Date::new(2024, 7, day));
+        let date_time = |day, hour| {
+            DateOrDateTime::DateTime(DateTime {
+                date: Date::new(2024, 7, day),
+                time: Time::new_utc(hour, 0, 0),
+            })
+        };
+        let mut values = vec![
+            date(6),
+            date_time(5, 12),
+            date_time(5, 0),
+            date(5),
+            date_time(4, 23),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                date_time(4, 23),
+                date(5),
+                date_time(5, 0),
+                date_time(5, 12),
+                date(6)
+            ]
+        );
+    }
+}
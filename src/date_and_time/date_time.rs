@@ -1,14 +1,19 @@
 use {
-    crate::{Date, Time},
+    super::duration::SECONDS_PER_DAY,
+    crate::{ConversionError, Date, Duration, ParseDateError, ParseTimeError, Time},
     std::{
         error::Error,
         fmt::{self, Display, Formatter},
+        ops::{Add, Sub},
         str::FromStr,
+        time::{Duration as StdDuration, SystemTime, UNIX_EPOCH},
     },
 };
 
 /// Represents a date and time as specified in
 /// [RFC 5545 section 3.3.5](https://tools.ietf.org/html/rfc5545#section-3.3.5).
+///
+/// Whether the date-time is in UTC time or local time is determined by its [`Time`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DateTime {
     /// Date component.
@@ -17,17 +22,318 @@ pub struct DateTime {
     pub time: Time,
 }
 
+impl DateTime {
+    /// The current date and time in UTC time, according to the system clock.
+    ///
+    /// This is useful for the `DTSTAMP` property.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set to a time outside of the years 0-9999.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn now_utc() -> Self {
+        Self::try_from(SystemTime::now())
+            .expect("The system clock is set to a time within the years 0-9999")
+    }
+
+    /// Create a date-time in UTC time from a Unix timestamp, that is, the number of seconds since
+    /// 1970-01-01 00:00:00 UTC.
+    ///
+    /// Returns `None` if the timestamp is outside of the years 0-9999.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Time};
+    ///
+    /// let date_time = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_utc(9, 0, 0),
+    /// };
+    /// assert_eq!(DateTime::from_unix_timestamp(1_720_170_000), Some(date_time));
+    /// assert_eq!(date_time.to_unix_timestamp(), Some(1_720_170_000));
+    /// ```
+    #[must_use]
+    pub fn from_unix_timestamp(timestamp: i64) -> Option<Self> {
+        let epoch = DateTime {
+            date: Date::from_days_since_epoch(0)?,
+            time: Time::try_new_utc(0, 0, 0).ok()?,
+        };
+        epoch.checked_add(Duration::from_total_seconds(timestamp)?)
+    }
+
+    /// Convert the date-time to a Unix timestamp, that is, the number of seconds since
+    /// 1970-01-01 00:00:00 UTC.
+    ///
+    /// Returns `None` if the date-time is in local time, because the point in time it refers to
+    /// depends on the time zone of the observer.
+    #[must_use]
+    pub fn to_unix_timestamp(self) -> Option<i64> {
+        self.time.is_utc().then(|| self.seconds_since_epoch())
+    }
+
+    /// Add `duration` to the date-time.
+    ///
+    /// The result is in UTC time if this date-time is in UTC time and in local time otherwise.
+    /// Returns `None` if the result is outside of the range of supported dates.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Duration, Time};
+    ///
+    /// let start = DateTime {
+    ///     date: Date::new(2024, 12, 31),
+    ///     time: Time::new_utc(23, 30, 0),
+    /// };
+    /// let end = DateTime {
+    ///     date: Date::new(2025, 1, 1),
+    ///     time: Time::new_utc(0, 15, 0),
+    /// };
+    /// assert_eq!(start.checked_add(Duration::from_minutes(45)), Some(end));
+    /// assert_eq!(end.checked_sub(Duration::from_minutes(45)), Some(start));
+    /// assert_eq!(end.duration_since(start), Some(Duration::new(0, 0, 45, 0)));
+    /// assert_eq!(start + "PT45M".parse().unwrap(), end);
+    /// ```
+    #[must_use]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let seconds = self
+            .seconds_since_epoch()
+            .checked_add(duration.as_seconds())?;
+        let date =
+            Date::from_days_since_epoch(i32::try_from(seconds.div_euclid(SECONDS_PER_DAY)).ok()?)?;
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+        let component = |value: i64| u8::try_from(value).ok();
+        let (hour, minute, second) = (
+            component(seconds / 3600)?,
+            component(seconds / 60 % 60)?,
+            component(seconds % 60)?,
+        );
+        let time = if self.time.is_utc() {
+            Time::try_new_utc(hour, minute, second)
+        } else {
+            Time::try_new_local(hour, minute, second)
+        };
+        Some(Self {
+            date,
+            time: time.ok()?,
+        })
+    }
+
+    /// Subtract `duration` from the date-time.
+    ///
+    /// See [`DateTime::checked_add`].
+    #[must_use]
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_add(-duration)
+    }
+
+    /// The duration from `earlier` to this date-time, negative if `earlier` is after this
+    /// date-time.
+    ///
+    /// The result consists of days, hours, minutes and seconds. Whether the date-times are in
+    /// UTC time or local time is ignored.
+    ///
+    /// Returns `None` if the number of days is too large for a [`Duration`], which cannot happen
+    /// for the years 0-9999 supported by [`Date`].
+    #[must_use]
+    pub fn duration_since(self, earlier: Self) -> Option<Duration> {
+        Duration::from_total_seconds(self.seconds_since_epoch() - earlier.seconds_since_epoch())
+    }
+
+    /// The number of seconds since 1970-01-01 at midnight.
+    pub(crate) fn seconds_since_epoch(self) -> i64 {
+        i64::from(self.date.days_since_epoch()) * SECONDS_PER_DAY
+            + i64::from(self.time.seconds_of_day())
+    }
+}
+
+/// RFC 3339 formatting.
+impl DateTime {
+    /// Parse a date-time in the format of
+    /// [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), such as
+    /// `2024-07-05T09:00:00Z` or `2024-07-05T11:00:00.250+02:00`.
+    ///
+    /// A date-time with a UTC offset is converted to UTC time. Fractions of a second are
+    /// ignored. As an extension to RFC 3339, a date-time without an offset, such as
+    /// `2024-07-05T09:00:00`, is parsed as a local time. The date and time may also be separated
+    /// by a space or a lowercase `t`.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Time};
+    ///
+    /// let date_time = DateTime::parse_rfc3339("2024-07-05T11:00:00+02:00").unwrap();
+    /// assert_eq!(
+    ///     date_time,
+    ///     DateTime {
+    ///         date: Date::new(2024, 7, 5),
+    ///         time: Time::new_utc(9, 0, 0),
+    ///     }
+    /// );
+    /// assert_eq!(date_time.to_rfc3339(), "2024-07-05T09:00:00Z");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not in the format described above, or if the date-time is
+    /// outside of the years 0-9999 after the conversion to UTC time.
+    pub fn parse_rfc3339(s: &str) -> Result<Self, ParseDateTimeError> {
+        let (date, time) = s
+            .split_once(['T', 't', ' '])
+            .ok_or(ParseDateTimeError::MissingSeparator)?;
+        let date = Date::parse_iso8601(date).map_err(ParseDateTimeError::Date)?;
+        let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+            (time, Some(0))
+        } else if let Some(index) = time.rfind(['+', '-']) {
+            (&time[..index], Some(parse_offset(&time[index..])?))
+        } else {
+            (time, None)
+        };
+        let time =
+            Time::parse_extended(time, offset.is_some()).map_err(ParseDateTimeError::Time)?;
+        let date_time = DateTime { date, time };
+        match offset {
+            Some(offset) => Duration::from_total_seconds(offset)
+                .and_then(|offset| date_time.checked_sub(offset))
+                .ok_or(ParseDateTimeError::OutOfRange),
+            None => Ok(date_time),
+        }
+    }
+
+    /// Format the date-time in the format of
+    /// [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), such as
+    /// `2024-07-05T09:00:00Z`.
+    ///
+    /// A date-time in local time is formatted without an offset, such as `2024-07-05T09:00:00`.
+    /// This is not valid RFC 3339, but valid ISO 8601.
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        let utc = if self.time.is_utc() { "Z" } else { "" };
+        format!(
+            "{}T{}{utc}",
+            self.date.to_iso8601(),
+            self.time.to_extended()
+        )
+    }
+}
+
+/// Parse a UTC offset of the form `+HH:MM` or `-HH:MM` into seconds.
+fn parse_offset(s: &str) -> Result<i64, ParseDateTimeError> {
+    let bytes = s.as_bytes();
+    let valid = bytes.len() == 6
+        && bytes[3] == b':'
+        && [1, 2, 4, 5].iter().all(|&i| bytes[i].is_ascii_digit());
+    if !valid {
+        return Err(ParseDateTimeError::InvalidOffset);
+    }
+    let hours: i64 = s[1..3]
+        .parse()
+        .map_err(|_| ParseDateTimeError::InvalidOffset)?;
+    let minutes: i64 = s[4..6]
+        .parse()
+        .map_err(|_| ParseDateTimeError::InvalidOffset)?;
+    if hours > 23 || minutes > 59 {
+        return Err(ParseDateTimeError::InvalidOffset);
+    }
+    let offset = hours * 3600 + minutes * 60;
+    Ok(if bytes[0] == b'-' { -offset } else { offset })
+}
+
 impl FromStr for DateTime {
     type Err = ParseDateTimeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.split_once('T')
-            .map_or(Err(ParseDateTimeError {}), |(date, time)| {
-                Ok(DateTime {
-                    date: date.parse().map_err(|_| ParseDateTimeError {})?,
-                    time: time.parse().map_err(|_| ParseDateTimeError {})?,
-                })
-            })
+        let (date, time) = s
+            .split_once('T')
+            .ok_or(ParseDateTimeError::MissingSeparator)?;
+        Ok(DateTime {
+            date: date.parse().map_err(ParseDateTimeError::Date)?,
+            time: time.parse().map_err(ParseDateTimeError::Time)?,
+        })
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = Self;
+
+    /// Add `duration` to the date-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside of the range of supported dates. Use
+    /// [`DateTime::checked_add`] to handle this case.
+    fn add(self, duration: Duration) -> Self {
+        self.checked_add(duration)
+            .expect("Date-time out of range after adding a duration")
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = Self;
+
+    /// Subtract `duration` from the date-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside of the range of supported dates. Use
+    /// [`DateTime::checked_sub`] to handle this case.
+    fn sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration)
+            .expect("Date-time out of range after subtracting a duration")
+    }
+}
+
+/// The result is in UTC time. Fractions of a second are discarded, rounding towards the past.
+///
+/// ```
+/// use {
+///     ical::{Date, DateTime, Time},
+///     std::time::{Duration, SystemTime, UNIX_EPOCH},
+/// };
+///
+/// let modified = UNIX_EPOCH + Duration::from_millis(1_720_170_000_250);
+/// let date_time = DateTime {
+///     date: Date::new(2024, 7, 5),
+///     time: Time::new_utc(9, 0, 0),
+/// };
+/// assert_eq!(DateTime::try_from(modified), Ok(date_time));
+/// assert_eq!(
+///     SystemTime::try_from(date_time),
+///     Ok(UNIX_EPOCH + Duration::from_secs(1_720_170_000))
+/// );
+/// ```
+impl TryFrom<SystemTime> for DateTime {
+    type Error = ConversionError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => i64::try_from(since_epoch.as_secs()).ok(),
+            Err(err) => {
+                let before_epoch = err.duration();
+                let seconds = before_epoch.as_secs() + u64::from(before_epoch.subsec_nanos() > 0);
+                i64::try_from(seconds).ok().map(|seconds| -seconds)
+            }
+        };
+        seconds
+            .and_then(Self::from_unix_timestamp)
+            .ok_or(ConversionError::OutOfRange)
+    }
+}
+
+/// Only date-times in UTC time can be converted, see [`ConversionError::LocalTime`].
+impl TryFrom<DateTime> for SystemTime {
+    type Error = ConversionError;
+
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let timestamp = date_time
+            .to_unix_timestamp()
+            .ok_or(ConversionError::LocalTime)?;
+        let since_epoch = StdDuration::from_secs(timestamp.unsigned_abs());
+        if timestamp < 0 {
+            UNIX_EPOCH.checked_sub(since_epoch)
+        } else {
+            UNIX_EPOCH.checked_add(since_epoch)
+        }
+        .ok_or(ConversionError::OutOfRange)
     }
 }
 
@@ -38,24 +344,78 @@ impl Display for DateTime {
 }
 
 /// Error type for parsing a [`DateTime`].
-#[derive(Debug, Clone)]
-pub struct ParseDateTimeError {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDateTimeError {
+    /// The text does not contain the `T` that separates the date and the time.
+    MissingSeparator,
+    /// The date part is invalid.
+    Date(ParseDateError),
+    /// The time part is invalid.
+    Time(ParseTimeError),
+    /// The UTC offset of an RFC 3339 date-time is not of the form `+HH:MM` or `-HH:MM`.
+    InvalidOffset,
+    /// The date-time is outside of the supported range of dates after the conversion to UTC
+    /// time.
+    OutOfRange,
+}
 
 impl Display for ParseDateTimeError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Invalid date-time")
+        match self {
+            ParseDateTimeError::MissingSeparator => {
+                write!(f, "Invalid date-time: expected a 'T' between date and time")
+            }
+            ParseDateTimeError::Date(err) => write!(f, "{err}"),
+            ParseDateTimeError::Time(err) => write!(f, "{err}"),
+            ParseDateTimeError::InvalidOffset => {
+                write!(f, "Invalid date-time: expected a UTC offset like +02:00")
+            }
+            ParseDateTimeError::OutOfRange => {
+                write!(f, "Invalid date-time: out of the range of supported dates")
+            }
+        }
     }
 }
 
-impl Error for ParseDateTimeError {}
+impl Error for ParseDateTimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDateTimeError::MissingSeparator
+            | ParseDateTimeError::InvalidOffset
+            | ParseDateTimeError::OutOfRange => None,
+            ParseDateTimeError::Date(err) => Some(err),
+            ParseDateTimeError::Time(err) => Some(err),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use {
-        super::DateTime,
-        crate::{Date, Time},
+        super::{DateTime, ParseDateTimeError},
+        crate::{
+            ConversionError, Date, Duration, InvalidTimeError, ParseDateError, ParseTimeError, Time,
+        },
+        std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH},
     };
 
+    #[test]
+    fn system_time() {
+        let before_epoch = UNIX_EPOCH - StdDuration::from_millis(500);
+        assert_eq!(
+            DateTime::try_from(before_epoch),
+            Ok(DateTime {
+                date: Date::new(1969, 12, 31),
+                time: Time::new_utc(23, 59, 59),
+            })
+        );
+        let local = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        assert_eq!(SystemTime::try_from(local), Err(ConversionError::LocalTime));
+    }
+
     #[test]
     fn parse() {
         assert_eq!(
@@ -65,11 +425,153 @@ mod tests {
                 time: Time::new_utc(7, 0, 0),
             }
         );
-        // Invalid for this implementation, but technically valid
-        assert!("19980118T230000".parse::<DateTime>().is_err());
+        assert_eq!(
+            "19980118T230000".parse::<DateTime>().unwrap(),
+            DateTime {
+                date: Date::new(1998, 1, 18),
+                time: Time::new_local(23, 0, 0),
+            }
+        );
         assert!("19980119T230000-0800".parse::<DateTime>().is_err());
     }
 
+    #[test]
+    #[cfg(feature = "clock")]
+    fn now_utc() {
+        let now = DateTime::now_utc();
+        assert!(now.time.is_utc());
+        assert!(now.date >= Date::new(2024, 1, 1));
+        assert!(Date::today_utc() >= now.date);
+    }
+
+    #[test]
+    fn unix_timestamp() {
+        let min = DateTime {
+            date: Date::new(0, 1, 1),
+            time: Time::new_utc(0, 0, 0),
+        };
+        let max = DateTime {
+            date: Date::new(9999, 12, 31),
+            time: Time::new_utc(23, 59, 59),
+        };
+        assert_eq!(min.to_unix_timestamp(), Some(-62_167_219_200));
+        assert_eq!(max.to_unix_timestamp(), Some(253_402_300_799));
+        assert_eq!(DateTime::from_unix_timestamp(-62_167_219_200), Some(min));
+        assert_eq!(DateTime::from_unix_timestamp(253_402_300_799), Some(max));
+        assert_eq!(DateTime::from_unix_timestamp(-62_167_219_201), None);
+        assert_eq!(DateTime::from_unix_timestamp(253_402_300_800), None);
+        assert_eq!(DateTime::from_unix_timestamp(i64::MIN), None);
+
+        let local = DateTime {
+            date: Date::new(1970, 1, 1),
+            time: Time::new_local(0, 0, 0),
+        };
+        assert_eq!(local.to_unix_timestamp(), None);
+    }
+
+    #[test]
+    fn rfc3339() {
+        let utc = |hour, minute, second| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, minute, second),
+        };
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05T09:30:15Z"),
+            Ok(utc(9, 30, 15))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05t09:30:15.999z"),
+            Ok(utc(9, 30, 15))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05 04:00:00-05:30"),
+            Ok(utc(9, 30, 0))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-06T01:00:00+15:30").unwrap(),
+            utc(9, 30, 0)
+        );
+        let local = DateTime::parse_rfc3339("2024-07-05T09:30:00").unwrap();
+        assert!(!local.time.is_utc());
+        assert_eq!(local.to_rfc3339(), "2024-07-05T09:30:00");
+        assert_eq!(utc(9, 30, 0).to_rfc3339(), "2024-07-05T09:30:00Z");
+
+        assert_eq!(
+            DateTime::parse_rfc3339("20240705T093000Z"),
+            Err(ParseDateTimeError::Date(ParseDateError::Length(8)))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05T09-30-00Z"),
+            Err(ParseDateTimeError::Time(ParseTimeError::Separator))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05T09:30:00+2:00"),
+            Err(ParseDateTimeError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05T09:30.5Z"),
+            Err(ParseDateTimeError::Time(ParseTimeError::Length(5)))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2024-07-05T09.30.00Z"),
+            Err(ParseDateTimeError::Time(ParseTimeError::NotADigit))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("0000-01-01T00:30:00+01:00"),
+            Err(ParseDateTimeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let date_time = DateTime {
+            date: Date::new(2024, 3, 1),
+            time: Time::new_local(0, 0, 0),
+        };
+        let earlier = DateTime {
+            date: Date::new(2024, 2, 28),
+            time: Time::new_local(12, 0, 0),
+        };
+        assert_eq!(
+            date_time.checked_sub(Duration::new(1, 12, 0, 0)),
+            Some(earlier)
+        );
+        assert_eq!(
+            earlier.checked_add(Duration::from_weeks(1)),
+            Some(DateTime {
+                date: Date::new(2024, 3, 6),
+                time: Time::new_local(12, 0, 0),
+            })
+        );
+        assert_eq!(
+            date_time.duration_since(earlier),
+            Some(Duration::new(1, 12, 0, 0))
+        );
+        assert_eq!(
+            earlier.duration_since(date_time),
+            Some(-Duration::new(1, 12, 0, 0))
+        );
+        assert_eq!(date_time.checked_add(Duration::from_weeks(u32::MAX)), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "19980119".parse::<DateTime>(),
+            Err(ParseDateTimeError::MissingSeparator)
+        );
+        assert_eq!(
+            "1998011T070000Z".parse::<DateTime>(),
+            Err(ParseDateTimeError::Date(ParseDateError::Length(7)))
+        );
+        assert_eq!(
+            "19980119T076000Z".parse::<DateTime>(),
+            Err(ParseDateTimeError::Time(ParseTimeError::Invalid(
+                InvalidTimeError::Minute(60)
+            )))
+        );
+    }
+
     #[test]
     fn display() {
         assert_eq!(
@@ -82,5 +584,15 @@ mod tests {
             ),
             "19980119T070000Z"
         );
+        assert_eq!(
+            format!(
+                "{}",
+                DateTime {
+                    date: Date::new(1998, 1, 18),
+                    time: Time::new_local(23, 0, 0),
+                }
+            ),
+            "19980118T230000"
+        );
     }
 }
@@ -0,0 +1,405 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Neg,
+    str::FromStr,
+};
+
+/// Represents a duration as specified in
+/// [RFC 5545 section 3.3.6](https://tools.ietf.org/html/rfc5545#section-3.3.6).
+///
+/// A duration consists of a sign and a number of weeks, days, hours, minutes and seconds. The
+/// components are kept as they are given, so a duration of one week is not equal to a duration
+/// of seven days, even though both are equally long. Use [`Duration::as_seconds`] to compare the
+/// lengths of durations.
+///
+/// ```
+/// use ical::Duration;
+///
+/// let reminder = -Duration::from_minutes(15);
+/// assert!(reminder.is_negative());
+/// assert_eq!(reminder.as_seconds(), -900);
+/// assert_ne!(Duration::from_weeks(1), Duration::from_days(7));
+/// assert_eq!(
+///     Duration::from_weeks(1).as_seconds(),
+///     Duration::from_days(7).as_seconds()
+/// );
+/// ```
+///
+/// Durations are parsed from and displayed in the RFC 5545 syntax:
+///
+/// ```
+/// use ical::Duration;
+///
+/// let duration: Duration = "-PT15M".parse().unwrap();
+/// assert_eq!(duration, -Duration::from_minutes(15));
+/// assert_eq!(Duration::new(1, 2, 0, 30).to_string(), "P1DT2H0M30S");
+/// assert_eq!(Duration::from_weeks(2).to_string(), "P2W");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Duration {
+    negative: bool,
+    weeks: u32,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+}
+
+/// The number of seconds in a day, ignoring leap seconds.
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+impl Duration {
+    /// Create a new positive `Duration` of `days` days and the given time.
+    #[must_use]
+    pub fn new(days: u32, hours: u32, minutes: u32, seconds: u32) -> Self {
+        Self {
+            negative: false,
+            weeks: 0,
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Create a new positive `Duration` of `weeks` weeks.
+    #[must_use]
+    pub fn from_weeks(weeks: u32) -> Self {
+        Self {
+            weeks,
+            ..Self::default()
+        }
+    }
+
+    /// Create a new positive `Duration` of `days` days.
+    #[must_use]
+    pub fn from_days(days: u32) -> Self {
+        Self::new(days, 0, 0, 0)
+    }
+
+    /// Create a new positive `Duration` of `hours` hours.
+    #[must_use]
+    pub fn from_hours(hours: u32) -> Self {
+        Self::new(0, hours, 0, 0)
+    }
+
+    /// Create a new positive `Duration` of `minutes` minutes.
+    #[must_use]
+    pub fn from_minutes(minutes: u32) -> Self {
+        Self::new(0, 0, minutes, 0)
+    }
+
+    /// Create a new positive `Duration` of `seconds` seconds.
+    #[must_use]
+    pub fn from_seconds(seconds: u32) -> Self {
+        Self::new(0, 0, 0, seconds)
+    }
+
+    /// Create a `Duration` of `seconds` seconds, split into days, hours, minutes and seconds.
+    ///
+    /// Returns `None` if the number of days does not fit into a `u32`.
+    pub(crate) fn from_total_seconds(seconds: i64) -> Option<Self> {
+        let magnitude = seconds.unsigned_abs();
+        let day_seconds = magnitude % 86_400;
+        let duration = Self::new(
+            u32::try_from(magnitude / 86_400).ok()?,
+            u32::try_from(day_seconds / 3600).ok()?,
+            u32::try_from(day_seconds / 60 % 60).ok()?,
+            u32::try_from(day_seconds % 60).ok()?,
+        );
+        Some(if seconds < 0 { -duration } else { duration })
+    }
+
+    /// Returns `true` if the duration is negative.
+    ///
+    /// A duration of zero length is never negative.
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Set the sign of the duration, keeping a duration of zero length positive so that it is
+    /// equal to, and displayed like, any other duration of zero length with the same components.
+    fn with_sign(self, negative: bool) -> Self {
+        let is_zero = self.weeks == 0
+            && self.days == 0
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0;
+        Self {
+            negative: negative && !is_zero,
+            ..self
+        }
+    }
+
+    /// Get the number of weeks.
+    #[must_use]
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+
+    /// Get the number of days.
+    #[must_use]
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Get the number of hours.
+    #[must_use]
+    pub fn hours(&self) -> u32 {
+        self.hours
+    }
+
+    /// Get the number of minutes.
+    #[must_use]
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    /// Get the number of seconds.
+    #[must_use]
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// The total length of the duration in seconds, negative if the duration is negative.
+    ///
+    /// Weeks and days are treated as 7 and 1 times 24 hours, which is exact for date-times in
+    /// UTC time and local time without a time zone.
+    #[must_use]
+    pub fn as_seconds(&self) -> i64 {
+        let seconds = (i64::from(self.weeks) * 7 + i64::from(self.days)) * SECONDS_PER_DAY
+            + i64::from(self.hours) * 3600
+            + i64::from(self.minutes) * 60
+            + i64::from(self.seconds);
+        if self.negative { -seconds } else { seconds }
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.with_sign(!self.negative)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseDurationError;
+
+    /// Parse a duration such as `P15DT5H0M20S`, `-PT15M` or `+P7W`.
+    ///
+    /// As an extension to RFC 5545, the hours, minutes and seconds may be given in any
+    /// combination, as long as they are in this order, such as `PT1H30S`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let mut rest = s
+            .strip_prefix('P')
+            .ok_or(ParseDurationError::MissingDesignator)?;
+        if rest.is_empty() || rest == "T" {
+            return Err(ParseDurationError::Empty);
+        }
+        let mut duration = Self::default();
+        let mut in_time = false;
+        // The designators that may still follow, in this order.
+        let mut allowed: &[char] = &['W', 'D', 'T'];
+        while let Some(first) = rest.chars().next() {
+            if first == 'T' {
+                rest = &rest[1..];
+                if !allowed.contains(&'T') || rest.is_empty() {
+                    return Err(ParseDurationError::UnexpectedCharacter('T'));
+                }
+                in_time = true;
+                allowed = &['H', 'M', 'S'];
+                continue;
+            }
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits == 0 {
+                return Err(ParseDurationError::UnexpectedCharacter(first));
+            }
+            let designator = rest[digits..]
+                .chars()
+                .next()
+                .ok_or(ParseDurationError::UnexpectedEnd)?;
+            if !allowed.contains(&designator) {
+                return Err(ParseDurationError::UnexpectedCharacter(designator));
+            }
+            let value: u32 = rest[..digits]
+                .parse()
+                .map_err(|_| ParseDurationError::Overflow)?;
+            let (component, next): (&mut u32, &[char]) = match (designator, in_time) {
+                ('W', false) => (&mut duration.weeks, &[]),
+                ('D', false) => (&mut duration.days, &['T']),
+                ('H', true) => (&mut duration.hours, &['M', 'S']),
+                ('M', true) => (&mut duration.minutes, &['S']),
+                (_, true) => (&mut duration.seconds, &[]),
+                (_, false) => return Err(ParseDurationError::UnexpectedCharacter(designator)),
+            };
+            *component = value;
+            allowed = next;
+            rest = &rest[digits + designator.len_utf8()..];
+        }
+        Ok(duration.with_sign(negative))
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        let (hours, minutes, seconds) = (self.hours, self.minutes, self.seconds);
+        let has_time = hours != 0 || minutes != 0 || seconds != 0;
+        if self.weeks != 0 && self.days == 0 && !has_time {
+            return write!(f, "{}W", self.weeks);
+        }
+        // RFC 5545 does not allow weeks to be combined with other components.
+        let days = u64::from(self.weeks) * 7 + u64::from(self.days);
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+        if has_time {
+            write!(f, "T")?;
+            if hours != 0 {
+                write!(f, "{hours}H")?;
+            }
+            // RFC 5545 does not allow seconds to follow hours directly.
+            if minutes != 0 || (hours != 0 && seconds != 0) {
+                write!(f, "{minutes}M")?;
+            }
+            if seconds != 0 {
+                write!(f, "{seconds}S")?;
+            }
+        } else if days == 0 {
+            write!(f, "T0S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error type for parsing a [`Duration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The text does not start with `P`, optionally preceded by a sign.
+    MissingDesignator,
+    /// The text does not contain any component, such as `P` or `PT`.
+    Empty,
+    /// The text contains an unexpected character, or a component is out of order.
+    UnexpectedCharacter(char),
+    /// The text ends with a number that is not followed by a designator such as `D`.
+    UnexpectedEnd,
+    /// A component is too large.
+    Overflow,
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseDurationError::MissingDesignator => {
+                write!(f, "Invalid duration: expected 'P'")
+            }
+            ParseDurationError::Empty => write!(f, "Invalid duration: no components"),
+            ParseDurationError::UnexpectedCharacter(c) => {
+                write!(f, "Invalid duration: unexpected '{c}'")
+            }
+            ParseDurationError::UnexpectedEnd => {
+                write!(f, "Invalid duration: unexpected end")
+            }
+            ParseDurationError::Overflow => write!(f, "Invalid duration: component too large"),
+        }
+    }
+}
+
+impl Error for ParseDurationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Duration, ParseDurationError};
+
+    #[test]
+    fn from_total_seconds() {
+        assert_eq!(
+            Duration::from_total_seconds(90_061),
+            Some(Duration::new(1, 1, 1, 1))
+        );
+        assert_eq!(
+            Duration::from_total_seconds(-3600),
+            Some(-Duration::from_hours(1))
+        );
+        assert_eq!(Duration::from_total_seconds(i64::MIN), None);
+    }
+
+    #[test]
+    fn zero_is_not_negative() {
+        assert!(!(-Duration::default()).is_negative());
+        assert_eq!((-Duration::from_days(0)).as_seconds(), 0);
+        assert_eq!(-Duration::default(), Duration::default());
+
+        let zero: Duration = "-P0D".parse().unwrap();
+        assert!(!zero.is_negative());
+        assert_eq!(zero, "PT0S".parse().unwrap());
+        assert_eq!(zero.to_string(), "PT0S");
+        assert_eq!("-PT0H0M".parse::<Duration>().unwrap().to_string(), "PT0S");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("P15DT5H0M20S".parse(), Ok(Duration::new(15, 5, 0, 20)));
+        assert_eq!("+P7W".parse(), Ok(Duration::from_weeks(7)));
+        assert_eq!("-PT15M".parse(), Ok(-Duration::from_minutes(15)));
+        assert_eq!("PT1H30S".parse(), Ok(Duration::new(0, 1, 0, 30)));
+        assert_eq!("P0D".parse(), Ok(Duration::default()));
+        assert_eq!(
+            "15M".parse::<Duration>(),
+            Err(ParseDurationError::MissingDesignator)
+        );
+        assert_eq!("PT".parse::<Duration>(), Err(ParseDurationError::Empty));
+        assert_eq!(
+            "P1DT".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedCharacter('T'))
+        );
+        assert_eq!(
+            "P1W2D".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedCharacter('D'))
+        );
+        assert_eq!(
+            "PT5M1H".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedCharacter('H'))
+        );
+        assert_eq!(
+            "P1H".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedCharacter('H'))
+        );
+        assert_eq!(
+            "PT-1S".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedCharacter('-'))
+        );
+        assert_eq!(
+            "P15".parse::<Duration>(),
+            Err(ParseDurationError::UnexpectedEnd)
+        );
+        assert_eq!(
+            "P99999999999D".parse::<Duration>(),
+            Err(ParseDurationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Duration::default().to_string(), "PT0S");
+        assert_eq!((-Duration::from_minutes(15)).to_string(), "-PT15M");
+        assert_eq!(Duration::from_days(2).to_string(), "P2D");
+        assert_eq!(Duration::new(1, 2, 0, 0).to_string(), "P1DT2H");
+        assert_eq!(Duration::new(0, 1, 0, 30).to_string(), "PT1H0M30S");
+        for text in ["P15DT5H0M20S", "-P3W", "PT20S", "P1DT1M"] {
+            assert_eq!(text.parse::<Duration>().unwrap().to_string(), text);
+        }
+    }
+}
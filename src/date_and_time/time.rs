@@ -8,16 +8,22 @@ use std::{
 /// [RFC 5545 section 3.3.12](https://tools.ietf.org/html/rfc5545#section-3.3.12).
 ///
 /// [RFC 5545](https://tools.ietf.org/html/rfc5545#section-3.3.12) specifies that a time may be in
-/// either local time or UTC time. At the moment, only UTC time is supported.
+/// either UTC time (written with a `Z` suffix, e.g. `123000Z`) or local time (written without a
+/// suffix, e.g. `123000`). A local time is "floating", it refers to the same hour, minute and
+/// second in whatever time zone the observer is in.
+///
+/// Times are ordered by their hour, minute and second first. Comparing a UTC time to a local time
+/// is only meaningful if the time zone of the observer is UTC.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Time {
     hour: u8,
     minute: u8,
     second: u8,
+    utc: bool,
 }
 
 impl Time {
-    /// Create a new `Time`.
+    /// Create a new `Time` in UTC time.
     ///
     /// # Panics
     ///
@@ -28,22 +34,88 @@ impl Time {
     ///
     /// Note that RFC 5545 technically allows for leap seconds, that is, a `second` value of 60.
     /// However, this is currently not supported.
+    ///
+    /// Use [`Time::try_new_utc`] if the values are not known to be valid, for example because
+    /// they come from user input.
     #[must_use]
     pub fn new_utc(hour: u8, minute: u8, second: u8) -> Self {
+        Self::try_new_utc(hour, minute, second).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `Time` in local ("floating") time.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`Time::new_utc`].
+    ///
+    /// Use [`Time::try_new_local`] if the values are not known to be valid.
+    #[must_use]
+    pub fn new_local(hour: u8, minute: u8, second: u8) -> Self {
+        Self::try_new_local(hour, minute, second).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `Time` in UTC time, or return an error if the values do not form a valid
+    /// time.
+    ///
+    /// ```
+    /// use ical::{InvalidTimeError, Time};
+    ///
+    /// assert_eq!(Time::try_new_utc(12, 30, 0), Ok(Time::new_utc(12, 30, 0)));
+    /// assert_eq!(Time::try_new_utc(24, 0, 0), Err(InvalidTimeError::Hour(24)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Time::new_utc`] panics.
+    pub fn try_new_utc(hour: u8, minute: u8, second: u8) -> Result<Self, InvalidTimeError> {
+        Self::try_new(hour, minute, second, true)
+    }
+
+    /// Create a new `Time` in local ("floating") time, or return an error if the values do not
+    /// form a valid time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Time::new_utc`] panics.
+    pub fn try_new_local(hour: u8, minute: u8, second: u8) -> Result<Self, InvalidTimeError> {
+        Self::try_new(hour, minute, second, false)
+    }
+
+    fn try_new(hour: u8, minute: u8, second: u8, utc: bool) -> Result<Self, InvalidTimeError> {
         if hour > 23 {
-            panic!("Hour must be between 0 and 23");
+            Err(InvalidTimeError::Hour(hour))
         } else if minute > 59 {
-            panic!("Minute must be between 0 and 59");
+            Err(InvalidTimeError::Minute(minute))
         } else if second > 59 {
-            panic!("Second must be between 0 and 59");
-        }
-        Self {
-            hour,
-            minute,
-            second,
+            Err(InvalidTimeError::Second(second))
+        } else {
+            Ok(Self {
+                hour,
+                minute,
+                second,
+                utc,
+            })
         }
     }
 
+    /// Returns `true` if this is a UTC time and `false` if this is a local time.
+    #[must_use]
+    pub fn is_utc(&self) -> bool {
+        self.utc
+    }
+
+    /// Set whether this is a UTC time or a local time.
+    ///
+    /// This does not convert between the two, only the hour, minute and second are kept.
+    pub fn set_utc(&mut self, utc: bool) {
+        self.utc = utc;
+    }
+
+    /// The number of seconds since midnight, regardless of whether this is a UTC or local time.
+    pub(crate) fn seconds_of_day(self) -> u32 {
+        u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
     /// Get the hour.
     #[must_use]
     pub fn hour(&self) -> u8 {
@@ -93,61 +165,155 @@ impl Time {
     }
 }
 
+impl Time {
+    /// Parse a time in the ISO 8601 extended format `HH:MM:SS`, optionally followed by a fraction
+    /// of a second, which is ignored.
+    pub(crate) fn parse_extended(s: &str, utc: bool) -> Result<Self, ParseTimeError> {
+        let s = match s.split_once('.') {
+            Some((s, fraction)) => {
+                if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseTimeError::NotADigit);
+                }
+                s
+            }
+            None => s,
+        };
+        if s.len() != 8 {
+            return Err(ParseTimeError::Length(s.len()));
+        }
+        let bytes = s.as_bytes();
+        if bytes[2] != b':' || bytes[5] != b':' {
+            return Err(ParseTimeError::Separator);
+        }
+        let mut time: Self = [&s[0..2], &s[3..5], &s[6..8]].concat().parse()?;
+        time.utc = utc;
+        Ok(time)
+    }
+
+    /// Format the time in the ISO 8601 extended format `HH:MM:SS`, without a UTC designator.
+    pub(crate) fn to_extended(self) -> String {
+        format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
 impl FromStr for Time {
     type Err = ParseTimeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 7 {
-            return Err(ParseTimeError {});
-        }
-        let bytes = s.as_bytes();
-        if !bytes[0..6].iter().all(|&b| b.is_ascii_digit()) {
-            return Err(ParseTimeError {});
-        }
-        if bytes[6] != b'Z' {
-            return Err(ParseTimeError {});
+        let (s, utc) = match s.strip_suffix('Z') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        if s.len() != 6 {
+            return Err(ParseTimeError::Length(s.len()));
         }
-        let hour = s[0..2].parse().map_err(|_| ParseTimeError {})?;
-        if hour > 23 {
-            return Err(ParseTimeError {});
-        }
-        let minute = s[2..4].parse().map_err(|_| ParseTimeError {})?;
-        if minute > 59 {
-            return Err(ParseTimeError {});
-        }
-        let second = s[4..6].parse().map_err(|_| ParseTimeError {})?;
-        if second > 59 {
-            return Err(ParseTimeError {});
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTimeError::NotADigit);
         }
-        Ok(Self {
-            hour,
-            minute,
-            second,
-        })
+        // All characters are ASCII digits, so the parts are valid numbers.
+        let hour = s[0..2].parse().expect("2 digits fit in a u8");
+        let minute = s[2..4].parse().expect("2 digits fit in a u8");
+        let second = s[4..6].parse().expect("2 digits fit in a u8");
+        Self::try_new(hour, minute, second, utc).map_err(ParseTimeError::Invalid)
     }
 }
 
 impl Display for Time {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:02}{:02}{:02}Z", self.hour, self.minute, self.second)
+        write!(f, "{:02}{:02}{:02}", self.hour, self.minute, self.second)?;
+        if self.utc {
+            write!(f, "Z")?;
+        }
+        Ok(())
     }
 }
 
 /// Error type for parsing a [`Time`].
-#[derive(Debug, Clone)]
-pub struct ParseTimeError {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTimeError {
+    /// The text without the optional `Z` suffix does not have the length of the expected
+    /// format, that is, 6 characters (`HHMMSS`) or 8 characters in RFC 3339 date-times
+    /// (`HH:MM:SS`). Contains the actual length in bytes.
+    ///
+    /// This is also the error for times with a UTC offset, such as `230000-0800`, which RFC 5545
+    /// does not allow.
+    Length(usize),
+    /// The text does not contain the `:` separators expected in RFC 3339 date-times.
+    Separator,
+    /// The text contains a character that is not an ASCII digit, apart from the `Z` suffix.
+    NotADigit,
+    /// The text has the right format, but does not describe a valid time, such as `240000`.
+    Invalid(InvalidTimeError),
+}
 
 impl Display for ParseTimeError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Invalid time")
+        match self {
+            ParseTimeError::Length(length) => {
+                write!(f, "Invalid time: unexpected length ({length} characters)")
+            }
+            ParseTimeError::Separator => {
+                write!(
+                    f,
+                    "Invalid time: expected ':' between hour, minute and second"
+                )
+            }
+            ParseTimeError::NotADigit => write!(f, "Invalid time: expected only digits"),
+            ParseTimeError::Invalid(err) => write!(f, "Invalid time: {err}"),
+        }
     }
 }
 
-impl Error for ParseTimeError {}
+impl Error for ParseTimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseTimeError::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Error type for creating a [`Time`] from values that do not form a valid time.
+///
+/// Returned by [`Time::try_new_utc`] and [`Time::try_new_local`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidTimeError {
+    /// The hour is greater than 23.
+    Hour(u8),
+    /// The minute is greater than 59.
+    Minute(u8),
+    /// The second is greater than 59.
+    Second(u8),
+}
+
+impl Display for InvalidTimeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            InvalidTimeError::Hour(_) => write!(f, "Hour must be between 0 and 23"),
+            InvalidTimeError::Minute(_) => write!(f, "Minute must be between 0 and 59"),
+            InvalidTimeError::Second(_) => write!(f, "Second must be between 0 and 59"),
+        }
+    }
+}
+
+impl Error for InvalidTimeError {}
 
 #[cfg(test)]
 mod tests {
-    use super::Time;
+    use super::{InvalidTimeError, ParseTimeError, Time};
+
+    #[test]
+    fn try_new() {
+        assert_eq!(Time::try_new_utc(23, 59, 59), Ok(Time::new_utc(23, 59, 59)));
+        assert_eq!(
+            Time::try_new_local(0, 60, 0),
+            Err(InvalidTimeError::Minute(60))
+        );
+        assert_eq!(
+            Time::try_new_local(0, 0, 60),
+            Err(InvalidTimeError::Second(60))
+        );
+    }
 
     #[test]
     fn parse() {
@@ -165,7 +331,28 @@ mod tests {
         assert!("006000Z".parse::<Time>().is_err());
         assert!("000060Z".parse::<Time>().is_err());
         assert!("235960Z".parse::<Time>().is_err());
-        assert!("123456".parse::<Time>().is_err());
+        assert_eq!(
+            "123456".parse::<Time>().unwrap(),
+            Time::new_local(12, 34, 56)
+        );
+        assert!(!"123456".parse::<Time>().unwrap().is_utc());
+        assert!("12345Z".parse::<Time>().is_err());
+        assert!("1234567".parse::<Time>().is_err());
+        assert!("123456ZZ".parse::<Time>().is_err());
+        assert!("+12345Z".parse::<Time>().is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "230000-0800".parse::<Time>(),
+            Err(ParseTimeError::Length(11))
+        );
+        assert_eq!("12:000Z".parse::<Time>(), Err(ParseTimeError::NotADigit));
+        assert_eq!(
+            "240000Z".parse::<Time>(),
+            Err(ParseTimeError::Invalid(InvalidTimeError::Hour(24)))
+        );
     }
 
     #[test]
@@ -174,6 +361,32 @@ mod tests {
         assert_eq!(format!("{}", Time::new_utc(0, 0, 0)), "000000Z");
         assert_eq!(format!("{}", Time::new_utc(12, 0, 0)), "120000Z");
         assert_eq!(format!("{}", Time::new_utc(12, 34, 56)), "123456Z");
+        assert_eq!(format!("{}", Time::new_local(12, 34, 56)), "123456");
+    }
+
+    #[test]
+    fn round_trip() {
+        for time in [
+            Time::new_utc(0, 0, 0),
+            Time::new_utc(23, 59, 59),
+            Time::new_local(0, 0, 0),
+            Time::new_local(9, 30, 5),
+        ] {
+            assert_eq!(time.to_string().parse::<Time>(), Ok(time));
+        }
+        // Floating and UTC times with the same digits are different times.
+        assert_ne!("093005".parse::<Time>(), "093005Z".parse::<Time>());
+    }
+
+    #[test]
+    fn invalid_suffixes() {
+        assert_eq!("123456z".parse::<Time>(), Err(ParseTimeError::Length(7)));
+        assert_eq!("123456ZZ".parse::<Time>(), Err(ParseTimeError::Length(7)));
+        assert_eq!("Z123456".parse::<Time>(), Err(ParseTimeError::Length(7)));
+        assert_eq!("123456 ".parse::<Time>(), Err(ParseTimeError::Length(7)));
+        assert_eq!("12345Z6".parse::<Time>(), Err(ParseTimeError::Length(7)));
+        assert_eq!("12345ZZ".parse::<Time>(), Err(ParseTimeError::NotADigit));
+        assert_eq!("Z".parse::<Time>(), Err(ParseTimeError::Length(0)));
     }
 
     #[test]
@@ -184,19 +397,19 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Hour must be between 0 and 23")]
     fn invalid_hour() {
         let _ = Time::new_utc(24, 0, 0);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Minute must be between 0 and 59")]
     fn invalid_minute() {
         let _ = Time::new_utc(0, 60, 0);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Second must be between 0 and 59")]
     fn invalid_second() {
         let _ = Time::new_utc(0, 0, 60);
     }
@@ -0,0 +1,192 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Represents a UTC offset as specified in
+/// [RFC 5545 section 3.3.14](https://tools.ietf.org/html/rfc5545#section-3.3.14).
+///
+/// A UTC offset is written as `+HHMM` or `-HHMM`, optionally followed by seconds (`+HHMMSS`).
+/// It is used by the `TZOFFSETFROM` and `TZOFFSETTO` properties of time zone components.
+///
+/// ```
+/// use ical::UtcOffset;
+///
+/// let offset: UtcOffset = "-0530".parse().unwrap();
+/// assert_eq!(offset.as_seconds(), -(5 * 3600 + 30 * 60));
+/// assert_eq!(offset.to_string(), "-0530");
+/// assert_eq!(UtcOffset::from_seconds(3661).unwrap().to_string(), "+010101");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself, `+0000`.
+    pub const UTC: Self = Self { seconds: 0 };
+
+    /// Create a UTC offset of `seconds` seconds, negative for time zones west of UTC.
+    ///
+    /// Returns `None` if the offset is 24 hours or more in either direction.
+    #[must_use]
+    pub fn from_seconds(seconds: i32) -> Option<Self> {
+        (seconds.abs() < 24 * 3600).then_some(Self { seconds })
+    }
+
+    /// The offset in seconds, negative for time zones west of UTC.
+    #[must_use]
+    pub fn as_seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = ParseUtcOffsetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = if let Some(digits) = s.strip_prefix('+') {
+            (false, digits)
+        } else if let Some(digits) = s.strip_prefix('-') {
+            (true, digits)
+        } else {
+            return Err(ParseUtcOffsetError::MissingSign);
+        };
+        if digits.len() != 4 && digits.len() != 6 {
+            return Err(ParseUtcOffsetError::Length(s.len()));
+        }
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseUtcOffsetError::NotADigit);
+        }
+        // All characters are ASCII digits, so the parts are valid numbers.
+        let part = |index: usize| -> i32 {
+            digits
+                .get(index..index + 2)
+                .map_or(0, |part| part.parse().expect("2 digits fit in an i32"))
+        };
+        let (hours, minutes, seconds) = (part(0), part(2), part(4));
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(ParseUtcOffsetError::OutOfRange);
+        }
+        let seconds = hours * 3600 + minutes * 60 + seconds;
+        if negative && seconds == 0 {
+            return Err(ParseUtcOffsetError::NegativeZero);
+        }
+        Ok(Self {
+            seconds: if negative { -seconds } else { seconds },
+        })
+    }
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.seconds < 0 { '-' } else { '+' };
+        let seconds = self.seconds.unsigned_abs();
+        write!(f, "{sign}{:02}{:02}", seconds / 3600, seconds / 60 % 60)?;
+        if !seconds.is_multiple_of(60) {
+            write!(f, "{:02}", seconds % 60)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error type for parsing a [`UtcOffset`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseUtcOffsetError {
+    /// The text does not start with `+` or `-`.
+    MissingSign,
+    /// The text does not consist of a sign followed by 4 (`HHMM`) or 6 (`HHMMSS`) characters.
+    /// Contains the actual length in bytes.
+    Length(usize),
+    /// The text contains a character that is not an ASCII digit after the sign.
+    NotADigit,
+    /// The hours, minutes or seconds are out of range.
+    OutOfRange,
+    /// The offset is `-0000` (or `-000000`), which RFC 5545 does not allow.
+    NegativeZero,
+}
+
+impl Display for ParseUtcOffsetError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseUtcOffsetError::MissingSign => {
+                write!(f, "Invalid UTC offset: expected '+' or '-'")
+            }
+            ParseUtcOffsetError::Length(length) => {
+                write!(
+                    f,
+                    "Invalid UTC offset: unexpected length ({length} characters)"
+                )
+            }
+            ParseUtcOffsetError::NotADigit => {
+                write!(f, "Invalid UTC offset: expected only digits after the sign")
+            }
+            ParseUtcOffsetError::OutOfRange => {
+                write!(
+                    f,
+                    "Invalid UTC offset: hours, minutes or seconds out of range"
+                )
+            }
+            ParseUtcOffsetError::NegativeZero => {
+                write!(f, "Invalid UTC offset: -0000 is not allowed")
+            }
+        }
+    }
+}
+
+impl Error for ParseUtcOffsetError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseUtcOffsetError, UtcOffset};
+
+    #[test]
+    fn parse() {
+        assert_eq!("+0000".parse::<UtcOffset>(), Ok(UtcOffset::UTC));
+        assert_eq!(
+            "+0100".parse::<UtcOffset>(),
+            Ok(UtcOffset::from_seconds(3600).unwrap())
+        );
+        assert_eq!(
+            "-235959".parse::<UtcOffset>(),
+            Ok(UtcOffset::from_seconds(-86_399).unwrap())
+        );
+        assert_eq!(
+            "0100".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::MissingSign)
+        );
+        assert_eq!(
+            "+01:00".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::Length(6))
+        );
+        assert_eq!(
+            "+01h0".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::NotADigit)
+        );
+        assert_eq!(
+            "+100".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::Length(4))
+        );
+        assert_eq!(
+            "+2400".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::OutOfRange)
+        );
+        assert_eq!(
+            "-0000".parse::<UtcOffset>(),
+            Err(ParseUtcOffsetError::NegativeZero)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(UtcOffset::UTC.to_string(), "+0000");
+        assert_eq!(
+            UtcOffset::from_seconds(-(4 * 3600 + 1))
+                .unwrap()
+                .to_string(),
+            "-040001"
+        );
+        assert_eq!(UtcOffset::from_seconds(86_400), None);
+    }
+}
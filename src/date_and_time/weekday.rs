@@ -0,0 +1,89 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A day of the week.
+///
+/// Displayed as the two-letter abbreviation used by RFC 5545 (`MO`, `TU`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday (`MO`).
+    Monday,
+    /// Tuesday (`TU`).
+    Tuesday,
+    /// Wednesday (`WE`).
+    Wednesday,
+    /// Thursday (`TH`).
+    Thursday,
+    /// Friday (`FR`).
+    Friday,
+    /// Saturday (`SA`).
+    Saturday,
+    /// Sunday (`SU`).
+    Sunday,
+}
+
+impl Weekday {
+    /// The weekday that is `days` days after Monday.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is greater than 6.
+    pub(crate) fn from_days_from_monday(days: u8) -> Self {
+        match days {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            6 => Weekday::Sunday,
+            _ => panic!("Invalid number of days from Monday: {days}"),
+        }
+    }
+
+    /// The number of days since Monday, that is, 0 for Monday and 6 for Sunday.
+    pub(crate) fn days_from_monday(self) -> u8 {
+        match self {
+            Weekday::Monday => 0,
+            Weekday::Tuesday => 1,
+            Weekday::Wednesday => 2,
+            Weekday::Thursday => 3,
+            Weekday::Friday => 4,
+            Weekday::Saturday => 5,
+            Weekday::Sunday => 6,
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Weekday::Monday => write!(f, "MO"),
+            Weekday::Tuesday => write!(f, "TU"),
+            Weekday::Wednesday => write!(f, "WE"),
+            Weekday::Thursday => write!(f, "TH"),
+            Weekday::Friday => write!(f, "FR"),
+            Weekday::Saturday => write!(f, "SA"),
+            Weekday::Sunday => write!(f, "SU"),
+        }
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "MO" => Ok(Weekday::Monday),
+            "TU" => Ok(Weekday::Tuesday),
+            "WE" => Ok(Weekday::Wednesday),
+            "TH" => Ok(Weekday::Thursday),
+            "FR" => Ok(Weekday::Friday),
+            "SA" => Ok(Weekday::Saturday),
+            "SU" => Ok(Weekday::Sunday),
+            _ => Err(()),
+        }
+    }
+}
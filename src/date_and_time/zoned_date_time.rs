@@ -0,0 +1,91 @@
+use {crate::DateTime, ical_vcard::ParamValue};
+
+/// A date-time in local time together with the identifier of its time zone.
+///
+/// This corresponds to a `DATE-TIME` value with a `TZID` parameter, as described in
+/// [RFC 5545 section 3.3.5](https://tools.ietf.org/html/rfc5545#section-3.3.5) (form #3) and
+/// [RFC 5545 section 3.2.19](https://tools.ietf.org/html/rfc5545#section-3.2.19).
+///
+/// ```
+/// use ical::{Date, DateTime, Time, ZonedDateTime};
+///
+/// let start = ZonedDateTime::new(
+///     DateTime {
+///         date: Date::new(2024, 7, 5),
+///         time: Time::new_local(9, 0, 0),
+///     },
+///     "Europe/Zurich",
+/// );
+/// assert_eq!(start.time_zone(), "Europe/Zurich");
+/// ```
+///
+/// The time zone identifier is not checked against any time zone database.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonedDateTime {
+    date_time: DateTime,
+    time_zone: ParamValue<String>,
+}
+
+impl ZonedDateTime {
+    /// Create a new `ZonedDateTime`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in any of the following cases:
+    /// - The time of `date_time` is a UTC time. RFC 5545 does not allow UTC times to have a time
+    ///   zone identifier.
+    /// - `time_zone` is not a valid [`ParamValue`].
+    #[must_use]
+    pub fn new<S: Into<String>>(date_time: DateTime, time_zone: S) -> Self {
+        assert!(
+            !date_time.time.is_utc(),
+            "A date-time with a time zone must be in local time"
+        );
+        Self {
+            date_time,
+            time_zone: ParamValue::new(time_zone.into()).unwrap_or_else(|err| {
+                panic!("Invalid time zone identifier: {err}");
+            }),
+        }
+    }
+
+    /// Get the date-time in local time.
+    #[must_use]
+    pub fn date_time(&self) -> DateTime {
+        self.date_time
+    }
+
+    /// Get the time zone identifier.
+    #[must_use]
+    pub fn time_zone(&self) -> &str {
+        self.time_zone.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::ZonedDateTime,
+        crate::{Date, DateTime, Time},
+    };
+
+    #[test]
+    #[should_panic(expected = "A date-time with a time zone must be in local time")]
+    fn utc_time() {
+        let date_time = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(9, 0, 0),
+        };
+        let _ = ZonedDateTime::new(date_time, "Europe/Zurich");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid time zone identifier")]
+    fn invalid_time_zone() {
+        let date_time = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let _ = ZonedDateTime::new(date_time, "Europe/\"Zurich\"");
+    }
+}
@@ -0,0 +1,260 @@
+use {
+    crate::{Alarm, Calendar, Component, DateTime, value, write_to_string},
+    std::{
+        collections::HashMap,
+        io::{self, Write},
+        mem,
+    },
+};
+
+/// A source of the current date and time, such as the system clock.
+pub trait Clock {
+    /// The current date and time in UTC time.
+    fn now_utc(&mut self) -> DateTime;
+}
+
+/// A clock that always returns the same date-time.
+///
+/// This is useful to get reproducible `DTSTAMP` properties, see
+/// [`Calendar::write_deterministic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime);
+
+impl Clock for FixedClock {
+    fn now_utc(&mut self) -> DateTime {
+        self.0
+    }
+}
+
+/// A source of unique identifiers for the `UID` property.
+pub trait UidGenerator {
+    /// Generate a new unique identifier.
+    fn generate_uid(&mut self) -> String;
+}
+
+/// Generates the identifiers `1@<domain>`, `2@<domain>`, `3@<domain>`, ...
+///
+/// This is useful to get reproducible `UID` properties, see [`Calendar::write_deterministic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequentialUids {
+    domain: String,
+    next: u64,
+}
+
+impl SequentialUids {
+    /// Create a new [`SequentialUids`] generator, starting with `1@<domain>`.
+    #[must_use]
+    pub fn new<S: Into<String>>(domain: S) -> Self {
+        Self {
+            domain: domain.into(),
+            next: 1,
+        }
+    }
+}
+
+impl UidGenerator for SequentialUids {
+    fn generate_uid(&mut self) -> String {
+        let uid = format!("{}@{}", self.next, self.domain);
+        self.next += 1;
+        uid
+    }
+}
+
+impl Calendar {
+    /// Write the calendar such that calendars with the same content always produce
+    /// byte-identical output, for example for snapshot tests.
+    ///
+    /// The output differs from [`Calendar::write`] in the following ways:
+    /// - The `DTSTAMP` of every event is set to the date-time returned by `clock`.
+    /// - The `UID` of every event is replaced with an identifier generated by `uids`. Events
+    ///   that share a `UID`, such as the instances of a recurring event that are modified with
+    ///   `RECURRENCE-ID`, still share it afterwards.
+    /// - The additional properties are sorted by name and value.
+    /// - The components are sorted by their content.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, FixedClock, SequentialUids, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let write = |summaries: &[&str]| {
+    ///     let mut calendar = Calendar::new();
+    ///     for summary in summaries {
+    ///         let mut event = Event::with_uid(*summary, Date::new(2024, 7, 5).into(), stamp);
+    ///         event.set_summary(*summary);
+    ///         calendar.add_component(event);
+    ///     }
+    ///     let mut output = Vec::new();
+    ///     calendar
+    ///         .write_deterministic(
+    ///             &mut output,
+    ///             &mut FixedClock(stamp),
+    ///             &mut SequentialUids::new("example.com"),
+    ///         )
+    ///         .unwrap();
+    ///     output
+    /// };
+    /// assert_eq!(write(&["Lunch", "Dinner"]), write(&["Dinner", "Lunch"]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`], and an error of kind
+    /// [`io::ErrorKind::InvalidInput`] wrapping an
+    /// [`InvalidValueError`](crate::InvalidValueError) if a generated `UID` is not a valid
+    /// value.
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn write_deterministic<W, C, G>(
+        &self,
+        writer: W,
+        clock: &mut C,
+        uids: &mut G,
+    ) -> io::Result<()>
+    where
+        W: Write,
+        C: Clock + ?Sized,
+        G: UidGenerator + ?Sized,
+    {
+        self.validate_before_write()?;
+        let invalid_uid = |err| io::Error::new(io::ErrorKind::InvalidInput, err);
+        let stamp = clock.now_utc();
+        let blank_uid = value("UID", String::new()).expect("The empty string is a valid value");
+
+        let mut calendar = self.clone();
+        calendar.properties.sort_by(|a, b| {
+            (a.name.as_str(), a.value.as_str()).cmp(&(b.name.as_str(), b.value.as_str()))
+        });
+
+        // The components are sorted by their content without their UIDs, so that neither the
+        // order of the components nor the generated UIDs depend on the original UIDs.
+        let mut components: Vec<_> = calendar
+            .components
+            .drain(..)
+            .map(|mut component| {
+                let Component::Event(event) = &mut component;
+                event.date_time = stamp;
+                let uid = mem::replace(&mut event.uid, blank_uid.clone());
+                if let Some(location) = &mut event.structured_location {
+                    location.set_uid(blank_uid.clone());
+                }
+                for alarm in &mut event.alarms {
+                    for location in alarm.locations_mut() {
+                        location.set_uid(blank_uid.clone());
+                    }
+                }
+                let content = write_to_string(|writer| component.write(writer));
+                (content, uid.as_str().to_owned(), component)
+            })
+            .collect();
+
+        // Components that share a UID are grouped, and the groups are ordered by the content of
+        // their components.
+        let mut groups: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (content, uid, _) in &components {
+            groups.entry(uid).or_default().push(content);
+        }
+        let mut groups: Vec<_> = groups
+            .into_iter()
+            .map(|(uid, mut contents)| {
+                contents.sort_unstable();
+                (contents, uid)
+            })
+            .collect();
+        groups.sort_unstable();
+        let generated: HashMap<String, (usize, String)> = groups
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (_, uid))| (uid.to_owned(), (rank, uids.generate_uid())))
+            .collect();
+        components.sort_by(|(a, a_uid, _), (b, b_uid, _)| {
+            a.cmp(b)
+                .then_with(|| generated[a_uid].0.cmp(&generated[b_uid].0))
+        });
+
+        for (_, uid, mut component) in components {
+            let uid = generated[&uid].1.clone();
+            let Component::Event(event) = &mut component;
+            if let Some(location) = &mut event.structured_location {
+                location.set_uid(value("UID", format!("{uid}-location")).map_err(invalid_uid)?);
+            }
+            let alarm_locations = event.alarms.iter_mut().flat_map(Alarm::locations_mut);
+            for (index, location) in alarm_locations.enumerate() {
+                let location_uid = format!("{uid}-alarm-location-{}", index + 1);
+                location.set_uid(value("UID", location_uid).map_err(invalid_uid)?);
+            }
+            event.uid = value("UID", uid).map_err(invalid_uid)?;
+            calendar.components.push(component);
+        }
+        calendar.write_contents(&mut ical_vcard::Writer::new(writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{FixedClock, SequentialUids},
+        crate::{Calendar, Date, DateTime, Event, Time},
+    };
+
+    fn stamp(hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    fn write(calendar: &Calendar) -> String {
+        let mut output = Vec::new();
+        calendar
+            .write_deterministic(
+                &mut output,
+                &mut FixedClock(stamp(12)),
+                &mut SequentialUids::new("example.com"),
+            )
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn uids_and_stamps() {
+        let start = Date::new(2024, 7, 5);
+        let mut modified = Event::with_uid("a", start.into(), stamp(9));
+        modified.set_recurrence_id(start);
+        let events = [
+            Event::with_uid("b", start.into(), stamp(8)),
+            modified,
+            Event::with_uid("a", start.into(), stamp(10)),
+        ];
+        let mut calendar = Calendar::new();
+        for event in events.clone() {
+            calendar.add_component(event);
+        }
+        let output = write(&calendar);
+        assert!(!output.contains("UID:a\r\n") && !output.contains("UID:b\r\n"));
+        assert_eq!(output.matches("UID:1@example.com\r\n").count(), 1);
+        assert_eq!(output.matches("UID:2@example.com\r\n").count(), 2);
+        assert_eq!(output.matches("DTSTAMP:20240701T120000Z\r\n").count(), 3);
+
+        let mut reversed = Calendar::new();
+        for event in events.into_iter().rev() {
+            reversed.add_component(event);
+        }
+        assert_eq!(write(&reversed), output);
+    }
+
+    #[test]
+    fn property_order() {
+        let mut first = Calendar::new();
+        first
+            .add_property("X-B", "1", &[])
+            .add_property("X-A", "2", &[]);
+        let mut second = Calendar::new();
+        second
+            .add_property("X-A", "2", &[])
+            .add_property("X-B", "1", &[]);
+        assert_eq!(write(&first), write(&second));
+        assert!(write(&first).contains("X-A:2\r\nX-B:1\r\n"));
+    }
+}
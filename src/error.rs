@@ -0,0 +1,417 @@
+#[cfg(feature = "serde_json")]
+use crate::{FromJcalError, FromJscalendarError};
+use {
+    crate::{
+        ApplyItipError, ConversionError, DiffTextError, InvalidDateError, InvalidTimeError,
+        ItipReplyError, ItipRequestError, MissingTimeZoneError, ParseCalendarError, ParseDateError,
+        ParseDateTimeError, ParseDurationError, ParseRecurrenceRuleError, ParseTimeError,
+        ParseTzifError, ParseUtcOffsetError, RuleError, ValidationIssue,
+    },
+    ical_vcard::{ParamValue, Value},
+    std::{
+        error,
+        fmt::{self, Display, Formatter},
+        io,
+    },
+};
+
+/// Any error produced by this crate.
+///
+/// Every error type of this crate, as well as [`io::Error`], converts into this type, so
+/// applications can use `?` uniformly.
+///
+/// ```
+/// use ical::{Date, DateTime, Event, RecurrenceRule};
+///
+/// fn parse_event(start: &str, rule: &str) -> Result<Event, ical::Error> {
+///     let start: DateTime = start.parse()?;
+///     let mut event = Event::with_uid("1@example.com", start.into(), start);
+///     event.set_recurrence_rule(rule.parse::<RecurrenceRule>()?);
+///     event.try_set_summary("Stand-up")?;
+///     Ok(event)
+/// }
+///
+/// assert!(parse_event("20240101T090000Z", "FREQ=DAILY").is_ok());
+/// assert!(matches!(
+///     parse_event("20240101T090000Z", "FREQ=NEVER"),
+///     Err(ical::Error::ParseRecurrenceRule(_)),
+/// ));
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// A [`Date`](crate::Date) could not be parsed.
+    ParseDate(ParseDateError),
+    /// A [`Time`](crate::Time) could not be parsed.
+    ParseTime(ParseTimeError),
+    /// A [`DateTime`](crate::DateTime) could not be parsed.
+    ParseDateTime(ParseDateTimeError),
+    /// A [`Duration`](crate::Duration) could not be parsed.
+    ParseDuration(ParseDurationError),
+    /// A [`UtcOffset`](crate::UtcOffset) could not be parsed.
+    ParseUtcOffset(ParseUtcOffsetError),
+    /// A [`RecurrenceRule`](crate::RecurrenceRule) could not be parsed.
+    ParseRecurrenceRule(ParseRecurrenceRuleError),
+    /// A value could not be converted from or to a type of another crate.
+    Conversion(ConversionError),
+    /// The values do not form a valid [`Date`](crate::Date).
+    InvalidDate(InvalidDateError),
+    /// The values do not form a valid [`Time`](crate::Time).
+    InvalidTime(InvalidTimeError),
+    /// A text is not a valid property or parameter value.
+    InvalidValue(InvalidValueError),
+    /// A text contains a character that is not allowed in a property value.
+    InvalidText(InvalidTextError),
+    /// An additional property could not be added to a [`Calendar`](crate::Calendar).
+    AddProperty(AddPropertyError),
+    /// A [`Calendar`](crate::Calendar) did not pass
+    /// [`Calendar::validate`](crate::Calendar::validate).
+    Validation(Vec<ValidationIssue>),
+    /// A [`RecurrenceRule`](crate::RecurrenceRule) violates RFC 5545.
+    Rule(RuleError),
+    /// Two iCalendar texts could not be compared.
+    DiffText(DiffTextError),
+    /// An iTIP request could not be created.
+    ItipRequest(ItipRequestError),
+    /// An iTIP reply could not be created.
+    ItipReply(ItipReplyError),
+    /// An iTIP message could not be applied to a calendar.
+    ApplyItip(ApplyItipError),
+    /// A calendar could not be read from the iCalendar format.
+    ParseCalendar(ParseCalendarError),
+    /// An event refers to a time zone that is not defined.
+    MissingTimeZone(MissingTimeZoneError),
+    /// A time zone could not be read from data in the Time Zone Information Format.
+    ParseTzif(ParseTzifError),
+    /// A calendar could not be read from jCal.
+    #[cfg(feature = "serde_json")]
+    FromJcal(FromJcalError),
+    /// An event or a calendar could not be read from JSCalendar.
+    #[cfg(feature = "serde_json")]
+    FromJscalendar(FromJscalendarError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {err}"),
+            Error::ParseDate(err) => err.fmt(f),
+            Error::ParseTime(err) => err.fmt(f),
+            Error::ParseDateTime(err) => err.fmt(f),
+            Error::ParseDuration(err) => err.fmt(f),
+            Error::ParseUtcOffset(err) => err.fmt(f),
+            Error::ParseRecurrenceRule(err) => err.fmt(f),
+            Error::Conversion(err) => err.fmt(f),
+            Error::InvalidDate(err) => err.fmt(f),
+            Error::InvalidTime(err) => err.fmt(f),
+            Error::InvalidValue(err) => err.fmt(f),
+            Error::InvalidText(err) => err.fmt(f),
+            Error::AddProperty(err) => err.fmt(f),
+            Error::Validation(issues) => {
+                write!(f, "Invalid calendar")?;
+                for (index, issue) in issues.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { "; " };
+                    write!(f, "{separator}{issue}")?;
+                }
+                Ok(())
+            }
+            Error::Rule(err) => err.fmt(f),
+            Error::DiffText(err) => err.fmt(f),
+            Error::ItipRequest(err) => err.fmt(f),
+            Error::ItipReply(err) => err.fmt(f),
+            Error::ApplyItip(err) => err.fmt(f),
+            Error::ParseCalendar(err) => err.fmt(f),
+            Error::MissingTimeZone(err) => err.fmt(f),
+            Error::ParseTzif(err) => err.fmt(f),
+            #[cfg(feature = "serde_json")]
+            Error::FromJcal(err) => err.fmt(f),
+            #[cfg(feature = "serde_json")]
+            Error::FromJscalendar(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::ParseDate(err) => Some(err),
+            Error::ParseTime(err) => Some(err),
+            Error::ParseDateTime(err) => Some(err),
+            Error::ParseDuration(err) => Some(err),
+            Error::ParseUtcOffset(err) => Some(err),
+            Error::ParseRecurrenceRule(err) => Some(err),
+            Error::Conversion(err) => Some(err),
+            Error::InvalidDate(err) => Some(err),
+            Error::InvalidTime(err) => Some(err),
+            Error::InvalidValue(err) => Some(err),
+            Error::InvalidText(err) => Some(err),
+            Error::AddProperty(err) => Some(err),
+            Error::Validation(issues) => issues.first().map(|issue| issue as _),
+            Error::Rule(err) => Some(err),
+            Error::DiffText(err) => Some(err),
+            Error::ItipRequest(err) => Some(err),
+            Error::ItipReply(err) => Some(err),
+            Error::ApplyItip(err) => Some(err),
+            Error::ParseCalendar(err) => Some(err),
+            Error::MissingTimeZone(err) => Some(err),
+            Error::ParseTzif(err) => Some(err),
+            #[cfg(feature = "serde_json")]
+            Error::FromJcal(err) => Some(err),
+            #[cfg(feature = "serde_json")]
+            Error::FromJscalendar(err) => Some(err),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($(#[$attr:meta])* $variant:ident($error:ty)),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl From<$error> for Error {
+                fn from(err: $error) -> Self {
+                    Error::$variant(err)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    Io(io::Error),
+    ParseDate(ParseDateError),
+    ParseTime(ParseTimeError),
+    ParseDateTime(ParseDateTimeError),
+    ParseDuration(ParseDurationError),
+    ParseUtcOffset(ParseUtcOffsetError),
+    ParseRecurrenceRule(ParseRecurrenceRuleError),
+    Conversion(ConversionError),
+    InvalidDate(InvalidDateError),
+    InvalidTime(InvalidTimeError),
+    InvalidValue(InvalidValueError),
+    InvalidText(InvalidTextError),
+    AddProperty(AddPropertyError),
+    Validation(Vec<ValidationIssue>),
+    Rule(RuleError),
+    DiffText(DiffTextError),
+    ItipRequest(ItipRequestError),
+    ItipReply(ItipReplyError),
+    ApplyItip(ApplyItipError),
+    ParseCalendar(ParseCalendarError),
+    MissingTimeZone(MissingTimeZoneError),
+    ParseTzif(ParseTzifError),
+    #[cfg(feature = "serde_json")]
+    FromJcal(FromJcalError),
+    #[cfg(feature = "serde_json")]
+    FromJscalendar(FromJscalendarError),
+);
+
+/// Error type for a text that is not a valid property or parameter value, for example because it
+/// contains control characters.
+///
+/// Returned by the `try_*` setters, such as [`Event::try_set_description`](crate::Event::try_set_description).
+/// The character that is not allowed is available through [`InvalidValueError::text_error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidValueError {
+    what: &'static str,
+    text_error: InvalidTextError,
+}
+
+impl InvalidValueError {
+    pub(crate) fn new(what: &'static str, text_error: InvalidTextError) -> Self {
+        Self { what, text_error }
+    }
+
+    /// A short description of the value that is invalid, such as `"description"`.
+    #[must_use]
+    pub fn what(&self) -> &'static str {
+        self.what
+    }
+
+    /// The character that makes the value invalid.
+    #[must_use]
+    pub fn text_error(&self) -> InvalidTextError {
+        self.text_error
+    }
+}
+
+impl Display for InvalidValueError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid {}: character {:?} at index {} is not allowed",
+            self.what, self.text_error.character, self.text_error.index
+        )
+    }
+}
+
+impl error::Error for InvalidValueError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.text_error)
+    }
+}
+
+/// Error type for a character that is not allowed in a property or parameter value, such as a
+/// control character other than a horizontal tab.
+///
+/// This allows callers to remove or replace the character and try again.
+///
+/// ```
+/// use ical::{Date, DateTime, Event, Time};
+///
+/// let date_time = DateTime {
+///     date: Date::new(2024, 1, 1),
+///     time: Time::new_utc(9, 0, 0),
+/// };
+/// let mut event = Event::with_uid("1@example.com", date_time.into(), date_time);
+/// let err = event.try_set_summary("Café\u{7}").unwrap_err().text_error();
+/// assert_eq!(err.character(), '\u{7}');
+/// assert_eq!(err.index(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTextError {
+    index: usize,
+    character: char,
+}
+
+impl InvalidTextError {
+    /// Find the first character of `text` that is not allowed in a property value, if any.
+    pub(crate) fn find(text: &str) -> Option<Self> {
+        Self::find_with(text, |character| Value::new(character).is_err())
+    }
+
+    /// Find the first character of `text` that is not allowed in a parameter value, if any.
+    pub(crate) fn find_in_param(text: &str) -> Option<Self> {
+        Self::find_with(text, |character| ParamValue::new(character).is_err())
+    }
+
+    fn find_with(text: &str, is_invalid: impl Fn(&str) -> bool) -> Option<Self> {
+        let mut buffer = [0; 4];
+        text.char_indices()
+            .find(|(_, character)| is_invalid(character.encode_utf8(&mut buffer)))
+            .map(|(index, character)| Self { index, character })
+    }
+
+    /// The byte index of the character in the text.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The character that is not allowed.
+    #[must_use]
+    pub fn character(&self) -> char {
+        self.character
+    }
+}
+
+impl Display for InvalidTextError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Character {:?} at index {} is not allowed in a property value",
+            self.character, self.index
+        )
+    }
+}
+
+impl error::Error for InvalidTextError {}
+
+/// Error type for [`Calendar::try_add_property`](crate::Calendar::try_add_property).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddPropertyError {
+    /// The name is not a valid property name.
+    InvalidName {
+        /// The name of the property.
+        name: String,
+    },
+    /// The property is managed by this crate, such as `PRODID` or `IMAGE`.
+    ManagedProperty {
+        /// The name of the property.
+        name: String,
+    },
+    /// The value is not a valid property value.
+    InvalidValue(InvalidValueError),
+    /// The name or the value of a parameter is invalid.
+    InvalidParam {
+        /// The name of the parameter.
+        name: String,
+    },
+}
+
+impl Display for AddPropertyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AddPropertyError::InvalidName { name } => write!(f, "Invalid property name: {name:?}"),
+            AddPropertyError::ManagedProperty { name } => {
+                write!(f, "The {name} property is managed by this crate")
+            }
+            AddPropertyError::InvalidValue(err) => err.fmt(f),
+            AddPropertyError::InvalidParam { name } => write!(f, "Invalid parameter: {name:?}"),
+        }
+    }
+}
+
+impl error::Error for AddPropertyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AddPropertyError::InvalidValue(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Error,
+        crate::{Date, DateTime, Event, InvalidDateError, InvalidTextError, ParseDateError, Time},
+        std::{error::Error as _, io},
+    };
+
+    #[test]
+    fn from() {
+        let err = Error::from("2024013".parse::<Date>().unwrap_err());
+        assert!(matches!(err, Error::ParseDate(ParseDateError::Length(7))));
+        assert!(err.source().is_some());
+
+        let err = Error::from(Date::try_new(2024, 2, 30).unwrap_err());
+        assert!(matches!(
+            err,
+            Error::InvalidDate(InvalidDateError::Day { .. })
+        ));
+        assert_eq!(err.to_string(), "Day (30) out of range for month (2)");
+
+        let err = Error::from(io::Error::other("disk full"));
+        assert_eq!(err.to_string(), "I/O error: disk full");
+    }
+
+    #[test]
+    fn invalid_text() {
+        let date_time = DateTime {
+            date: Date::new(2024, 1, 1),
+            time: Time::new_utc(9, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", date_time.into(), date_time);
+        assert_eq!(InvalidTextError::find("Tab\tis fine"), None);
+
+        let err = event.try_set_location("Zürich\r\nHB").unwrap_err();
+        assert_eq!(err.what(), "location");
+        assert_eq!(err.text_error().index(), 7);
+        assert_eq!(err.text_error().character(), '\r');
+        assert_eq!(
+            err.to_string(),
+            "Invalid location: character '\\r' at index 7 is not allowed"
+        );
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            "Character '\\r' at index 7 is not allowed in a property value"
+        );
+
+        let err = Error::from(err.text_error());
+        assert!(matches!(err, Error::InvalidText(_)));
+        assert!(err.source().is_some());
+    }
+}
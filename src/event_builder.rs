@@ -0,0 +1,367 @@
+use crate::{
+    Attendee, Conference, CssColorName, DateOrDateTime, DateTime, Event, EventStatus,
+    InvalidValueError, Organizer, RecurrenceRule, StartDateTime,
+};
+
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+/// Marks a required property of an [`EventBuilder`] that has not been set yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unset;
+
+/// A builder for [`Event`]s that checks at compile time that all required properties are set.
+///
+/// The required properties are the start (`DTSTART`), the date-time stamp (`DTSTAMP`) and the
+/// unique identifier (`UID`). Their types take the place of [`Unset`] in the type of the
+/// builder as they are set, and [`EventBuilder::build`] is only available once all of them are
+/// set. Unlike the setters of [`Event`], the setters of this builder accept any value. All
+/// values are checked by [`EventBuilder::build`].
+///
+/// ```
+/// use ical::{Date, DateTime, Event, Time};
+///
+/// let stamp = DateTime {
+///     date: Date::new(2024, 7, 1),
+///     time: Time::new_utc(12, 0, 0),
+/// };
+/// let event = Event::builder()
+///     .start(Date::new(2024, 7, 5))
+///     .stamp(stamp)
+///     .uid("1@example.com")
+///     .summary("Lunch")
+///     .build()
+///     .unwrap();
+/// assert_eq!(event.uid(), "1@example.com");
+///
+/// let err = Event::builder()
+///     .start(Date::new(2024, 7, 5))
+///     .stamp(stamp)
+///     .uid("1@example.com")
+///     .summary("Lunch\u{7}")
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid summary: character '\\u{7}' at index 5 is not allowed"
+/// );
+/// ```
+///
+/// Forgetting a required property is a compile error:
+///
+/// ```compile_fail
+/// use ical::{Date, Event};
+///
+/// let event = Event::builder()
+///     .start(Date::new(2024, 7, 5))
+///     .uid("1@example.com")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventBuilder<Start = Unset, Stamp = Unset, Uid = Unset> {
+    start: Start,
+    stamp: Stamp,
+    uid: Uid,
+    optional: OptionalProperties,
+}
+
+/// The optional properties of an [`EventBuilder`].
+#[derive(Debug, Clone, Default)]
+struct OptionalProperties {
+    end: Option<DateOrDateTime>,
+    recurrence_id: Option<DateOrDateTime>,
+    sequence: u32,
+    status: Option<EventStatus>,
+    description: Option<String>,
+    location: Option<String>,
+    summary: Option<String>,
+    organizer: Option<Organizer>,
+    attendees: Vec<Attendee>,
+    recurrence_rule: Option<RecurrenceRule>,
+    exception_rule: Option<RecurrenceRule>,
+    recurrence_dates: Vec<DateOrDateTime>,
+    exception_dates: Vec<DateOrDateTime>,
+    conferences: Vec<Conference>,
+    color: Option<CssColorName>,
+}
+
+impl EventBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Unset,
+            stamp: Unset,
+            uid: Unset,
+            optional: OptionalProperties::default(),
+        }
+    }
+}
+
+impl<Start, Stamp, Uid> EventBuilder<Start, Stamp, Uid> {
+    /// Set the start of the event (`DTSTART`).
+    #[must_use]
+    pub fn start<S: Into<StartDateTime>>(
+        self,
+        start: S,
+    ) -> EventBuilder<StartDateTime, Stamp, Uid> {
+        EventBuilder {
+            start: start.into(),
+            stamp: self.stamp,
+            uid: self.uid,
+            optional: self.optional,
+        }
+    }
+
+    /// Set the date-time at which the event was created or last modified (`DTSTAMP`).
+    #[must_use]
+    pub fn stamp(self, stamp: DateTime) -> EventBuilder<Start, DateTime, Uid> {
+        EventBuilder {
+            start: self.start,
+            stamp,
+            uid: self.uid,
+            optional: self.optional,
+        }
+    }
+
+    /// Set the date-time stamp (`DTSTAMP`) to the current date and time.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn stamp_now(self) -> EventBuilder<Start, DateTime, Uid> {
+        self.stamp(DateTime::now_utc())
+    }
+
+    /// Set the unique identifier of the event (`UID`), see [`Event::with_uid`].
+    #[must_use]
+    pub fn uid<S: Into<String>>(self, uid: S) -> EventBuilder<Start, Stamp, String> {
+        EventBuilder {
+            start: self.start,
+            stamp: self.stamp,
+            uid: uid.into(),
+            optional: self.optional,
+        }
+    }
+
+    /// Set the unique identifier of the event (`UID`) to a random UUID (v4).
+    ///
+    /// Requires the `uuid` feature, which is enabled by default.
+    #[cfg(feature = "uuid")]
+    #[must_use]
+    pub fn random_uid(self) -> EventBuilder<Start, Stamp, String> {
+        self.uid(Uuid::new_v4().to_string())
+    }
+
+    /// Set the end of the event (`DTEND`), see [`Event::set_end`].
+    #[must_use]
+    pub fn end<D: Into<DateOrDateTime>>(mut self, end: D) -> Self {
+        self.optional.end = Some(end.into());
+        self
+    }
+
+    /// Set the recurrence identifier of the event (`RECURRENCE-ID`), see
+    /// [`Event::set_recurrence_id`].
+    #[must_use]
+    pub fn recurrence_id<D: Into<DateOrDateTime>>(mut self, recurrence_id: D) -> Self {
+        self.optional.recurrence_id = Some(recurrence_id.into());
+        self
+    }
+
+    /// Set the revision number of the event (`SEQUENCE`), see [`Event::set_sequence`].
+    #[must_use]
+    pub fn sequence(mut self, sequence: u32) -> Self {
+        self.optional.sequence = sequence;
+        self
+    }
+
+    /// Set the status of the event (`STATUS`).
+    #[must_use]
+    pub fn status(mut self, status: EventStatus) -> Self {
+        self.optional.status = Some(status);
+        self
+    }
+
+    /// Set the description of the event (`DESCRIPTION`).
+    #[must_use]
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.optional.description = Some(description.into());
+        self
+    }
+
+    /// Set the location of the event (`LOCATION`).
+    #[must_use]
+    pub fn location<S: Into<String>>(mut self, location: S) -> Self {
+        self.optional.location = Some(location.into());
+        self
+    }
+
+    /// Set the summary of the event (`SUMMARY`).
+    #[must_use]
+    pub fn summary<S: Into<String>>(mut self, summary: S) -> Self {
+        self.optional.summary = Some(summary.into());
+        self
+    }
+
+    /// Set the organizer of the event (`ORGANIZER`).
+    #[must_use]
+    pub fn organizer(mut self, organizer: Organizer) -> Self {
+        self.optional.organizer = Some(organizer);
+        self
+    }
+
+    /// Add an attendee to the event (`ATTENDEE`).
+    #[must_use]
+    pub fn attendee(mut self, attendee: Attendee) -> Self {
+        self.optional.attendees.push(attendee);
+        self
+    }
+
+    /// Set the recurrence rule of the event (`RRULE`).
+    #[must_use]
+    pub fn recurrence_rule(mut self, recurrence_rule: RecurrenceRule) -> Self {
+        self.optional.recurrence_rule = Some(recurrence_rule);
+        self
+    }
+
+    /// Set the exception rule of the event (`EXRULE`), see [`Event::set_exception_rule`].
+    #[must_use]
+    pub fn exception_rule(mut self, exception_rule: RecurrenceRule) -> Self {
+        self.optional.exception_rule = Some(exception_rule);
+        self
+    }
+
+    /// Add a recurrence date to the event (`RDATE`), see [`Event::add_recurrence_date`].
+    #[must_use]
+    pub fn recurrence_date<D: Into<DateOrDateTime>>(mut self, date: D) -> Self {
+        self.optional.recurrence_dates.push(date.into());
+        self
+    }
+
+    /// Add an exception date to the event (`EXDATE`), see [`Event::add_exception_date`].
+    #[must_use]
+    pub fn exception_date<D: Into<DateOrDateTime>>(mut self, date: D) -> Self {
+        self.optional.exception_dates.push(date.into());
+        self
+    }
+
+    /// Add a conference to the event (`CONFERENCE`).
+    #[must_use]
+    pub fn conference(mut self, conference: Conference) -> Self {
+        self.optional.conferences.push(conference);
+        self
+    }
+
+    /// Set the color of the event (`COLOR`).
+    #[must_use]
+    pub fn color(mut self, color: CssColorName) -> Self {
+        self.optional.color = Some(color);
+        self
+    }
+}
+
+impl EventBuilder<StartDateTime, DateTime, String> {
+    /// Build the [`Event`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `UID`, the description, the location or the summary is not a
+    /// valid [`Value`](ical_vcard::Value).
+    pub fn build(self) -> Result<Event, InvalidValueError> {
+        let optional = self.optional;
+        let mut event = Event::try_with_uid(self.uid, self.start, self.stamp)?;
+        if let Some(end) = optional.end {
+            event.set_end(end);
+        }
+        if let Some(recurrence_id) = optional.recurrence_id {
+            event.set_recurrence_id(recurrence_id);
+        }
+        event.set_sequence(optional.sequence);
+        if let Some(status) = optional.status {
+            event.set_status(status);
+        }
+        if let Some(description) = optional.description {
+            event.try_set_description(description)?;
+        }
+        if let Some(location) = optional.location {
+            event.try_set_location(location)?;
+        }
+        if let Some(summary) = optional.summary {
+            event.try_set_summary(summary)?;
+        }
+        if let Some(organizer) = optional.organizer {
+            event.set_organizer(organizer);
+        }
+        for attendee in optional.attendees {
+            event.add_attendee(attendee);
+        }
+        if let Some(recurrence_rule) = optional.recurrence_rule {
+            event.set_recurrence_rule(recurrence_rule);
+        }
+        if let Some(exception_rule) = optional.exception_rule {
+            event.set_exception_rule(exception_rule);
+        }
+        for date in optional.recurrence_dates {
+            event.add_recurrence_date(date);
+        }
+        for date in optional.exception_dates {
+            event.add_exception_date(date);
+        }
+        for conference in optional.conferences {
+            event.add_conference(conference);
+        }
+        if let Some(color) = optional.color {
+            event.set_color(color);
+        }
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+
+    fn stamp() -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        }
+    }
+
+    #[test]
+    fn build() {
+        // The order in which the properties are set does not matter.
+        let event = Event::builder()
+            .summary("Standup")
+            .uid("1@example.com")
+            .recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily))
+            .stamp(stamp())
+            .start(Date::new(2024, 7, 5))
+            .end(Date::new(2024, 7, 6))
+            .sequence(2)
+            .build()
+            .unwrap();
+
+        let mut expected = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp());
+        expected.set_summary("Standup");
+        expected.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        expected.set_end(Date::new(2024, 7, 6));
+        expected.set_sequence(2);
+        assert_eq!(event, expected);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let builder = Event::builder().start(Date::new(2024, 7, 5)).stamp(stamp());
+        let err = builder.clone().uid("1@example.com\n").build().unwrap_err();
+        assert_eq!(err.what(), "UID");
+        let err = builder
+            .uid("1@example.com")
+            .location("Room\u{0}1")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.what(), "location");
+    }
+}
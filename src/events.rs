@@ -0,0 +1,203 @@
+use {
+    crate::{Calendar, Component, Event},
+    std::{
+        iter::FusedIterator,
+        slice::{Iter, IterMut},
+    },
+};
+
+impl Calendar {
+    /// Iterate over the events of the calendar, skipping all other components.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, Weekday};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// for day in 1..=7 {
+    ///     let uid = format!("{day}@example.com");
+    ///     calendar.add_component(Event::with_uid(uid, Date::new(2024, 7, day).into(), stamp));
+    /// }
+    /// let weekend = calendar
+    ///     .events()
+    ///     .filter(|event| {
+    ///         let weekday = event.start().value().date().weekday();
+    ///         matches!(weekday, Weekday::Saturday | Weekday::Sunday)
+    ///     })
+    ///     .count();
+    /// assert_eq!(weekend, 2);
+    /// ```
+    #[must_use]
+    pub fn events(&self) -> Events<'_> {
+        Events {
+            components: self.components.iter(),
+        }
+    }
+
+    /// Iterate mutably over the events of the calendar, skipping all other components.
+    #[must_use]
+    pub fn events_mut(&mut self) -> EventsMut<'_> {
+        EventsMut {
+            components: self.components.iter_mut(),
+        }
+    }
+
+    /// Create a calendar that contains the given events.
+    ///
+    /// This is equivalent to [`Calendar::new`] followed by [`Calendar::extend_events`]. The
+    /// same is available through [`FromIterator`], that is, with [`Iterator::collect`].
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// // For example the result of a database query.
+    /// let rows = [(1, "Lunch"), (2, "Dinner")];
+    /// let calendar = Calendar::from_events(rows.into_iter().map(|(id, summary)| {
+    ///     let mut event =
+    ///         Event::with_uid(format!("{id}@example.com"), Date::new(2024, 7, 5).into(), stamp);
+    ///     event.set_summary(summary);
+    ///     event
+    /// }));
+    /// assert_eq!(calendar.events().count(), 2);
+    /// ```
+    #[must_use]
+    pub fn from_events<I: IntoIterator<Item = Event>>(events: I) -> Self {
+        let mut calendar = Self::new();
+        calendar.extend_events(events);
+        calendar
+    }
+
+    /// Add the given events to the calendar, after the existing components.
+    ///
+    /// Space for the events is reserved up front, based on the lower bound of the
+    /// [size hint](Iterator::size_hint) of the iterator. The same is available through
+    /// [`Extend`].
+    pub fn extend_events<I: IntoIterator<Item = Event>>(&mut self, events: I) -> &mut Self {
+        let events = events.into_iter();
+        self.components.reserve(events.size_hint().0);
+        self.components.extend(events.map(Component::Event));
+        self
+    }
+
+    /// Iterate over the components of the calendar.
+    pub fn iter(&self) -> Iter<'_, Component> {
+        self.components.iter()
+    }
+
+    /// Iterate mutably over the components of the calendar.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Component> {
+        self.components.iter_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Calendar {
+    type Item = &'a Component;
+    type IntoIter = Iter<'a, Component>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Calendar {
+    type Item = &'a mut Component;
+    type IntoIter = IterMut<'a, Component>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+/// Adds the events to the calendar, see [`Calendar::extend_events`].
+impl Extend<Event> for Calendar {
+    fn extend<I: IntoIterator<Item = Event>>(&mut self, events: I) {
+        self.extend_events(events);
+    }
+}
+
+/// Creates a calendar that contains the events, see [`Calendar::from_events`].
+impl FromIterator<Event> for Calendar {
+    fn from_iter<I: IntoIterator<Item = Event>>(events: I) -> Self {
+        Self::from_events(events)
+    }
+}
+
+/// An iterator over the events of a [`Calendar`].
+///
+/// Created by [`Calendar::events`].
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    components: Iter<'a, Component>,
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = &'a Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.components.find_map(Component::as_event)
+    }
+}
+
+impl DoubleEndedIterator for Events<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.components.by_ref().rev().find_map(Component::as_event)
+    }
+}
+
+impl FusedIterator for Events<'_> {}
+
+/// A mutable iterator over the events of a [`Calendar`].
+///
+/// Created by [`Calendar::events_mut`].
+#[derive(Debug)]
+pub struct EventsMut<'a> {
+    components: IterMut<'a, Component>,
+}
+
+impl<'a> Iterator for EventsMut<'a> {
+    type Item = &'a mut Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.components.find_map(Component::as_event_mut)
+    }
+}
+
+impl DoubleEndedIterator for EventsMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.components
+            .by_ref()
+            .rev()
+            .find_map(Component::as_event_mut)
+    }
+}
+
+impl FusedIterator for EventsMut<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Calendar, Date, DateTime, Event, Time};
+
+    fn event(uid: &str) -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp)
+    }
+
+    #[test]
+    fn extend() {
+        let mut calendar: Calendar = ["a", "b"].into_iter().map(event).collect();
+        calendar.extend([event("c")]);
+        calendar.extend_events(Vec::new());
+        let uids: Vec<_> = calendar.events().map(Event::uid).collect();
+        assert_eq!(uids, ["a", "b", "c"]);
+    }
+}
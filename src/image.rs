@@ -0,0 +1,81 @@
+use {
+    crate::{InvalidValueError, param, value},
+    ical_vcard::{Contentline, Value},
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+/// Represents an `IMAGE` property as specified in
+/// [RFC 7986 section 5.10](https://tools.ietf.org/html/rfc7986#section-5.10).
+///
+/// The image is always referenced by URI, that is, it is written as `IMAGE;VALUE=URI`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Image {
+    uri: Value<String>,
+    display: Option<ImageDisplay>,
+}
+
+impl Image {
+    /// Create a new [`Image`], or return an error if `uri` is not a valid [`Value`].
+    pub(crate) fn try_new(
+        uri: String,
+        display: Option<ImageDisplay>,
+    ) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            uri: value("image URI", uri)?,
+            display,
+        })
+    }
+
+    /// Get the URI of the image.
+    #[cfg(feature = "serde_json")]
+    pub(crate) fn uri(&self) -> &str {
+        self.uri.as_str()
+    }
+
+    /// Get the display hint of the image.
+    #[cfg(feature = "serde_json")]
+    pub(crate) fn display(&self) -> Option<ImageDisplay> {
+        self.display
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        let mut contentline = Contentline::new("IMAGE", self.uri.as_str());
+        contentline.params.push(param("VALUE", "URI"));
+        if let Some(display) = self.display {
+            contentline
+                .params
+                .push(param("DISPLAY", &display.to_string()));
+        }
+        writer.write(&contentline)
+    }
+}
+
+/// The display hint of an image, corresponding to the `DISPLAY` parameter.
+///
+/// See [RFC 7986 section 6.1 - Display](https://tools.ietf.org/html/rfc7986#section-6.1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageDisplay {
+    /// The image is intended to be displayed inline next to the title of the calendar or
+    /// component.
+    Badge,
+    /// The image is intended to be displayed alongside the calendar or component data.
+    Graphic,
+    /// The image is intended to be displayed on its own, in full size.
+    Fullsize,
+    /// The image is a reduced size version of a larger image.
+    Thumbnail,
+}
+
+impl Display for ImageDisplay {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ImageDisplay::Badge => write!(f, "BADGE"),
+            ImageDisplay::Graphic => write!(f, "GRAPHIC"),
+            ImageDisplay::Fullsize => write!(f, "FULLSIZE"),
+            ImageDisplay::Thumbnail => write!(f, "THUMBNAIL"),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use {
+    crate::Calendar,
+    std::fmt::{self, Display, Formatter},
+};
+
+/// The maximum length of a line of base64 encoded text, see [RFC 2045 section 6.8 - Base64
+/// Content-Transfer-Encoding](https://tools.ietf.org/html/rfc2045#section-6.8).
+const BASE64_LINE_LENGTH: usize = 76;
+
+/// The alphabet of base64 encoding.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// How the body of an [`ImipPart`] is encoded (`Content-Transfer-Encoding`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransferEncoding {
+    /// Base64 encoding, which can be sent through any mail server.
+    #[default]
+    Base64,
+    /// No encoding, which requires mail servers that support 8-bit data.
+    EightBit,
+}
+
+impl Display for TransferEncoding {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TransferEncoding::Base64 => write!(f, "base64"),
+            TransferEncoding::EightBit => write!(f, "8bit"),
+        }
+    }
+}
+
+/// A MIME body part containing a calendar, for sending iTIP messages (see
+/// [`ItipRequest`](crate::ItipRequest)) by email as specified in [RFC 6047 -
+/// iMIP](https://tools.ietf.org/html/rfc6047).
+///
+/// The part has the media type `text/calendar` with the `method` parameter set to the method of
+/// the calendar, as required by RFC 6047, and the `UTF-8` charset. The [`Display`]
+/// implementation writes the headers and the encoded body, ready to be included in a
+/// `multipart` email. Libraries that build the email themselves, such as `lettre`, can use
+/// [`ImipPart::content_type`] and [`ImipPart::calendar`] instead.
+///
+/// Requires the `imip` feature.
+///
+/// ```
+/// use ical::{Attendee, Date, DateTime, Event, ImipPart, ItipRequest, Organizer, Time};
+///
+/// let stamp = DateTime {
+///     date: Date::new(2024, 7, 1),
+///     time: Time::new_utc(12, 0, 0),
+/// };
+/// let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+/// let organizer = Organizer::new("mailto:alice@example.com");
+/// let request =
+///     ItipRequest::new(event, organizer, [Attendee::new("mailto:bob@example.com")]).unwrap();
+///
+/// let mut part = ImipPart::new(request.calendar());
+/// part.set_filename("lunch.ics");
+/// assert_eq!(
+///     part.content_type(),
+///     "text/calendar; method=REQUEST; charset=UTF-8; name=\"lunch.ics\""
+/// );
+/// assert!(part.to_string().starts_with(
+///     "Content-Type: text/calendar; method=REQUEST; charset=UTF-8; name=\"lunch.ics\"\r\n\
+///      Content-Transfer-Encoding: base64\r\n\
+///      Content-Disposition: attachment; filename=\"lunch.ics\"\r\n\
+///      \r\n\
+///      QkVHSU46VkNBTEVOREFS"
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImipPart {
+    method: Option<String>,
+    calendar: String,
+    filename: String,
+    transfer_encoding: TransferEncoding,
+}
+
+impl ImipPart {
+    /// Create a body part containing `calendar`.
+    ///
+    /// The file name is `invite.ics` and the body is base64 encoded, unless they are changed
+    /// with [`ImipPart::set_filename`] and [`ImipPart::set_transfer_encoding`].
+    #[must_use]
+    pub fn new(calendar: &Calendar) -> Self {
+        Self {
+            method: calendar.method().map(|method| method.to_string()),
+            calendar: calendar.to_ics_string(),
+            filename: "invite.ics".to_owned(),
+            transfer_encoding: TransferEncoding::default(),
+        }
+    }
+
+    /// Set the file name under which email clients save the calendar.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `filename` is empty or contains a `"`, a `\` or a control character.
+    pub fn set_filename<S: Into<String>>(&mut self, filename: S) -> &mut Self {
+        let filename = filename.into();
+        assert!(
+            !filename.is_empty()
+                && !filename.contains(|c: char| c == '"' || c == '\\' || c.is_control()),
+            "Invalid file name: {filename:?}"
+        );
+        self.filename = filename;
+        self
+    }
+
+    /// Get the file name under which email clients save the calendar.
+    #[must_use]
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Set how the body is encoded.
+    pub fn set_transfer_encoding(&mut self, transfer_encoding: TransferEncoding) -> &mut Self {
+        self.transfer_encoding = transfer_encoding;
+        self
+    }
+
+    /// Get how the body is encoded.
+    #[must_use]
+    pub fn transfer_encoding(&self) -> TransferEncoding {
+        self.transfer_encoding
+    }
+
+    /// Get the value of the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> String {
+        let mut content_type = "text/calendar".to_owned();
+        if let Some(method) = &self.method {
+            content_type.push_str("; method=");
+            content_type.push_str(method);
+        }
+        content_type.push_str("; charset=UTF-8; name=\"");
+        content_type.push_str(&self.filename);
+        content_type.push('"');
+        content_type
+    }
+
+    /// Get the calendar as text, before it is encoded.
+    #[must_use]
+    pub fn calendar(&self) -> &str {
+        &self.calendar
+    }
+}
+
+/// Writes the headers, an empty line and the encoded body of the part.
+impl Display for ImipPart {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Content-Type: {}\r\n", self.content_type())?;
+        write!(
+            f,
+            "Content-Transfer-Encoding: {}\r\n",
+            self.transfer_encoding
+        )?;
+        write!(
+            f,
+            "Content-Disposition: attachment; filename=\"{}\"\r\n\r\n",
+            self.filename
+        )?;
+        match self.transfer_encoding {
+            TransferEncoding::Base64 => write_base64(f, self.calendar.as_bytes()),
+            TransferEncoding::EightBit => f.write_str(&self.calendar),
+        }
+    }
+}
+
+/// Write `bytes` base64 encoded, in lines of at most [`BASE64_LINE_LENGTH`] characters that are
+/// each terminated by CRLF.
+fn write_base64(f: &mut Formatter, bytes: &[u8]) -> fmt::Result {
+    let mut line_length = 0;
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |group, (index, &byte)| {
+                group | u32::from(byte) << (16 - 8 * index)
+            });
+        for index in 0..4 {
+            let character = if index <= chunk.len() {
+                BASE64_ALPHABET[(group >> (18 - 6 * index) & 0x3f) as usize]
+            } else {
+                b'='
+            };
+            write!(f, "{}", char::from(character))?;
+        }
+        line_length += 4;
+        if line_length == BASE64_LINE_LENGTH {
+            f.write_str("\r\n")?;
+            line_length = 0;
+        }
+    }
+    if line_length > 0 {
+        f.write_str("\r\n")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::write_base64,
+        std::fmt::{self, Display, Formatter},
+    };
+
+    struct Base64<'a>(&'a [u8]);
+
+    impl Display for Base64<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write_base64(f, self.0)
+        }
+    }
+
+    #[test]
+    fn base64() {
+        assert_eq!(Base64(b"").to_string(), "");
+        assert_eq!(Base64(b"f").to_string(), "Zg==\r\n");
+        assert_eq!(Base64(b"fo").to_string(), "Zm8=\r\n");
+        assert_eq!(Base64(b"foo").to_string(), "Zm9v\r\n");
+        assert_eq!(Base64(b"foobar").to_string(), "Zm9vYmFy\r\n");
+        let encoded = Base64(&[0xff; 60]).to_string();
+        let lines: Vec<_> = encoded.split_terminator("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "/".repeat(76));
+        assert_eq!(lines[1], "/".repeat(4));
+    }
+}
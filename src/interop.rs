@@ -0,0 +1,47 @@
+//! Conversions between the date and time types of this crate and those of other crates.
+//!
+//! The conversions from and to [`SystemTime`](std::time::SystemTime) are implemented next to
+//! [`DateTime`](crate::DateTime).
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+
+/// Error type for conversions between the date and time types of this crate and those of the
+/// standard library or other crates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The value is outside of the range supported by the target type, such as a date before
+    /// the year 0 or after the year 9999.
+    OutOfRange,
+    /// The date-time is in local time, so it does not refer to a unique point in time.
+    LocalTime,
+    /// The time zone is not in the time zone database, or it has no identifier.
+    UnknownTimeZone,
+    /// The local time is skipped or repeated because of a daylight saving time transition, see
+    /// `Disambiguation::Reject`.
+    Ambiguous,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ConversionError::OutOfRange => write!(f, "Value out of range"),
+            ConversionError::LocalTime => {
+                write!(f, "A date-time in local time is not a unique point in time")
+            }
+            ConversionError::UnknownTimeZone => write!(f, "Unknown time zone"),
+            ConversionError::Ambiguous => {
+                write!(f, "The local time is skipped or repeated in the time zone")
+            }
+        }
+    }
+}
+
+impl Error for ConversionError {}
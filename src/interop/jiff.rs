@@ -0,0 +1,175 @@
+//! Conversions from and to the types of the [`jiff`] crate.
+//!
+//! Dates and times convert losslessly into their `jiff` counterparts. The reverse conversions
+//! drop sub-second precision and fail if the year is outside of the range 0-9999.
+
+use {
+    super::ConversionError,
+    crate::{Date, DateTime, Time, ZonedDateTime},
+    jiff::{
+        Zoned, civil,
+        tz::{self, TimeZone},
+    },
+};
+
+impl From<Date> for civil::Date {
+    fn from(date: Date) -> Self {
+        let year = i16::try_from(date.year()).expect("Years are in the range 0-9999");
+        let month = i8::try_from(date.month()).expect("Months are in the range 1-12");
+        let day = i8::try_from(date.day()).expect("Days are in the range 1-31");
+        civil::Date::new(year, month, day).expect("Years 0-9999 are supported by jiff")
+    }
+}
+
+impl TryFrom<civil::Date> for Date {
+    type Error = ConversionError;
+
+    fn try_from(date: civil::Date) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date.year()).map_err(|_| ConversionError::OutOfRange)?;
+        let month = date.month().unsigned_abs();
+        Date::try_new(year, month, date.day().unsigned_abs())
+            .map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+/// Whether the time is in UTC time or local time is not preserved.
+impl From<Time> for civil::Time {
+    fn from(time: Time) -> Self {
+        let component = |value: u8| i8::try_from(value).expect("Time components are below 60");
+        civil::Time::constant(
+            component(time.hour()),
+            component(time.minute()),
+            component(time.second()),
+            0,
+        )
+    }
+}
+
+/// The result is in local time.
+impl From<civil::Time> for Time {
+    fn from(time: civil::Time) -> Self {
+        Time::new_local(
+            time.hour().unsigned_abs(),
+            time.minute().unsigned_abs(),
+            time.second().unsigned_abs(),
+        )
+    }
+}
+
+/// Whether the date-time is in UTC time or local time is not preserved.
+impl From<DateTime> for civil::DateTime {
+    fn from(date_time: DateTime) -> Self {
+        civil::DateTime::from_parts(date_time.date.into(), date_time.time.into())
+    }
+}
+
+/// The result is in local time.
+impl TryFrom<civil::DateTime> for DateTime {
+    type Error = ConversionError;
+
+    fn try_from(date_time: civil::DateTime) -> Result<Self, Self::Error> {
+        Ok(DateTime {
+            date: date_time.date().try_into()?,
+            time: date_time.time().into(),
+        })
+    }
+}
+
+/// The result is converted to UTC time.
+impl TryFrom<&Zoned> for DateTime {
+    type Error = ConversionError;
+
+    fn try_from(zoned: &Zoned) -> Result<Self, Self::Error> {
+        let mut date_time = DateTime::try_from(TimeZone::UTC.to_datetime(zoned.timestamp()))?;
+        date_time.time.set_utc(true);
+        Ok(date_time)
+    }
+}
+
+/// Only time zones with an IANA identifier, such as `Europe/Zurich`, can be converted, see
+/// [`ConversionError::UnknownTimeZone`].
+impl TryFrom<&Zoned> for ZonedDateTime {
+    type Error = ConversionError;
+
+    fn try_from(zoned: &Zoned) -> Result<Self, Self::Error> {
+        let time_zone = zoned
+            .time_zone()
+            .iana_name()
+            .ok_or(ConversionError::UnknownTimeZone)?;
+        Ok(ZonedDateTime::new(zoned.datetime().try_into()?, time_zone))
+    }
+}
+
+/// The time zone identifier is looked up in the time zone database of `jiff`. Local times that
+/// are skipped or repeated because of a daylight saving time transition are resolved the way
+/// RFC 5545 requires, that is, a skipped time is moved forward by the length of the gap and a
+/// repeated time refers to its first occurrence.
+impl TryFrom<&ZonedDateTime> for Zoned {
+    type Error = ConversionError;
+
+    fn try_from(date_time: &ZonedDateTime) -> Result<Self, Self::Error> {
+        let time_zone = tz::db()
+            .get(date_time.time_zone())
+            .map_err(|_| ConversionError::UnknownTimeZone)?;
+        time_zone
+            .to_zoned(date_time.date_time().into())
+            .map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{ConversionError, Date, DateTime, Time, ZonedDateTime},
+        jiff::{
+            Zoned, civil,
+            tz::{self, TimeZone},
+        },
+    };
+
+    #[test]
+    fn date() {
+        assert_eq!(
+            civil::Date::from(Date::new(2024, 2, 29)),
+            civil::date(2024, 2, 29)
+        );
+        assert_eq!(
+            Date::try_from(civil::date(2024, 2, 29)),
+            Ok(Date::new(2024, 2, 29))
+        );
+        assert_eq!(
+            Date::try_from(civil::date(-1, 1, 1)),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn zoned() {
+        let local = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 30, 0),
+        };
+        let zoned = Zoned::try_from(&ZonedDateTime::new(local, "Europe/Zurich")).unwrap();
+        assert_eq!(zoned.datetime(), civil::date(2024, 7, 5).at(9, 30, 0, 0));
+        assert_eq!(
+            DateTime::try_from(&zoned),
+            Ok(DateTime {
+                date: Date::new(2024, 7, 5),
+                time: Time::new_utc(7, 30, 0),
+            })
+        );
+        assert_eq!(
+            ZonedDateTime::try_from(&zoned),
+            Ok(ZonedDateTime::new(local, "Europe/Zurich"))
+        );
+        assert_eq!(
+            Zoned::try_from(&ZonedDateTime::new(local, "Mars/Olympus_Mons")),
+            Err(ConversionError::UnknownTimeZone)
+        );
+        let fixed = zoned.with_time_zone(TimeZone::fixed(tz::offset(2)));
+        assert_eq!(
+            ZonedDateTime::try_from(&fixed),
+            Err(ConversionError::UnknownTimeZone)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Conversions from and to the types of the [`time`] crate.
+//!
+//! Dates and times convert losslessly into their `time` counterparts. The reverse conversions
+//! drop sub-second precision and fail if the year is outside of the range 0-9999.
+
+use {
+    super::ConversionError,
+    crate::{Date, DateTime, Time},
+    time::{Month, OffsetDateTime, PrimitiveDateTime, UtcOffset},
+};
+
+impl From<Date> for time::Date {
+    fn from(date: Date) -> Self {
+        let month = Month::try_from(date.month()).expect("Months are in the range 1-12");
+        time::Date::from_calendar_date(i32::from(date.year()), month, date.day())
+            .expect("Years 0-9999 are supported by time::Date")
+    }
+}
+
+impl TryFrom<time::Date> for Date {
+    type Error = ConversionError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date.year()).map_err(|_| ConversionError::OutOfRange)?;
+        Date::try_new(year, u8::from(date.month()), date.day())
+            .map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+/// Whether the time is in UTC time or local time is not preserved.
+impl From<Time> for time::Time {
+    fn from(time: Time) -> Self {
+        time::Time::from_hms(time.hour(), time.minute(), time.second())
+            .expect("Times are valid time::Times")
+    }
+}
+
+/// The result is in local time.
+impl From<time::Time> for Time {
+    fn from(time: time::Time) -> Self {
+        Time::new_local(time.hour(), time.minute(), time.second())
+    }
+}
+
+/// Whether the date-time is in UTC time or local time is not preserved.
+impl From<DateTime> for PrimitiveDateTime {
+    fn from(date_time: DateTime) -> Self {
+        PrimitiveDateTime::new(date_time.date.into(), date_time.time.into())
+    }
+}
+
+/// The result is in local time.
+impl TryFrom<PrimitiveDateTime> for DateTime {
+    type Error = ConversionError;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(DateTime {
+            date: date_time.date().try_into()?,
+            time: date_time.time().into(),
+        })
+    }
+}
+
+/// Only date-times in UTC time can be converted, see [`ConversionError::LocalTime`].
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = ConversionError;
+
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        if !date_time.time.is_utc() {
+            return Err(ConversionError::LocalTime);
+        }
+        Ok(PrimitiveDateTime::from(date_time).assume_utc())
+    }
+}
+
+/// The result is converted to UTC time.
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = ConversionError;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        let date_time = date_time.to_offset(UtcOffset::UTC);
+        let time = date_time.time();
+        Ok(DateTime {
+            date: date_time.date().try_into()?,
+            time: Time::new_utc(time.hour(), time.minute(), time.second()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{ConversionError, Date, DateTime, Time},
+        time::{Month, OffsetDateTime, PrimitiveDateTime, UtcOffset},
+    };
+
+    #[test]
+    fn date() {
+        let date = time::Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        assert_eq!(time::Date::from(Date::new(2024, 2, 29)), date);
+        assert_eq!(Date::try_from(date), Ok(Date::new(2024, 2, 29)));
+        assert_eq!(
+            Date::try_from(time::Date::from_calendar_date(-1, Month::January, 1).unwrap()),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn date_time() {
+        let utc = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(9, 30, 0),
+        };
+        let local = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 30, 0),
+        };
+        let offset = OffsetDateTime::try_from(utc).unwrap();
+        assert_eq!(offset.unix_timestamp(), utc.to_unix_timestamp().unwrap());
+        assert_eq!(DateTime::try_from(offset), Ok(utc));
+        assert_eq!(
+            OffsetDateTime::try_from(local),
+            Err(ConversionError::LocalTime)
+        );
+        let zurich = offset.to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(DateTime::try_from(zurich), Ok(utc));
+        assert_eq!(DateTime::try_from(PrimitiveDateTime::from(utc)), Ok(local));
+    }
+}
@@ -0,0 +1,934 @@
+use {
+    crate::{
+        Attendee, Calendar, DateOrDateTime, DateTime, Event, EventStatus, MissingTimeZoneError,
+        Organizer, PartStat, StartDateTime, TimeZone,
+    },
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+/// The iTIP method of a calendar, corresponding to the values of the `METHOD` property.
+///
+/// The method states what a calendar that is sent to other calendar users is meant to do, see
+/// [RFC 5546 section 1.4 - Methods](https://tools.ietf.org/html/rfc5546#section-1.4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// Publish the calendar without expecting replies.
+    Publish,
+    /// Invite the attendees to an event, or update an event.
+    Request,
+    /// Reply to a request, for example to accept an invitation.
+    Reply,
+    /// Add instances to a recurring event.
+    Add,
+    /// Cancel an event or some of its instances.
+    Cancel,
+    /// Ask the organizer for the latest version of an event.
+    Refresh,
+    /// Propose changes to an event to the organizer.
+    Counter,
+    /// Decline a counter proposal.
+    DeclineCounter,
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Method::Publish => write!(f, "PUBLISH"),
+            Method::Request => write!(f, "REQUEST"),
+            Method::Reply => write!(f, "REPLY"),
+            Method::Add => write!(f, "ADD"),
+            Method::Cancel => write!(f, "CANCEL"),
+            Method::Refresh => write!(f, "REFRESH"),
+            Method::Counter => write!(f, "COUNTER"),
+            Method::DeclineCounter => write!(f, "DECLINECOUNTER"),
+        }
+    }
+}
+
+/// An iTIP `REQUEST` message, which invites the attendees to an event, as specified in
+/// [RFC 5546 section 3.2.2 - REQUEST](https://tools.ietf.org/html/rfc5546#section-3.2.2).
+///
+/// The same message is sent again to update the event. In that case, the sequence number of the
+/// event (see [`Event::set_sequence`]) must be incremented for significant changes, such as a
+/// new start.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItipRequest {
+    calendar: Calendar,
+}
+
+impl ItipRequest {
+    /// Create a request that invites the `attendees` to `event` on behalf of `organizer`.
+    ///
+    /// The organizer and the attendees replace those that are already set on the event. RFC 5546
+    /// requires a `SUMMARY` in requests, which [`Calendar::validate`] reports if the event has
+    /// none.
+    ///
+    /// The time zones that the event refers to are included in the request (see
+    /// [`Calendar::set_include_time_zones`]), so they must be in the time zone database, which
+    /// requires the `tzdata` feature. Use [`ItipRequest::with_time_zones`] to include other time
+    /// zones.
+    ///
+    /// ```
+    /// use ical::{Attendee, Date, DateTime, Event, ItipRequest, Method, Organizer, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event.set_summary("Team lunch");
+    /// let mut attendee = Attendee::new("mailto:bob@example.com");
+    /// attendee.set_rsvp(true);
+    /// let organizer = Organizer::new("mailto:alice@example.com");
+    /// let request = ItipRequest::new(event, organizer, [attendee]).unwrap();
+    ///
+    /// assert_eq!(request.calendar().method(), Some(Method::Request));
+    /// let output = request.to_string();
+    /// assert!(output.contains("METHOD:REQUEST\r\n"));
+    /// assert!(output.contains("ORGANIZER:mailto:alice@example.com\r\n"));
+    /// assert!(output.contains("ATTENDEE;RSVP=TRUE:mailto:bob@example.com\r\n"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ItipRequestError::NoAttendees`] if `attendees` is empty, and
+    /// [`ItipRequestError::MissingTimeZone`] if the event refers to a time zone that is not in
+    /// the time zone database.
+    pub fn new<I>(
+        event: Event,
+        organizer: Organizer,
+        attendees: I,
+    ) -> Result<Self, ItipRequestError>
+    where
+        I: IntoIterator<Item = Attendee>,
+    {
+        Self::with_time_zones(event, organizer, attendees, [])
+    }
+
+    /// Create a request like [`ItipRequest::new`] that includes the given time zone definitions,
+    /// for example time zones that are not in the time zone database.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Attendee, Date, DateTime, Event, ItipRequest, ItipRequestError, Organizer, Time,
+    ///     TimeZone, ZonedDateTime,
+    /// };
+    ///
+    /// let start = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_local(9, 0, 0),
+    /// };
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let start = ZonedDateTime::new(start, "Custom/Office");
+    /// let event = Event::with_uid("1@example.com", start.into(), stamp);
+    /// let organizer = Organizer::new("mailto:alice@example.com");
+    /// let attendees = [Attendee::new("mailto:bob@example.com")];
+    ///
+    /// let err = ItipRequest::new(event.clone(), organizer.clone(), attendees.clone());
+    /// assert!(matches!(err, Err(ItipRequestError::MissingTimeZone(_))));
+    ///
+    /// let time_zones = [TimeZone::new("Custom/Office")];
+    /// let request = ItipRequest::with_time_zones(event, organizer, attendees, time_zones).unwrap();
+    /// assert!(request.to_string().contains("BEGIN:VTIMEZONE\r\nTZID:Custom/Office\r\n"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`ItipRequest::new`], except that the time zones
+    /// in `time_zones` need not be in the time zone database.
+    pub fn with_time_zones<I, T>(
+        mut event: Event,
+        organizer: Organizer,
+        attendees: I,
+        time_zones: T,
+    ) -> Result<Self, ItipRequestError>
+    where
+        I: IntoIterator<Item = Attendee>,
+        T: IntoIterator<Item = TimeZone>,
+    {
+        event.attendees = attendees.into_iter().collect();
+        if event.attendees.is_empty() {
+            return Err(ItipRequestError::NoAttendees);
+        }
+        event.organizer = Some(organizer);
+        let mut calendar = Calendar::from_event(event);
+        for time_zone in time_zones {
+            calendar.add_time_zone(time_zone);
+        }
+        calendar
+            .set_method(Method::Request)
+            .set_include_time_zones(true);
+        calendar
+            .check_time_zones()
+            .map_err(ItipRequestError::MissingTimeZone)?;
+        Ok(Self { calendar })
+    }
+
+    /// Get the calendar that makes up the request.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Get the calendar that makes up the request, for example to change its product identifier
+    /// before it is sent.
+    #[must_use]
+    pub fn into_calendar(self) -> Calendar {
+        self.calendar
+    }
+}
+
+impl From<ItipRequest> for Calendar {
+    fn from(request: ItipRequest) -> Self {
+        request.into_calendar()
+    }
+}
+
+/// Writes the calendar of the request as text, see [`Calendar::to_ics_string`].
+impl Display for ItipRequest {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.calendar.fmt(f)
+    }
+}
+
+/// An iTIP `REPLY` message, which tells the organizer of an event whether an attendee will
+/// attend, as specified in [RFC 5546 section 3.2.3 -
+/// REPLY](https://tools.ietf.org/html/rfc5546#section-3.2.3).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItipReply {
+    calendar: Calendar,
+}
+
+impl ItipReply {
+    /// Create the reply of `attendee` to `request`, which is an iTIP `REQUEST` message (see
+    /// [`ItipRequest`]), with the date-time stamp (`DTSTAMP`) set to the current date and time.
+    ///
+    /// See [`ItipReply::from_request_at`] for details.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`ItipReply::from_request_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(feature = "clock")]
+    pub fn from_request(
+        request: &Calendar,
+        attendee: &str,
+        participation_status: PartStat,
+    ) -> Result<Self, ItipReplyError> {
+        Self::from_request_at(request, attendee, participation_status, DateTime::now_utc())
+    }
+
+    /// Create the reply of `attendee` to `request`, which is an iTIP `REQUEST` message (see
+    /// [`ItipRequest`]), with the date-time stamp (`DTSTAMP`) set to `stamp`.
+    ///
+    /// `attendee` is the calendar user address of the attendee, such as
+    /// `mailto:bob@example.com`, and is compared ignoring case. The reply is minimal, that is,
+    /// it contains one event for every event of the request that the attendee is invited to,
+    /// with only the `UID`, `RECURRENCE-ID`, `SEQUENCE`, start, organizer and the attendee
+    /// itself, with its participation status set to `participation_status`.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Attendee, Date, DateTime, Event, ItipReply, ItipRequest, Organizer, PartStat, Time,
+    /// };
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event.set_summary("Team lunch").set_sequence(1);
+    /// let organizer = Organizer::new("mailto:alice@example.com");
+    /// let request =
+    ///     ItipRequest::new(event, organizer, [Attendee::new("mailto:bob@example.com")]).unwrap();
+    ///
+    /// let reply = ItipReply::from_request_at(
+    ///     request.calendar(),
+    ///     "mailto:Bob@example.com",
+    ///     PartStat::Accepted,
+    ///     stamp,
+    /// )
+    /// .unwrap();
+    /// let output = reply.to_string();
+    /// assert!(output.contains("METHOD:REPLY\r\n"));
+    /// assert!(output.contains("SEQUENCE:1\r\n"));
+    /// assert!(output.contains("ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n"));
+    /// assert!(!output.contains("SUMMARY"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `request` is not an iTIP `REQUEST` message, if an event of the
+    /// request has no organizer, or if `attendee` is not invited to any of its events.
+    pub fn from_request_at(
+        request: &Calendar,
+        attendee: &str,
+        participation_status: PartStat,
+        stamp: DateTime,
+    ) -> Result<Self, ItipReplyError> {
+        if request.method() != Some(Method::Request) {
+            return Err(ItipReplyError::NotARequest);
+        }
+        let mut calendar = Calendar::new();
+        calendar.set_method(Method::Reply);
+        for event in request.events() {
+            let Some(invited) = event
+                .attendees
+                .iter()
+                .find(|invited| invited.address().eq_ignore_ascii_case(attendee))
+            else {
+                continue;
+            };
+            let organizer = event
+                .organizer
+                .clone()
+                .ok_or(ItipReplyError::MissingOrganizer)?;
+            let mut replying = invited.clone();
+            replying
+                .set_participation_status(participation_status)
+                .set_rsvp(false);
+            let mut reply = minimal_event(event, stamp);
+            reply.organizer = Some(organizer);
+            reply.attendees = vec![replying];
+            calendar.add_component(reply);
+        }
+        if calendar.components().is_empty() {
+            return Err(ItipReplyError::NotInvited);
+        }
+        Ok(Self { calendar })
+    }
+
+    /// Get the calendar that makes up the reply.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Get the calendar that makes up the reply, for example to change its product identifier
+    /// before it is sent.
+    #[must_use]
+    pub fn into_calendar(self) -> Calendar {
+        self.calendar
+    }
+}
+
+impl From<ItipReply> for Calendar {
+    fn from(reply: ItipReply) -> Self {
+        reply.into_calendar()
+    }
+}
+
+/// Writes the calendar of the reply as text, see [`Calendar::to_ics_string`].
+impl Display for ItipReply {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.calendar.fmt(f)
+    }
+}
+
+/// An iTIP `CANCEL` message, which cancels an event or one of its instances, as specified in
+/// [RFC 5546 section 3.2.5 - CANCEL](https://tools.ietf.org/html/rfc5546#section-3.2.5).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItipCancel {
+    calendar: Calendar,
+}
+
+impl ItipCancel {
+    /// Create a message that cancels `event`, with the date-time stamp (`DTSTAMP`) set to the
+    /// current date and time.
+    ///
+    /// See [`ItipCancel::new_at`] for details.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn new(event: &Event, recurrence_id: Option<DateOrDateTime>) -> Self {
+        Self::new_at(event, recurrence_id, DateTime::now_utc())
+    }
+
+    /// Create a message that cancels `event`, with the date-time stamp (`DTSTAMP`) set to
+    /// `stamp`.
+    ///
+    /// `event` is the event as it was last sent to the attendees, for example with an
+    /// [`ItipRequest`]. If `recurrence_id` is `None`, the whole event is cancelled, including all
+    /// of its recurrences. Otherwise, only the instance that originally started at
+    /// `recurrence_id` is cancelled.
+    ///
+    /// The message contains the `UID`, the organizer, the attendees and the summary of the
+    /// event, its sequence number (`SEQUENCE`) incremented by one and the status
+    /// [`EventStatus::Cancelled`]. The sequence number of the stored event should be incremented
+    /// as well.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Attendee, Date, DateTime, Event, EventStatus, ItipCancel, Organizer,
+    ///     RecurrenceFrequency, RecurrenceRule, Time,
+    /// };
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event
+    ///     .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly))
+    ///     .set_organizer(Organizer::new("mailto:alice@example.com"))
+    ///     .add_attendee(Attendee::new("mailto:bob@example.com"));
+    ///
+    /// let cancel = ItipCancel::new_at(&event, Some(Date::new(2024, 7, 12).into()), stamp);
+    /// let cancelled = cancel.calendar().events().next().unwrap();
+    /// assert_eq!(cancelled.sequence(), 1);
+    /// assert_eq!(cancelled.status(), Some(EventStatus::Cancelled));
+    /// assert_eq!(cancelled.recurrence_id(), Some(Date::new(2024, 7, 12).into()));
+    /// assert!(cancel.to_string().contains("METHOD:CANCEL\r\n"));
+    /// ```
+    #[must_use]
+    pub fn new_at(event: &Event, recurrence_id: Option<DateOrDateTime>, stamp: DateTime) -> Self {
+        let start_date_time = match recurrence_id {
+            Some(recurrence_id) => StartDateTime {
+                value: recurrence_id,
+                time_zone: event.start_date_time.time_zone.clone(),
+            },
+            None => event.start_date_time.clone(),
+        };
+        let mut cancelled = minimal_event(event, stamp);
+        cancelled.start_date_time = start_date_time;
+        cancelled.recurrence_id = recurrence_id.or(event.recurrence_id);
+        cancelled.sequence = event.sequence.saturating_add(1);
+        cancelled.status = Some(EventStatus::Cancelled);
+        cancelled.summary.clone_from(&event.summary);
+        cancelled.organizer.clone_from(&event.organizer);
+        cancelled.attendees.clone_from(&event.attendees);
+        let mut calendar = Calendar::from_event(cancelled);
+        calendar.set_method(Method::Cancel);
+        Self { calendar }
+    }
+
+    /// Get the calendar that makes up the message.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Get the calendar that makes up the message, for example to change its product
+    /// identifier before it is sent.
+    #[must_use]
+    pub fn into_calendar(self) -> Calendar {
+        self.calendar
+    }
+}
+
+impl From<ItipCancel> for Calendar {
+    fn from(cancel: ItipCancel) -> Self {
+        cancel.into_calendar()
+    }
+}
+
+/// Writes the calendar of the message as text, see [`Calendar::to_ics_string`].
+impl Display for ItipCancel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.calendar.fmt(f)
+    }
+}
+
+/// An iTIP `COUNTER` message, in which an attendee proposes a new time for an event to the
+/// organizer, as specified in [RFC 5546 section 3.2.7 -
+/// COUNTER](https://tools.ietf.org/html/rfc5546#section-3.2.7).
+///
+/// The organizer accepts the proposal by sending an updated [`ItipRequest`], or rejects it with
+/// an [`ItipDeclineCounter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItipCounter {
+    calendar: Calendar,
+}
+
+impl ItipCounter {
+    /// Create a message that proposes to move `event` to start at `start` and end at `end`,
+    /// with the date-time stamp (`DTSTAMP`) set to the current date and time.
+    ///
+    /// See [`ItipCounter::new_at`] for details.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn new<S: Into<StartDateTime>>(
+        event: &Event,
+        start: S,
+        end: Option<DateOrDateTime>,
+    ) -> Self {
+        Self::new_at(event, start, end, DateTime::now_utc())
+    }
+
+    /// Create a message that proposes to move `event` to start at `start` and end at `end`,
+    /// with the date-time stamp (`DTSTAMP`) set to `stamp`.
+    ///
+    /// `event` is the event as it was received from the organizer, for example with an
+    /// [`ItipRequest`]. The proposal is a copy of the event with the new start and end, so it
+    /// has the same `UID` and sequence number (`SEQUENCE`).
+    ///
+    /// ```
+    /// use ical::{
+    ///     Attendee, Date, DateTime, Event, ItipCounter, ItipDeclineCounter, Method, Organizer,
+    ///     Time,
+    /// };
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event
+    ///     .set_organizer(Organizer::new("mailto:alice@example.com"))
+    ///     .add_attendee(Attendee::new("mailto:bob@example.com"));
+    ///
+    /// let counter = ItipCounter::new_at(&event, Date::new(2024, 7, 6), None, stamp);
+    /// assert_eq!(counter.calendar().method(), Some(Method::Counter));
+    /// let proposal = counter.calendar().events().next().unwrap();
+    /// assert_eq!(proposal.start().value(), Date::new(2024, 7, 6).into());
+    ///
+    /// let decline = ItipDeclineCounter::new_at(proposal, stamp);
+    /// assert!(decline.to_string().contains("METHOD:DECLINECOUNTER\r\n"));
+    /// ```
+    #[must_use]
+    pub fn new_at<S: Into<StartDateTime>>(
+        event: &Event,
+        start: S,
+        end: Option<DateOrDateTime>,
+        stamp: DateTime,
+    ) -> Self {
+        let mut proposal = event.clone();
+        proposal.date_time = stamp;
+        proposal.start_date_time = start.into();
+        proposal.end = end;
+        let mut calendar = Calendar::from_event(proposal);
+        calendar.set_method(Method::Counter);
+        Self { calendar }
+    }
+
+    /// Get the calendar that makes up the message.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Get the calendar that makes up the message, for example to change its product
+    /// identifier before it is sent.
+    #[must_use]
+    pub fn into_calendar(self) -> Calendar {
+        self.calendar
+    }
+}
+
+impl From<ItipCounter> for Calendar {
+    fn from(counter: ItipCounter) -> Self {
+        counter.into_calendar()
+    }
+}
+
+/// Writes the calendar of the message as text, see [`Calendar::to_ics_string`].
+impl Display for ItipCounter {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.calendar.fmt(f)
+    }
+}
+
+/// An iTIP `DECLINECOUNTER` message, in which the organizer of an event rejects the proposal
+/// of an [`ItipCounter`], as specified in [RFC 5546 section 3.2.8 -
+/// DECLINECOUNTER](https://tools.ietf.org/html/rfc5546#section-3.2.8).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItipDeclineCounter {
+    calendar: Calendar,
+}
+
+impl ItipDeclineCounter {
+    /// Create a message that declines the proposal `counter`, with the date-time stamp
+    /// (`DTSTAMP`) set to the current date and time.
+    ///
+    /// See [`ItipDeclineCounter::new_at`] for details.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn new(counter: &Event) -> Self {
+        Self::new_at(counter, DateTime::now_utc())
+    }
+
+    /// Create a message that declines the proposal `counter`, with the date-time stamp
+    /// (`DTSTAMP`) set to `stamp`.
+    ///
+    /// `counter` is the event of the received `COUNTER` message. The message only contains the
+    /// `UID`, `RECURRENCE-ID`, `SEQUENCE`, start, organizer and attendees of that event. See
+    /// [`ItipCounter::new_at`] for an example.
+    #[must_use]
+    pub fn new_at(counter: &Event, stamp: DateTime) -> Self {
+        let mut declined = minimal_event(counter, stamp);
+        declined.organizer.clone_from(&counter.organizer);
+        declined.attendees.clone_from(&counter.attendees);
+        let mut calendar = Calendar::from_event(declined);
+        calendar.set_method(Method::DeclineCounter);
+        Self { calendar }
+    }
+
+    /// Get the calendar that makes up the message.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Get the calendar that makes up the message, for example to change its product
+    /// identifier before it is sent.
+    #[must_use]
+    pub fn into_calendar(self) -> Calendar {
+        self.calendar
+    }
+}
+
+impl From<ItipDeclineCounter> for Calendar {
+    fn from(decline: ItipDeclineCounter) -> Self {
+        decline.into_calendar()
+    }
+}
+
+/// Writes the calendar of the message as text, see [`Calendar::to_ics_string`].
+impl Display for ItipDeclineCounter {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.calendar.fmt(f)
+    }
+}
+
+/// An event that only has the `UID`, `RECURRENCE-ID`, `SEQUENCE` and start of `event`, with the
+/// date-time stamp (`DTSTAMP`) set to `stamp`.
+///
+/// iTIP messages other than requests only contain the properties that are needed to identify
+/// the event, together with the properties that are specific to the method.
+fn minimal_event(event: &Event, stamp: DateTime) -> Event {
+    Event {
+        uid: event.uid.clone(),
+        date_time: stamp,
+        start_date_time: event.start_date_time.clone(),
+        end: None,
+        recurrence_id: event.recurrence_id,
+        sequence: event.sequence,
+        status: None,
+        transparency: None,
+        description: None,
+        location: None,
+        structured_location: None,
+        summary: None,
+        organizer: None,
+        attendees: Vec::new(),
+        recurrence_rule: None,
+        exception_rule: None,
+        recurrence_dates: Vec::new(),
+        exception_dates: Vec::new(),
+        image: None,
+        conferences: Vec::new(),
+        color: None,
+        alarms: Vec::new(),
+    }
+}
+
+/// Error type for [`ItipRequest::new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItipRequestError {
+    /// The request has no attendees to invite.
+    NoAttendees,
+    /// The event refers to a time zone that is neither given nor in the time zone database.
+    MissingTimeZone(MissingTimeZoneError),
+}
+
+impl Display for ItipRequestError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ItipRequestError::NoAttendees => write!(f, "The request has no attendees"),
+            ItipRequestError::MissingTimeZone(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ItipRequestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ItipRequestError::NoAttendees => None,
+            ItipRequestError::MissingTimeZone(err) => Some(err),
+        }
+    }
+}
+
+/// Error type for [`ItipReply::from_request`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItipReplyError {
+    /// The calendar is not an iTIP `REQUEST` message, that is, its method is not
+    /// [`Method::Request`].
+    NotARequest,
+    /// An event of the request has no organizer (`ORGANIZER`) to reply to.
+    MissingOrganizer,
+    /// The attendee is not invited to any event of the request.
+    NotInvited,
+}
+
+impl Display for ItipReplyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ItipReplyError::NotARequest => write!(f, "The calendar is not an iTIP request"),
+            ItipReplyError::MissingOrganizer => write!(f, "The request has no organizer"),
+            ItipReplyError::NotInvited => write!(f, "The attendee is not invited"),
+        }
+    }
+}
+
+impl Error for ItipReplyError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Attendee, Calendar, Date, DateTime, Event, ItipCancel, ItipCounter, ItipDeclineCounter,
+        ItipReply, ItipReplyError, ItipRequest, ItipRequestError, Method, Organizer, PartStat,
+        Time, TimeZone, ValidationIssue, ZonedDateTime,
+    };
+
+    fn stamp() -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        }
+    }
+
+    #[test]
+    fn required_properties() {
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp());
+        event.add_attendee(Attendee::new("mailto:old@example.com"));
+        let request = ItipRequest::new(
+            event,
+            Organizer::new("mailto:alice@example.com"),
+            [Attendee::new("mailto:bob@example.com")],
+        )
+        .unwrap();
+        let event = request.calendar().events().next().unwrap();
+        assert_eq!(event.summary(), None);
+        assert_eq!(event.attendees().len(), 1);
+        assert!(!request.to_string().contains("SUMMARY"));
+        assert_eq!(
+            request.calendar().validate(),
+            Err(vec![ValidationIssue::MissingProperty {
+                uid: "1@example.com".to_owned(),
+                method: Method::Request,
+                property: "SUMMARY",
+            }])
+        );
+    }
+
+    #[test]
+    fn no_attendees() {
+        let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp());
+        assert_eq!(
+            ItipRequest::new(event, Organizer::new("mailto:alice@example.com"), []),
+            Err(ItipRequestError::NoAttendees)
+        );
+    }
+
+    #[test]
+    fn time_zones() {
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let event = |time_zone| {
+            Event::with_uid(
+                "1@example.com",
+                ZonedDateTime::new(start, time_zone).into(),
+                stamp(),
+            )
+        };
+        let organizer = Organizer::new("mailto:alice@example.com");
+        let attendees = [Attendee::new("mailto:bob@example.com")];
+        let err = ItipRequest::new(event("Custom/Office"), organizer.clone(), attendees.clone())
+            .unwrap_err();
+        assert!(
+            matches!(&err, ItipRequestError::MissingTimeZone(err) if err.id() == "Custom/Office")
+        );
+
+        #[cfg(feature = "tzdata")]
+        {
+            let request =
+                ItipRequest::new(event("Europe/Zurich"), organizer.clone(), attendees.clone())
+                    .unwrap();
+            assert!(
+                request
+                    .to_string()
+                    .contains("BEGIN:VTIMEZONE\r\nTZID:Europe/Zurich\r\n")
+            );
+        }
+
+        let request = ItipRequest::with_time_zones(
+            event("Custom/Office"),
+            organizer,
+            attendees,
+            [TimeZone::new("Custom/Office")],
+        )
+        .unwrap();
+        assert_eq!(request.calendar().time_zones().len(), 1);
+    }
+
+    #[test]
+    fn reply() {
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", start.into(), stamp());
+        event.set_end(DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(13, 0, 0),
+        });
+        let mut bob = Attendee::new("mailto:bob@example.com");
+        bob.set_common_name("Bob").set_rsvp(true);
+        let request = ItipRequest::new(
+            event,
+            Organizer::new("mailto:alice@example.com"),
+            [Attendee::new("mailto:carol@example.com"), bob],
+        )
+        .unwrap();
+        let reply = ItipReply::from_request_at(
+            request.calendar(),
+            "mailto:bob@example.com",
+            PartStat::Declined,
+            stamp(),
+        )
+        .unwrap();
+        let output = reply.to_string();
+        let lines: Vec<_> = output.lines().skip(3).collect();
+        assert_eq!(
+            lines,
+            [
+                "METHOD:REPLY",
+                "BEGIN:VEVENT",
+                "UID:1@example.com",
+                "DTSTAMP:20240701T120000Z",
+                "DTSTART:20240705T120000Z",
+                "ORGANIZER:mailto:alice@example.com",
+                "ATTENDEE;CN=Bob;PARTSTAT=DECLINED:mailto:bob@example.com",
+                "END:VEVENT",
+                "END:VCALENDAR",
+            ]
+        );
+    }
+
+    #[test]
+    fn reply_errors() {
+        let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp());
+        let request = ItipRequest::new(
+            event.clone(),
+            Organizer::new("mailto:alice@example.com"),
+            [Attendee::new("mailto:bob@example.com")],
+        )
+        .unwrap();
+        let reply = |calendar: &Calendar, attendee| {
+            ItipReply::from_request_at(calendar, attendee, PartStat::Accepted, stamp())
+        };
+        assert_eq!(
+            reply(request.calendar(), "mailto:carol@example.com"),
+            Err(ItipReplyError::NotInvited)
+        );
+        assert_eq!(
+            reply(&Calendar::from_event(event), "mailto:bob@example.com"),
+            Err(ItipReplyError::NotARequest)
+        );
+
+        let mut calendar = request.into_calendar();
+        calendar.set_method(Method::Publish);
+        assert_eq!(
+            reply(&calendar, "mailto:bob@example.com"),
+            Err(ItipReplyError::NotARequest)
+        );
+    }
+
+    #[test]
+    fn cancel_series() {
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp());
+        event
+            .set_summary("Team lunch")
+            .set_description("Bring your own lunch")
+            .set_sequence(2)
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .add_attendee(Attendee::new("mailto:bob@example.com"));
+        let cancel = ItipCancel::new_at(&event, None, stamp());
+        let output = cancel.to_string();
+        let lines: Vec<_> = output.lines().skip(3).collect();
+        assert_eq!(
+            lines,
+            [
+                "METHOD:CANCEL",
+                "BEGIN:VEVENT",
+                "UID:1@example.com",
+                "DTSTAMP:20240701T120000Z",
+                "DTSTART;VALUE=DATE:20240705",
+                "SEQUENCE:3",
+                "STATUS:CANCELLED",
+                "SUMMARY:Team lunch",
+                "ORGANIZER:mailto:alice@example.com",
+                "ATTENDEE:mailto:bob@example.com",
+                "END:VEVENT",
+                "END:VCALENDAR",
+            ]
+        );
+    }
+
+    #[test]
+    fn counter() {
+        let at = |hour| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", at(12).into(), stamp());
+        event
+            .set_end(at(13))
+            .set_summary("Team lunch")
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .add_attendee(Attendee::new("mailto:bob@example.com"));
+        let counter = ItipCounter::new_at(&event, at(13), Some(at(14).into()), stamp());
+        let proposal = counter.calendar().events().next().unwrap();
+        assert_eq!(proposal.summary(), Some("Team lunch"));
+        assert_eq!(proposal.end(), Some(at(14).into()));
+
+        let decline = ItipDeclineCounter::new_at(proposal, stamp());
+        let output = decline.to_string();
+        let lines: Vec<_> = output.lines().skip(3).collect();
+        assert_eq!(
+            lines,
+            [
+                "METHOD:DECLINECOUNTER",
+                "BEGIN:VEVENT",
+                "UID:1@example.com",
+                "DTSTAMP:20240701T120000Z",
+                "DTSTART:20240705T130000Z",
+                "ORGANIZER:mailto:alice@example.com",
+                "ATTENDEE:mailto:bob@example.com",
+                "END:VEVENT",
+                "END:VCALENDAR",
+            ]
+        );
+    }
+}
@@ -0,0 +1,638 @@
+use {
+    crate::{
+        Calendar,
+        read::{ComponentNode, ReadError, read_calendar},
+        value_type::{
+            default_value_type, escape_text, extended_date_time, extended_utc_offset, unescape_text,
+        },
+    },
+    ical_vcard::{Contentline, Identifier, Param, ParamValue, Parser, Value as ContentValue},
+    serde_json::{Map, Value},
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+impl Calendar {
+    /// Convert the calendar to its JSON representation, as specified in [RFC 7265 -
+    /// jCal](https://tools.ietf.org/html/rfc7265).
+    ///
+    /// The result contains the same properties and components as [`Calendar::to_ics_string`].
+    /// Every property is converted to an array of its lower-case name, its parameters, its value
+    /// type and its value. Dates and date-times are written in the extended ISO 8601 format, such
+    /// as `2024-07-05T12:00:00Z`, recurrence rules are written as objects, and text is written
+    /// without the escape sequences of iCalendar, such as `\,` and `\n`.
+    ///
+    /// Requires the `serde_json` feature.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    /// use serde_json::json;
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// calendar.set_product_identifier("-//Example//Example Calendar//EN");
+    /// let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// calendar.add_component(event);
+    ///
+    /// assert_eq!(
+    ///     calendar.to_jcal(),
+    ///     json!([
+    ///         "vcalendar",
+    ///         [
+    ///             ["prodid", {}, "text", "-//Example//Example Calendar//EN"],
+    ///             ["version", {}, "text", "2.0"],
+    ///         ],
+    ///         [[
+    ///             "vevent",
+    ///             [
+    ///                 ["uid", {}, "text", "1@example.com"],
+    ///                 ["dtstamp", {}, "date-time", "2024-07-01T12:00:00Z"],
+    ///                 ["dtstart", {}, "date", "2024-07-05"],
+    ///             ],
+    ///             [],
+    ///         ]],
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Never panics, since the calendar is always written as valid content lines.
+    #[must_use]
+    pub fn to_jcal(&self) -> Value {
+        let text = self.to_ics_string();
+        let mut stack: Vec<Vec<Value>> = Vec::new();
+        let mut root = Value::Null;
+        for contentline in Parser::new(text.as_bytes()) {
+            let contentline = contentline.expect("Calendars are written as valid content lines");
+            let name = contentline.name.as_str().to_ascii_lowercase();
+            if name == "begin" {
+                let component = contentline.value.as_str().to_ascii_lowercase();
+                stack.push(vec![
+                    Value::String(component),
+                    Value::Array(Vec::new()),
+                    Value::Array(Vec::new()),
+                ]);
+            } else if name == "end" {
+                let component = Value::Array(stack.pop().expect("Components are balanced"));
+                match stack.last_mut() {
+                    Some(parent) => push(parent, 2, component),
+                    None => root = component,
+                }
+            } else {
+                let parent = stack.last_mut().expect("Properties are inside a component");
+                push(parent, 1, property(&name, &contentline));
+            }
+        }
+        root
+    }
+
+    /// Read a calendar from its JSON representation, as specified in [RFC 7265 -
+    /// jCal](https://tools.ietf.org/html/rfc7265).
+    ///
+    /// This is the inverse of [`Calendar::to_jcal`], so text is escaped as in iCalendar.
+    /// Components other than events are ignored, and so are the properties of events that this
+    /// crate does not know. Unknown properties of the calendar are kept as additional
+    /// properties, see [`Calendar::add_property`].
+    ///
+    /// Requires the `serde_json` feature.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date};
+    ///
+    /// let calendar = Calendar::from_jcal(
+    ///     r#"["vcalendar", [["prodid", {}, "text", "-//Example//Example Calendar//EN"]], [
+    ///         ["vevent", [
+    ///             ["uid", {}, "text", "1@example.com"],
+    ///             ["dtstamp", {}, "date-time", "2024-07-01T12:00:00Z"],
+    ///             ["dtstart", {}, "date", "2024-07-05"],
+    ///             ["summary", {}, "text", "Lunch"]
+    ///         ], []]
+    ///     ]]"#,
+    /// )
+    /// .unwrap();
+    /// let event = calendar.events().next().unwrap();
+    /// assert_eq!(event.summary(), Some("Lunch"));
+    /// assert_eq!(event.start().value(), Date::new(2024, 7, 5).into());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `text` is not a jCal calendar, if an event lacks a required property
+    /// (`UID`, `DTSTAMP` or `DTSTART`), or if the value of a property is invalid.
+    pub fn from_jcal(text: &str) -> Result<Calendar, FromJcalError> {
+        let json: Value = serde_json::from_str(text).map_err(|err| FromJcalError::InvalidJson {
+            line: err.line(),
+            column: err.column(),
+        })?;
+        let node = read_component(&json)?;
+        if node.name != "VCALENDAR" {
+            return Err(FromJcalError::NotACalendar);
+        }
+        Ok(read_calendar(node)?)
+    }
+}
+
+/// Append `value` to the array at `index` of the jCal component `component`.
+fn push(component: &mut [Value], index: usize, value: Value) {
+    if let Value::Array(values) = &mut component[index] {
+        values.push(value);
+    }
+}
+
+/// Convert a property to jCal, see [RFC 7265 section 3.4 -
+/// Properties](https://tools.ietf.org/html/rfc7265#section-3.4).
+fn property(name: &str, contentline: &Contentline) -> Value {
+    let mut value_type = default_value_type(name).to_owned();
+    let mut params = Map::new();
+    for param in &contentline.params {
+        let param_name = param.name.as_str().to_ascii_lowercase();
+        let mut values: Vec<_> = param
+            .values
+            .iter()
+            .map(|value| Value::String(value.as_str().to_owned()))
+            .collect();
+        if param_name == "value" {
+            if let Some(Value::String(value)) = values.first() {
+                value_type = value.to_ascii_lowercase();
+            }
+        } else if values.len() == 1 {
+            params.insert(param_name, values.remove(0));
+        } else {
+            params.insert(param_name, Value::Array(values));
+        }
+    }
+    let mut property = vec![
+        Value::String(name.to_owned()),
+        Value::Object(params),
+        Value::String(value_type.clone()),
+    ];
+    let value = contentline.value.as_str();
+    match value_type.as_str() {
+        "date" | "date-time" => {
+            property.extend(
+                value
+                    .split(',')
+                    .map(|value| extended_date_time(value).into()),
+            );
+        }
+        "period" => property.extend(value.split(',').map(|period| {
+            let (start, end) = period.split_once('/').unwrap_or((period, ""));
+            format!("{}/{}", extended_date_time(start), extended_date_time(end)).into()
+        })),
+        "float" if name == "geo" => {
+            property.push(Value::Array(value.split(';').map(number).collect()));
+        }
+        "integer" | "float" => property.push(number(value)),
+        "utc-offset" => property.push(extended_utc_offset(value).into()),
+        "recur" => property.push(recurrence_rule(value)),
+        "text" => property.push(unescape_text(value).into()),
+        _ => property.push(value.into()),
+    }
+    Value::Array(property)
+}
+
+/// Convert a number to a JSON number, or to a string if it is not a valid number.
+fn number(value: &str) -> Value {
+    if let Ok(integer) = value.parse::<i64>() {
+        integer.into()
+    } else if let Ok(float) = value.parse::<f64>()
+        && let Some(number) = serde_json::Number::from_f64(float)
+    {
+        Value::Number(number)
+    } else {
+        value.into()
+    }
+}
+
+/// Convert a recurrence rule to a jCal object, see [RFC 7265 section 3.6.10 -
+/// Recurrence Rule](https://tools.ietf.org/html/rfc7265#section-3.6.10).
+fn recurrence_rule(value: &str) -> Value {
+    let mut rule = Map::new();
+    for part in value.split(';') {
+        let (name, values) = part.split_once('=').unwrap_or((part, ""));
+        let name = name.to_ascii_lowercase();
+        let mut values: Vec<Value> = values
+            .split(',')
+            .map(|value| match name.as_str() {
+                "until" => extended_date_time(value).into(),
+                "freq" | "wkst" | "byday" => value.into(),
+                _ => number(value),
+            })
+            .collect();
+        let values = if values.len() == 1 {
+            values.remove(0)
+        } else {
+            Value::Array(values)
+        };
+        rule.insert(name, values);
+    }
+    Value::Object(rule)
+}
+
+/// Convert a jCal component, see [RFC 7265 section 3.3 -
+/// Components](https://tools.ietf.org/html/rfc7265#section-3.3).
+fn read_component(json: &Value) -> Result<ComponentNode, FromJcalError> {
+    let Some(
+        [
+            Value::String(name),
+            Value::Array(properties),
+            Value::Array(components),
+        ],
+    ) = json.as_array().map(Vec::as_slice)
+    else {
+        return Err(FromJcalError::NotACalendar);
+    };
+    Ok(ComponentNode {
+        name: name.to_ascii_uppercase(),
+        properties: properties
+            .iter()
+            .map(read_property)
+            .collect::<Result<_, _>>()?,
+        components: components
+            .iter()
+            .map(read_component)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Convert a jCal property to a content line, see [RFC 7265 section 3.4 -
+/// Properties](https://tools.ietf.org/html/rfc7265#section-3.4).
+fn read_property(json: &Value) -> Result<Contentline, FromJcalError> {
+    let Some(
+        [
+            Value::String(name),
+            Value::Object(params),
+            Value::String(value_type),
+            values @ ..,
+        ],
+    ) = json.as_array().map(Vec::as_slice)
+    else {
+        return Err(FromJcalError::NotACalendar);
+    };
+    let invalid = || FromJcalError::InvalidProperty {
+        property: name.to_ascii_uppercase(),
+    };
+    let mut contentline = Contentline {
+        group: None,
+        name: Identifier::new(name.to_ascii_uppercase()).map_err(|_| invalid())?,
+        params: Vec::new(),
+        value: ContentValue::new(property_value(name, value_type, values).ok_or_else(invalid)?)
+            .map_err(|_| invalid())?,
+    };
+    for (param_name, values) in params {
+        let values = match values {
+            Value::String(value) => vec![value.as_str()],
+            Value::Array(values) => values
+                .iter()
+                .map(Value::as_str)
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+        contentline.params.push(Param {
+            name: Identifier::new(param_name.to_ascii_uppercase()).map_err(|_| invalid())?,
+            values: values
+                .into_iter()
+                .map(|value| ParamValue::new(value.to_owned()))
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid())?,
+        });
+    }
+    let value_type = value_type.to_ascii_lowercase();
+    if value_type != "unknown" && value_type != default_value_type(&name.to_ascii_lowercase()) {
+        contentline.params.push(Param {
+            name: Identifier::new("VALUE".to_owned()).expect("VALUE is a valid identifier"),
+            values: vec![ParamValue::new(value_type.to_ascii_uppercase()).map_err(|_| invalid())?],
+        });
+    }
+    Ok(contentline)
+}
+
+/// Convert the values of a jCal property to the value of a content line.
+///
+/// Returns `None` if the values do not match the value type.
+fn property_value(name: &str, value_type: &str, values: &[Value]) -> Option<String> {
+    let value_type = value_type.to_ascii_lowercase();
+    match (value_type.as_str(), values) {
+        ("recur", [Value::Object(rule)]) => recurrence_rule_text(rule),
+        ("float", [Value::Array(values)]) if name.eq_ignore_ascii_case("geo") => {
+            let values: Option<Vec<_>> = values.iter().map(|value| scalar(value, "")).collect();
+            Some(values?.join(";"))
+        }
+        (_, [_, ..]) => {
+            let values: Option<Vec<_>> = values
+                .iter()
+                .map(|value| scalar(value, &value_type))
+                .collect();
+            Some(values?.join(","))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a single jCal value of the type `value_type` to text.
+fn scalar(value: &Value, value_type: &str) -> Option<String> {
+    match value {
+        Value::String(value) => Some(match value_type {
+            "date" | "date-time" | "utc-offset" => basic_format(value),
+            "period" => value
+                .split('/')
+                .map(basic_format)
+                .collect::<Vec<_>>()
+                .join("/"),
+            "text" => escape_text(value),
+            _ => value.clone(),
+        }),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(true) => Some("TRUE".to_owned()),
+        Value::Bool(false) => Some("FALSE".to_owned()),
+        _ => None,
+    }
+}
+
+/// Convert a date, date-time or UTC offset from the extended format (`2024-07-05T12:00:00Z`)
+/// back to the basic format (`20240705T120000Z`).
+///
+/// Durations are returned unchanged.
+fn basic_format(value: &str) -> String {
+    if value.trim_start_matches(['+', '-']).starts_with('P') {
+        value.to_owned()
+    } else {
+        value.replace(['-', ':'], "")
+    }
+}
+
+/// Convert a jCal recurrence rule to text, see [RFC 7265 section 3.6.10 -
+/// Recurrence Rule](https://tools.ietf.org/html/rfc7265#section-3.6.10).
+///
+/// The `FREQ` part is written first, as RFC 5545 recommends for compatibility.
+fn recurrence_rule_text(rule: &Map<String, Value>) -> Option<String> {
+    let mut parts: Vec<_> = rule.iter().collect();
+    parts.sort_by_key(|(name, _)| !name.eq_ignore_ascii_case("freq"));
+    let parts: Option<Vec<_>> = parts
+        .into_iter()
+        .map(|(name, values)| {
+            let value_type = if name.eq_ignore_ascii_case("until") {
+                "date-time"
+            } else {
+                "text"
+            };
+            let values = match values {
+                Value::Array(values) => values
+                    .iter()
+                    .map(|value| scalar(value, value_type))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(","),
+                value => scalar(value, value_type)?,
+            };
+            Some(format!("{}={values}", name.to_ascii_uppercase()))
+        })
+        .collect();
+    Some(parts?.join(";"))
+}
+
+/// Error type for [`Calendar::from_jcal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromJcalError {
+    /// The text is not valid JSON.
+    InvalidJson {
+        /// The (1-based) line of the error.
+        line: usize,
+        /// The (1-based) column of the error.
+        column: usize,
+    },
+    /// The JSON does not have the structure of a jCal calendar.
+    NotACalendar,
+    /// A component lacks a required property.
+    MissingProperty {
+        /// The name of the component, such as `VEVENT`.
+        component: String,
+        /// The name of the missing property, such as `DTSTART`.
+        property: String,
+    },
+    /// The value or the parameters of a property are invalid.
+    InvalidProperty {
+        /// The name of the property, such as `DTSTART`.
+        property: String,
+    },
+}
+
+impl Display for FromJcalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FromJcalError::InvalidJson { line, column } => {
+                write!(f, "Invalid JSON (line {line}, column {column})")
+            }
+            FromJcalError::NotACalendar => write!(f, "The JSON is not a jCal calendar"),
+            FromJcalError::MissingProperty {
+                component,
+                property,
+            } => write!(f, "Missing {property} property in {component} component"),
+            FromJcalError::InvalidProperty { property } => {
+                write!(f, "Invalid {property} property")
+            }
+        }
+    }
+}
+
+impl Error for FromJcalError {}
+
+impl From<ReadError> for FromJcalError {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::MissingProperty {
+                component,
+                property,
+            } => FromJcalError::MissingProperty {
+                component: component.to_owned(),
+                property: property.to_owned(),
+            },
+            ReadError::InvalidProperty(property) => FromJcalError::InvalidProperty { property },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            Attendee, Calendar, Conference, ConferenceFeature, CssColorName, Date, DateTime, Event,
+            EventStatus, FromJcalError, Geo, ImageDisplay, Method, Organizer, PartStat,
+            RecurrenceFrequency, RecurrenceRule, Time, Weekday, ZonedDateTime,
+        },
+        serde_json::json,
+    };
+
+    fn calendar() -> Calendar {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(12, 30, 0),
+        };
+        let mut event = Event::with_uid(
+            "1@example.com",
+            ZonedDateTime::new(start, "Europe/Zurich").into(),
+            stamp,
+        );
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        rule.set_count(3).set_by_day([Weekday::Monday]);
+        let mut attendee = Attendee::new("mailto:bob@example.com");
+        attendee.set_participation_status(PartStat::Accepted);
+        event
+            .set_summary("Lunch\\, with friends")
+            .set_sequence(2)
+            .set_recurrence_rule(rule)
+            .add_attendee(attendee)
+            .add_exception_date(DateTime {
+                date: Date::new(2024, 7, 8),
+                time: Time::new_local(12, 30, 0),
+            });
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+        calendar
+    }
+
+    #[test]
+    fn properties() {
+        let properties = &calendar().to_jcal()[2][0][1];
+        assert_eq!(
+            properties[2],
+            json!([
+                "dtstart",
+                {"tzid": "Europe/Zurich"},
+                "date-time",
+                "2024-07-05T12:30:00"
+            ])
+        );
+        assert_eq!(properties[3], json!(["sequence", {}, "integer", 2]));
+        assert_eq!(
+            properties[4],
+            json!(["summary", {}, "text", "Lunch, with friends"])
+        );
+        assert_eq!(
+            properties[5],
+            json!([
+                "attendee",
+                {"partstat": "ACCEPTED"},
+                "cal-address",
+                "mailto:bob@example.com"
+            ])
+        );
+        assert_eq!(
+            properties[6],
+            json!(["rrule", {}, "recur", {"freq": "WEEKLY", "count": 3, "byday": "MO"}])
+        );
+        assert_eq!(
+            properties[7],
+            json!([
+                "exdate",
+                {"tzid": "Europe/Zurich"},
+                "date-time",
+                "2024-07-08T12:30:00"
+            ])
+        );
+    }
+
+    #[test]
+    fn text() {
+        let mut calendar = calendar();
+        calendar.set_product_identifier("-//Example//Example Calendar//EN");
+        let event = calendar.events_mut().next().unwrap();
+        event.set_description("Line 1\\nLine 2\\, with a comma\\; and a semicolon");
+        let jcal = calendar.to_jcal();
+        let properties = jcal[2][0][1].as_array().unwrap();
+        assert!(properties.contains(&json!([
+            "description",
+            {},
+            "text",
+            "Line 1\nLine 2, with a comma; and a semicolon"
+        ])));
+        assert_eq!(Calendar::from_jcal(&jcal.to_string()), Ok(calendar));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut calendar = calendar();
+        calendar
+            .set_product_identifier("-//Example//Example Calendar//EN")
+            .set_method(Method::Publish)
+            .set_image("https://example.com/logo.png", Some(ImageDisplay::Badge))
+            .add_property("X-WR-CALNAME", "Team", &[]);
+        let event = calendar.events_mut().next().unwrap();
+        let mut conference = Conference::new("https://example.com/call");
+        conference
+            .add_feature(ConferenceFeature::Video)
+            .set_label("Call");
+        event
+            .set_end(Date::new(2024, 7, 6))
+            .set_status(EventStatus::Confirmed)
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .set_location_structured(
+                "Office",
+                Some("Main Street 1"),
+                Some(Geo::new(47.37, 8.54)),
+                None,
+            )
+            .add_conference(conference)
+            .set_color(CssColorName::Teal);
+        let text = calendar.to_jcal().to_string();
+        assert_eq!(Calendar::from_jcal(&text), Ok(calendar));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Calendar::from_jcal("[\"vcalendar\""),
+            Err(FromJcalError::InvalidJson {
+                line: 1,
+                column: 12
+            })
+        );
+        assert_eq!(
+            Calendar::from_jcal("[\"vevent\", [], []]"),
+            Err(FromJcalError::NotACalendar)
+        );
+        assert_eq!(
+            Calendar::from_jcal("[\"vcalendar\", [], [[\"vevent\", [], []]]]"),
+            Err(FromJcalError::MissingProperty {
+                component: "VEVENT".to_owned(),
+                property: "UID".to_owned(),
+            })
+        );
+        assert_eq!(
+            Calendar::from_jcal("[\"vcalendar\", [[\"method\", {}, \"text\", \"NONE\"]], []]"),
+            Err(FromJcalError::InvalidProperty {
+                property: "METHOD".to_owned(),
+            })
+        );
+        assert_eq!(
+            Calendar::from_jcal(
+                r#"["vcalendar", [], [["vevent", [
+                    ["uid", {}, "text", "1@example.com"],
+                    ["dtstamp", {}, "date-time", "2024-07-01T12:00:00Z"],
+                    ["dtstart", {"tzid": "Europe/Zurich"}, "date-time", "2024-01-01T09:00:00Z"]
+                ], []]]]"#
+            ),
+            Err(FromJcalError::InvalidProperty {
+                property: "DTSTART".to_owned(),
+            })
+        );
+        assert_eq!(
+            Calendar::from_jcal(
+                r#"["vcalendar", [["x-offset", {}, "utc-offse\t", "+01:00"]], []]"#
+            ),
+            Err(FromJcalError::InvalidProperty {
+                property: "X-OFFSET".to_owned(),
+            })
+        );
+    }
+}
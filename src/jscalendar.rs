@@ -0,0 +1,1079 @@
+use {
+    crate::{
+        Attendee, Calendar, Conference, CssColorName, DateOrDateTime, DateTime, Duration, Event,
+        Geo, Image, Organizer, RecurrenceRule, Role, StartDateTime, StructuredLocation, Time,
+        read::{
+            CONFERENCE_FEATURES, EVENT_STATUSES, IMAGE_DISPLAYS, PARTICIPATION_STATUSES,
+            find_keyword,
+        },
+        value_type::{escape_text, unescape_text},
+    },
+    ical_vcard::{ParamValue, Value as ContentValue},
+    serde_json::{Map, Value, json},
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+/// The time zone of date-times in UTC time.
+const UTC: &str = "Etc/UTC";
+
+impl Calendar {
+    /// Convert the calendar to a JSCalendar `Group` object, as specified in [RFC 8984 section
+    /// 2.3 - Group](https://tools.ietf.org/html/rfc8984#section-2.3).
+    ///
+    /// The events of the calendar become the entries of the group, see
+    /// [`Event::to_jscalendar`]. Since a calendar has no `UID`, the `uid` of the group must be
+    /// given.
+    ///
+    /// Requires the `serde_json` feature.
+    #[must_use]
+    pub fn to_jscalendar_group(&self, uid: &str) -> Value {
+        json!({
+            "@type": "Group",
+            "uid": uid,
+            "prodId": self.product_identifier(),
+            "entries": self.events().map(Event::to_jscalendar).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Read a calendar from a JSCalendar `Group` object, as specified in [RFC 8984 section 2.3 -
+    /// Group](https://tools.ietf.org/html/rfc8984#section-2.3).
+    ///
+    /// This is the inverse of [`Calendar::to_jscalendar_group`]. Entries other than events,
+    /// such as tasks, are ignored.
+    ///
+    /// Requires the `serde_json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `group` is not a `Group` object, or if one of its events cannot be
+    /// read, see [`Event::from_jscalendar`].
+    pub fn from_jscalendar_group(group: &Value) -> Result<Calendar, FromJscalendarError> {
+        let group = typed_object(group, "Group")?;
+        let mut calendar = Calendar::new();
+        if let Some(product_identifier) = string(group, "prodId")? {
+            calendar.product_identifier = Some(text("prodId", product_identifier)?);
+        }
+        for entry in array(group, "entries")? {
+            if entry.get("@type").and_then(Value::as_str) == Some("Event") {
+                calendar.add_component(Event::from_jscalendar(entry)?);
+            }
+        }
+        Ok(calendar)
+    }
+}
+
+impl Event {
+    /// Convert the event to a JSCalendar `Event` object, as specified in [RFC 8984 -
+    /// JSCalendar](https://tools.ietf.org/html/rfc8984).
+    ///
+    /// The start is written as a local date-time together with a time zone (`timeZone`), which
+    /// is the time zone of the start, `Etc/UTC` for a start in UTC time, or none for a floating
+    /// start. All-day events have `showWithoutTime` set. The end is converted to a `duration`,
+    /// the organizer and the attendees to `participants`, the conferences to
+    /// `virtualLocations`, and the recurrence dates and exception dates to
+    /// `recurrenceOverrides`. The title, the description and the locations are written without
+    /// the escape sequences of iCalendar, such as `\,` and `\n`.
+    ///
+    /// Requires the `serde_json` feature.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, Time};
+    /// use serde_json::json;
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let start = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", start.into(), stamp);
+    /// event
+    ///     .set_summary("Lunch")
+    ///     .set_end(DateTime {
+    ///         date: Date::new(2024, 7, 5),
+    ///         time: Time::new_utc(13, 30, 0),
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     event.to_jscalendar(),
+    ///     json!({
+    ///         "@type": "Event",
+    ///         "uid": "1@example.com",
+    ///         "updated": "2024-07-01T12:00:00Z",
+    ///         "title": "Lunch",
+    ///         "start": "2024-07-05T12:00:00",
+    ///         "timeZone": "Etc/UTC",
+    ///         "duration": "PT1H30M",
+    ///     })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_jscalendar(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("@type".to_owned(), "Event".into());
+        object.insert("uid".to_owned(), self.uid().into());
+        object.insert("updated".to_owned(), self.date_time.to_rfc3339().into());
+        if self.sequence != 0 {
+            object.insert("sequence".to_owned(), self.sequence.into());
+        }
+        if let Some(summary) = self.summary() {
+            object.insert("title".to_owned(), unescape_text(summary).into());
+        }
+        if let Some(description) = self.description() {
+            object.insert("description".to_owned(), unescape_text(description).into());
+        }
+        let start = self.start_date_time.value;
+        object.insert("start".to_owned(), local_date_time(start).into());
+        if start.is_date() {
+            object.insert("showWithoutTime".to_owned(), true.into());
+        }
+        let time_zone = self.start_date_time.time_zone().or(match start {
+            DateOrDateTime::DateTime(date_time) if date_time.time.is_utc() => Some(UTC),
+            _ => None,
+        });
+        if let Some(time_zone) = time_zone {
+            object.insert("timeZone".to_owned(), time_zone.into());
+        }
+        if let Some(end) = self.end
+            && let Some(duration) = midnight(end).duration_since(midnight(start))
+            && !duration.is_negative()
+        {
+            object.insert("duration".to_owned(), duration.to_string().into());
+        }
+        if let Some(recurrence_id) = self.recurrence_id {
+            object.insert(
+                "recurrenceId".to_owned(),
+                local_date_time(recurrence_id).into(),
+            );
+        }
+        if let Some(status) = self.status {
+            let status = status.to_string().to_ascii_lowercase();
+            object.insert("status".to_owned(), status.into());
+        }
+        if let Some(location) = self.locations_to_jscalendar() {
+            object.insert("locations".to_owned(), location);
+        }
+        if !self.conferences.is_empty() {
+            let virtual_locations = numbered(self.conferences.iter().map(|conference| {
+                let mut virtual_location = Map::new();
+                virtual_location.insert("@type".to_owned(), "VirtualLocation".into());
+                virtual_location.insert("uri".to_owned(), conference.uri().into());
+                if let Some(label) = conference.label() {
+                    virtual_location.insert("name".to_owned(), label.into());
+                }
+                if !conference.features().is_empty() {
+                    let features = conference
+                        .features()
+                        .iter()
+                        .map(|feature| (feature.to_string().to_ascii_lowercase(), true.into()))
+                        .collect();
+                    virtual_location.insert("features".to_owned(), Value::Object(features));
+                }
+                Value::Object(virtual_location)
+            }));
+            object.insert("virtualLocations".to_owned(), virtual_locations);
+        }
+        if let Some(organizer) = &self.organizer {
+            object.insert("replyTo".to_owned(), json!({ "imip": organizer.address() }));
+        }
+        if let Some(participants) = self.participants_to_jscalendar() {
+            object.insert("participants".to_owned(), participants);
+        }
+        if let Some(recurrence_rule) = &self.recurrence_rule {
+            let rules = json!([recurrence_rule_to_jscalendar(recurrence_rule)]);
+            object.insert("recurrenceRules".to_owned(), rules);
+        }
+        if let Some(exception_rule) = &self.exception_rule {
+            let rules = json!([recurrence_rule_to_jscalendar(exception_rule)]);
+            object.insert("excludedRecurrenceRules".to_owned(), rules);
+        }
+        if !self.recurrence_dates.is_empty() || !self.exception_dates.is_empty() {
+            let mut overrides = Map::new();
+            for date in &self.recurrence_dates {
+                overrides.insert(local_date_time(*date), json!({}));
+            }
+            for date in &self.exception_dates {
+                overrides.insert(local_date_time(*date), json!({ "excluded": true }));
+            }
+            object.insert("recurrenceOverrides".to_owned(), Value::Object(overrides));
+        }
+        if let Some(image) = &self.image {
+            let mut link = Map::new();
+            link.insert("@type".to_owned(), "Link".into());
+            link.insert("href".to_owned(), image.uri().into());
+            if let Some(display) = image.display() {
+                let display = display.to_string().to_ascii_lowercase();
+                link.insert("display".to_owned(), display.into());
+            }
+            object.insert("links".to_owned(), numbered([Value::Object(link)]));
+        }
+        if let Some(color) = self.color {
+            object.insert("color".to_owned(), color.as_str().into());
+        }
+        Value::Object(object)
+    }
+
+    /// The `locations` of the JSCalendar representation of the event.
+    fn locations_to_jscalendar(&self) -> Option<Value> {
+        let mut location = Map::new();
+        location.insert("@type".to_owned(), "Location".into());
+        if let Some(structured_location) = &self.structured_location {
+            location.insert(
+                "name".to_owned(),
+                unescape_text(structured_location.name()).into(),
+            );
+            if let Some(address) = structured_location.address() {
+                location.insert("description".to_owned(), unescape_text(address).into());
+            }
+            if let Some(geo) = structured_location.geo() {
+                let coordinates = format!("geo:{},{}", geo.latitude(), geo.longitude());
+                location.insert("coordinates".to_owned(), coordinates.into());
+            }
+            if let Some(uri) = structured_location.uri() {
+                let link = json!({ "@type": "Link", "href": uri });
+                location.insert("links".to_owned(), numbered([link]));
+            }
+        } else {
+            location.insert("name".to_owned(), unescape_text(self.location()?).into());
+        }
+        Some(numbered([Value::Object(location)]))
+    }
+
+    /// The `participants` of the JSCalendar representation of the event.
+    ///
+    /// An attendee with the address of the organizer is a single participant with the `owner`
+    /// role.
+    fn participants_to_jscalendar(&self) -> Option<Value> {
+        let is_organizer = |address: &str| {
+            self.organizer
+                .as_ref()
+                .is_some_and(|organizer| organizer.address().eq_ignore_ascii_case(address))
+        };
+        let mut participants: Vec<_> = self
+            .attendees
+            .iter()
+            .map(|attendee| {
+                let mut participant = participant(attendee.address(), attendee.common_name());
+                let roles: &[&str] = match attendee.role().unwrap_or(Role::RequiredParticipant) {
+                    Role::Chair => &["attendee", "chair"],
+                    Role::RequiredParticipant => &["attendee"],
+                    Role::OptionalParticipant => &["attendee", "optional"],
+                    Role::NonParticipant => &["informational"],
+                };
+                let mut roles: Map<_, _> = roles
+                    .iter()
+                    .map(|role| ((*role).to_owned(), true.into()))
+                    .collect();
+                if is_organizer(attendee.address()) {
+                    roles.insert("owner".to_owned(), true.into());
+                }
+                participant.insert("roles".to_owned(), Value::Object(roles));
+                if let Some(participation_status) = attendee.participation_status() {
+                    let participation_status = participation_status.to_string().to_lowercase();
+                    participant.insert(
+                        "participationStatus".to_owned(),
+                        participation_status.into(),
+                    );
+                }
+                if attendee.rsvp() {
+                    participant.insert("expectReply".to_owned(), true.into());
+                }
+                Value::Object(participant)
+            })
+            .collect();
+        if let Some(organizer) = &self.organizer
+            && !self
+                .attendees
+                .iter()
+                .any(|attendee| is_organizer(attendee.address()))
+        {
+            let mut participant = participant(organizer.address(), organizer.common_name());
+            participant.insert("roles".to_owned(), json!({ "owner": true }));
+            participants.insert(0, Value::Object(participant));
+        }
+        (!participants.is_empty()).then(|| numbered(participants))
+    }
+
+    /// Read an event from a JSCalendar `Event` object, as specified in [RFC 8984 -
+    /// JSCalendar](https://tools.ietf.org/html/rfc8984).
+    ///
+    /// This is the inverse of [`Event::to_jscalendar`], so text is escaped as in iCalendar. The
+    /// `uid`, `updated` and `start` properties are required. Properties that cannot be
+    /// represented by [`Event`] are ignored, such as recurrence rules after the first one,
+    /// recurrence overrides that modify an occurrence, locations after the first one, and colors
+    /// that are not CSS color names.
+    ///
+    /// Requires the `serde_json` feature.
+    ///
+    /// ```
+    /// use ical::{Date, Event};
+    /// use serde_json::json;
+    ///
+    /// let event = Event::from_jscalendar(&json!({
+    ///     "@type": "Event",
+    ///     "uid": "1@example.com",
+    ///     "updated": "2024-07-01T12:00:00Z",
+    ///     "title": "Holiday",
+    ///     "start": "2024-07-05T00:00:00",
+    ///     "showWithoutTime": true,
+    ///     "duration": "P2D",
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(event.summary(), Some("Holiday"));
+    /// assert_eq!(event.end(), Some(Date::new(2024, 7, 7).into()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `event` is not an `Event` object, if a required property is missing,
+    /// or if a property has an invalid value.
+    pub fn from_jscalendar(event: &Value) -> Result<Event, FromJscalendarError> {
+        let object = typed_object(event, "Event")?;
+        let uid = required(object, "uid")?;
+        let updated = required(object, "updated")?;
+        let stamp = DateTime::parse_rfc3339(updated)
+            .ok()
+            .filter(|stamp| stamp.time.is_utc())
+            .ok_or_else(|| invalid("updated"))?;
+
+        let time_zone = string(object, "timeZone")?;
+        let is_utc = time_zone.is_some_and(|time_zone| ["Etc/UTC", "UTC"].contains(&time_zone));
+        let show_without_time = boolean(object, "showWithoutTime")?;
+        let date_or_date_time = |name: &str, value: &str| {
+            let mut date_time = DateTime::parse_rfc3339(value).map_err(|_| invalid(name))?;
+            date_time.time.set_utc(is_utc);
+            Ok(
+                if show_without_time && date_time.time == Time::new_local(0, 0, 0) {
+                    DateOrDateTime::Date(date_time.date)
+                } else {
+                    DateOrDateTime::DateTime(date_time)
+                },
+            )
+        };
+        let start = date_or_date_time("start", required(object, "start")?)?;
+        let start = StartDateTime {
+            value: start,
+            time_zone: match (start, time_zone) {
+                (DateOrDateTime::DateTime(_), Some(time_zone)) if !is_utc => {
+                    ParamValue::new(time_zone.to_owned()).map_err(|_| invalid("timeZone"))?;
+                    Some(time_zone.to_owned())
+                }
+                _ => None,
+            },
+        };
+        let mut event = Event::try_with_uid(uid, start, stamp).map_err(|_| invalid("uid"))?;
+
+        if let Some(duration) = string(object, "duration")? {
+            let duration: Duration = duration.parse().map_err(|_| invalid("duration"))?;
+            event.end = Some(
+                end(event.start_date_time.value, duration).ok_or_else(|| invalid("duration"))?,
+            );
+        }
+        if let Some(recurrence_id) = string(object, "recurrenceId")? {
+            event.recurrence_id = Some(date_or_date_time("recurrenceId", recurrence_id)?);
+        }
+        if let Some(sequence) = object.get("sequence") {
+            event.sequence = sequence
+                .as_u64()
+                .and_then(|sequence| u32::try_from(sequence).ok())
+                .ok_or_else(|| invalid("sequence"))?;
+        }
+        if let Some(title) = string(object, "title")? {
+            event.summary = Some(text("title", &escape_text(title))?);
+        }
+        if let Some(description) = string(object, "description")? {
+            event.description = Some(text("description", &escape_text(description))?);
+        }
+        if let Some(status) = string(object, "status")? {
+            event.status =
+                Some(find_keyword(status, &EVENT_STATUSES).ok_or_else(|| invalid("status"))?);
+        }
+        event.read_locations(object)?;
+        for virtual_location in values(object, "virtualLocations")? {
+            event.conferences.push(conference(virtual_location)?);
+        }
+        event.read_participants(object)?;
+        if let Some(rule) = array(object, "recurrenceRules")?.first() {
+            event.recurrence_rule = Some(
+                recurrence_rule_from_jscalendar(rule, &date_or_date_time)
+                    .ok_or_else(|| invalid("recurrenceRules"))?,
+            );
+        }
+        if let Some(rule) = array(object, "excludedRecurrenceRules")?.first() {
+            event.exception_rule = Some(
+                recurrence_rule_from_jscalendar(rule, &date_or_date_time)
+                    .ok_or_else(|| invalid("excludedRecurrenceRules"))?,
+            );
+        }
+        if let Some(overrides) = object.get("recurrenceOverrides") {
+            let overrides = overrides
+                .as_object()
+                .ok_or_else(|| invalid("recurrenceOverrides"))?;
+            for (date, patch) in overrides {
+                let date = date_or_date_time("recurrenceOverrides", date)?;
+                match patch.as_object() {
+                    Some(patch) if patch.is_empty() => event.recurrence_dates.push(date),
+                    Some(patch) if patch.get("excluded") == Some(&Value::Bool(true)) => {
+                        event.exception_dates.push(date);
+                    }
+                    Some(_) => (),
+                    None => return Err(invalid("recurrenceOverrides")),
+                }
+            }
+        }
+        if let Some(link) = values(object, "links")?.next() {
+            let link = link.as_object().ok_or_else(|| invalid("links"))?;
+            let href = required(link, "href")?;
+            let display =
+                string(link, "display")?.and_then(|display| find_keyword(display, &IMAGE_DISPLAYS));
+            event.image =
+                Some(Image::try_new(href.to_owned(), display).map_err(|_| invalid("links"))?);
+        }
+        event.color = string(object, "color")?.and_then(CssColorName::from_name);
+        Ok(event)
+    }
+
+    /// Read the first of the `locations` of a JSCalendar event.
+    fn read_locations(&mut self, object: &Map<String, Value>) -> Result<(), FromJscalendarError> {
+        let Some(location) = values(object, "locations")?.next() else {
+            return Ok(());
+        };
+        let location = location.as_object().ok_or_else(|| invalid("locations"))?;
+        let Some(name) = string(location, "name")? else {
+            return Ok(());
+        };
+        let address = string(location, "description")?;
+        let geo = string(location, "coordinates")?
+            .map(|coordinates| geo(coordinates).ok_or_else(|| invalid("locations")))
+            .transpose()?;
+        let uri = values(location, "links")?
+            .next()
+            .map(|link| {
+                link.get("href")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid("locations"))
+            })
+            .transpose()?;
+        let name = escape_text(name);
+        let address = address.map(escape_text);
+        if address.is_none() && geo.is_none() && uri.is_none() {
+            self.location = Some(text("locations", &name)?);
+        } else {
+            self.structured_location = Some(
+                StructuredLocation::try_new(self.uid(), 0, name, address.as_deref(), geo, uri)
+                    .map_err(|_| invalid("locations"))?,
+            );
+        }
+        Ok(())
+    }
+
+    /// Read the organizer and the attendees from the `participants` and the `replyTo` of a
+    /// JSCalendar event.
+    fn read_participants(
+        &mut self,
+        object: &Map<String, Value>,
+    ) -> Result<(), FromJscalendarError> {
+        for participant in values(object, "participants")? {
+            let participant = participant
+                .as_object()
+                .ok_or_else(|| invalid("participants"))?;
+            let Some(address) = participant_address(participant)? else {
+                continue;
+            };
+            text("participants", &address)?;
+            let common_name = string(participant, "name")?;
+            if let Some(common_name) = common_name {
+                ParamValue::new(common_name.to_owned()).map_err(|_| invalid("participants"))?;
+            }
+            let roles = participant
+                .get("roles")
+                .map(|roles| roles.as_object().ok_or_else(|| invalid("participants")))
+                .transpose()?;
+            let has_role =
+                |role: &str| roles.is_some_and(|roles| roles.get(role) == Some(&Value::Bool(true)));
+            if has_role("owner") && self.organizer.is_none() {
+                let mut organizer = Organizer::new(address.as_str());
+                if let Some(common_name) = common_name {
+                    organizer.set_common_name(common_name);
+                }
+                self.organizer = Some(organizer);
+            }
+            let role = if has_role("chair") {
+                Role::Chair
+            } else if has_role("informational") {
+                Role::NonParticipant
+            } else if has_role("optional") {
+                Role::OptionalParticipant
+            } else if has_role("attendee") || roles.is_none() {
+                Role::RequiredParticipant
+            } else {
+                continue;
+            };
+            let mut attendee = Attendee::new(address);
+            if let Some(common_name) = common_name {
+                attendee.set_common_name(common_name);
+            }
+            if role != Role::RequiredParticipant {
+                attendee.set_role(role);
+            }
+            if let Some(participation_status) = string(participant, "participationStatus")?
+                .and_then(|participation_status| {
+                    find_keyword(participation_status, &PARTICIPATION_STATUSES)
+                })
+            {
+                attendee.set_participation_status(participation_status);
+            }
+            attendee.set_rsvp(boolean(participant, "expectReply")?);
+            self.attendees.push(attendee);
+        }
+        if self.organizer.is_none()
+            && let Some(reply_to) = object.get("replyTo")
+        {
+            let address = reply_to
+                .get("imip")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("replyTo"))?;
+            text("replyTo", address)?;
+            self.organizer = Some(Organizer::new(address));
+        }
+        Ok(())
+    }
+}
+
+/// Convert a recurrence rule to a JSCalendar `RecurrenceRule` object, see [RFC 8984 section
+/// 4.3.3 - recurrenceRules](https://tools.ietf.org/html/rfc8984#section-4.3.3).
+fn recurrence_rule_to_jscalendar(rule: &RecurrenceRule) -> Value {
+    let mut object = Map::new();
+    object.insert("@type".to_owned(), "RecurrenceRule".into());
+    let frequency = rule.freq().to_string().to_ascii_lowercase();
+    object.insert("frequency".to_owned(), frequency.into());
+    if rule.interval() != 1 {
+        object.insert("interval".to_owned(), rule.interval().into());
+    }
+    if let Some(count) = rule.count() {
+        object.insert("count".to_owned(), count.into());
+    }
+    if let Some(until) = rule.until() {
+        object.insert("until".to_owned(), local_date_time(until).into());
+    }
+    if !rule.by_day().is_empty() {
+        let days: Vec<_> = rule
+            .by_day()
+            .iter()
+            .map(|day| {
+                let mut n_day = Map::new();
+                n_day.insert("@type".to_owned(), "NDay".into());
+                let weekday = day.weekday().to_string().to_ascii_lowercase();
+                n_day.insert("day".to_owned(), weekday.into());
+                if let Some(ordinal) = day.ordinal() {
+                    n_day.insert("nthOfPeriod".to_owned(), ordinal.into());
+                }
+                Value::Object(n_day)
+            })
+            .collect();
+        object.insert("byDay".to_owned(), days.into());
+    }
+    if !rule.by_month_day().is_empty() {
+        object.insert("byMonthDay".to_owned(), rule.by_month_day().into());
+    }
+    if !rule.by_month().is_empty() {
+        let months: Vec<_> = rule.by_month().iter().map(ToString::to_string).collect();
+        object.insert("byMonth".to_owned(), months.into());
+    }
+    if !rule.by_year_day().is_empty() {
+        object.insert("byYearDay".to_owned(), rule.by_year_day().into());
+    }
+    if !rule.by_week_no().is_empty() {
+        object.insert("byWeekNo".to_owned(), rule.by_week_no().into());
+    }
+    if !rule.by_set_pos().is_empty() {
+        object.insert("bySetPosition".to_owned(), rule.by_set_pos().into());
+    }
+    if let Some(week_start) = rule.week_start() {
+        let week_start = week_start.to_string().to_ascii_lowercase();
+        object.insert("firstDayOfWeek".to_owned(), week_start.into());
+    }
+    Value::Object(object)
+}
+
+/// Read a JSCalendar `RecurrenceRule` object.
+///
+/// The rule is converted to the text of an `RRULE` property and then parsed, so that it is
+/// checked in the same way. `date_or_date_time` converts the `until` date-time, which is in the
+/// time zone of the start of the event.
+///
+/// Returns `None` if the rule is invalid.
+fn recurrence_rule_from_jscalendar<F>(rule: &Value, date_or_date_time: &F) -> Option<RecurrenceRule>
+where
+    F: Fn(&str, &str) -> Result<DateOrDateTime, FromJscalendarError>,
+{
+    let rule = rule.as_object()?;
+    let mut parts = vec![format!("FREQ={}", rule.get("frequency")?.as_str()?)];
+    for (name, part) in [
+        ("interval", "INTERVAL"),
+        ("count", "COUNT"),
+        ("byMonthDay", "BYMONTHDAY"),
+        ("byMonth", "BYMONTH"),
+        ("byYearDay", "BYYEARDAY"),
+        ("byWeekNo", "BYWEEKNO"),
+        ("bySetPosition", "BYSETPOS"),
+        ("firstDayOfWeek", "WKST"),
+    ] {
+        let values = match rule.get(name) {
+            None => continue,
+            Some(Value::Array(values)) => {
+                values.iter().map(json_text).collect::<Option<Vec<_>>>()?
+            }
+            Some(value) => vec![json_text(value)?],
+        };
+        parts.push(format!("{part}={}", values.join(",")));
+    }
+    if let Some(until) = rule.get("until") {
+        parts.push(format!(
+            "UNTIL={}",
+            date_or_date_time("until", until.as_str()?).ok()?
+        ));
+    }
+    if let Some(days) = rule.get("byDay") {
+        let days = days
+            .as_array()?
+            .iter()
+            .map(|day| {
+                let weekday = day.get("day")?.as_str()?;
+                Some(match day.get("nthOfPeriod") {
+                    Some(ordinal) => format!("{}{weekday}", ordinal.as_i64()?),
+                    None => weekday.to_owned(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        parts.push(format!("BYDAY={}", days.join(",")));
+    }
+    parts.join(";").parse().ok()
+}
+
+/// The text of a string or a number.
+fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// Create a JSCalendar `Participant` object with the given address and name.
+fn participant(address: &str, name: Option<&str>) -> Map<String, Value> {
+    let mut participant = Map::new();
+    participant.insert("@type".to_owned(), "Participant".into());
+    if let Some(name) = name {
+        participant.insert("name".to_owned(), name.into());
+    }
+    participant.insert("sendTo".to_owned(), json!({ "imip": address }));
+    participant
+}
+
+/// The calendar user address of a JSCalendar `Participant` object, taken from `sendTo` or
+/// `email`.
+fn participant_address(
+    participant: &Map<String, Value>,
+) -> Result<Option<String>, FromJscalendarError> {
+    if let Some(send_to) = participant.get("sendTo") {
+        let imip = send_to.get("imip").and_then(Value::as_str);
+        if let Some(imip) = imip {
+            return Ok(Some(imip.to_owned()));
+        }
+    }
+    Ok(string(participant, "email")?.map(|email| format!("mailto:{email}")))
+}
+
+/// Read a JSCalendar `VirtualLocation` object.
+fn conference(virtual_location: &Value) -> Result<Conference, FromJscalendarError> {
+    let virtual_location = virtual_location
+        .as_object()
+        .ok_or_else(|| invalid("virtualLocations"))?;
+    let uri = required(virtual_location, "uri")?;
+    text("virtualLocations", uri)?;
+    let mut conference = Conference::new(uri);
+    if let Some(name) = string(virtual_location, "name")? {
+        ParamValue::new(name.to_owned()).map_err(|_| invalid("virtualLocations"))?;
+        conference.set_label(name);
+    }
+    if let Some(features) = virtual_location.get("features") {
+        let features = features
+            .as_object()
+            .ok_or_else(|| invalid("virtualLocations"))?;
+        for feature in features.keys() {
+            if let Some(feature) = find_keyword(feature, &CONFERENCE_FEATURES) {
+                conference.add_feature(feature);
+            }
+        }
+    }
+    Ok(conference)
+}
+
+/// Parse a `geo:` URI such as `geo:47.37,8.54`.
+fn geo(coordinates: &str) -> Option<Geo> {
+    let (latitude, longitude) = coordinates.strip_prefix("geo:")?.split_once(',')?;
+    // Ignore the altitude and the parameters of the URI.
+    let longitude = longitude.split([',', ';']).next()?;
+    let (latitude, longitude): (f64, f64) = (latitude.parse().ok()?, longitude.parse().ok()?);
+    ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude))
+        .then(|| Geo::new(latitude, longitude))
+}
+
+/// Format a date or date-time as a JSCalendar `LocalDateTime`, such as `2024-07-05T09:00:00`.
+///
+/// A date is formatted as midnight of that day.
+fn local_date_time(value: DateOrDateTime) -> String {
+    let date_time = midnight(value);
+    format!(
+        "{}T{}",
+        date_time.date.to_iso8601(),
+        date_time.time.to_extended()
+    )
+}
+
+/// Convert a date to a date-time at midnight in local time.
+fn midnight(value: DateOrDateTime) -> DateTime {
+    match value {
+        DateOrDateTime::Date(date) => DateTime {
+            date,
+            time: Time::new_local(0, 0, 0),
+        },
+        DateOrDateTime::DateTime(date_time) => date_time,
+    }
+}
+
+/// The end of an event that starts at `start` and lasts for `duration`.
+///
+/// Returns `None` if `start` is a date and `duration` is not a number of whole days, or if the
+/// end is out of range.
+fn end(start: DateOrDateTime, duration: Duration) -> Option<DateOrDateTime> {
+    match start {
+        DateOrDateTime::Date(date) => {
+            let seconds = duration.as_seconds();
+            (seconds % 86400 == 0)
+                .then(|| date.checked_add_days(seconds / 86400))
+                .flatten()
+                .map(DateOrDateTime::Date)
+        }
+        DateOrDateTime::DateTime(date_time) => date_time
+            .checked_add(duration)
+            .map(DateOrDateTime::DateTime),
+    }
+}
+
+/// Number the values as `"1"`, `"2"`, ..., which is how this crate identifies the entries of
+/// JSCalendar `Id[...]` maps.
+fn numbered<I: IntoIterator<Item = Value>>(values: I) -> Value {
+    Value::Object(
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| ((index + 1).to_string(), value))
+            .collect(),
+    )
+}
+
+/// Check that `value` is a JSCalendar object of the type `expected`.
+fn typed_object<'a>(
+    value: &'a Value,
+    expected: &str,
+) -> Result<&'a Map<String, Value>, FromJscalendarError> {
+    value
+        .as_object()
+        .filter(|object| object.get("@type").and_then(Value::as_str) == Some(expected))
+        .ok_or_else(|| FromJscalendarError::UnexpectedType {
+            expected: expected.to_owned(),
+        })
+}
+
+/// The string property `name` of `object`, if present.
+fn string<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+) -> Result<Option<&'a str>, FromJscalendarError> {
+    object
+        .get(name)
+        .map(|value| value.as_str().ok_or_else(|| invalid(name)))
+        .transpose()
+}
+
+/// The string property `name` of `object`, which is required.
+fn required<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+) -> Result<&'a str, FromJscalendarError> {
+    string(object, name)?.ok_or_else(|| FromJscalendarError::MissingProperty {
+        property: name.to_owned(),
+    })
+}
+
+/// The boolean property `name` of `object`, which is `false` if it is not present.
+fn boolean(object: &Map<String, Value>, name: &str) -> Result<bool, FromJscalendarError> {
+    object.get(name).map_or(Ok(false), |value| {
+        value.as_bool().ok_or_else(|| invalid(name))
+    })
+}
+
+/// The array property `name` of `object`, which is empty if it is not present.
+fn array<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+) -> Result<&'a [Value], FromJscalendarError> {
+    object.get(name).map_or(Ok(&[]), |value| {
+        value
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| invalid(name))
+    })
+}
+
+/// The values of the object property `name` of `object`, such as the participants of an event.
+fn values<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+) -> Result<impl Iterator<Item = &'a Value>, FromJscalendarError> {
+    let values = object
+        .get(name)
+        .map(|value| value.as_object().ok_or_else(|| invalid(name)))
+        .transpose()?;
+    Ok(values.into_iter().flat_map(Map::values))
+}
+
+/// Create the value of a text property from the JSCalendar property `name`.
+fn text(name: &str, text: &str) -> Result<ContentValue<String>, FromJscalendarError> {
+    ContentValue::new(text.to_owned()).map_err(|_| invalid(name))
+}
+
+/// The error for the JSCalendar property `name` with an invalid value.
+fn invalid(name: &str) -> FromJscalendarError {
+    FromJscalendarError::InvalidProperty {
+        property: name.to_owned(),
+    }
+}
+
+/// Error type for [`Event::from_jscalendar`] and [`Calendar::from_jscalendar_group`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromJscalendarError {
+    /// The JSON value is not a JSCalendar object of the expected type (`@type`).
+    UnexpectedType {
+        /// The expected type, such as `Event`.
+        expected: String,
+    },
+    /// A required property is missing.
+    MissingProperty {
+        /// The name of the property, such as `uid`.
+        property: String,
+    },
+    /// A property has an invalid value.
+    InvalidProperty {
+        /// The name of the property, such as `start`.
+        property: String,
+    },
+}
+
+impl Display for FromJscalendarError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FromJscalendarError::UnexpectedType { expected } => {
+                write!(f, "Expected a JSCalendar {expected} object")
+            }
+            FromJscalendarError::MissingProperty { property } => {
+                write!(f, "Missing JSCalendar property: {property}")
+            }
+            FromJscalendarError::InvalidProperty { property } => {
+                write!(f, "Invalid JSCalendar property: {property}")
+            }
+        }
+    }
+}
+
+impl Error for FromJscalendarError {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            Attendee, Calendar, Conference, ConferenceFeature, CssColorName, Date, DateTime, Event,
+            EventStatus, FromJscalendarError, Geo, Organizer, PartStat, RecurrenceFrequency,
+            RecurrenceRule, Role, Time, Weekday, WeekdayNum, ZonedDateTime,
+        },
+        serde_json::json,
+    };
+
+    fn event() -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let mut event = Event::with_uid(
+            "1@example.com",
+            ZonedDateTime::new(start, "Europe/Zurich").into(),
+            stamp,
+        );
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+        rule.set_interval(2)
+            .set_count(5)
+            .set_by_day([WeekdayNum::nth(-1, Weekday::Friday)]);
+        let mut organizer = Organizer::new("mailto:alice@example.com");
+        organizer.set_common_name("Alice");
+        let mut attendee = Attendee::new("mailto:bob@example.com");
+        attendee
+            .set_role(Role::OptionalParticipant)
+            .set_participation_status(PartStat::Tentative)
+            .set_rsvp(true);
+        let mut conference = Conference::new("https://example.com/call");
+        conference
+            .add_feature(ConferenceFeature::Video)
+            .set_label("Call");
+        event
+            .set_summary("Review")
+            .set_description("Monthly review")
+            .set_end(DateTime {
+                date: Date::new(2024, 7, 5),
+                time: Time::new_local(10, 30, 0),
+            })
+            .set_sequence(3)
+            .set_status(EventStatus::Confirmed)
+            .set_location_structured(
+                "Office\\, 2nd floor",
+                Some("Main Street 1\\; Zurich"),
+                Some(Geo::new(47.37, 8.54)),
+                None,
+            )
+            .set_organizer(organizer)
+            .add_attendee(attendee)
+            .set_recurrence_rule(rule)
+            .add_exception_date(DateTime {
+                date: Date::new(2024, 9, 27),
+                time: Time::new_local(9, 0, 0),
+            })
+            .add_conference(conference)
+            .set_color(CssColorName::Teal);
+        event
+    }
+
+    #[test]
+    fn to_jscalendar() {
+        let object = event().to_jscalendar();
+        assert_eq!(object["start"], json!("2024-07-05T09:00:00"));
+        assert_eq!(object["timeZone"], json!("Europe/Zurich"));
+        assert_eq!(object["duration"], json!("PT1H30M"));
+        assert_eq!(
+            object["recurrenceRules"],
+            json!([{
+                "@type": "RecurrenceRule",
+                "frequency": "monthly",
+                "interval": 2,
+                "count": 5,
+                "byDay": [{ "@type": "NDay", "day": "fr", "nthOfPeriod": -1 }],
+            }])
+        );
+        assert_eq!(
+            object["participants"],
+            json!({
+                "1": {
+                    "@type": "Participant",
+                    "name": "Alice",
+                    "sendTo": { "imip": "mailto:alice@example.com" },
+                    "roles": { "owner": true },
+                },
+                "2": {
+                    "@type": "Participant",
+                    "sendTo": { "imip": "mailto:bob@example.com" },
+                    "roles": { "attendee": true, "optional": true },
+                    "participationStatus": "tentative",
+                    "expectReply": true,
+                },
+            })
+        );
+        assert_eq!(
+            object["recurrenceOverrides"],
+            json!({ "2024-09-27T09:00:00": { "excluded": true } })
+        );
+        assert_eq!(
+            object["locations"]["1"]["coordinates"],
+            json!("geo:47.37,8.54")
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut calendar = Calendar::new();
+        calendar
+            .set_product_identifier("-//Example//Example Calendar//EN")
+            .add_component(event());
+        let mut all_day = Event::with_uid(
+            "2@example.com",
+            Date::new(2024, 8, 1).into(),
+            DateTime {
+                date: Date::new(2024, 7, 1),
+                time: Time::new_utc(12, 0, 0),
+            },
+        );
+        all_day
+            .set_end(Date::new(2024, 8, 3))
+            .set_summary("Holiday\\; finally")
+            .set_description("Bring:\\nsunscreen\\, towels\\\\hats")
+            .set_location("Beach\\, north end");
+        calendar.add_component(all_day);
+
+        let group = calendar.to_jscalendar_group("group@example.com");
+        let entry = &group["entries"][1];
+        assert_eq!(entry["title"], json!("Holiday; finally"));
+        assert_eq!(
+            entry["description"],
+            json!("Bring:\nsunscreen, towels\\hats")
+        );
+        assert_eq!(entry["locations"]["1"]["name"], json!("Beach, north end"));
+        let location = &group["entries"][0]["locations"]["1"];
+        assert_eq!(location["name"], json!("Office, 2nd floor"));
+        assert_eq!(location["description"], json!("Main Street 1; Zurich"));
+        let mut read = Calendar::from_jscalendar_group(&group).unwrap();
+        // The UID of the structured location is not part of JSCalendar.
+        let original = calendar
+            .events()
+            .next()
+            .unwrap()
+            .structured_location
+            .clone();
+        read.events_mut().next().unwrap().structured_location = original;
+        assert_eq!(read, calendar);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Event::from_jscalendar(&json!({ "@type": "Task" })),
+            Err(FromJscalendarError::UnexpectedType {
+                expected: "Event".to_owned()
+            })
+        );
+        assert_eq!(
+            Event::from_jscalendar(&json!({ "@type": "Event", "uid": "1" })),
+            Err(FromJscalendarError::MissingProperty {
+                property: "updated".to_owned()
+            })
+        );
+        assert_eq!(
+            Event::from_jscalendar(&json!({
+                "@type": "Event",
+                "uid": "1",
+                "updated": "2024-07-01T12:00:00Z",
+                "start": "2024-07-05",
+            })),
+            Err(FromJscalendarError::InvalidProperty {
+                property: "start".to_owned()
+            })
+        );
+    }
+}
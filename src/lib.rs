@@ -18,20 +18,163 @@
 #![allow(clippy::too_many_lines, reason = "This is not bad in my opinion")]
 
 use {
-    ical_vcard::{Contentline, Value},
-    std::io::{self, Write},
-    uuid::Uuid,
+    ical_vcard::{Contentline, Identifier, Param, ParamValue, Value},
+    std::{
+        cmp::Ordering,
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
 };
 
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
 mod date_and_time;
-pub use date_and_time::{Date, DateTime, ParseDateError, ParseDateTimeError, ParseTimeError, Time};
+pub use date_and_time::{
+    Date, DateOrDateTime, DateTime, Days, Duration, InvalidDateError, InvalidTimeError,
+    ParseDateError, ParseDateTimeError, ParseDurationError, ParseTimeError, ParseUtcOffsetError,
+    Time, UtcOffset, Weekday, ZonedDateTime,
+};
+
+mod agenda;
+pub use agenda::Occurrence;
+
+mod alarm;
+pub use alarm::{Alarm, AlarmAction, Proximity, Trigger, TriggerRelation};
+
+mod apply_itip;
+pub use apply_itip::ApplyItipError;
+
+mod calendar_diff;
+pub use calendar_diff::CalendarDiff;
+
+mod calendar_writer;
+pub use calendar_writer::CalendarWriter;
+
+mod color;
+pub use color::CssColorName;
+
+mod compat;
+pub use compat::{CompatEntry, CompatFeature, CompatImpact, CompatReport, Profile};
+
+mod conference;
+pub use conference::{Conference, ConferenceFeature};
+
+mod conflicts;
+
+mod deterministic;
+pub use deterministic::{Clock, FixedClock, SequentialUids, UidGenerator};
+
+mod error;
+pub use error::{AddPropertyError, Error, InvalidTextError, InvalidValueError};
+
+mod event_builder;
+pub use event_builder::{EventBuilder, Unset};
+
+mod events;
+pub use events::{Events, EventsMut};
+
+#[cfg(feature = "imip")]
+mod imip;
+#[cfg(feature = "imip")]
+pub use imip::{ImipPart, TransferEncoding};
+
+mod image;
+use image::Image;
+pub use image::ImageDisplay;
+
+mod interop;
+pub use interop::ConversionError;
+
+mod locale;
+pub use locale::Locale;
+
+mod location;
+pub use location::{Geo, StructuredLocation};
+
+mod itip;
+pub use itip::{
+    ItipCancel, ItipCounter, ItipDeclineCounter, ItipReply, ItipReplyError, ItipRequest,
+    ItipRequestError, Method,
+};
+
+#[cfg(feature = "serde_json")]
+mod jcal;
+#[cfg(feature = "serde_json")]
+pub use jcal::FromJcalError;
+
+#[cfg(feature = "serde_json")]
+mod jscalendar;
+#[cfg(feature = "serde_json")]
+pub use jscalendar::FromJscalendarError;
+
+mod merge;
+pub use merge::MergePolicy;
+
+mod normalize;
+
+mod occurrences;
+pub use occurrences::{EventsBetween, Occurrences};
+
+mod parse;
+pub use parse::{ParseCalendarError, ParseOptions};
+
+mod participant;
+pub use participant::{Attendee, Organizer, PartStat, Role};
 
 mod recurrence_rule;
-pub use recurrence_rule::{RecurrenceFrequency, RecurrenceRule};
+use recurrence_rule::RuleInstances;
+pub use recurrence_rule::{
+    ParseRecurrenceRuleError, RecurrenceFrequency, RecurrenceRule, RecurrenceRuleBuilder,
+    RuleError, WeekdayNum,
+};
+
+mod read;
+
+mod redaction;
+pub use redaction::RedactionPolicy;
+
+mod search;
+pub use search::SearchMode;
+
+mod split;
 
 mod start_date_time;
 pub use start_date_time::StartDateTime;
 
+mod stats;
+pub use stats::CalendarStats;
+
+mod status;
+pub use status::EventStatus;
+
+mod text_diff;
+pub use text_diff::{DiffTextError, diff_text};
+
+mod time_zone;
+#[cfg(feature = "tzdata")]
+pub use time_zone::{Disambiguation, ZonedOccurrences};
+pub use time_zone::{MissingTimeZoneError, Observance, ObservanceKind, ParseTzifError, TimeZone};
+
+mod transparency;
+pub use transparency::Transparency;
+
+#[cfg(feature = "tokio")]
+mod write_async;
+
+mod validation;
+pub use validation::{ValidationIssue, ValidationReport, ValidationWarning};
+
+mod value_type;
+
+mod vcalendar;
+
+mod write_options;
+use write_options::FoldingWriter;
+pub use write_options::{LineEnding, WriteOptions};
+
+mod xcal;
+
 /// Default product identifier.
 pub const DEFAULT_PRODUCT_IDENTIFIER: &str = concat!(
     "nicolabruhin.com : ",
@@ -40,6 +183,94 @@ pub const DEFAULT_PRODUCT_IDENTIFIER: &str = concat!(
     env!("CARGO_PKG_VERSION")
 );
 
+/// Create a [`Param`] with a single value.
+///
+/// # Panics
+///
+/// Panics if `name` is not a valid parameter name or if `value` is not a valid parameter value.
+/// Only pass values that are known to be valid.
+pub(crate) fn param(name: &str, value: &str) -> Param<String> {
+    param_with_values(name, [value])
+}
+
+/// Write a property whose value is either a `DATE` or a `DATE-TIME`.
+///
+/// The `TZID` parameter is set to `time_zone` if the value is a date-time in local time.
+fn write_date_or_date_time<W: Write>(
+    writer: &mut ical_vcard::Writer<W>,
+    name: &str,
+    value: DateOrDateTime,
+    time_zone: Option<&str>,
+) -> io::Result<()> {
+    let mut contentline = Contentline::new(name, value.to_string());
+    match value {
+        DateOrDateTime::Date(_) => contentline.params.push(param("VALUE", "DATE")),
+        DateOrDateTime::DateTime(date_time) => {
+            if let Some(time_zone) = time_zone
+                && !date_time.time.is_utc()
+            {
+                contentline.params.push(param("TZID", time_zone));
+            }
+        }
+    }
+    writer.write(&contentline)
+}
+
+/// Create a [`Param`] with multiple values.
+///
+/// # Panics
+///
+/// Panics if `name` is not a valid parameter name or if any of the `values` is not a valid
+/// parameter value. Only pass values that are known to be valid.
+pub(crate) fn param_with_values<I, S>(name: &str, values: I) -> Param<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    Param {
+        name: Identifier::new(name.to_owned())
+            .unwrap_or_else(|err| panic!("Invalid parameter name: {err}")),
+        values: values
+            .into_iter()
+            .map(|value| {
+                ParamValue::new(value.into())
+                    .unwrap_or_else(|err| panic!("Invalid parameter value: {err}"))
+            })
+            .collect(),
+    }
+}
+
+/// Create a [`Value`], or return an [`InvalidValueError`] for the value described by `what`.
+pub(crate) fn value(what: &'static str, value: String) -> Result<Value<String>, InvalidValueError> {
+    if let Some(err) = InvalidTextError::find(&value) {
+        return Err(InvalidValueError::new(what, err));
+    }
+    Ok(Value::new(value).expect("A text without invalid characters is a valid value"))
+}
+
+/// Create a [`ParamValue`], or return an [`InvalidValueError`] for the parameter value described
+/// by `what`.
+pub(crate) fn param_value(
+    what: &'static str,
+    value: String,
+) -> Result<ParamValue<String>, InvalidValueError> {
+    if let Some(err) = InvalidTextError::find_in_param(&value) {
+        return Err(InvalidValueError::new(what, err));
+    }
+    Ok(ParamValue::new(value)
+        .expect("A text without invalid characters is a valid parameter value"))
+}
+
+/// Write the content lines produced by `write` into a `String`.
+fn write_to_string<F>(write: F) -> String
+where
+    F: FnOnce(&mut ical_vcard::Writer<&mut Vec<u8>>) -> io::Result<()>,
+{
+    let mut output = Vec::new();
+    write(&mut ical_vcard::Writer::new(&mut output)).expect("Writing to a Vec never fails");
+    String::from_utf8(output).expect("Content lines are valid UTF-8")
+}
+
 /// Represents an iCalendar object.
 ///
 /// > The body of the iCalendar object consists of a sequence of calendar properies and
@@ -56,10 +287,31 @@ pub struct Calendar {
     /// See [RFC 5545 section 3.7.3 - Product
     /// Identifier](https://tools.ietf.org/html/rfc5545#section-3.7.3)
     product_identifier: Option<Value<String>>,
+    /// Corresponds to the `METHOD` property.
+    ///
+    /// See [RFC 5545 section 3.7.2 - Method](https://tools.ietf.org/html/rfc5545#section-3.7.2)
+    method: Option<Method>,
+    /// Corresponds to the `IMAGE` property.
+    ///
+    /// See [RFC 7986 section 5.10 - IMAGE](https://tools.ietf.org/html/rfc7986#section-5.10)
+    image: Option<Image>,
+    /// Additional properties, such as `X-` properties.
+    properties: Vec<Contentline>,
+    /// The `VTIMEZONE` components, which are written before all other components.
+    time_zones: Vec<TimeZone>,
     components: Vec<Component>,
+    /// Whether the components are validated before the calendar is written.
+    strict: bool,
+    /// Whether the time zones referenced by the events are included when the calendar is
+    /// written.
+    include_time_zones: bool,
 }
 
 impl Calendar {
+    /// The media type of iCalendar objects, see [RFC 5545 section 8.1 - iCalendar Media Type
+    /// Registration](https://tools.ietf.org/html/rfc5545#section-8.1).
+    pub const MIME_TYPE: &'static str = "text/calendar";
+
     #[allow(
         clippy::new_without_default,
         reason = "The default value is not deterministic"
@@ -69,10 +321,42 @@ impl Calendar {
     pub fn new() -> Self {
         Calendar {
             product_identifier: None,
+            method: None,
+            image: None,
+            properties: Vec::new(),
+            time_zones: Vec::new(),
             components: Vec::new(),
+            strict: false,
+            include_time_zones: false,
         }
     }
 
+    /// Create a calendar that contains only `event`.
+    ///
+    /// This is the most common use of iCalendar objects, for example to send an invitation by
+    /// email. The calendar has the default product identifier, see [`Calendar::new`]. The same
+    /// conversion is available as `Calendar::from(event)`.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event.set_summary("Lunch");
+    /// let calendar = Calendar::from_event(event);
+    /// assert_eq!(calendar.events().count(), 1);
+    /// assert!(calendar.validate().is_ok());
+    /// ```
+    #[must_use]
+    pub fn from_event(event: Event) -> Self {
+        let mut calendar = Self::new();
+        calendar.add_component(event);
+        calendar
+    }
+
     /// Set the product identifier of the calendar.
     ///
     /// By default, the product identifier is set to [`DEFAULT_PRODUCT_IDENTIFIER`].
@@ -82,13 +366,26 @@ impl Calendar {
     ///
     /// # Panics
     ///
-    /// Panics if the product identifier is not a valid [`Value`].
+    /// Panics if the product identifier is not a valid [`Value`]. Use
+    /// [`Calendar::try_set_product_identifier`] if the product identifier is not known to be
+    /// valid.
     pub fn set_product_identifier<S: Into<String>>(&mut self, product_identifier: S) -> &mut Self {
-        self.product_identifier =
-            Some(Value::new(product_identifier.into()).unwrap_or_else(|err| {
-                panic!("Invalid product identifier: {err}");
-            }));
-        self
+        self.try_set_product_identifier(product_identifier)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the product identifier of the calendar, or return an error if it is not a valid
+    /// [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Calendar::set_product_identifier`] panics.
+    pub fn try_set_product_identifier<S: Into<String>>(
+        &mut self,
+        product_identifier: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.product_identifier = Some(value("product identifier", product_identifier.into())?);
+        Ok(self)
     }
 
     /// Get the product identifier of the calendar.
@@ -104,6 +401,176 @@ impl Calendar {
             .map_or(DEFAULT_PRODUCT_IDENTIFIER, |s| s.as_str())
     }
 
+    /// Set the iTIP method of the calendar (`METHOD`), see [`Method`].
+    ///
+    /// Calendars that are not iTIP messages, such as calendars that are stored or published
+    /// for subscription, do not have a method. Use [`Calendar::clear_method`] to remove it.
+    pub fn set_method(&mut self, method: Method) -> &mut Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Remove the iTIP method of the calendar (`METHOD`).
+    pub fn clear_method(&mut self) -> &mut Self {
+        self.method = None;
+        self
+    }
+
+    /// Get the iTIP method of the calendar (`METHOD`).
+    #[must_use]
+    pub fn method(&self) -> Option<Method> {
+        self.method
+    }
+
+    /// Set an image for the calendar, such as a logo or a banner.
+    ///
+    /// The image is referenced by `uri`. The optional `display` hint tells clients how the image
+    /// is intended to be displayed.
+    ///
+    /// See [RFC 7986 section 5.10 - IMAGE](https://tools.ietf.org/html/rfc7986#section-5.10)
+    /// for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uri` is not a valid [`Value`]. Use [`Calendar::try_set_image`] if the URI is
+    /// not known to be valid.
+    pub fn set_image<S: Into<String>>(
+        &mut self,
+        uri: S,
+        display: Option<ImageDisplay>,
+    ) -> &mut Self {
+        self.try_set_image(uri, display)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set an image for the calendar, or return an error if `uri` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Calendar::set_image`] panics.
+    pub fn try_set_image<S: Into<String>>(
+        &mut self,
+        uri: S,
+        display: Option<ImageDisplay>,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.image = Some(Image::try_new(uri.into(), display)?);
+        Ok(self)
+    }
+
+    /// Add an additional property to the calendar, such as `X-PUBLISHED-TTL`.
+    ///
+    /// `params` is a list of `(name, value)` pairs. The property is written after the properties
+    /// that are managed by this crate, in the order in which the properties are added.
+    ///
+    /// # Panics
+    ///
+    /// Panics in any of the following cases:
+    /// - `name` is not a valid property name.
+    /// - `name` is the name of a property that is written by this crate for every calendar
+    ///   (`BEGIN`, `END`, `PRODID`, `VERSION`) or that is set through another method (`METHOD`,
+    ///   `IMAGE`).
+    /// - `value` is not a valid [`Value`].
+    /// - any of the `params` is not a valid parameter.
+    ///
+    /// Use [`Calendar::try_add_property`] if the property is not known to be valid.
+    pub fn add_property<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+        params: &[(&str, &str)],
+    ) -> &mut Self {
+        self.try_add_property(name, value, params)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add an additional property to the calendar, or return an error if it is not valid.
+    ///
+    /// ```
+    /// use ical::{AddPropertyError, Calendar};
+    ///
+    /// let mut calendar = Calendar::new();
+    /// assert!(calendar.try_add_property("X-WR-CALNAME", "Team", &[]).is_ok());
+    /// assert_eq!(
+    ///     calendar.try_add_property("image", "https://example.com/logo.png", &[]),
+    ///     Err(AddPropertyError::ManagedProperty {
+    ///         name: "image".to_owned()
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Calendar::add_property`] panics. The
+    /// calendar is left unchanged.
+    pub fn try_add_property<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+        params: &[(&str, &str)],
+    ) -> Result<&mut Self, AddPropertyError> {
+        let name = name.into();
+        if ["BEGIN", "END", "IMAGE", "METHOD", "PRODID", "VERSION"]
+            .iter()
+            .any(|managed| managed.eq_ignore_ascii_case(&name))
+        {
+            return Err(AddPropertyError::ManagedProperty { name });
+        }
+        let name =
+            Identifier::new(name.clone()).map_err(|_| AddPropertyError::InvalidName { name })?;
+        let value =
+            crate::value("property value", value.into()).map_err(AddPropertyError::InvalidValue)?;
+        let params = params
+            .iter()
+            .map(|&(name, value)| {
+                let invalid = || AddPropertyError::InvalidParam {
+                    name: name.to_owned(),
+                };
+                Ok(Param {
+                    name: Identifier::new(name.to_owned()).map_err(|_| invalid())?,
+                    values: vec![ParamValue::new(value.to_owned()).map_err(|_| invalid())?],
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        self.properties.push(Contentline {
+            group: None,
+            name,
+            params,
+            value,
+        });
+        Ok(self)
+    }
+
+    /// Add a time zone definition (`VTIMEZONE`) to the calendar.
+    ///
+    /// The date-times of the events that refer to the time zone by its identifier are
+    /// interpreted according to its definition. A time zone with the same identifier as `time_zone`
+    /// is replaced.
+    pub fn add_time_zone(&mut self, time_zone: TimeZone) -> &mut Self {
+        match self
+            .time_zones
+            .iter_mut()
+            .find(|existing| existing.id() == time_zone.id())
+        {
+            Some(existing) => *existing = time_zone,
+            None => self.time_zones.push(time_zone),
+        }
+        self
+    }
+
+    /// Get the time zone definitions (`VTIMEZONE`) of the calendar.
+    #[must_use]
+    pub fn time_zones(&self) -> &[TimeZone] {
+        &self.time_zones
+    }
+
+    /// Get the time zone definition with the identifier `id`.
+    #[must_use]
+    pub fn time_zone(&self, id: &str) -> Option<&TimeZone> {
+        self.time_zones
+            .iter()
+            .find(|time_zone| time_zone.id() == id)
+    }
+
     /// Add a [`Component`] to the calendar.
     pub fn add_component<C: Into<Component>>(&mut self, component: C) -> &mut Self {
         self.components.push(component.into());
@@ -116,6 +583,134 @@ impl Calendar {
         &self.components
     }
 
+    /// Get the [`Component`]s of the calendar mutably.
+    #[must_use]
+    pub fn components_mut(&mut self) -> &mut [Component] {
+        &mut self.components
+    }
+
+    /// Remove the [`Component`] at position `index` and return it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_component(&mut self, index: usize) -> Component {
+        assert!(
+            index < self.components.len(),
+            "Component index {index} out of bounds ({} components)",
+            self.components.len()
+        );
+        self.components.remove(index)
+    }
+
+    /// Keep only the [`Component`]s for which `keep` returns `true`.
+    ///
+    /// ```
+    /// use ical::{Calendar, Component, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// for day in 1..=7 {
+    ///     let uid = format!("{day}@example.com");
+    ///     calendar.add_component(Event::with_uid(uid, Date::new(2024, 7, day).into(), stamp));
+    /// }
+    /// calendar.retain(|Component::Event(event)| event.start().value().date().day() > 5);
+    /// assert_eq!(calendar.components().len(), 2);
+    ///
+    /// let event = calendar.find_event_by_uid("6@example.com").unwrap();
+    /// event.set_summary("Party");
+    /// assert!(calendar.find_event_by_uid("1@example.com").is_none());
+    /// ```
+    pub fn retain<F: FnMut(&Component) -> bool>(&mut self, keep: F) -> &mut Self {
+        self.components.retain(keep);
+        self
+    }
+
+    /// Sort the components chronologically by the start (`DTSTART`) of their events.
+    ///
+    /// The sort is stable, that is, components with the same start keep their relative order.
+    /// Components that are not events are placed before all events. Time zones are not
+    /// resolved, so starts in different time zones are compared as if they were in the same
+    /// time zone. A date is treated as the start of that day.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// for day in [3, 1, 2] {
+    ///     let uid = format!("{day}@example.com");
+    ///     calendar.add_component(Event::with_uid(uid, Date::new(2024, 7, day).into(), stamp));
+    /// }
+    /// calendar.sort_by_start();
+    /// let uids: Vec<_> = calendar.events().map(Event::uid).collect();
+    /// assert_eq!(uids, ["1@example.com", "2@example.com", "3@example.com"]);
+    /// ```
+    pub fn sort_by_start(&mut self) -> &mut Self {
+        self.components
+            .sort_by_key(|component| component.as_event().map(|event| event.start().value()));
+        self
+    }
+
+    /// Sort the components with the comparator function `compare`.
+    ///
+    /// The sort is stable, that is, components that compare equal keep their relative order.
+    /// See [`Calendar::sort_by_start`] for a chronological order.
+    pub fn sort_by<F>(&mut self, compare: F) -> &mut Self
+    where
+        F: FnMut(&Component, &Component) -> Ordering,
+    {
+        self.components.sort_by(compare);
+        self
+    }
+
+    /// Find the first event with the given `UID`.
+    ///
+    /// Note that the modified instances of a recurring event (see [`Event::set_recurrence_id`])
+    /// have the same `UID` as the recurring event itself.
+    pub fn find_event_by_uid(&mut self, uid: &str) -> Option<&mut Event> {
+        self.events_mut().find(|event| event.uid() == uid)
+    }
+
+    /// Returns `true` if both calendars have the same properties and their components are
+    /// pairwise [semantically equal](Event::semantically_equal), that is, equal apart from the
+    /// `DTSTAMP` and `SEQUENCE` of their events.
+    ///
+    /// Like [`PartialEq`], this depends on the order of the components.
+    #[must_use]
+    pub fn semantically_equal(&self, other: &Calendar) -> bool {
+        self.product_identifier == other.product_identifier
+            && self.method == other.method
+            && self.image == other.image
+            && self.properties == other.properties
+            && self.time_zones == other.time_zones
+            && self.components.len() == other.components.len()
+            && self
+                .components
+                .iter()
+                .zip(&other.components)
+                .all(|pair| match pair {
+                    (Component::Event(a), Component::Event(b)) => a.semantically_equal(b),
+                })
+    }
+
+    /// Enable or disable strict mode.
+    ///
+    /// In strict mode, [`Calendar::write`] validates all components before anything is written
+    /// and fails if any of them violates RFC 5545, for example because the recurrence rule of an
+    /// event is invalid (see [`RecurrenceRule::validate`]) or does not match the start of the
+    /// event (see [`RecurrenceRule::validate_against`]). Strict mode is disabled by default.
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
     /// Write the calendar to the given writer.
     ///
     /// It is advisable to pass a buffered writer such as [`std::io::BufWriter`] to this function.
@@ -124,20 +719,195 @@ impl Calendar {
     ///
     /// # Errors
     ///
-    /// Returns an error if writing to the writer fails.
+    /// Returns an error if writing to the writer fails. In [strict mode](Calendar::set_strict),
+    /// an error of kind [`io::ErrorKind::InvalidData`] wrapping a [`RuleError`] is returned if a
+    /// component is invalid. If [time zones are included](Calendar::set_include_time_zones), an
+    /// error of the same kind wrapping a [`MissingTimeZoneError`] is returned if an event refers
+    /// to a time zone that is not defined.
     pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
-        let mut writer = ical_vcard::Writer::new(writer);
+        self.validate_before_write()?;
+        self.write_contents(&mut ical_vcard::Writer::new(writer))
+    }
+
+    /// Write the calendar to the given writer with custom line folding and line endings.
+    ///
+    /// See [`WriteOptions`] for the available options. With the default options, this is the
+    /// same as [`Calendar::write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`].
+    pub fn write_with_options<W: Write>(
+        &self,
+        writer: W,
+        options: &WriteOptions,
+    ) -> io::Result<()> {
+        if options.is_default() {
+            return self.write(writer);
+        }
+        self.validate_before_write()?;
+        let mut writer = FoldingWriter::new(writer, *options);
+        self.write_contents(&mut ical_vcard::Writer::new(&mut writer))?;
+        writer.finish()
+    }
+
+    /// Write the calendar to a `String`, with folded lines and CRLF line endings.
+    ///
+    /// This is the same as [`ToString::to_string`], see the [`Display`] implementation. Unlike
+    /// [`Calendar::write`], this does not validate the components in [strict
+    /// mode](Calendar::set_strict).
+    ///
+    /// ```
+    /// use ical::Calendar;
+    ///
+    /// let mut calendar = Calendar::new();
+    /// calendar.set_product_identifier("-//Example//Example Calendar//EN");
+    /// assert_eq!(
+    ///     calendar.to_ics_string(),
+    ///     "BEGIN:VCALENDAR\r\n\
+    ///      PRODID:-//Example//Example Calendar//EN\r\n\
+    ///      VERSION:2.0\r\n\
+    ///      END:VCALENDAR\r\n"
+    /// );
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn to_ics_string(&self) -> String {
+        write_to_string(|writer| self.write_contents(writer))
+    }
+
+    /// Write the calendar to a byte vector, with folded lines and CRLF line endings.
+    ///
+    /// This is the same as [`Calendar::to_ics_string`], and is useful for the body of an HTTP
+    /// response, see [`Calendar::content_type`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_ics_string().into_bytes()
+    }
+
+    /// The value of the `Content-Type` header for serving calendars written by this crate, that
+    /// is, [`Calendar::MIME_TYPE`] with the UTF-8 charset.
+    ///
+    /// ```
+    /// use ical::Calendar;
+    ///
+    /// assert_eq!(Calendar::content_type(), "text/calendar; charset=utf-8");
+    /// ```
+    #[must_use]
+    pub fn content_type() -> &'static str {
+        "text/calendar; charset=utf-8"
+    }
+
+    /// In [strict mode](Calendar::set_strict), check that all components are valid, and check
+    /// that all referenced time zones can be [included](Calendar::set_include_time_zones).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] wrapping a [`RuleError`] if a
+    /// component is invalid, or wrapping a [`MissingTimeZoneError`] if a time zone is missing.
+    fn validate_before_write(&self) -> io::Result<()> {
+        if self.strict {
+            for component in &self.components {
+                component
+                    .validate()
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            }
+        }
+        if self.include_time_zones {
+            self.check_time_zones()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+        Ok(())
+    }
+
+    /// Write the properties and components of the calendar without validating them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    fn write_contents<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        self.write_header(writer)?;
+        for component in &self.components {
+            component.write(writer)?;
+        }
+        write_footer(writer)
+    }
+
+    /// Write everything that comes before the components, that is, `BEGIN:VCALENDAR`, the
+    /// properties of the calendar and its time zones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the writer fails.
+    fn write_header<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
         writer.write(&Contentline::new("BEGIN", "VCALENDAR"))?;
         writer.write(&Contentline::new("PRODID", self.product_identifier()))?;
         writer.write(&Contentline::new("VERSION", "2.0"))?;
-        for component in &self.components {
-            component.write(&mut writer)?;
+        if let Some(method) = self.method {
+            writer.write(&Contentline::new("METHOD", method.to_string()))?;
+        }
+        if let Some(image) = &self.image {
+            image.write(writer)?;
+        }
+        for property in &self.properties {
+            writer.write(property)?;
+        }
+        for time_zone in &self.time_zones {
+            time_zone.write(writer)?;
         }
-        writer.write(&Contentline::new("END", "VCALENDAR"))?;
+        #[cfg(feature = "tzdata")]
+        self.write_referenced_time_zones(writer)?;
         Ok(())
     }
 }
 
+/// Write everything that comes after the components of a calendar, that is, `END:VCALENDAR`.
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails.
+fn write_footer<W: Write>(writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+    writer.write(&Contentline::new("END", "VCALENDAR"))
+}
+
+/// Two calendars are equal if they have the same properties and the same components in the same
+/// order. Whether [strict mode](Calendar::set_strict) is enabled does not matter.
+///
+/// ```
+/// use ical::{Calendar, Date, DateTime, Event, Time};
+///
+/// let stamp = DateTime {
+///     date: Date::new(2024, 7, 1),
+///     time: Time::new_utc(12, 0, 0),
+/// };
+/// let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+/// let mut a = Calendar::new();
+/// a.add_component(event.clone());
+/// let mut b = Calendar::new();
+/// b.add_component(event).set_strict(true);
+/// assert_eq!(a, b);
+///
+/// b.set_product_identifier("-//Example//Calendar//EN");
+/// assert_ne!(a, b);
+/// ```
+impl PartialEq for Calendar {
+    fn eq(&self, other: &Self) -> bool {
+        self.product_identifier == other.product_identifier
+            && self.method == other.method
+            && self.image == other.image
+            && self.properties == other.properties
+            && self.time_zones == other.time_zones
+            && self.components == other.components
+    }
+}
+
+/// Writes the calendar as text, see [`Calendar::to_ics_string`].
+impl Display for Calendar {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.to_ics_string())
+    }
+}
+
 /// Represents a component of a calendar.
 ///
 /// Citing from [RFC 5545 section 3.6 - Calendar
@@ -150,13 +920,44 @@ impl Calendar {
 /// > component can specify an event, a to-do, a journal entry, time zone
 /// > information, free/busy time information, or an alarm.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Component {
     /// An event component.
     Event(Event),
 }
 
 impl Component {
+    /// The name of the component, such as `VEVENT`.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Component::Event(_) => "VEVENT",
+        }
+    }
+
+    /// Get the event, if this component is an event.
+    #[must_use]
+    pub fn as_event(&self) -> Option<&Event> {
+        match self {
+            Component::Event(event) => Some(event),
+        }
+    }
+
+    /// Get the event mutably, if this component is an event.
+    #[must_use]
+    pub fn as_event_mut(&mut self) -> Option<&mut Event> {
+        match self {
+            Component::Event(event) => Some(event),
+        }
+    }
+
+    /// Check that the component does not violate RFC 5545.
+    fn validate(&self) -> Result<(), RuleError> {
+        match self {
+            Component::Event(event) => event.validate(),
+        }
+    }
+
     /// Write the component to the given writer.
     ///
     /// # Errors
@@ -175,13 +976,40 @@ impl From<Event> for Component {
     }
 }
 
+/// Creates a calendar that contains only the event, see [`Calendar::from_event`].
+impl From<Event> for Calendar {
+    fn from(event: Event) -> Self {
+        Self::from_event(event)
+    }
+}
+
 /// Represents an event component of a calendar.
 ///
 /// See [RFC 5545 section 3.6.1 - Event
 /// Component](https://tools.ietf.org/html/rfc5545#section-3.6.1)
-#[derive(Debug, Clone)]
-pub struct Event {
-    /// Corresponds to the `UID` property.
+///
+/// Two events are equal if all of their properties are equal, including their `UID`, `DTSTAMP`
+/// and `SEQUENCE`. Use [`Event::semantically_equal`] to ignore the `DTSTAMP` and `SEQUENCE`.
+///
+/// The setters return `&mut Self`, so several properties can be set in one expression:
+///
+/// ```
+/// use ical::{Date, DateTime, Event, Time};
+///
+/// let stamp = DateTime {
+///     date: Date::new(2024, 7, 1),
+///     time: Time::new_utc(12, 0, 0),
+/// };
+/// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+/// event
+///     .set_summary("Team offsite")
+///     .set_location("Zurich")
+///     .set_end(Date::new(2024, 7, 6));
+/// assert_eq!(event.end(), Some(Date::new(2024, 7, 6).into()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// Corresponds to the `UID` property.
     ///
     /// See [RFC 5545 section 3.8.4.7 - Unique
     /// Identifier](https://tools.ietf.org/html/rfc5545#section-3.8.4.7)
@@ -196,6 +1024,31 @@ pub struct Event {
     /// See [RFC 5545 section 3.8.2.4 - Date-Time
     /// Start](https://tools.ietf.org/html/rfc5545#section-3.8.2.4)
     start_date_time: StartDateTime,
+    /// Corresponds to the `DTEND` property.
+    ///
+    /// See [RFC 5545 section 3.8.2.2 - Date-Time
+    /// End](https://tools.ietf.org/html/rfc5545#section-3.8.2.2)
+    end: Option<DateOrDateTime>,
+    /// Corresponds to the `RECURRENCE-ID` property.
+    ///
+    /// See [RFC 5545 section 3.8.4.4 - Recurrence
+    /// ID](https://tools.ietf.org/html/rfc5545#section-3.8.4.4)
+    recurrence_id: Option<DateOrDateTime>,
+    /// Corresponds to the `SEQUENCE` property.
+    ///
+    /// See [RFC 5545 section 3.8.7.4 - Sequence
+    /// Number](https://tools.ietf.org/html/rfc5545#section-3.8.7.4)
+    sequence: u32,
+    /// Corresponds to the `STATUS` property.
+    ///
+    /// See [RFC 5545 section 3.8.1.11 -
+    /// Status](https://tools.ietf.org/html/rfc5545#section-3.8.1.11)
+    status: Option<EventStatus>,
+    /// Corresponds to the `TRANSP` property.
+    ///
+    /// See [RFC 5545 section 3.8.2.7 - Time
+    /// Transparency](https://tools.ietf.org/html/rfc5545#section-3.8.2.7)
+    transparency: Option<Transparency>,
     /// Corresponds to the `DESCRIPTION` property.
     ///
     /// See [RFC 5545 section 3.8.1.5 -
@@ -206,72 +1059,843 @@ pub struct Event {
     /// See [RFC 5545 section 3.8.1.7 -
     /// Location](https://tools.ietf.org/html/rfc5545#section-3.8.1.7)
     location: Option<Value<String>>,
+    /// Corresponds to the `VLOCATION` component.
+    ///
+    /// If this is set, the `LOCATION` property is derived from it.
+    ///
+    /// See [RFC 9073 section 7.2 - Location
+    /// Component](https://tools.ietf.org/html/rfc9073#section-7.2)
+    structured_location: Option<StructuredLocation>,
     /// Corresponds to the `SUMMARY` property.
     ///
     /// See [RFC 5545 section 3.8.1.12 -
     /// Summary](https://tools.ietf.org/html/rfc5545#section-3.8.1.12)
     summary: Option<Value<String>>,
+    /// Corresponds to the `ORGANIZER` property.
+    ///
+    /// See [RFC 5545 section 3.8.4.3 -
+    /// Organizer](https://tools.ietf.org/html/rfc5545#section-3.8.4.3)
+    organizer: Option<Organizer>,
+    /// Corresponds to the `ATTENDEE` properties.
+    ///
+    /// See [RFC 5545 section 3.8.4.1 -
+    /// Attendee](https://tools.ietf.org/html/rfc5545#section-3.8.4.1)
+    attendees: Vec<Attendee>,
     /// Corresponds to the `RRULE` property.
     ///
     /// See [RFC 5545 section 3.8.5.3 - Recurrence
     /// Rule](https://tools.ietf.org/html/rfc5545#section-3.8.5.3)
     recurrence_rule: Option<RecurrenceRule>,
+    /// Corresponds to the `EXRULE` property.
+    ///
+    /// See [RFC 2445 section 4.8.5.2 - Exception
+    /// Rule](https://tools.ietf.org/html/rfc2445#section-4.8.5.2)
+    exception_rule: Option<RecurrenceRule>,
+    /// Corresponds to the `RDATE` properties.
+    ///
+    /// See [RFC 5545 section 3.8.5.2 - Recurrence
+    /// Date-Times](https://tools.ietf.org/html/rfc5545#section-3.8.5.2)
+    recurrence_dates: Vec<DateOrDateTime>,
+    /// Corresponds to the `EXDATE` properties.
+    ///
+    /// See [RFC 5545 section 3.8.5.1 - Exception
+    /// Date-Times](https://tools.ietf.org/html/rfc5545#section-3.8.5.1)
+    exception_dates: Vec<DateOrDateTime>,
+    /// Corresponds to the `IMAGE` property.
+    ///
+    /// See [RFC 7986 section 5.10 - IMAGE](https://tools.ietf.org/html/rfc7986#section-5.10)
+    image: Option<Image>,
+    /// Corresponds to the `CONFERENCE` properties.
+    ///
+    /// See [RFC 7986 section 5.11 -
+    /// CONFERENCE](https://tools.ietf.org/html/rfc7986#section-5.11)
+    conferences: Vec<Conference>,
+    /// Corresponds to the `COLOR` property.
+    ///
+    /// See [RFC 7986 section 5.9 - COLOR](https://tools.ietf.org/html/rfc7986#section-5.9)
+    color: Option<CssColorName>,
+    /// Corresponds to the `VALARM` components.
+    ///
+    /// See [RFC 5545 section 3.6.6 - Alarm
+    /// Component](https://tools.ietf.org/html/rfc5545#section-3.6.6)
+    alarms: Vec<Alarm>,
 }
 
 impl Event {
     /// Create a new [`Event`].
     ///
     /// The `UID` property is automatically set to a random UUID (v4).
+    ///
+    /// Requires the `uuid` feature, which is enabled by default.
+    #[cfg(feature = "uuid")]
     #[must_use]
-    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
     pub fn new(start_date_time: StartDateTime, date_time: DateTime) -> Self {
-        Self {
-            uid: Value::new(Uuid::new_v4().to_string()).expect("UUIDs are always valid values"),
+        Self::with_uid(Uuid::new_v4().to_string(), start_date_time, date_time)
+    }
+
+    /// Create a new event that lasts all day on `date`.
+    ///
+    /// The start (`DTSTART`) is set to `date` and the end (`DTEND`), which is exclusive, to the
+    /// following day, both as `DATE` values. The `UID` is set to a random UUID (v4) and the
+    /// `DTSTAMP` to the current date and time. Use [`Event::all_day_span`] for events that last
+    /// several days.
+    ///
+    /// ```
+    /// use ical::{Date, Event};
+    ///
+    /// let event = Event::all_day(Date::new(2024, 12, 31));
+    /// assert!(event.start().is_all_day());
+    /// assert_eq!(event.end(), Some(Date::new(2025, 1, 1).into()));
+    /// ```
+    ///
+    /// Requires the `clock` and `uuid` features, which are enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(all(feature = "clock", feature = "uuid"))]
+    #[must_use]
+    pub fn all_day(date: Date) -> Self {
+        let mut event = Self::new(date.into(), DateTime::now_utc());
+        // An event on the last representable day has no end, which also means that it lasts
+        // all day according to RFC 5545.
+        if let Some(end) = date.succ() {
+            event.set_end(end);
+        }
+        event
+    }
+
+    /// Create a new event that lasts all day from `start_date` until the day before
+    /// `end_date_exclusive`.
+    ///
+    /// The end (`DTEND`) of an all-day event is exclusive, so an event that lasts from Monday to
+    /// Wednesday ends on Thursday. The start and end are written as `DATE` values. The `UID` is
+    /// set to a random UUID (v4) and the `DTSTAMP` to the current date and time.
+    ///
+    /// ```
+    /// use ical::{Date, Event};
+    ///
+    /// // A conference from Monday, 2024-07-01, to Wednesday, 2024-07-03.
+    /// let event = Event::all_day_span(Date::new(2024, 7, 1), Date::new(2024, 7, 4));
+    /// let text = event.to_string();
+    /// assert!(text.contains("DTSTART;VALUE=DATE:20240701\r\n"));
+    /// assert!(text.contains("DTEND;VALUE=DATE:20240704\r\n"));
+    /// ```
+    ///
+    /// Requires the `clock` and `uuid` features, which are enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end_date_exclusive` is not after `start_date`, or in the same cases as
+    /// [`DateTime::now_utc`].
+    #[cfg(all(feature = "clock", feature = "uuid"))]
+    #[must_use]
+    pub fn all_day_span(start_date: Date, end_date_exclusive: Date) -> Self {
+        assert!(
+            end_date_exclusive > start_date,
+            "The end of an all-day event must be after its start"
+        );
+        let mut event = Self::new(start_date.into(), DateTime::now_utc());
+        event.set_end(end_date_exclusive);
+        event
+    }
+
+    /// Create a copy of the event that starts at `start`, for example to create many similar
+    /// events such as classes or shifts from a template.
+    ///
+    /// The copy gets a new random `UID` (v4) and its `DTSTAMP` is set to the current date and
+    /// time. Its structured location and its alarms get new `UID`s as well. The descriptive
+    /// properties, such as the summary, the description, the location and the recurrence rule,
+    /// are copied unchanged. The end is moved such that the copy lasts as long as the original,
+    /// and is dropped if the start and the end of the original or the new start do not all have
+    /// the same value type. The properties that refer to the timing of the original, that is,
+    /// the `RECURRENCE-ID`, the recurrence dates and the exception dates, are not copied, and
+    /// the `SEQUENCE` is reset to 0.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, Time};
+    ///
+    /// let at = |day, hour| DateTime {
+    ///     date: Date::new(2024, 9, day),
+    ///     time: Time::new_utc(hour, 0, 0),
+    /// };
+    /// let mut template = Event::new(at(2, 17).into(), at(1, 12));
+    /// template.set_summary("Yoga").set_end(at(2, 18));
+    ///
+    /// let class = template.clone_for(at(4, 17).into());
+    /// assert_ne!(class.uid(), template.uid());
+    /// assert_eq!(class.end(), Some(at(4, 18).into()));
+    /// assert!(class.to_string().contains("SUMMARY:Yoga\r\n"));
+    /// ```
+    ///
+    /// Requires the `clock` and `uuid` features, which are enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`DateTime::now_utc`].
+    #[cfg(all(feature = "clock", feature = "uuid"))]
+    #[must_use]
+    pub fn clone_for(&self, start: StartDateTime) -> Self {
+        let end = self
+            .end
+            .and_then(|end| shifted_end(self.start_date_time.value(), end, start.value()));
+        let mut event = self.clone();
+        event.uid = value("UID", Uuid::new_v4().to_string()).expect("UUIDs are valid values");
+        event.date_time = DateTime::now_utc();
+        event.start_date_time = start;
+        event.end = end;
+        event.recurrence_id = None;
+        event.sequence = 0;
+        event.recurrence_dates.clear();
+        event.exception_dates.clear();
+        if let Some(location) = &mut event.structured_location {
+            location.regenerate_uid(event.uid.as_str(), 0);
+        }
+        Alarm::regenerate_uids(&mut event.alarms);
+        event
+    }
+
+    /// Create a new [`Event`] with the given `UID` property.
+    ///
+    /// The `UID` must be globally unique, see [RFC 5545 section 3.8.4.7 - Unique
+    /// Identifier](https://tools.ietf.org/html/rfc5545#section-3.8.4.7).
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = Event::with_uid("19970610T172345Z-AF23B2@example.com", stamp.into(), stamp);
+    /// assert_eq!(event.uid(), "19970610T172345Z-AF23B2@example.com");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uid` is not a valid [`Value`]. Use [`Event::try_with_uid`] if the UID is not
+    /// known to be valid.
+    #[must_use]
+    pub fn with_uid<S: Into<String>>(
+        uid: S,
+        start_date_time: StartDateTime,
+        date_time: DateTime,
+    ) -> Self {
+        Self::try_with_uid(uid, start_date_time, date_time).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new [`Event`] with the given `UID` property, or return an error if it is not a
+    /// valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::with_uid`] panics.
+    pub fn try_with_uid<S: Into<String>>(
+        uid: S,
+        start_date_time: StartDateTime,
+        date_time: DateTime,
+    ) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            uid: value("UID", uid.into())?,
             date_time,
             start_date_time,
+            end: None,
+            recurrence_id: None,
+            sequence: 0,
+            status: None,
+            transparency: None,
             description: None,
             location: None,
+            structured_location: None,
             summary: None,
+            organizer: None,
+            attendees: Vec::new(),
             recurrence_rule: None,
+            exception_rule: None,
+            recurrence_dates: Vec::new(),
+            exception_dates: Vec::new(),
+            image: None,
+            conferences: Vec::new(),
+            color: None,
+            alarms: Vec::new(),
+        })
+    }
+
+    /// Create an [`EventBuilder`], which checks at compile time that all required properties
+    /// are set.
+    #[must_use]
+    pub fn builder() -> EventBuilder {
+        EventBuilder::new()
+    }
+
+    /// Get the unique identifier of the event (`UID`).
+    #[must_use]
+    pub fn uid(&self) -> &str {
+        self.uid.as_str()
+    }
+
+    /// Get the date-time at which the event was created or last modified (`DTSTAMP`).
+    #[must_use]
+    pub fn stamp(&self) -> DateTime {
+        self.date_time
+    }
+
+    /// Get the start of the event (`DTSTART`).
+    #[must_use]
+    pub fn start(&self) -> &StartDateTime {
+        &self.start_date_time
+    }
+
+    /// Set the end of the event (`DTEND`), which is exclusive.
+    ///
+    /// The end should have the same value type as the start, that is, it should be a date if the
+    /// start is a date. If the start has a time zone, an end in local time is written with the
+    /// same time zone.
+    ///
+    /// See [RFC 5545 section 3.8.2.2 - Date-Time
+    /// End](https://tools.ietf.org/html/rfc5545#section-3.8.2.2) for more information.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, ZonedDateTime};
+    ///
+    /// let local = |hour| DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_local(hour, 0, 0),
+    /// };
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid(
+    ///     "1@example.com",
+    ///     ZonedDateTime::new(local(9), "Europe/Zurich").into(),
+    ///     stamp,
+    /// );
+    /// event.set_end(local(10));
+    /// let mut calendar = Calendar::new();
+    /// calendar.add_component(event);
+    /// let mut output = Vec::new();
+    /// calendar.write(&mut output).unwrap();
+    /// let output = String::from_utf8(output).unwrap();
+    /// assert!(output.contains("DTSTART;TZID=Europe/Zurich:20240705T090000\r\n"));
+    /// assert!(output.contains("DTEND;TZID=Europe/Zurich:20240705T100000\r\n"));
+    /// ```
+    pub fn set_end<D: Into<DateOrDateTime>>(&mut self, end: D) -> &mut Self {
+        self.end = Some(end.into());
+        self
+    }
+
+    /// Get the end of the event (`DTEND`), if any.
+    #[must_use]
+    pub fn end(&self) -> Option<DateOrDateTime> {
+        self.end
+    }
+
+    /// Set the recurrence identifier of the event (`RECURRENCE-ID`).
+    ///
+    /// An event with a recurrence identifier replaces the instance of the recurring event with the
+    /// same `UID` that starts at the given date or date-time. As for the end, a recurrence
+    /// identifier in local time is written with the time zone of the start, if any.
+    ///
+    /// See [RFC 5545 section 3.8.4.4 - Recurrence
+    /// ID](https://tools.ietf.org/html/rfc5545#section-3.8.4.4) for more information.
+    pub fn set_recurrence_id<D: Into<DateOrDateTime>>(&mut self, recurrence_id: D) -> &mut Self {
+        self.recurrence_id = Some(recurrence_id.into());
+        self
+    }
+
+    /// Get the recurrence identifier of the event (`RECURRENCE-ID`), if any.
+    #[must_use]
+    pub fn recurrence_id(&self) -> Option<DateOrDateTime> {
+        self.recurrence_id
+    }
+
+    /// Set the revision number of the event (`SEQUENCE`).
+    ///
+    /// The sequence number starts at 0 and must be incremented whenever the organizer makes a
+    /// significant change to the event, such as changing its start. It is only written if it is
+    /// not 0.
+    ///
+    /// See [RFC 5545 section 3.8.7.4 - Sequence
+    /// Number](https://tools.ietf.org/html/rfc5545#section-3.8.7.4) for more information.
+    pub fn set_sequence(&mut self, sequence: u32) -> &mut Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Get the revision number of the event (`SEQUENCE`).
+    #[must_use]
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// Set the status of the event (`STATUS`), for example to mark it as cancelled.
+    pub fn set_status(&mut self, status: EventStatus) -> &mut Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Get the status of the event (`STATUS`).
+    #[must_use]
+    pub fn status(&self) -> Option<EventStatus> {
+        self.status
+    }
+
+    /// Set whether the event blocks time in free/busy searches (`TRANSP`).
+    pub fn set_transparency(&mut self, transparency: Transparency) -> &mut Self {
+        self.transparency = Some(transparency);
+        self
+    }
+
+    /// Get whether the event blocks time in free/busy searches (`TRANSP`).
+    ///
+    /// If no transparency is set, RFC 5545 specifies [`Transparency::Opaque`] as the default.
+    #[must_use]
+    pub fn transparency(&self) -> Option<Transparency> {
+        self.transparency
+    }
+
+    /// Returns `true` if the events are equal apart from their `DTSTAMP` and `SEQUENCE`.
+    ///
+    /// This is useful to check whether an event that was generated again actually changed.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, Time};
+    ///
+    /// let stamp = |hour| DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(hour, 0, 0),
+    /// };
+    /// let event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp(8));
+    /// let mut other = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp(9));
+    /// other.set_sequence(1);
+    /// assert_ne!(event, other);
+    /// assert!(event.semantically_equal(&other));
+    ///
+    /// other.set_summary("Lunch");
+    /// assert!(!event.semantically_equal(&other));
+    /// ```
+    #[must_use]
+    pub fn semantically_equal(&self, other: &Event) -> bool {
+        let mut other = other.clone();
+        other.date_time = self.date_time;
+        other.sequence = self.sequence;
+        *self == other
+    }
+
+    /// Compute a digest of the content of the event.
+    ///
+    /// The digest covers everything that is written for the event, except for the `DTSTAMP`
+    /// property and the `UID`s of the structured location, of the alarms and of the locations of
+    /// the alarms, which usually change every time an event is generated. Two events with the same digest can therefore be considered equal,
+    /// for example to avoid incrementing the `SEQUENCE` of an event that was generated again but
+    /// did not change.
+    ///
+    /// The digest is a 64-bit FNV-1a hash. It does not depend on the platform or the Rust
+    /// version, but it may change between versions of this crate, so it should not be stored
+    /// across upgrades.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, StartDateTime, Time};
+    ///
+    /// let stamp = |hour| DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(hour, 0, 0),
+    /// };
+    /// let start: StartDateTime = Date::new(2024, 7, 5).into();
+    /// let mut event = Event::with_uid("1@example.com", start.clone(), stamp(8));
+    /// let regenerated = Event::with_uid("1@example.com", start, stamp(9));
+    /// assert_eq!(event.content_hash(), regenerated.content_hash());
+    ///
+    /// event.set_summary("Lunch");
+    /// assert_ne!(event.content_hash(), regenerated.content_hash());
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn content_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let mut event = self.clone();
+        event.date_time = DateTime {
+            date: Date::new(1970, 1, 1),
+            time: Time::new_utc(0, 0, 0),
+        };
+        let uid = value("UID", String::new()).expect("The empty string is a valid value");
+        if let Some(location) = &mut event.structured_location {
+            location.set_uid(uid.clone());
+        }
+        for alarm in &mut event.alarms {
+            alarm.replace_uids(&uid);
         }
+        event.to_string().bytes().fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
     }
 
     /// Set the description of the event.
     ///
     /// # Panics
     ///
-    /// Panics if `description` is not a valid [`Value`].
-    pub fn set_description<S: Into<String>>(&mut self, description: S) {
-        self.description = Some(Value::new(description.into()).unwrap_or_else(|err| {
-            panic!("Invalid description: {err}");
-        }));
+    /// Panics if `description` is not a valid [`Value`]. Use [`Event::try_set_description`] if
+    /// the description is not known to be valid.
+    pub fn set_description<S: Into<String>>(&mut self, description: S) -> &mut Self {
+        self.try_set_description(description)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the description of the event, or return an error if it is not a valid [`Value`].
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Event, Time};
+    ///
+    /// let date_time = DateTime {
+    ///     date: Date::new(2024, 1, 1),
+    ///     time: Time::new_utc(9, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", date_time.into(), date_time);
+    /// assert!(event.try_set_description("Bring a cake").is_ok());
+    /// assert!(event.try_set_description("Bring\u{7}a cake").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::set_description`] panics.
+    pub fn try_set_description<S: Into<String>>(
+        &mut self,
+        description: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.description = Some(value("description", description.into())?);
+        Ok(self)
+    }
+
+    /// Get the description of the event (`DESCRIPTION`), if any.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(Value::as_str)
     }
 
     /// Set the location of the event.
     ///
+    /// This replaces a location previously set with [`Event::set_location_structured`].
+    ///
     /// # Panics
     ///
-    /// Panics if `location` is not a valid [`Value`].
-    pub fn set_location<S: Into<String>>(&mut self, location: S) {
-        self.location = Some(Value::new(location.into()).unwrap_or_else(|err| {
-            panic!("Invalid location: {err}");
-        }));
+    /// Panics if `location` is not a valid [`Value`]. Use [`Event::try_set_location`] if the
+    /// location is not known to be valid.
+    pub fn set_location<S: Into<String>>(&mut self, location: S) -> &mut Self {
+        self.try_set_location(location)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the location of the event, or return an error if it is not a valid [`Value`].
+    ///
+    /// This replaces a location previously set with [`Event::set_location_structured`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::set_location`] panics.
+    pub fn try_set_location<S: Into<String>>(
+        &mut self,
+        location: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.location = Some(value("location", location.into())?);
+        self.structured_location = None;
+        Ok(self)
+    }
+
+    /// Get the location of the event (`LOCATION`), if any.
+    ///
+    /// This is `None` if the location was set with [`Event::set_location_structured`], see
+    /// [`Event::structured_location`] instead.
+    #[must_use]
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_ref().map(Value::as_str)
+    }
+
+    /// Set a structured location for the event.
+    ///
+    /// The location is written both as a `VLOCATION` component and as a plain `LOCATION`
+    /// property (`<name>\, <address>`) for clients that do not support `VLOCATION`. The two are
+    /// always derived from the same data and therefore stay in sync.
+    ///
+    /// This replaces a location previously set with [`Event::set_location`].
+    ///
+    /// See [RFC 9073 section 7.2 - Location
+    /// Component](https://tools.ietf.org/html/rfc9073#section-7.2) for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name`, `address` or `uri` is not a valid [`Value`]. Use
+    /// [`Event::try_set_location_structured`] if they are not known to be valid.
+    pub fn set_location_structured<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: Option<&str>,
+        geo: Option<Geo>,
+        uri: Option<&str>,
+    ) -> &mut Self {
+        self.try_set_location_structured(name, address, geo, uri)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set a structured location for the event, or return an error if `name`, `address` or
+    /// `uri` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::set_location_structured`] panics.
+    pub fn try_set_location_structured<S: Into<String>>(
+        &mut self,
+        name: S,
+        address: Option<&str>,
+        geo: Option<Geo>,
+        uri: Option<&str>,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.structured_location = Some(StructuredLocation::try_new(
+            self.uid.as_str(),
+            0,
+            name.into(),
+            address,
+            geo,
+            uri,
+        )?);
+        self.location = None;
+        Ok(self)
+    }
+
+    /// Get the structured location of the event, if any.
+    #[must_use]
+    pub fn structured_location(&self) -> Option<&StructuredLocation> {
+        self.structured_location.as_ref()
     }
 
     /// Set the summary for the event.
     ///
     /// # Panics
     ///
-    /// Panics if `summary` is not a valid [`Value`].
-    pub fn set_summary<S: Into<String>>(&mut self, summary: S) {
-        self.summary = Some(Value::new(summary.into()).unwrap_or_else(|err| {
-            panic!("Invalid summary: {err}");
-        }));
+    /// Panics if `summary` is not a valid [`Value`]. Use [`Event::try_set_summary`] if the
+    /// summary is not known to be valid.
+    pub fn set_summary<S: Into<String>>(&mut self, summary: S) -> &mut Self {
+        self.try_set_summary(summary)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the summary for the event, or return an error if it is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::set_summary`] panics.
+    pub fn try_set_summary<S: Into<String>>(
+        &mut self,
+        summary: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.summary = Some(value("summary", summary.into())?);
+        Ok(self)
+    }
+
+    /// Get the summary of the event (`SUMMARY`), if any.
+    #[must_use]
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_ref().map(Value::as_str)
+    }
+
+    /// Set the organizer of the event (`ORGANIZER`).
+    ///
+    /// RFC 5545 requires an organizer if the event has attendees.
+    pub fn set_organizer(&mut self, organizer: Organizer) -> &mut Self {
+        self.organizer = Some(organizer);
+        self
+    }
+
+    /// Get the organizer of the event (`ORGANIZER`).
+    #[must_use]
+    pub fn organizer(&self) -> Option<&Organizer> {
+        self.organizer.as_ref()
+    }
+
+    /// Add an [`Attendee`] to the event.
+    pub fn add_attendee(&mut self, attendee: Attendee) -> &mut Self {
+        self.attendees.push(attendee);
+        self
+    }
+
+    /// Get the [`Attendee`]s of the event.
+    #[must_use]
+    pub fn attendees(&self) -> &[Attendee] {
+        &self.attendees
+    }
+
+    /// Get mutable references to the [`Attendee`]s of the event, for example to update their
+    /// participation status.
+    pub fn attendees_mut(&mut self) -> &mut [Attendee] {
+        &mut self.attendees
     }
 
     /// Set a recurrence rule for the event.
-    pub fn set_recurrence_rule(&mut self, recurrence_rule: RecurrenceRule) {
+    pub fn set_recurrence_rule(&mut self, recurrence_rule: RecurrenceRule) -> &mut Self {
         self.recurrence_rule = Some(recurrence_rule);
+        self
+    }
+
+    /// Set an exception rule for the event (`EXRULE`).
+    ///
+    /// The instances of the exception rule are excluded from the occurrences of the event.
+    ///
+    /// **`EXRULE` is deprecated.** It was defined in [RFC
+    /// 2445](https://tools.ietf.org/html/rfc2445#section-4.8.5.2), but removed in RFC 5545, and
+    /// many clients ignore it, showing the excluded occurrences anyway. Only use this to
+    /// interoperate with legacy systems that require it, such as older versions of Microsoft
+    /// Exchange. Otherwise, use [`Event::add_exception_date`] instead. The use of this property is
+    /// reported by [`Calendar::compat_report`].
+    pub fn set_exception_rule(&mut self, exception_rule: RecurrenceRule) -> &mut Self {
+        self.exception_rule = Some(exception_rule);
+        self
+    }
+
+    /// Add a date on which the event occurs in addition to the ones given by its recurrence
+    /// rule (`RDATE`).
+    pub fn add_recurrence_date<D: Into<DateOrDateTime>>(&mut self, date: D) -> &mut Self {
+        self.recurrence_dates.push(date.into());
+        self
+    }
+
+    /// Add a date on which the event does not occur, even though its recurrence rule or
+    /// recurrence dates say so (`EXDATE`).
+    ///
+    /// A date without a time excludes all occurrences on that day.
+    pub fn add_exception_date<D: Into<DateOrDateTime>>(&mut self, date: D) -> &mut Self {
+        self.exception_dates.push(date.into());
+        self
+    }
+
+    /// Get the occurrences of the event that start in the range from `start` (inclusive) to
+    /// `end` (exclusive).
+    ///
+    /// The recurrence rule, the recurrence dates, the exception rule and the exception dates of
+    /// the event are all taken into account. The start of the event is always its first
+    /// occurrence, unless it is excluded.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Date, DateOrDateTime, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time,
+    /// };
+    ///
+    /// let start = DateTime {
+    ///     date: Date::new(2024, 1, 1),
+    ///     time: Time::new_utc(9, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", start.into(), start);
+    /// event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+    /// event.add_exception_date(Date::new(2024, 1, 15));
+    ///
+    /// let occurrences: Vec<_> = event
+    ///     .occurrences_between(Date::new(2024, 1, 2), Date::new(2024, 2, 1))
+    ///     .collect();
+    /// assert_eq!(
+    ///     occurrences,
+    ///     [8, 22, 29].map(|day| DateOrDateTime::DateTime(DateTime {
+    ///         date: Date::new(2024, 1, day),
+    ///         time: Time::new_utc(9, 0, 0),
+    ///     }))
+    /// );
+    /// ```
+    ///
+    /// Unbounded recurrence rules are only expanded up to a limit, see [`Occurrences`].
+    pub fn occurrences_between<S, E>(&self, start: S, end: E) -> Occurrences<'_>
+    where
+        S: Into<DateOrDateTime>,
+        E: Into<DateOrDateTime>,
+    {
+        Occurrences::new(
+            RuleInstances::new(
+                self.recurrence_rule.as_ref(),
+                self.start_date_time.value,
+                Occurrences::DEFAULT_LIMIT,
+            ),
+            &self.recurrence_dates,
+            self.exception_rule.as_ref().map(|exception_rule| {
+                RuleInstances::without_start(
+                    exception_rule,
+                    self.start_date_time.value,
+                    Occurrences::DEFAULT_LIMIT,
+                )
+            }),
+            &self.exception_dates,
+            start.into(),
+            end.into(),
+        )
+    }
+
+    /// Set an image for the event.
+    ///
+    /// The image is referenced by `uri`. The optional `display` hint tells clients how the image
+    /// is intended to be displayed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uri` is not a valid [`Value`]. Use [`Event::try_set_image`] if the URI is not
+    /// known to be valid.
+    pub fn set_image<S: Into<String>>(
+        &mut self,
+        uri: S,
+        display: Option<ImageDisplay>,
+    ) -> &mut Self {
+        self.try_set_image(uri, display)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set an image for the event, or return an error if `uri` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Event::set_image`] panics.
+    pub fn try_set_image<S: Into<String>>(
+        &mut self,
+        uri: S,
+        display: Option<ImageDisplay>,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.image = Some(Image::try_new(uri.into(), display)?);
+        Ok(self)
+    }
+
+    /// Add a [`Conference`] to the event, such as a video call or a dial-in number.
+    pub fn add_conference(&mut self, conference: Conference) -> &mut Self {
+        self.conferences.push(conference);
+        self
+    }
+
+    /// Get the [`Conference`]s of the event.
+    #[must_use]
+    pub fn conferences(&self) -> &[Conference] {
+        &self.conferences
+    }
+
+    /// Set the color used by clients to display the event.
+    ///
+    /// See [RFC 7986 section 5.9 - COLOR](https://tools.ietf.org/html/rfc7986#section-5.9)
+    /// for more information.
+    pub fn set_color(&mut self, color: CssColorName) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The `UID` and `RECURRENCE-ID` of the event, which identify it within a calendar.
+    fn identity(&self) -> (String, Option<DateOrDateTime>) {
+        (self.uid.as_str().to_owned(), self.recurrence_id)
+    }
+
+    /// Check that the event does not violate RFC 5545.
+    fn validate(&self) -> Result<(), RuleError> {
+        if let Some(recurrence_rule) = &self.recurrence_rule {
+            recurrence_rule.validate()?;
+            recurrence_rule.validate_against(&self.start_date_time)?;
+        }
+        if let Some(exception_rule) = &self.exception_rule {
+            exception_rule.validate()?;
+            exception_rule.validate_against(&self.start_date_time)?;
+        }
+        Ok(())
     }
 
     /// Write the event to the given writer.
@@ -284,19 +1908,371 @@ impl Event {
         writer.write(&Contentline::new("UID", self.uid.as_str()))?;
         writer.write(&Contentline::new("DTSTAMP", self.date_time.to_string()))?;
         self.start_date_time.write(writer)?;
+        let time_zone = self.start_date_time.time_zone();
+        if let Some(end) = self.end {
+            write_date_or_date_time(writer, "DTEND", end, time_zone)?;
+        }
+        if let Some(recurrence_id) = self.recurrence_id {
+            write_date_or_date_time(writer, "RECURRENCE-ID", recurrence_id, time_zone)?;
+        }
+        if self.sequence != 0 {
+            writer.write(&Contentline::new("SEQUENCE", self.sequence.to_string()))?;
+        }
+        if let Some(status) = self.status {
+            writer.write(&Contentline::new("STATUS", status.to_string()))?;
+        }
+        if let Some(transparency) = self.transparency {
+            writer.write(&Contentline::new("TRANSP", transparency.to_string()))?;
+        }
         if let Some(description) = &self.description {
             writer.write(&Contentline::new("DESCRIPTION", description.as_str()))?;
         }
         if let Some(location) = &self.location {
             writer.write(&Contentline::new("LOCATION", location.as_str()))?;
+        } else if let Some(structured_location) = &self.structured_location {
+            writer.write(&Contentline::new("LOCATION", structured_location.text()))?;
         }
         if let Some(summary) = &self.summary {
             writer.write(&Contentline::new("SUMMARY", summary.as_str()))?;
         }
+        if let Some(organizer) = &self.organizer {
+            organizer.write(writer)?;
+        }
+        for attendee in &self.attendees {
+            attendee.write(writer)?;
+        }
         if let Some(recurrence_rule) = &self.recurrence_rule {
             writer.write(&Contentline::new("RRULE", recurrence_rule.to_string()))?;
         }
+        if let Some(exception_rule) = &self.exception_rule {
+            writer.write(&Contentline::new("EXRULE", exception_rule.to_string()))?;
+        }
+        for date in &self.recurrence_dates {
+            write_date_or_date_time(writer, "RDATE", *date, time_zone)?;
+        }
+        for date in &self.exception_dates {
+            write_date_or_date_time(writer, "EXDATE", *date, time_zone)?;
+        }
+        if let Some(image) = &self.image {
+            image.write(writer)?;
+        }
+        for conference in &self.conferences {
+            conference.write(writer)?;
+        }
+        if let Some(color) = self.color {
+            writer.write(&Contentline::new("COLOR", color.as_str()))?;
+        }
+        if let Some(structured_location) = &self.structured_location {
+            structured_location.write(writer)?;
+        }
+        for alarm in &self.alarms {
+            alarm.write(writer, self.summary())?;
+        }
         writer.write(&Contentline::new("END", "VEVENT"))?;
         Ok(())
     }
 }
+
+/// The end of an event that lasted from `start` to `end`, moved to start at `new_start`.
+///
+/// Returns `None` if the values do not all have the same type, or if the moved end is out of
+/// range.
+pub(crate) fn shifted_end(
+    start: DateOrDateTime,
+    end: DateOrDateTime,
+    new_start: DateOrDateTime,
+) -> Option<DateOrDateTime> {
+    match (start, end, new_start) {
+        (
+            DateOrDateTime::Date(start),
+            DateOrDateTime::Date(end),
+            DateOrDateTime::Date(new_start),
+        ) => new_start
+            .checked_add_days(i64::from(start.days_between(end)))
+            .map(DateOrDateTime::Date),
+        (
+            DateOrDateTime::DateTime(start),
+            DateOrDateTime::DateTime(end),
+            DateOrDateTime::DateTime(new_start),
+        ) => new_start
+            .checked_add(end.duration_since(start)?)
+            .map(DateOrDateTime::DateTime),
+        _ => None,
+    }
+}
+
+/// Writes the `VEVENT` component as text, with folded lines and CRLF line endings.
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&write_to_string(|writer| self.write(writer)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AddPropertyError, Attendee, Calendar, Conference, ConferenceFeature, CssColorName, Date,
+        DateTime, Event, Geo, ImageDisplay, Observance, ObservanceKind, Organizer, Time, UtcOffset,
+    };
+
+    fn event() -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp)
+    }
+
+    /// Write `calendar` and check that reading the output gives the same calendar.
+    fn round_trip(mut calendar: Calendar) -> String {
+        calendar.set_product_identifier("-//Example//Example Calendar//EN");
+        let output = calendar.to_ics_string();
+        assert_eq!(Calendar::from_ics(output.as_str()), Ok(calendar));
+        output
+    }
+
+    #[test]
+    fn image() {
+        let mut event = event();
+        event.set_image(
+            "https://example.com/event.png",
+            Some(ImageDisplay::Thumbnail),
+        );
+        let mut calendar = Calendar::from_event(event);
+        calendar.set_image("https://example.com/logo.png", None);
+        let output = round_trip(calendar);
+        assert!(output.contains("IMAGE;VALUE=URI:https://example.com/logo.png\r\n"));
+        assert!(
+            output.contains("IMAGE;VALUE=URI;DISPLAY=THUMBNAIL:https://example.com/event.png\r\n")
+        );
+    }
+
+    #[test]
+    fn conference() {
+        let mut video = Conference::new("https://example.com/call");
+        video
+            .add_feature(ConferenceFeature::Audio)
+            .add_feature(ConferenceFeature::Video)
+            .set_label("Video call");
+        let mut event = event();
+        event
+            .add_conference(video)
+            .add_conference(Conference::new("tel:+1-555-0100"));
+        let output = round_trip(Calendar::from_event(event)).replace("\r\n ", "");
+        assert!(output.contains(
+            "CONFERENCE;VALUE=URI;FEATURE=AUDIO,VIDEO;LABEL=Video call:https://example.com/call\r\n"
+        ));
+        assert!(output.contains("CONFERENCE;VALUE=URI:tel:+1-555-0100\r\n"));
+    }
+
+    #[test]
+    fn structured_location() {
+        let mut event = event();
+        event.set_location_structured(
+            "Conference Room",
+            Some("Main Street 1"),
+            Some(Geo::new(47.5, 8.5)),
+            Some("https://example.com/room"),
+        );
+        let output = round_trip(Calendar::from_event(event)).replace("\r\n ", "");
+        assert!(output.contains("LOCATION:Conference Room\\, Main Street 1\r\n"));
+        assert!(output.contains("BEGIN:VLOCATION\r\nUID:"));
+        assert!(output.contains(
+            "\r\nNAME:Conference Room\r\n\
+             DESCRIPTION:Main Street 1\r\n\
+             GEO:47.5;8.5\r\n\
+             URL:https://example.com/room\r\n\
+             END:VLOCATION\r\n"
+        ));
+    }
+
+    #[test]
+    fn color() {
+        let mut event = event();
+        event.set_color(CssColorName::MediumVioletRed);
+        let output = round_trip(Calendar::from_event(event));
+        assert!(output.contains("COLOR:mediumvioletred\r\n"));
+    }
+
+    #[test]
+    fn additional_properties() {
+        let mut calendar = Calendar::from_event(event());
+        calendar
+            .add_property("X-PUBLISHED-TTL", "PT1H", &[("VALUE", "DURATION")])
+            .add_property("X-WR-CALNAME", "Holidays", &[]);
+        let output = round_trip(calendar);
+        assert!(output.contains(
+            "X-PUBLISHED-TTL;VALUE=DURATION:PT1H\r\nX-WR-CALNAME:Holidays\r\nBEGIN:VEVENT\r\n"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "The version property is managed by this crate")]
+    fn additional_property_managed_by_crate() {
+        Calendar::new().add_property("version", "2.0", &[]);
+    }
+
+    #[test]
+    fn try_add_property() {
+        let mut calendar = Calendar::from_event(event());
+        let before = calendar.clone();
+        assert_eq!(
+            calendar.try_add_property("Image", "https://example.com/logo.png", &[]),
+            Err(AddPropertyError::ManagedProperty {
+                name: "Image".to_owned()
+            })
+        );
+        assert_eq!(
+            calendar.try_add_property("X WR", "Team", &[]),
+            Err(AddPropertyError::InvalidName {
+                name: "X WR".to_owned()
+            })
+        );
+        let err = calendar
+            .try_add_property("X-WR-CALNAME", "Te\u{7}am", &[])
+            .unwrap_err();
+        assert!(
+            matches!(err, AddPropertyError::InvalidValue(err) if err.text_error().index() == 2)
+        );
+        assert_eq!(
+            calendar.try_add_property("X-WR-CALNAME", "Team", &[("X PARAM", "a")]),
+            Err(AddPropertyError::InvalidParam {
+                name: "X PARAM".to_owned()
+            })
+        );
+        assert_eq!(calendar, before);
+    }
+
+    #[cfg(all(feature = "clock", feature = "uuid"))]
+    #[test]
+    fn clone_for_regenerates_alarm_uids() {
+        use crate::{Alarm, Duration, Trigger};
+
+        let at = |hour| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        let mut alarm = Alarm::display(Trigger::relative_to_start(Duration::default()));
+        alarm
+            .set_uid("alarm-1@example.com")
+            .add_location("Office", None, None, None);
+        let snoozed = alarm.snooze(at(8), at(9));
+        let mut template = event();
+        template.add_alarm(alarm).add_alarm(snoozed);
+
+        let copy = template.clone_for(Date::new(2024, 7, 12).into());
+        let [alarm, snoozed] = copy.alarms() else {
+            panic!("The copy should have two alarms");
+        };
+        let uid = alarm.uid().unwrap();
+        assert_ne!(uid, "alarm-1@example.com");
+        assert_eq!(snoozed.uid(), None);
+        assert_eq!(snoozed.snoozed_alarm(), Some(uid));
+        // The locations only differ in their `UID`s.
+        assert_ne!(alarm.locations(), template.alarms()[0].locations());
+        assert_ne!(alarm.locations(), snoozed.locations());
+    }
+
+    #[test]
+    fn try_setters_reject_control_characters() {
+        let mut event = event();
+        event
+            .set_description("Bring a cake")
+            .set_location("Office")
+            .set_summary("Offsite");
+        let before = event.clone();
+        let err = event.try_set_description("Bring\u{7}a cake").unwrap_err();
+        assert_eq!(err.what(), "description");
+        assert_eq!(err.text_error().index(), 5);
+        assert!(event.try_set_location("Off\u{0}ice").is_err());
+        assert!(event.try_set_summary("Off\u{1b}site").is_err());
+        assert!(
+            event
+                .try_set_location_structured("Office", Some("Main St\u{7}"), None, None)
+                .is_err()
+        );
+        assert!(
+            event
+                .try_set_image("https://example.com/\u{0}.png", None)
+                .is_err()
+        );
+        assert_eq!(event, before);
+
+        let stamp = event.date_time;
+        let start = event.start_date_time;
+        assert!(Event::try_with_uid("1\u{7f}@example.com", start, stamp).is_err());
+
+        let mut calendar = Calendar::new();
+        calendar.set_product_identifier("-//Example//Example Calendar//EN");
+        let before = calendar.clone();
+        assert!(
+            calendar
+                .try_set_product_identifier("-//Example//\u{8}//EN")
+                .is_err()
+        );
+        assert!(
+            calendar
+                .try_set_image("https://example.com/\u{8}.png", None)
+                .is_err()
+        );
+        assert_eq!(calendar, before);
+
+        let err = Conference::try_new("https://example.com/\u{7}").unwrap_err();
+        assert_eq!(err.what(), "conference URI");
+        let mut conference = Conference::new("https://example.com/call");
+        conference.set_label("Call");
+        let before = conference.clone();
+        let err = conference.try_set_label("Ca\u{7}ll").unwrap_err();
+        assert_eq!(err.what(), "conference label");
+        assert_eq!(err.text_error().index(), 2);
+        assert_eq!(conference, before);
+
+        let err = Organizer::try_new("mailto:jane\u{0}@example.com").unwrap_err();
+        assert_eq!(err.what(), "organizer address");
+        let err = Attendee::try_new("mailto:john\u{0}@example.com").unwrap_err();
+        assert_eq!(err.what(), "attendee address");
+
+        let start = DateTime {
+            date: Date::new(2024, 3, 31),
+            time: Time::new_local(2, 0, 0),
+        };
+        let offset_from = UtcOffset::from_seconds(3600).unwrap();
+        let offset_to = UtcOffset::from_seconds(7200).unwrap();
+        let mut observance =
+            Observance::new(ObservanceKind::Daylight, start, offset_from, offset_to);
+        observance.set_name("CEST");
+        let before = observance.clone();
+        assert!(observance.try_set_name("CE\u{7}ST").is_err());
+        assert_eq!(observance, before);
+    }
+
+    #[test]
+    fn content_hash_ignores_generated_uids() {
+        use crate::{Alarm, Duration, Trigger};
+
+        let at = |hour| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        let event = |alarm_uid: &str| {
+            let mut alarm = Alarm::display(Trigger::relative_to_start(-Duration::from_minutes(15)));
+            alarm
+                .set_uid(alarm_uid)
+                .add_location("Office", None, None, None);
+            let snoozed = alarm.snooze(at(8), at(9));
+            let mut event = event();
+            event
+                .set_location_structured("Office", None, None, None)
+                .add_alarm(alarm)
+                .add_alarm(snoozed);
+            event
+        };
+        let hash = event("alarm-1@example.com").content_hash();
+        assert_eq!(event("alarm-1@example.com").content_hash(), hash);
+        assert_eq!(event("alarm-2@example.com").content_hash(), hash);
+
+        let mut changed = event("alarm-1@example.com");
+        changed.set_summary("Lunch");
+        assert_ne!(changed.content_hash(), hash);
+    }
+}
@@ -0,0 +1,10 @@
+/// A language for human-readable text produced by this crate.
+///
+/// See for example [`RecurrenceRule::to_human_string`](crate::RecurrenceRule::to_human_string).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English.
+    English,
+    /// German.
+    German,
+}
@@ -0,0 +1,190 @@
+use {
+    crate::{InvalidValueError, value},
+    ical_vcard::{Contentline, Value},
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+/// Represents a structured location as specified in
+/// [RFC 9073 section 7.2 - Location
+/// Component](https://tools.ietf.org/html/rfc9073#section-7.2).
+///
+/// A structured location is written as a `VLOCATION` component. Since many clients do not support
+/// `VLOCATION` yet, a plain `LOCATION` property is derived from the same data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredLocation {
+    uid: Value<String>,
+    name: Value<String>,
+    address: Option<Value<String>>,
+    geo: Option<Geo>,
+    uri: Option<Value<String>>,
+}
+
+/// The `UID` of the `VLOCATION` component at the (0-based) position `index` of the event or
+/// alarm with the `UID` `owner_uid`.
+#[cfg(feature = "uuid")]
+fn location_uid(_owner_uid: &str, _index: usize) -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// The `UID` of the `VLOCATION` component at the (0-based) position `index` of the event or
+/// alarm with the `UID` `owner_uid`.
+#[cfg(not(feature = "uuid"))]
+fn location_uid(owner_uid: &str, index: usize) -> String {
+    match index {
+        0 => format!("{owner_uid}-location"),
+        _ => format!("{owner_uid}-location-{}", index + 1),
+    }
+}
+
+impl StructuredLocation {
+    /// Create a new [`StructuredLocation`].
+    ///
+    /// The `UID` of the `VLOCATION` component is automatically set to a random UUID (v4). Without
+    /// the `uuid` feature, it is derived from `owner_uid`, the `UID` of the event or alarm, and
+    /// from `index`, the position of the location, instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name`, `address` or `uri` is not a valid [`Value`].
+    pub(crate) fn try_new(
+        owner_uid: &str,
+        index: usize,
+        name: String,
+        address: Option<&str>,
+        geo: Option<Geo>,
+        uri: Option<&str>,
+    ) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            uid: Value::new(location_uid(owner_uid, index))
+                .expect("UUIDs and event UIDs are valid values"),
+            name: value("location name", name)?,
+            address: address
+                .map(|address| value("location address", address.to_owned()))
+                .transpose()?,
+            geo,
+            uri: uri
+                .map(|uri| value("location URI", uri.to_owned()))
+                .transpose()?,
+        })
+    }
+
+    /// Get the name of the location.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Get the address of the location.
+    #[must_use]
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_ref().map(Value::as_str)
+    }
+
+    /// Get the geographic position of the location.
+    #[must_use]
+    pub fn geo(&self) -> Option<Geo> {
+        self.geo
+    }
+
+    /// Get the URI of the location.
+    #[must_use]
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_ref().map(Value::as_str)
+    }
+
+    /// Give the `VLOCATION` component a new `UID`, as if it was created at the position `index`
+    /// of the event or alarm with the `UID` `owner_uid`.
+    pub(crate) fn regenerate_uid(&mut self, owner_uid: &str, index: usize) {
+        self.uid = Value::new(location_uid(owner_uid, index))
+            .expect("UUIDs and event UIDs are valid values");
+    }
+
+    /// Set the `UID` of the `VLOCATION` component.
+    pub(crate) fn set_uid(&mut self, uid: Value<String>) {
+        self.uid = uid;
+    }
+
+    /// The text of the plain `LOCATION` property corresponding to this location.
+    ///
+    /// The comma between the name and the address is escaped, since it is part of the text.
+    pub(crate) fn text(&self) -> String {
+        match &self.address {
+            Some(address) => format!("{}\\, {}", self.name.as_str(), address.as_str()),
+            None => self.name.as_str().to_owned(),
+        }
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        writer.write(&Contentline::new("BEGIN", "VLOCATION"))?;
+        writer.write(&Contentline::new("UID", self.uid.as_str()))?;
+        writer.write(&Contentline::new("NAME", self.name.as_str()))?;
+        if let Some(address) = &self.address {
+            writer.write(&Contentline::new("DESCRIPTION", address.as_str()))?;
+        }
+        if let Some(geo) = self.geo {
+            writer.write(&Contentline::new("GEO", geo.to_string()))?;
+        }
+        if let Some(uri) = &self.uri {
+            writer.write(&Contentline::new("URL", uri.as_str()))?;
+        }
+        writer.write(&Contentline::new("END", "VLOCATION"))?;
+        Ok(())
+    }
+}
+
+/// Represents a geographic position as specified in
+/// [RFC 5545 section 3.8.1.6](https://tools.ietf.org/html/rfc5545#section-3.8.1.6).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geo {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Geo {
+    /// Create a new [`Geo`] from a latitude and a longitude in degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics in any of the following cases:
+    /// - `latitude` is not in the range -90 to 90.
+    /// - `longitude` is not in the range -180 to 180.
+    #[must_use]
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        assert!(
+            (-90.0..=90.0).contains(&latitude),
+            "Latitude must be between -90 and 90"
+        );
+        assert!(
+            (-180.0..=180.0).contains(&longitude),
+            "Longitude must be between -180 and 180"
+        );
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Get the latitude in degrees.
+    #[must_use]
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Get the longitude in degrees.
+    #[must_use]
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+}
+
+impl Display for Geo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{};{}", self.latitude, self.longitude)
+    }
+}
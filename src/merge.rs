@@ -0,0 +1,168 @@
+use {
+    crate::{Calendar, DateOrDateTime, Event},
+    std::collections::HashMap,
+};
+
+/// How [`Calendar::merge`] resolves conflicts between two events with the same `UID` and
+/// `RECURRENCE-ID`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Keep the event of the calendar that is merged into.
+    KeepExisting,
+    /// Replace the event with the event of the calendar that is merged.
+    Replace,
+    /// Keep the newer event, that is, the event with the higher `SEQUENCE`, or the later
+    /// `DTSTAMP` if the sequence numbers are equal. If both are equal, the existing event is
+    /// kept.
+    Newest,
+}
+
+impl Calendar {
+    /// Add the components of `other` to this calendar.
+    ///
+    /// Events are identified by their `UID` and `RECURRENCE-ID`. If an event of `other` has the
+    /// same identity as an event of this calendar, only one of them is kept, as determined by
+    /// `policy`. All other components of `other` are added after the existing components. The
+    /// properties of `other`, such as its product identifier, are ignored.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, MergePolicy, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = |summary: &str, sequence| {
+    ///     let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    ///     event.set_summary(summary);
+    ///     event.set_sequence(sequence);
+    ///     event
+    /// };
+    /// let mut local = Calendar::new();
+    /// local.add_component(event("Lunch", 1));
+    /// let mut remote = Calendar::new();
+    /// remote.add_component(event("Dinner", 2));
+    /// local.merge(remote, MergePolicy::Newest);
+    /// assert_eq!(local.events().count(), 1);
+    /// assert_eq!(local.events().next().unwrap().sequence(), 2);
+    /// ```
+    pub fn merge(&mut self, other: Calendar, policy: MergePolicy) -> &mut Self {
+        let mut positions: HashMap<(String, Option<DateOrDateTime>), usize> = self
+            .components
+            .iter()
+            .enumerate()
+            .filter_map(|(position, component)| Some((component.as_event()?.identity(), position)))
+            .collect();
+        for component in other.components {
+            let Some(event) = component.as_event() else {
+                self.components.push(component);
+                continue;
+            };
+            let identity = event.identity();
+            if let Some(&position) = positions.get(&identity) {
+                let replace = self.components[position]
+                    .as_event()
+                    .is_some_and(|current| replaces(current, event, policy));
+                if replace {
+                    self.components[position] = component;
+                }
+            } else {
+                positions.insert(identity, self.components.len());
+                self.components.push(component);
+            }
+        }
+        self
+    }
+}
+
+/// Returns `true` if `new` replaces `current` according to `policy`.
+fn replaces(current: &Event, new: &Event, policy: MergePolicy) -> bool {
+    match policy {
+        MergePolicy::KeepExisting => false,
+        MergePolicy::Replace => true,
+        MergePolicy::Newest => {
+            (new.sequence(), new.stamp()) > (current.sequence(), current.stamp())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::MergePolicy,
+        crate::{Calendar, Date, DateTime, Event, Time},
+    };
+
+    fn event(uid: &str, hour: u8, sequence: u32) -> Event {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(hour, 0, 0),
+        };
+        let mut event = Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+        event.set_sequence(sequence);
+        event
+    }
+
+    fn merge(policy: MergePolicy) -> Vec<(String, u8, u32)> {
+        let mut calendar = Calendar::new();
+        calendar
+            .add_component(event("a", 8, 1))
+            .add_component(event("b", 8, 0))
+            .add_component(event("c", 8, 0));
+        let mut modified = event("a", 9, 0);
+        modified.set_recurrence_id(Date::new(2024, 7, 12));
+        let mut other = Calendar::new();
+        other
+            .add_component(event("a", 9, 0))
+            .add_component(event("b", 9, 0))
+            .add_component(event("c", 8, 0))
+            .add_component(modified)
+            .add_component(event("d", 9, 0));
+        calendar.merge(other, policy);
+        calendar
+            .events()
+            .map(|event| {
+                (
+                    event.uid().to_owned(),
+                    event.stamp().time.hour(),
+                    event.sequence(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn policies() {
+        let entry = |uid: &str, hour, sequence| (uid.to_owned(), hour, sequence);
+        assert_eq!(
+            merge(MergePolicy::KeepExisting),
+            [
+                entry("a", 8, 1),
+                entry("b", 8, 0),
+                entry("c", 8, 0),
+                entry("a", 9, 0),
+                entry("d", 9, 0),
+            ]
+        );
+        assert_eq!(
+            merge(MergePolicy::Replace),
+            [
+                entry("a", 9, 0),
+                entry("b", 9, 0),
+                entry("c", 8, 0),
+                entry("a", 9, 0),
+                entry("d", 9, 0),
+            ]
+        );
+        assert_eq!(
+            merge(MergePolicy::Newest),
+            [
+                entry("a", 8, 1),
+                entry("b", 9, 0),
+                entry("c", 8, 0),
+                entry("a", 9, 0),
+                entry("d", 9, 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use {
+    crate::{Calendar, Component, DateOrDateTime, Event},
+    ical_vcard::{Contentline, Identifier},
+};
+
+impl Calendar {
+    /// Bring the calendar into a canonical form, so that two calendars with the same content
+    /// are written identically. This is useful to compare two feeds with a textual diff.
+    ///
+    /// Normalizing does not change the meaning of the calendar:
+    /// - The components are sorted by their start (`DTSTART`), then by their `UID`, then by
+    ///   their `RECURRENCE-ID`, with the master event before its modified instances.
+    ///   Components that are not events are placed before all events.
+    /// - The [additional properties](Calendar::add_property) are sorted by name and their
+    ///   names and parameter names are converted to upper case. The properties that are managed
+    ///   by this crate are always written in the same order, following the order in which they
+    ///   are listed in RFC 5545.
+    /// - Redundant defaults are removed, that is, a `VALUE=TEXT` parameter of an additional
+    ///   property and `WKST=MO` in recurrence rules. The end (`DTEND`) of an event is always
+    ///   kept, even if it is equal to the end that is implied by the start, since clients
+    ///   disagree about the end of an event without one.
+    /// - Recurrence dates (`RDATE`), exception dates (`EXDATE`) and the values of the parts of
+    ///   recurrence rules are sorted and deduplicated.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = |uid: &str, day| {
+    ///     let mut event = Event::with_uid(uid, Date::new(2024, 7, day).into(), stamp);
+    ///     event.set_end(Date::new(2024, 7, day + 1));
+    ///     event
+    /// };
+    /// let mut a = Calendar::from_events([event("b@example.com", 5), event("a@example.com", 5)]);
+    /// a.add_property("x-wr-calname", "Team", &[]);
+    /// let mut b = Calendar::from_events([event("a@example.com", 5), event("b@example.com", 5)]);
+    /// b.add_property("X-WR-CALNAME", "Team", &[]);
+    /// assert_ne!(a, b);
+    ///
+    /// a.normalize();
+    /// b.normalize();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn normalize(&mut self) -> &mut Self {
+        for property in &mut self.properties {
+            normalize_property(property);
+        }
+        self.properties
+            .sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        for component in &mut self.components {
+            match component {
+                Component::Event(event) => event.normalize(),
+            }
+        }
+        self.components
+            .sort_by(|a, b| sort_key(a).cmp(&sort_key(b)));
+        self
+    }
+}
+
+impl Event {
+    /// Bring the event into a canonical form, see [`Calendar::normalize`].
+    fn normalize(&mut self) {
+        for rule in [&mut self.recurrence_rule, &mut self.exception_rule]
+            .into_iter()
+            .flatten()
+        {
+            rule.normalize();
+        }
+        for dates in [&mut self.recurrence_dates, &mut self.exception_dates] {
+            dates.sort_unstable();
+            dates.dedup();
+        }
+    }
+}
+
+/// The key by which [`Calendar::normalize`] sorts the components.
+fn sort_key(component: &Component) -> Option<(DateOrDateTime, &str, Option<DateOrDateTime>)> {
+    component
+        .as_event()
+        .map(|event| (event.start().value(), event.uid(), event.recurrence_id))
+}
+
+/// Convert the name and the parameter names of `property` to upper case and remove a
+/// `VALUE=TEXT` parameter, since `TEXT` is the default value type of additional properties.
+fn normalize_property(property: &mut Contentline) {
+    let upper_case = |name: &Identifier<String>| {
+        Identifier::new(name.as_str().to_ascii_uppercase())
+            .unwrap_or_else(|_| unreachable!("Changing the case keeps the identifier valid"))
+    };
+    property.name = upper_case(&property.name);
+    if let Some(group) = &property.group {
+        property.group = Some(upper_case(group));
+    }
+    for param in &mut property.params {
+        param.name = upper_case(&param.name);
+    }
+    property.params.retain(|param| {
+        !(param.name.as_str() == "VALUE"
+            && param.values.len() == 1
+            && param.values[0].as_str().eq_ignore_ascii_case("TEXT"))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time, Weekday,
+    };
+
+    fn at(day: u8, hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, day),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        rule.set_by_day([Weekday::Friday, Weekday::Monday, Weekday::Friday])
+            .set_week_start(Weekday::Monday);
+        let mut master = Event::with_uid("weekly", at(1, 9).into(), at(1, 0));
+        master
+            .set_end(at(1, 10))
+            .set_recurrence_rule(rule)
+            .add_exception_date(at(15, 9))
+            .add_exception_date(at(8, 9))
+            .add_exception_date(at(15, 9));
+        let mut moved = Event::with_uid("weekly", at(1, 9).into(), at(1, 0));
+        moved.set_end(at(1, 9)).set_recurrence_id(at(5, 9));
+        let mut calendar = Calendar::from_events([moved, master]);
+        calendar
+            .add_property("x-b", "2", &[("value", "text")])
+            .add_property("X-A", "1", &[("x-param", "a")]);
+
+        calendar.normalize();
+        let output = calendar.to_string();
+        let lines: Vec<_> = output
+            .lines()
+            .filter(|line| {
+                ["X-", "RRULE", "EXDATE", "DTEND", "RECURRENCE-ID"]
+                    .iter()
+                    .any(|name| line.starts_with(name))
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "X-A;X-PARAM=a:1",
+                "X-B:2",
+                "DTEND:20240701T100000Z",
+                "RRULE:FREQ=WEEKLY;BYDAY=MO,FR",
+                "EXDATE:20240708T090000Z",
+                "EXDATE:20240715T090000Z",
+                "DTEND:20240701T090000Z",
+                "RECURRENCE-ID:20240705T090000Z",
+            ]
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use {
+    crate::{Calendar, DateOrDateTime, Event, recurrence_rule::RuleInstances},
+    std::{
+        collections::{HashSet, VecDeque},
+        iter::Peekable,
+    },
+};
+
+/// An iterator over the occurrences of an [`Event`](crate::Event) within a time range, in
+/// chronological order.
+///
+/// Created by [`Event::occurrences_between`](crate::Event::occurrences_between).
+///
+/// The occurrences are the instances of the recurrence rule (`RRULE`) of the event together with
+/// its recurrence dates (`RDATE`), without the instances of its exception rule (`EXRULE`) and its
+/// exception dates (`EXDATE`). An exception date without a time excludes all occurrences on that
+/// day.
+///
+/// Unbounded recurrence rules can produce an infinite number of instances, and some rules never
+/// produce an instance at all. To guarantee termination, at most [`Occurrences::DEFAULT_LIMIT`]
+/// periods (years, months, weeks, ... depending on the frequency of the rule) are evaluated for
+/// each rule.
+/// The limit can be changed with [`Occurrences::limit`].
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    start: DateOrDateTime,
+    end: DateOrDateTime,
+    rule_instances: RuleInstances,
+    next_rule_instance: Option<DateOrDateTime>,
+    recurrence_dates: VecDeque<DateOrDateTime>,
+    exception_rule_instances: Option<RuleInstances>,
+    next_exception_rule_instance: Option<DateOrDateTime>,
+    exception_dates: &'a [DateOrDateTime],
+    last: Option<DateOrDateTime>,
+}
+
+impl<'a> Occurrences<'a> {
+    /// The default maximum number of periods of the recurrence rule that are evaluated.
+    pub const DEFAULT_LIMIT: usize = 1_000_000;
+
+    pub(crate) fn new(
+        rule_instances: RuleInstances,
+        recurrence_dates: &[DateOrDateTime],
+        exception_rule_instances: Option<RuleInstances>,
+        exception_dates: &'a [DateOrDateTime],
+        start: DateOrDateTime,
+        end: DateOrDateTime,
+    ) -> Self {
+        let mut recurrence_dates = recurrence_dates.to_vec();
+        recurrence_dates.sort_by_key(|date| date.chronological_key());
+        Self {
+            start,
+            end,
+            rule_instances,
+            next_rule_instance: None,
+            recurrence_dates: recurrence_dates.into(),
+            exception_rule_instances,
+            next_exception_rule_instance: None,
+            exception_dates,
+            last: None,
+        }
+    }
+
+    /// Set the maximum number of periods of the recurrence rule that are evaluated.
+    ///
+    /// This must be called before the iterator is advanced.
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.rule_instances.set_period_limit(limit);
+        if let Some(exception_rule_instances) = &mut self.exception_rule_instances {
+            exception_rule_instances.set_period_limit(limit);
+        }
+        self
+    }
+
+    /// Returns `true` if the expansion of the recurrence rule or the exception rule stopped
+    /// because the limit was reached.
+    ///
+    /// If this is the case, there may be further occurrences in the range that were not
+    /// produced, or occurrences that should have been excluded.
+    #[must_use]
+    pub fn limit_reached(&self) -> bool {
+        self.rule_instances.limit_reached()
+            || self
+                .exception_rule_instances
+                .as_ref()
+                .is_some_and(RuleInstances::limit_reached)
+    }
+
+    /// Returns `true` if `occurrence` is excluded by the exception rule or an exception date.
+    ///
+    /// Must be called with occurrences in chronological order.
+    fn is_excluded(&mut self, occurrence: DateOrDateTime) -> bool {
+        if let Some(exception_rule_instances) = &mut self.exception_rule_instances {
+            let key = occurrence.chronological_key();
+            while self
+                .next_exception_rule_instance
+                .is_none_or(|instance| instance.chronological_key() < key)
+            {
+                match exception_rule_instances.next() {
+                    Some(instance) => self.next_exception_rule_instance = Some(instance),
+                    None => break,
+                }
+            }
+            if self
+                .next_exception_rule_instance
+                .is_some_and(|instance| instance.chronological_key() == key)
+            {
+                return true;
+            }
+        }
+        self.exception_dates
+            .iter()
+            .any(|&exception| match exception {
+                DateOrDateTime::Date(date) => occurrence.date() == date,
+                DateOrDateTime::DateTime(_) => occurrence == exception,
+            })
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateOrDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.next_rule_instance.is_none() {
+                self.next_rule_instance = self.rule_instances.next();
+            }
+            let from_rule = self.next_rule_instance;
+            let from_dates = self.recurrence_dates.front().copied();
+            let next = match (from_rule, from_dates) {
+                (Some(instance), Some(date))
+                    if date.chronological_key() < instance.chronological_key() =>
+                {
+                    self.recurrence_dates.pop_front()
+                }
+                (Some(_), _) => self.next_rule_instance.take(),
+                (None, Some(_)) => self.recurrence_dates.pop_front(),
+                (None, None) => None,
+            }?;
+            if next.chronological_key() >= self.end.chronological_key() {
+                self.recurrence_dates.clear();
+                return None;
+            }
+            if self.last == Some(next) {
+                continue;
+            }
+            self.last = Some(next);
+            if next.chronological_key() < self.start.chronological_key() || self.is_excluded(next) {
+                continue;
+            }
+            return Some(next);
+        }
+    }
+}
+
+impl Calendar {
+    /// Get the occurrences of all events of the calendar that start in the range from `start`
+    /// (inclusive) to `end` (exclusive), in chronological order, together with their events.
+    ///
+    /// The occurrences of each event are computed as described for
+    /// [`Event::occurrences_between`]. If an instance of a recurring event is modified by an
+    /// event with the same `UID` and a `RECURRENCE-ID` (see [`Event::set_recurrence_id`]), the
+    /// modified event replaces the original instance, even if the modified event is moved out
+    /// of the range. Occurrences that start at the same time are ordered like their events in the
+    /// calendar.
+    ///
+    /// Occurrences that start before `start` are not included, even if they are still ongoing at
+    /// `start`.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+    ///
+    /// let at = |day, hour| DateTime {
+    ///     date: Date::new(2024, 7, day),
+    ///     time: Time::new_utc(hour, 0, 0),
+    /// };
+    /// let mut standup = Event::with_uid("standup@example.com", at(1, 9).into(), at(1, 0));
+    /// standup
+    ///     .set_summary("Standup")
+    ///     .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+    /// // The standup on July 3 is moved to 11:00.
+    /// let mut moved = Event::with_uid("standup@example.com", at(3, 11).into(), at(1, 0));
+    /// moved.set_summary("Standup").set_recurrence_id(at(3, 9));
+    /// let mut lunch = Event::with_uid("lunch@example.com", at(2, 12).into(), at(1, 0));
+    /// lunch.set_summary("Lunch");
+    ///
+    /// let calendar = Calendar::from_events([standup, moved, lunch]);
+    /// let agenda: Vec<_> = calendar
+    ///     .events_between(Date::new(2024, 7, 2), Date::new(2024, 7, 4))
+    ///     .map(|(event, start)| (event.uid(), start))
+    ///     .collect();
+    /// assert_eq!(
+    ///     agenda,
+    ///     [
+    ///         ("standup@example.com", at(2, 9).into()),
+    ///         ("lunch@example.com", at(2, 12).into()),
+    ///         ("standup@example.com", at(3, 11).into()),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// Unbounded recurrence rules are only expanded up to a limit, see [`Occurrences`].
+    pub fn events_between<S, E>(&self, start: S, end: E) -> EventsBetween<'_>
+    where
+        S: Into<DateOrDateTime>,
+        E: Into<DateOrDateTime>,
+    {
+        let (start, end) = (start.into(), end.into());
+        EventsBetween {
+            occurrences: self
+                .events()
+                .map(|event| (event, event.occurrences_between(start, end).peekable()))
+                .collect(),
+            modified: self
+                .events()
+                .filter_map(|event| Some((event.uid(), event.recurrence_id()?)))
+                .collect(),
+        }
+    }
+}
+
+/// An iterator over the occurrences of the events of a [`Calendar`] within a time range, in
+/// chronological order.
+///
+/// Created by [`Calendar::events_between`].
+#[derive(Debug, Clone)]
+pub struct EventsBetween<'a> {
+    occurrences: Vec<(&'a Event, Peekable<Occurrences<'a>>)>,
+    /// The `UID`s and `RECURRENCE-ID`s of the modified instances of recurring events.
+    modified: HashSet<(&'a str, DateOrDateTime)>,
+}
+
+impl<'a> EventsBetween<'a> {
+    /// Get the next occurrence together with its event and the index of its event among the
+    /// events of the calendar.
+    pub(crate) fn next_indexed(&mut self) -> Option<(usize, &'a Event, DateOrDateTime)> {
+        loop {
+            let (index, event, occurrences) = self
+                .occurrences
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(index, (event, occurrences))| {
+                    let key = occurrences.peek()?.chronological_key();
+                    Some((key, index, event, occurrences))
+                })
+                // `min_by_key` returns the first minimum, which keeps the order of the events.
+                .min_by_key(|(key, _, _, _)| *key)
+                .map(|(_, index, event, occurrences)| (index, *event, occurrences))?;
+            let occurrence = occurrences.next()?;
+            let is_replaced = event.recurrence_id().is_none()
+                && self.modified.contains(&(event.uid(), occurrence));
+            if !is_replaced {
+                return Some((index, event, occurrence));
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for EventsBetween<'a> {
+    type Item = (&'a Event, DateOrDateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed()
+            .map(|(_, event, occurrence)| (event, occurrence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, Date, DateOrDateTime, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time,
+        Weekday,
+    };
+
+    fn date_time(month: u8, day: u8, hour: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, month, day),
+            time: Time::new_utc(hour, 0, 0),
+        }
+    }
+
+    fn daily_event() -> Event {
+        let start = date_time(1, 1, 9);
+        let mut event = Event::with_uid("1@example.com", start.into(), start);
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        event
+    }
+
+    #[test]
+    fn range() {
+        let event = daily_event();
+        let occurrences: Vec<_> = event
+            .occurrences_between(date_time(1, 3, 9), date_time(1, 5, 9))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [date_time(1, 3, 9).into(), date_time(1, 4, 9).into()]
+        );
+    }
+
+    #[test]
+    fn recurrence_and_exception_dates() {
+        let mut event = daily_event();
+        event.add_recurrence_date(date_time(1, 2, 12));
+        event.add_recurrence_date(date_time(1, 3, 9));
+        event.add_recurrence_date(Date::new(2024, 1, 10));
+        event.add_exception_date(Date::new(2024, 1, 3));
+        event.add_exception_date(date_time(1, 4, 9));
+        let occurrences: Vec<_> = event
+            .occurrences_between(Date::new(2024, 1, 2), Date::new(2024, 1, 6))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                date_time(1, 2, 9).into(),
+                date_time(1, 2, 12).into(),
+                date_time(1, 5, 9).into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn exception_rule() {
+        let mut event = daily_event();
+        // 2024-01-01 is a Monday, which is not excluded because it is the start of the event.
+        let mut exception_rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        exception_rule.set_by_day([Weekday::Tuesday]);
+        event.set_exception_rule(exception_rule);
+        let occurrences: Vec<_> = event
+            .occurrences_between(Date::new(2024, 1, 1), Date::new(2024, 1, 10))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [1, 3, 4, 5, 6, 7, 8].map(|day| DateOrDateTime::from(date_time(1, day, 9)))
+        );
+
+        let mut exception_rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        exception_rule.set_count(2);
+        event.set_exception_rule(exception_rule);
+        let occurrences: Vec<_> = event
+            .occurrences_between(Date::new(2024, 1, 1), Date::new(2024, 1, 5))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [3, 4].map(|day| DateOrDateTime::from(date_time(1, day, 9)))
+        );
+    }
+
+    #[test]
+    fn without_recurrence_rule() {
+        let start = date_time(1, 1, 9);
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 1, 1).into(), start);
+        event.add_recurrence_date(Date::new(2024, 2, 1));
+        let occurrences: Vec<_> = event
+            .occurrences_between(Date::new(2024, 1, 1), Date::new(2025, 1, 1))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                DateOrDateTime::Date(Date::new(2024, 1, 1)),
+                DateOrDateTime::Date(Date::new(2024, 2, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn limit() {
+        let event = daily_event();
+        let mut occurrences = event
+            .occurrences_between(Date::new(2030, 1, 1), Date::new(2030, 1, 2))
+            .limit(100);
+        assert_eq!(occurrences.next(), None);
+        assert!(occurrences.limit_reached());
+
+        let mut occurrences =
+            event.occurrences_between(Date::new(2030, 1, 1), Date::new(2030, 1, 2));
+        let expected = DateTime {
+            date: Date::new(2030, 1, 1),
+            time: Time::new_utc(9, 0, 0),
+        };
+        assert_eq!(occurrences.next(), Some(expected.into()));
+        assert_eq!(occurrences.next(), None);
+        assert!(!occurrences.limit_reached());
+    }
+
+    #[test]
+    fn events_between() {
+        let event = daily_event();
+        let uid = event.uid().to_owned();
+        // The occurrence on January 2 is moved to February 1, out of the range.
+        let mut moved = Event::with_uid(&uid, date_time(2, 1, 9).into(), date_time(1, 1, 0));
+        moved.set_recurrence_id(date_time(1, 2, 9));
+        let other = Event::with_uid("other", date_time(1, 1, 9).into(), date_time(1, 1, 0));
+        let calendar = Calendar::from_events([event, moved, other]);
+        let occurrences: Vec<_> = calendar
+            .events_between(Date::new(2024, 1, 1), Date::new(2024, 1, 4))
+            .map(|(event, start)| (event.uid(), start))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                (uid.as_str(), date_time(1, 1, 9).into()),
+                ("other", date_time(1, 1, 9).into()),
+                (uid.as_str(), date_time(1, 3, 9).into()),
+            ]
+        );
+    }
+}
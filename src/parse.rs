@@ -0,0 +1,677 @@
+use {
+    crate::{
+        Calendar, DateOrDateTime, DateTime, RecurrenceRule, StartDateTime, Time,
+        read::{ComponentNode, ReadError, read_calendar},
+        value_type::escape_text,
+    },
+    ical_vcard::{Contentline, Parser, Value},
+    std::{
+        borrow::Cow,
+        error::Error,
+        fmt::{self, Display, Formatter},
+        str,
+    },
+};
+
+/// The UTF-8 encoded byte order mark, which some programs write at the start of a file.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Options for reading a calendar with
+/// [`Calendar::from_ics_with_options`](crate::Calendar::from_ics_with_options).
+///
+/// By default, only input that follows [RFC 5545](https://tools.ietf.org/html/rfc5545) is
+/// accepted. Files exported by old software need more lenient options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    quoted_printable: bool,
+    windows_1252_fallback: bool,
+    rfc2445: bool,
+}
+
+impl ParseOptions {
+    /// Create the default options, see [`ParseOptions`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            quoted_printable: false,
+            windows_1252_fallback: false,
+            rfc2445: false,
+        }
+    }
+
+    /// Set whether values with the parameter `ENCODING=QUOTED-PRINTABLE` are decoded.
+    ///
+    /// Such values are produced by old Outlook and Nokia exports, usually in vCalendar 1.0
+    /// files. Soft line breaks (a `=` at the end of a line) are joined, the encoded octets are
+    /// decoded as UTF-8, line breaks become `\n` and the `ENCODING` and `CHARSET` parameters are
+    /// removed. If this is disabled, which is the default, such values are rejected.
+    pub fn quoted_printable(&mut self, quoted_printable: bool) -> &mut Self {
+        self.quoted_printable = quoted_printable;
+        self
+    }
+
+    /// Set whether input that is not valid UTF-8 is decoded as Windows-1252 instead of being
+    /// rejected.
+    ///
+    /// Windows-1252 is a superset of the printable characters of Latin-1 (ISO 8859-1), so this
+    /// also covers Latin-1 input. The decoding is lossy: the five octets that are not defined
+    /// in Windows-1252 become U+FFFD REPLACEMENT CHARACTER. Input that is valid UTF-8 is always
+    /// decoded as UTF-8.
+    pub fn windows_1252_fallback(&mut self, windows_1252_fallback: bool) -> &mut Self {
+        self.windows_1252_fallback = windows_1252_fallback;
+        self
+    }
+
+    /// Set whether calendars written for [RFC 2445](https://tools.ietf.org/html/rfc2445), the
+    /// predecessor of RFC 5545, are accepted and converted to the semantics of RFC 5545.
+    ///
+    /// - `RANGE=THISANDPRIOR`, which was removed in RFC 5545, is accepted on `RECURRENCE-ID`.
+    ///   Like `RANGE=THISANDFUTURE`, it is ignored.
+    /// - RFC 2445 requires an `UNTIL` date-time to be in UTC time and allows an `UNTIL` date
+    ///   for a start date-time. The `UNTIL` part of recurrence rules is converted to the value
+    ///   type and the time form of the start: a date becomes the last second of that day, a
+    ///   date-time becomes its date, and the time of a date-time is taken as local time or as
+    ///   UTC time without conversion.
+    ///
+    /// If this is disabled, which is the default, `RANGE=THISANDPRIOR` is rejected and
+    /// recurrence rules are read as they are.
+    pub fn rfc2445(&mut self, rfc2445: bool) -> &mut Self {
+        self.rfc2445 = rfc2445;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Calendar {
+    /// Read a calendar in the iCalendar format, as written by [`Calendar::write`].
+    ///
+    /// This is the same as [`Calendar::from_ics_with_options`] with the default
+    /// [`ParseOptions`]. The input must be UTF-8, optionally starting with a byte order mark.
+    /// Components other than events and time zones are ignored, and so are the properties of
+    /// events that this crate does not know. Unknown properties of the calendar are kept as
+    /// additional properties, see [`Calendar::add_property`].
+    ///
+    /// ```
+    /// use ical::{Calendar, Date};
+    ///
+    /// let calendar = Calendar::from_ics(
+    ///     "BEGIN:VCALENDAR\r\n\
+    ///      PRODID:-//Example//Example Calendar//EN\r\n\
+    ///      VERSION:2.0\r\n\
+    ///      BEGIN:VEVENT\r\n\
+    ///      UID:1@example.com\r\n\
+    ///      DTSTAMP:20240701T120000Z\r\n\
+    ///      DTSTART;VALUE=DATE:20240705\r\n\
+    ///      SUMMARY:Lunch\r\n\
+    ///      END:VEVENT\r\n\
+    ///      END:VCALENDAR\r\n",
+    /// )
+    /// .unwrap();
+    /// let event = calendar.events().next().unwrap();
+    /// assert_eq!(event.summary(), Some("Lunch"));
+    /// assert_eq!(event.start().value(), Date::new(2024, 7, 5).into());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a calendar, if an event lacks a required property
+    /// (`UID`, `DTSTAMP` or `DTSTART`), or if the value of a property is invalid.
+    pub fn from_ics<I: AsRef<[u8]>>(input: I) -> Result<Calendar, ParseCalendarError> {
+        Calendar::from_ics_with_options(input, &ParseOptions::new())
+    }
+
+    /// Read a calendar in the iCalendar format with the given options, see
+    /// [`Calendar::from_ics`].
+    ///
+    /// ```
+    /// use ical::{Calendar, ParseOptions};
+    ///
+    /// let input = "BEGIN:VCALENDAR\r\n\
+    ///              BEGIN:VEVENT\r\n\
+    ///              UID:1@example.com\r\n\
+    ///              DTSTAMP:20240701T120000Z\r\n\
+    ///              DTSTART:20240705T120000Z\r\n\
+    ///              SUMMARY;ENCODING=QUOTED-PRINTABLE;CHARSET=UTF-8:Caf=C3=A9 =\r\n\
+    ///              am See\r\n\
+    ///              END:VEVENT\r\n\
+    ///              END:VCALENDAR\r\n";
+    /// assert!(Calendar::from_ics(input).is_err());
+    ///
+    /// let calendar =
+    ///     Calendar::from_ics_with_options(input, ParseOptions::new().quoted_printable(true))
+    ///         .unwrap();
+    /// let event = calendar.events().next().unwrap();
+    /// assert_eq!(event.summary(), Some("Café am See"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::from_ics`].
+    pub fn from_ics_with_options<I: AsRef<[u8]>>(
+        input: I,
+        options: &ParseOptions,
+    ) -> Result<Calendar, ParseCalendarError> {
+        let input = input.as_ref();
+        let without_bom = input.strip_prefix(BOM).unwrap_or(input);
+        let text = match str::from_utf8(without_bom) {
+            Ok(text) => Cow::Borrowed(text),
+            Err(_) if options.windows_1252_fallback => Cow::Owned(decode_windows_1252(without_bom)),
+            Err(err) => {
+                return Err(ParseCalendarError::InvalidUtf8 {
+                    offset: input.len() - without_bom.len() + err.valid_up_to(),
+                });
+            }
+        };
+        let text = if options.quoted_printable {
+            Cow::Owned(join_soft_line_breaks(&text))
+        } else {
+            text
+        };
+
+        let mut stack: Vec<ComponentNode> = Vec::new();
+        let mut calendar = None;
+        for (index, contentline) in Parser::new(text.as_bytes()).enumerate() {
+            let mut contentline =
+                contentline.map_err(|_| ParseCalendarError::InvalidContentLine {
+                    content_line: index + 1,
+                })?;
+            if calendar.is_some() {
+                return Err(ParseCalendarError::NotACalendar);
+            }
+            let name = contentline.name.as_str().to_ascii_uppercase();
+            let value = contentline.value.as_str().to_ascii_uppercase();
+            match name.as_str() {
+                "BEGIN" => stack.push(ComponentNode {
+                    name: value,
+                    properties: Vec::new(),
+                    components: Vec::new(),
+                }),
+                "END" => {
+                    let node = stack
+                        .pop()
+                        .filter(|node| node.name == value)
+                        .ok_or(ParseCalendarError::NotACalendar)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.components.push(node),
+                        None => calendar = Some(node),
+                    }
+                }
+                _ => {
+                    if is_quoted_printable(&contentline) {
+                        if !options.quoted_printable {
+                            return Err(ParseCalendarError::UnsupportedEncoding { property: name });
+                        }
+                        decode_quoted_printable(&mut contentline).ok_or_else(|| {
+                            ParseCalendarError::InvalidProperty {
+                                property: name.clone(),
+                            }
+                        })?;
+                    }
+                    if name == "RECURRENCE-ID" && is_this_and_prior(&contentline) {
+                        if !options.rfc2445 {
+                            return Err(ParseCalendarError::InvalidProperty { property: name });
+                        }
+                        contentline
+                            .params
+                            .retain(|param| !param.name.as_str().eq_ignore_ascii_case("RANGE"));
+                    }
+                    stack
+                        .last_mut()
+                        .ok_or(ParseCalendarError::NotACalendar)?
+                        .properties
+                        .push(contentline);
+                }
+            }
+        }
+        let calendar = calendar
+            .filter(|node| node.name == "VCALENDAR")
+            .ok_or(ParseCalendarError::NotACalendar)?;
+        let mut calendar = read_calendar(calendar)?;
+        if options.rfc2445 {
+            for event in calendar.events_mut() {
+                let start = event.start_date_time.clone();
+                for rule in [&mut event.recurrence_rule, &mut event.exception_rule]
+                    .into_iter()
+                    .flatten()
+                {
+                    convert_rfc2445_until(rule, &start);
+                }
+            }
+        }
+        Ok(calendar)
+    }
+}
+
+/// Convert the `UNTIL` part of a recurrence rule written for RFC 2445 to the value type and the
+/// time form of `start`, as required by RFC 5545, see [`ParseOptions::rfc2445`].
+fn convert_rfc2445_until(rule: &mut RecurrenceRule, start: &StartDateTime) {
+    let Some(until) = rule.until() else {
+        return;
+    };
+    match (until, start.value) {
+        (DateOrDateTime::Date(date), DateOrDateTime::DateTime(start_value)) => {
+            let mut time = Time::new_local(23, 59, 59);
+            time.set_utc(start_value.time.is_utc() || start.time_zone.is_some());
+            rule.set_until(DateTime { date, time });
+        }
+        (DateOrDateTime::DateTime(until), DateOrDateTime::Date(_)) => {
+            rule.set_until(until.date);
+        }
+        (DateOrDateTime::DateTime(mut until), DateOrDateTime::DateTime(start_value)) => {
+            until
+                .time
+                .set_utc(start_value.time.is_utc() || start.time_zone.is_some());
+            rule.set_until(until);
+        }
+        (DateOrDateTime::Date(_), DateOrDateTime::Date(_)) => (),
+    }
+}
+
+/// Decode `input` as Windows-1252, replacing undefined octets with U+FFFD REPLACEMENT CHARACTER.
+fn decode_windows_1252(input: &[u8]) -> String {
+    input
+        .iter()
+        .map(|&byte| match byte {
+            0x80..=0x9F => WINDOWS_1252[usize::from(byte - 0x80)],
+            // The other octets are the same as in Latin-1, which maps them to the same code
+            // points.
+            _ => char::from(byte),
+        })
+        .collect()
+}
+
+/// The characters of the octets 0x80 to 0x9F in Windows-1252.
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{FFFD}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{FFFD}', 'Ž',
+    '\u{FFFD}', '\u{FFFD}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{FFFD}',
+    'ž', 'Ÿ',
+];
+
+/// Whether `contentline` has the parameter `RANGE=THISANDPRIOR`.
+fn is_this_and_prior(contentline: &Contentline) -> bool {
+    contentline.params.iter().any(|param| {
+        param.name.as_str().eq_ignore_ascii_case("RANGE")
+            && param
+                .values
+                .iter()
+                .any(|value| value.as_str().eq_ignore_ascii_case("THISANDPRIOR"))
+    })
+}
+
+/// Whether `contentline` has the parameter `ENCODING=QUOTED-PRINTABLE`.
+fn is_quoted_printable(contentline: &Contentline) -> bool {
+    contentline.params.iter().any(|param| {
+        param.name.as_str().eq_ignore_ascii_case("ENCODING")
+            && param
+                .values
+                .iter()
+                .any(|value| value.as_str().eq_ignore_ascii_case("QUOTED-PRINTABLE"))
+    })
+}
+
+/// Join the soft line breaks of quoted-printable values, which are not folded like other content
+/// lines.
+fn join_soft_line_breaks(text: &str) -> String {
+    let mut joined = String::with_capacity(text.len());
+    let mut in_soft_line_break = false;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let is_quoted_printable = in_soft_line_break
+            || content.split_once(':').is_some_and(|(head, _)| {
+                head.to_ascii_uppercase()
+                    .contains("ENCODING=QUOTED-PRINTABLE")
+            });
+        if is_quoted_printable && let Some(content) = content.strip_suffix('=') {
+            joined.push_str(content);
+            in_soft_line_break = true;
+        } else {
+            joined.push_str(line);
+            in_soft_line_break = false;
+        }
+    }
+    joined
+}
+
+/// Decode the quoted-printable value of `contentline`, escape the decoded text as a `TEXT` value
+/// and remove the `ENCODING` and `CHARSET` parameters.
+///
+/// Returns `None` if the value is not valid quoted-printable UTF-8 text.
+fn decode_quoted_printable(contentline: &mut Contentline) -> Option<()> {
+    let encoded = contentline.value.as_str().as_bytes();
+    let mut octets = Vec::with_capacity(encoded.len());
+    let mut index = 0;
+    while index < encoded.len() {
+        if encoded[index] == b'=' {
+            let hex = str::from_utf8(encoded.get(index + 1..index + 3)?).ok()?;
+            octets.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            octets.push(encoded[index]);
+            index += 1;
+        }
+    }
+    let text = String::from_utf8(octets).ok()?;
+    contentline.value = Value::new(escape_text(&text)).ok()?;
+    contentline.params.retain(|param| {
+        !param.name.as_str().eq_ignore_ascii_case("ENCODING")
+            && !param.name.as_str().eq_ignore_ascii_case("CHARSET")
+    });
+    Some(())
+}
+
+/// Error type for [`Calendar::from_ics`] and [`Calendar::from_ics_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCalendarError {
+    /// The input is not valid UTF-8, see [`ParseOptions::windows_1252_fallback`].
+    InvalidUtf8 {
+        /// The offset of the first invalid octet.
+        offset: usize,
+    },
+    /// A content line cannot be parsed.
+    InvalidContentLine {
+        /// The (1-based) number of the content line, counting folded lines as one.
+        content_line: usize,
+    },
+    /// The input does not have the structure of a calendar, for example because a component is
+    /// not closed.
+    NotACalendar,
+    /// A component lacks a required property.
+    MissingProperty {
+        /// The name of the component, such as `VEVENT`.
+        component: String,
+        /// The name of the missing property, such as `DTSTART`.
+        property: String,
+    },
+    /// The value or the parameters of a property are invalid.
+    InvalidProperty {
+        /// The name of the property, such as `DTSTART`.
+        property: String,
+    },
+    /// A property is encoded as quoted-printable, see [`ParseOptions::quoted_printable`].
+    UnsupportedEncoding {
+        /// The name of the property, such as `SUMMARY`.
+        property: String,
+    },
+}
+
+impl Display for ParseCalendarError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseCalendarError::InvalidUtf8 { offset } => {
+                write!(f, "Invalid UTF-8 at offset {offset}")
+            }
+            ParseCalendarError::InvalidContentLine { content_line } => {
+                write!(f, "Invalid content line {content_line}")
+            }
+            ParseCalendarError::NotACalendar => write!(f, "The input is not a calendar"),
+            ParseCalendarError::MissingProperty {
+                component,
+                property,
+            } => write!(f, "Missing {property} property in {component} component"),
+            ParseCalendarError::InvalidProperty { property } => {
+                write!(f, "Invalid {property} property")
+            }
+            ParseCalendarError::UnsupportedEncoding { property } => {
+                write!(f, "The {property} property is encoded as quoted-printable")
+            }
+        }
+    }
+}
+
+impl Error for ParseCalendarError {}
+
+impl From<ReadError> for ParseCalendarError {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::MissingProperty {
+                component,
+                property,
+            } => ParseCalendarError::MissingProperty {
+                component: component.to_owned(),
+                property: property.to_owned(),
+            },
+            ReadError::InvalidProperty(property) => {
+                ParseCalendarError::InvalidProperty { property }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Attendee, Calendar, Date, DateTime, Event, EventStatus, Observance, ObservanceKind,
+        Organizer, ParseCalendarError, ParseOptions, PartStat, RecurrenceFrequency, RecurrenceRule,
+        Time, TimeZone, Transparency, Weekday, WeekdayNum, ZonedDateTime,
+    };
+
+    #[test]
+    fn round_trip() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let mut event = Event::with_uid(
+            "1@example.com",
+            ZonedDateTime::new(start, "Europe/Zurich").into(),
+            stamp,
+        );
+        let mut attendee = Attendee::new("mailto:bob@example.com");
+        attendee.set_participation_status(PartStat::Accepted);
+        event
+            .set_summary("Review")
+            .set_status(EventStatus::Confirmed)
+            .set_transparency(Transparency::Transparent)
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .add_attendee(attendee)
+            .set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly))
+            .add_exception_date(Date::new(2024, 7, 12));
+        let mut daylight = Observance::new(
+            ObservanceKind::Daylight,
+            DateTime {
+                date: Date::new(1981, 3, 29),
+                time: Time::new_local(2, 0, 0),
+            },
+            "+0100".parse().unwrap(),
+            "+0200".parse().unwrap(),
+        );
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Yearly);
+        rule.set_by_month([3])
+            .set_by_day([WeekdayNum::nth(-1, Weekday::Sunday)]);
+        daylight
+            .set_name("CEST")
+            .set_recurrence_rule(rule)
+            .add_recurrence_date(DateTime {
+                date: Date::new(1941, 5, 5),
+                time: Time::new_local(1, 0, 0),
+            });
+        let mut time_zone = TimeZone::new("Europe/Zurich");
+        time_zone.add_observance(daylight);
+        let mut calendar = Calendar::new();
+        calendar
+            .set_product_identifier("-//Example//Example Calendar//EN")
+            .add_property("X-WR-CALNAME", "Work", &[])
+            .add_time_zone(time_zone)
+            .add_component(event);
+
+        assert_eq!(Calendar::from_ics(calendar.to_ics_string()), Ok(calendar));
+    }
+
+    #[test]
+    fn quoted_printable() {
+        let input = "BEGIN:VCALENDAR\r\n\
+                     BEGIN:VEVENT\r\n\
+                     UID:1@example.com\r\n\
+                     DTSTAMP:20240701T120000Z\r\n\
+                     DTSTART:20240705T120000Z\r\n\
+                     DESCRIPTION;ENCODING=QUOTED-PRINTABLE:First=0D=0ASecond=\r\n\
+                     =3D\r\n\
+                     LOCATION;ENCODING=QUOTED-PRINTABLE:Z=C3=BCrich\r\n\
+                     END:VEVENT\r\n\
+                     END:VCALENDAR\r\n";
+        assert_eq!(
+            Calendar::from_ics(input),
+            Err(ParseCalendarError::UnsupportedEncoding {
+                property: "DESCRIPTION".to_owned()
+            })
+        );
+        let calendar =
+            Calendar::from_ics_with_options(input, ParseOptions::new().quoted_printable(true))
+                .unwrap();
+        let event = calendar.events().next().unwrap();
+        assert_eq!(event.description(), Some("First\\nSecond="));
+        assert_eq!(event.location(), Some("Zürich"));
+    }
+
+    #[test]
+    fn quoted_printable_escaping() {
+        let input = "BEGIN:VCALENDAR\r\n\
+                     BEGIN:VEVENT\r\n\
+                     UID:1@example.com\r\n\
+                     DTSTAMP:20240701T120000Z\r\n\
+                     DTSTART:20240705T120000Z\r\n\
+                     SUMMARY;ENCODING=QUOTED-PRINTABLE:Lunch=3B dessert=2C coffee\r\n\
+                     DESCRIPTION;ENCODING=QUOTED-PRINTABLE:C:=5Cdocs=0ADone\r\n\
+                     END:VEVENT\r\n\
+                     END:VCALENDAR\r\n";
+        let calendar =
+            Calendar::from_ics_with_options(input, ParseOptions::new().quoted_printable(true))
+                .unwrap();
+        let event = calendar.events().next().unwrap();
+        assert_eq!(event.summary(), Some("Lunch\\; dessert\\, coffee"));
+        assert_eq!(event.description(), Some("C:\\\\docs\\nDone"));
+        let output = calendar.to_ics_string();
+        assert!(output.contains("SUMMARY:Lunch\\; dessert\\, coffee\r\n"));
+        assert!(output.contains("DESCRIPTION:C:\\\\docs\\nDone\r\n"));
+    }
+
+    #[test]
+    fn charset() {
+        let input = b"\xEF\xBB\xBFBEGIN:VCALENDAR\r\n\
+                      BEGIN:VEVENT\r\n\
+                      UID:1@example.com\r\n\
+                      DTSTAMP:20240701T120000Z\r\n\
+                      DTSTART:20240705T120000Z\r\n\
+                      SUMMARY:Caf\xE9 \x80 5\r\n\
+                      END:VEVENT\r\n\
+                      END:VCALENDAR\r\n";
+        let offset = input.iter().position(|&byte| byte == 0xE9).unwrap();
+        assert_eq!(
+            Calendar::from_ics(input),
+            Err(ParseCalendarError::InvalidUtf8 { offset })
+        );
+        let calendar =
+            Calendar::from_ics_with_options(input, ParseOptions::new().windows_1252_fallback(true))
+                .unwrap();
+        let event = calendar.events().next().unwrap();
+        assert_eq!(event.summary(), Some("Café € 5"));
+
+        let calendar = Calendar::from_ics(
+            "\u{FEFF}BEGIN:VCALENDAR\r\nPRODID:-//Example//Example Calendar//EN\r\n\
+             END:VCALENDAR\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            calendar.product_identifier(),
+            "-//Example//Example Calendar//EN"
+        );
+    }
+
+    #[test]
+    fn rfc2445() {
+        let input = "BEGIN:VCALENDAR\r\n\
+                     BEGIN:VEVENT\r\n\
+                     UID:1@example.com\r\n\
+                     DTSTAMP:20240701T120000Z\r\n\
+                     DTSTART:20240705T120000\r\n\
+                     RRULE:FREQ=WEEKLY;UNTIL=20240830T120000Z\r\n\
+                     EXRULE:FREQ=MONTHLY;UNTIL=20240801\r\n\
+                     END:VEVENT\r\n\
+                     BEGIN:VEVENT\r\n\
+                     UID:1@example.com\r\n\
+                     DTSTAMP:20240701T120000Z\r\n\
+                     DTSTART:20240712T130000\r\n\
+                     RECURRENCE-ID;RANGE=THISANDPRIOR:20240712T120000\r\n\
+                     END:VEVENT\r\n\
+                     END:VCALENDAR\r\n";
+        assert_eq!(
+            Calendar::from_ics(input),
+            Err(ParseCalendarError::InvalidProperty {
+                property: "RECURRENCE-ID".to_owned()
+            })
+        );
+
+        let calendar =
+            Calendar::from_ics_with_options(input, ParseOptions::new().rfc2445(true)).unwrap();
+        let event = calendar.events().next().unwrap();
+        let until = |rule: Option<&RecurrenceRule>| rule.unwrap().until().unwrap();
+        assert_eq!(
+            until(event.recurrence_rule.as_ref()),
+            DateTime {
+                date: Date::new(2024, 8, 30),
+                time: Time::new_local(12, 0, 0),
+            }
+            .into()
+        );
+        assert_eq!(
+            until(event.exception_rule.as_ref()),
+            DateTime {
+                date: Date::new(2024, 8, 1),
+                time: Time::new_local(23, 59, 59),
+            }
+            .into()
+        );
+        assert!(
+            event
+                .recurrence_rule
+                .as_ref()
+                .unwrap()
+                .validate_against(event.start())
+                .is_ok()
+        );
+        assert_eq!(calendar.events().count(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Calendar::from_ics("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"),
+            Err(ParseCalendarError::NotACalendar)
+        );
+        assert_eq!(
+            Calendar::from_ics("BEGIN:VEVENT\r\nEND:VEVENT\r\n"),
+            Err(ParseCalendarError::NotACalendar)
+        );
+        assert_eq!(
+            Calendar::from_ics(b"BEGIN:VCALENDAR\r\nX-NAME:\xFF\r\nEND:VCALENDAR\r\n"),
+            Err(ParseCalendarError::InvalidUtf8 { offset: 24 })
+        );
+        assert_eq!(
+            Calendar::from_ics(
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:1\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n"
+            ),
+            Err(ParseCalendarError::MissingProperty {
+                component: "VEVENT".to_owned(),
+                property: "DTSTAMP".to_owned()
+            })
+        );
+        assert_eq!(
+            Calendar::from_ics(
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:1\r\nDTSTAMP:20240701T120000Z\r\n\
+                 DTSTART;TZID=Europe/Zurich:20240101T090000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n"
+            ),
+            Err(ParseCalendarError::InvalidProperty {
+                property: "DTSTART".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use {
+    crate::{InvalidValueError, param, value},
+    ical_vcard::{Contentline, ParamValue, Value},
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+/// Represents an `ORGANIZER` property as specified in
+/// [RFC 5545 section 3.8.4.3](https://tools.ietf.org/html/rfc5545#section-3.8.4.3).
+///
+/// The organizer is identified by a calendar user address, which is usually a `mailto:` URI
+/// such as `mailto:jane@example.com`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Organizer {
+    address: Value<String>,
+    common_name: Option<ParamValue<String>>,
+}
+
+impl Organizer {
+    /// Create a new [`Organizer`] with the given calendar user address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `address` is not a valid [`Value`]. Use [`Organizer::try_new`] if the address is
+    /// not known to be valid.
+    #[must_use]
+    pub fn new<S: Into<String>>(address: S) -> Self {
+        Self::try_new(address).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new [`Organizer`], or return an error if `address` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Organizer::new`] panics.
+    pub fn try_new<S: Into<String>>(address: S) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            address: value("organizer address", address.into())?,
+            common_name: None,
+        })
+    }
+
+    /// Get the calendar user address of the organizer.
+    #[must_use]
+    pub fn address(&self) -> &str {
+        self.address.as_str()
+    }
+
+    /// Set the name of the organizer (`CN`), which clients display instead of the address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `common_name` is not a valid [`ParamValue`].
+    pub fn set_common_name<S: Into<String>>(&mut self, common_name: S) -> &mut Self {
+        self.common_name = Some(common_name_param(common_name.into()));
+        self
+    }
+
+    /// Get the name of the organizer (`CN`).
+    #[must_use]
+    pub fn common_name(&self) -> Option<&str> {
+        self.common_name.as_ref().map(ParamValue::as_str)
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        let mut contentline = Contentline::new("ORGANIZER", self.address.as_str());
+        if let Some(common_name) = &self.common_name {
+            contentline.params.push(param("CN", common_name.as_str()));
+        }
+        writer.write(&contentline)
+    }
+}
+
+/// Represents an `ATTENDEE` property as specified in
+/// [RFC 5545 section 3.8.4.1](https://tools.ietf.org/html/rfc5545#section-3.8.4.1).
+///
+/// Like an [`Organizer`], an attendee is identified by a calendar user address, which is usually
+/// a `mailto:` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attendee {
+    address: Value<String>,
+    common_name: Option<ParamValue<String>>,
+    role: Option<Role>,
+    participation_status: Option<PartStat>,
+    rsvp: bool,
+}
+
+impl Attendee {
+    /// Create a new [`Attendee`] with the given calendar user address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `address` is not a valid [`Value`]. Use [`Attendee::try_new`] if the address is
+    /// not known to be valid.
+    #[must_use]
+    pub fn new<S: Into<String>>(address: S) -> Self {
+        Self::try_new(address).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new [`Attendee`], or return an error if `address` is not a valid [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Attendee::new`] panics.
+    pub fn try_new<S: Into<String>>(address: S) -> Result<Self, InvalidValueError> {
+        Ok(Self {
+            address: value("attendee address", address.into())?,
+            common_name: None,
+            role: None,
+            participation_status: None,
+            rsvp: false,
+        })
+    }
+
+    /// Get the calendar user address of the attendee.
+    #[must_use]
+    pub fn address(&self) -> &str {
+        self.address.as_str()
+    }
+
+    /// Set the name of the attendee (`CN`), which clients display instead of the address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `common_name` is not a valid [`ParamValue`].
+    pub fn set_common_name<S: Into<String>>(&mut self, common_name: S) -> &mut Self {
+        self.common_name = Some(common_name_param(common_name.into()));
+        self
+    }
+
+    /// Get the name of the attendee (`CN`).
+    #[must_use]
+    pub fn common_name(&self) -> Option<&str> {
+        self.common_name.as_ref().map(ParamValue::as_str)
+    }
+
+    /// Set the role of the attendee (`ROLE`).
+    pub fn set_role(&mut self, role: Role) -> &mut Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Get the role of the attendee (`ROLE`).
+    ///
+    /// If no role is set, RFC 5545 specifies [`Role::RequiredParticipant`] as the default.
+    #[must_use]
+    pub fn role(&self) -> Option<Role> {
+        self.role
+    }
+
+    /// Set the participation status of the attendee (`PARTSTAT`).
+    pub fn set_participation_status(&mut self, participation_status: PartStat) -> &mut Self {
+        self.participation_status = Some(participation_status);
+        self
+    }
+
+    /// Get the participation status of the attendee (`PARTSTAT`).
+    ///
+    /// If no participation status is set, RFC 5545 specifies [`PartStat::NeedsAction`] as the
+    /// default.
+    #[must_use]
+    pub fn participation_status(&self) -> Option<PartStat> {
+        self.participation_status
+    }
+
+    /// Set whether a reply is expected from the attendee (`RSVP`).
+    ///
+    /// No reply is expected by default.
+    pub fn set_rsvp(&mut self, rsvp: bool) -> &mut Self {
+        self.rsvp = rsvp;
+        self
+    }
+
+    /// Returns `true` if a reply is expected from the attendee (`RSVP`).
+    #[must_use]
+    pub fn rsvp(&self) -> bool {
+        self.rsvp
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        let mut contentline = Contentline::new("ATTENDEE", self.address.as_str());
+        if let Some(common_name) = &self.common_name {
+            contentline.params.push(param("CN", common_name.as_str()));
+        }
+        if let Some(role) = self.role {
+            contentline.params.push(param("ROLE", &role.to_string()));
+        }
+        if let Some(participation_status) = self.participation_status {
+            contentline
+                .params
+                .push(param("PARTSTAT", &participation_status.to_string()));
+        }
+        if self.rsvp {
+            contentline.params.push(param("RSVP", "TRUE"));
+        }
+        writer.write(&contentline)
+    }
+}
+
+/// The role of an [`Attendee`], corresponding to the values of the `ROLE` parameter.
+///
+/// See [RFC 5545 section 3.2.16 - Participation
+/// Role](https://tools.ietf.org/html/rfc5545#section-3.2.16)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The chair of the event.
+    Chair,
+    /// A participant whose participation is required.
+    RequiredParticipant,
+    /// A participant whose participation is optional.
+    OptionalParticipant,
+    /// A participant who is copied for information purposes only.
+    NonParticipant,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Role::Chair => write!(f, "CHAIR"),
+            Role::RequiredParticipant => write!(f, "REQ-PARTICIPANT"),
+            Role::OptionalParticipant => write!(f, "OPT-PARTICIPANT"),
+            Role::NonParticipant => write!(f, "NON-PARTICIPANT"),
+        }
+    }
+}
+
+/// The participation status of an [`Attendee`] in an event, corresponding to the values of the
+/// `PARTSTAT` parameter.
+///
+/// See [RFC 5545 section 3.2.12 - Participation
+/// Status](https://tools.ietf.org/html/rfc5545#section-3.2.12)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartStat {
+    /// The attendee has not replied yet.
+    NeedsAction,
+    /// The attendee accepted the invitation.
+    Accepted,
+    /// The attendee declined the invitation.
+    Declined,
+    /// The attendee tentatively accepted the invitation.
+    Tentative,
+    /// The attendee delegated the invitation to someone else.
+    Delegated,
+}
+
+impl Display for PartStat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PartStat::NeedsAction => write!(f, "NEEDS-ACTION"),
+            PartStat::Accepted => write!(f, "ACCEPTED"),
+            PartStat::Declined => write!(f, "DECLINED"),
+            PartStat::Tentative => write!(f, "TENTATIVE"),
+            PartStat::Delegated => write!(f, "DELEGATED"),
+        }
+    }
+}
+
+/// Create the value of a `CN` parameter.
+///
+/// # Panics
+///
+/// Panics if `common_name` is not a valid [`ParamValue`].
+fn common_name_param(common_name: String) -> ParamValue<String> {
+    ParamValue::new(common_name).unwrap_or_else(|err| {
+        panic!("Invalid common name: {err}");
+    })
+}
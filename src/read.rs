@@ -0,0 +1,570 @@
+use {
+    crate::{
+        Alarm, AlarmAction, Attendee, Calendar, Conference, ConferenceFeature, CssColorName, Date,
+        DateOrDateTime, DateTime, Event, EventStatus, Geo, Image, ImageDisplay, Method, Observance,
+        ObservanceKind, Organizer, PartStat, Proximity, Role, StartDateTime, StructuredLocation,
+        TimeZone, Transparency, Trigger, TriggerRelation, ZonedDateTime, alarm::alarm_uid,
+    },
+    ical_vcard::{Contentline, ParamValue},
+    std::{fmt::Display, str::FromStr},
+};
+
+/// A component that was read from a calendar, before it is converted to the types of this crate.
+#[derive(Debug)]
+pub(crate) struct ComponentNode {
+    /// The name of the component in upper case, such as `VEVENT`.
+    pub(crate) name: String,
+    pub(crate) properties: Vec<Contentline>,
+    pub(crate) components: Vec<ComponentNode>,
+}
+
+/// Why a [`ComponentNode`] could not be converted.
+#[derive(Debug)]
+pub(crate) enum ReadError {
+    /// A required property of a component is missing.
+    MissingProperty {
+        component: &'static str,
+        property: &'static str,
+    },
+    /// The value of a property is invalid.
+    InvalidProperty(String),
+}
+
+/// Convert a `VCALENDAR` component.
+///
+/// Components other than events and time zones are ignored, and so are the properties of events
+/// that this crate does not know. Unknown properties of the calendar are kept as additional
+/// properties.
+pub(crate) fn read_calendar(node: ComponentNode) -> Result<Calendar, ReadError> {
+    let mut calendar = Calendar::new();
+    for property in node.properties {
+        match name(&property).as_str() {
+            "PRODID" => calendar.product_identifier = Some(property.value),
+            "VERSION" => (),
+            "METHOD" => calendar.method = Some(keyword(&property, &METHODS)?),
+            "IMAGE" => calendar.image = Some(image(&property)),
+            _ => calendar.properties.push(property),
+        }
+    }
+    for component in &node.components {
+        match component.name.as_str() {
+            "VEVENT" => {
+                calendar.add_component(read_event(component)?);
+            }
+            "VTIMEZONE" => {
+                calendar.add_time_zone(read_time_zone(component)?);
+            }
+            _ => (),
+        }
+    }
+    Ok(calendar)
+}
+
+const METHODS: [Method; 8] = [
+    Method::Publish,
+    Method::Request,
+    Method::Reply,
+    Method::Add,
+    Method::Cancel,
+    Method::Refresh,
+    Method::Counter,
+    Method::DeclineCounter,
+];
+
+const ALARM_ACTIONS: [AlarmAction; 4] = [
+    AlarmAction::Audio,
+    AlarmAction::Display,
+    AlarmAction::Email,
+    AlarmAction::None,
+];
+
+const PROXIMITIES: [Proximity; 4] = [
+    Proximity::Arrive,
+    Proximity::Depart,
+    Proximity::Connect,
+    Proximity::Disconnect,
+];
+
+const TRIGGER_RELATIONS: [TriggerRelation; 2] = [TriggerRelation::Start, TriggerRelation::End];
+
+pub(crate) const EVENT_STATUSES: [EventStatus; 3] = [
+    EventStatus::Tentative,
+    EventStatus::Confirmed,
+    EventStatus::Cancelled,
+];
+
+const TRANSPARENCIES: [Transparency; 2] = [Transparency::Opaque, Transparency::Transparent];
+
+pub(crate) const ROLES: [Role; 4] = [
+    Role::Chair,
+    Role::RequiredParticipant,
+    Role::OptionalParticipant,
+    Role::NonParticipant,
+];
+
+pub(crate) const PARTICIPATION_STATUSES: [PartStat; 5] = [
+    PartStat::NeedsAction,
+    PartStat::Accepted,
+    PartStat::Declined,
+    PartStat::Tentative,
+    PartStat::Delegated,
+];
+
+pub(crate) const CONFERENCE_FEATURES: [ConferenceFeature; 7] = [
+    ConferenceFeature::Audio,
+    ConferenceFeature::Chat,
+    ConferenceFeature::Feed,
+    ConferenceFeature::Moderator,
+    ConferenceFeature::Phone,
+    ConferenceFeature::Screen,
+    ConferenceFeature::Video,
+];
+
+pub(crate) const IMAGE_DISPLAYS: [ImageDisplay; 4] = [
+    ImageDisplay::Badge,
+    ImageDisplay::Graphic,
+    ImageDisplay::Fullsize,
+    ImageDisplay::Thumbnail,
+];
+
+/// Convert a `VEVENT` component.
+fn read_event(node: &ComponentNode) -> Result<Event, ReadError> {
+    let required = |property: &'static str| {
+        node.properties
+            .iter()
+            .find(|contentline| name(contentline) == property)
+            .ok_or(ReadError::MissingProperty {
+                component: "VEVENT",
+                property,
+            })
+    };
+    let uid = required("UID")?;
+    let stamp = required("DTSTAMP")?;
+    let stamp = parse(stamp, stamp.value.as_str())?;
+    let start = required("DTSTART")?;
+    let start = match (date_or_date_time(start)?, param(start, "TZID")) {
+        // A date-time with a time zone must be in local time.
+        (DateOrDateTime::DateTime(date_time), Some(_)) if date_time.time.is_utc() => {
+            return Err(invalid(start));
+        }
+        (DateOrDateTime::DateTime(date_time), Some(time_zone)) => {
+            ZonedDateTime::new(date_time, time_zone).into()
+        }
+        (value, _) => StartDateTime {
+            value,
+            time_zone: None,
+        },
+    };
+    let mut event = Event::try_with_uid(uid.value.as_str(), start, stamp)
+        .map_err(|_| ReadError::InvalidProperty("UID".to_owned()))?;
+
+    let has_structured_location = node
+        .components
+        .iter()
+        .any(|component| component.name == "VLOCATION");
+    for property in &node.properties {
+        let value = property.value.as_str();
+        match name(property).as_str() {
+            "DTEND" => event.end = Some(date_or_date_time(property)?),
+            "RECURRENCE-ID" => event.recurrence_id = Some(date_or_date_time(property)?),
+            "SEQUENCE" => event.sequence = parse(property, value)?,
+            "STATUS" => event.status = Some(keyword(property, &EVENT_STATUSES)?),
+            "TRANSP" => event.transparency = Some(keyword(property, &TRANSPARENCIES)?),
+            "DESCRIPTION" => event.description = Some(property.value.clone()),
+            "LOCATION" if !has_structured_location => {
+                event.location = Some(property.value.clone());
+            }
+            "SUMMARY" => event.summary = Some(property.value.clone()),
+            "ORGANIZER" => {
+                let mut organizer = Organizer::new(value);
+                if let Some(common_name) = param(property, "CN") {
+                    organizer.set_common_name(common_name);
+                }
+                event.organizer = Some(organizer);
+            }
+            "ATTENDEE" => event.attendees.push(attendee(property)),
+            "RRULE" => event.recurrence_rule = Some(parse(property, value)?),
+            "EXRULE" => event.exception_rule = Some(parse(property, value)?),
+            "RDATE" => event.recurrence_dates.extend(date_list(property)?),
+            "EXDATE" => event.exception_dates.extend(date_list(property)?),
+            "IMAGE" => event.image = Some(image(property)),
+            "CONFERENCE" => event.conferences.push(conference(property)),
+            "COLOR" => {
+                event.color =
+                    Some(CssColorName::from_name(value).ok_or_else(|| invalid(property))?);
+            }
+            _ => (),
+        }
+    }
+    for component in &node.components {
+        match component.name.as_str() {
+            "VLOCATION" => {
+                event.structured_location = Some(read_location(component, event.uid(), 0)?);
+            }
+            "VALARM" => {
+                let owner_uid = alarm_uid(event.uid(), event.alarms.len());
+                event.alarms.extend(read_alarm(component, &owner_uid)?);
+            }
+            _ => (),
+        }
+    }
+    Ok(event)
+}
+
+/// Convert a `VALARM` component.
+///
+/// Locations without a `UID` get one derived from the `UID` of the alarm or, if the alarm has no
+/// `UID`, from `owner_uid`.
+///
+/// Returns `None` for alarms with an unknown action.
+fn read_alarm(node: &ComponentNode, owner_uid: &str) -> Result<Option<Alarm>, ReadError> {
+    let required = |property: &'static str| {
+        node.properties
+            .iter()
+            .find(|contentline| name(contentline) == property)
+            .ok_or(ReadError::MissingProperty {
+                component: "VALARM",
+                property,
+            })
+    };
+    let action = required("ACTION")?;
+    let Some(action) = find_keyword(action.value.as_str(), &ALARM_ACTIONS) else {
+        return Ok(None);
+    };
+    let trigger = read_trigger(required("TRIGGER")?)?;
+    let mut attendees = node
+        .properties
+        .iter()
+        .filter(|property| name(property) == "ATTENDEE")
+        .map(attendee);
+    let mut alarm = match action {
+        AlarmAction::Audio => Alarm::audio(trigger, None),
+        AlarmAction::Display => Alarm::display(trigger),
+        AlarmAction::None => Alarm::none(trigger),
+        AlarmAction::Email => Alarm::email(
+            trigger,
+            required("SUMMARY")?.value.as_str(),
+            required("DESCRIPTION")?.value.as_str(),
+            attendees.next().ok_or(ReadError::MissingProperty {
+                component: "VALARM",
+                property: "ATTENDEE",
+            })?,
+        ),
+    };
+    for attendee in attendees {
+        alarm.add_attendee(attendee);
+    }
+    for property in &node.properties {
+        match name(property).as_str() {
+            "DESCRIPTION" => {
+                alarm.set_description(property.value.as_str());
+            }
+            "SUMMARY" => {
+                alarm.set_summary(property.value.as_str());
+            }
+            "UID" => {
+                alarm.set_uid(property.value.as_str());
+            }
+            "ACKNOWLEDGED" => {
+                // `utc_date_time` rejects local times, for which `set_acknowledged` panics.
+                alarm.set_acknowledged(utc_date_time(property)?);
+            }
+            "RELATED-TO"
+                if param(property, "RELTYPE")
+                    .is_some_and(|relation| relation.eq_ignore_ascii_case("SNOOZE")) =>
+            {
+                alarm.set_snoozed_alarm(property.value.as_str());
+            }
+            "PROXIMITY" => {
+                alarm.set_proximity(keyword(property, &PROXIMITIES)?);
+            }
+            // Inline attachments are not supported.
+            "ATTACH"
+                if param(property, "VALUE")
+                    .is_none_or(|value| value.eq_ignore_ascii_case("URI")) =>
+            {
+                alarm.add_attachment(property.value.as_str());
+            }
+            _ => (),
+        }
+    }
+    for component in &node.components {
+        if component.name == "VLOCATION" {
+            let owner_uid = alarm.uid().unwrap_or(owner_uid).to_owned();
+            let index = alarm.locations().len();
+            alarm.push_location(read_location(component, &owner_uid, index)?);
+        }
+    }
+    Ok(Some(alarm))
+}
+
+/// Convert a `TRIGGER` property.
+fn read_trigger(property: &Contentline) -> Result<Trigger, ReadError> {
+    let value = property.value.as_str();
+    match param(property, "VALUE") {
+        Some(value_type) if value_type.eq_ignore_ascii_case("DATE-TIME") => {
+            // `utc_date_time` rejects local times, for which `Trigger::absolute` panics.
+            Ok(Trigger::absolute(utc_date_time(property)?))
+        }
+        Some(value_type) if !value_type.eq_ignore_ascii_case("DURATION") => Err(invalid(property)),
+        _ => {
+            let offset = parse(property, value)?;
+            let related = param(property, "RELATED")
+                .map(|related| {
+                    find_keyword(related, &TRIGGER_RELATIONS).ok_or_else(|| invalid(property))
+                })
+                .transpose()?;
+            Ok(if related == Some(TriggerRelation::End) {
+                Trigger::relative_to_end(offset)
+            } else {
+                Trigger::relative_to_start(offset)
+            })
+        }
+    }
+}
+
+/// Convert a `VTIMEZONE` component.
+fn read_time_zone(node: &ComponentNode) -> Result<TimeZone, ReadError> {
+    let id = node
+        .properties
+        .iter()
+        .find(|property| name(property) == "TZID")
+        .ok_or(ReadError::MissingProperty {
+            component: "VTIMEZONE",
+            property: "TZID",
+        })?;
+    if ParamValue::new(id.value.as_str()).is_err() {
+        return Err(invalid(id));
+    }
+    let mut time_zone = TimeZone::new(id.value.as_str());
+    for component in &node.components {
+        let kind = match component.name.as_str() {
+            "STANDARD" => ObservanceKind::Standard,
+            "DAYLIGHT" => ObservanceKind::Daylight,
+            _ => continue,
+        };
+        time_zone.add_observance(read_observance(component, kind)?);
+    }
+    Ok(time_zone)
+}
+
+/// Convert a `STANDARD` or `DAYLIGHT` component.
+fn read_observance(node: &ComponentNode, kind: ObservanceKind) -> Result<Observance, ReadError> {
+    let required = |property: &'static str| {
+        node.properties
+            .iter()
+            .find(|contentline| name(contentline) == property)
+            .ok_or(ReadError::MissingProperty {
+                component: if kind == ObservanceKind::Standard {
+                    "STANDARD"
+                } else {
+                    "DAYLIGHT"
+                },
+                property,
+            })
+    };
+    let start = required("DTSTART")?;
+    let start = local_date_time(start, start.value.as_str())?;
+    let offset_from = required("TZOFFSETFROM")?;
+    let offset_from = parse(offset_from, offset_from.value.as_str())?;
+    let offset_to = required("TZOFFSETTO")?;
+    let offset_to = parse(offset_to, offset_to.value.as_str())?;
+    let mut observance = Observance::new(kind, start, offset_from, offset_to);
+    for property in &node.properties {
+        let value = property.value.as_str();
+        match name(property).as_str() {
+            "TZNAME" => {
+                observance.set_name(value);
+            }
+            "RRULE" => {
+                observance.set_recurrence_rule(parse(property, value)?);
+            }
+            "RDATE" => {
+                for value in value.split(',') {
+                    observance.add_recurrence_date(local_date_time(property, value)?);
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(observance)
+}
+
+/// Parse the value of `property` as a date-time in UTC time.
+fn utc_date_time(property: &Contentline) -> Result<DateTime, ReadError> {
+    let date_time: DateTime = parse(property, property.value.as_str())?;
+    if !date_time.time.is_utc() {
+        return Err(invalid(property));
+    }
+    Ok(date_time)
+}
+
+/// Parse `value`, which is (part of) the value of `property`, as a date-time in local time.
+fn local_date_time(property: &Contentline, value: &str) -> Result<DateTime, ReadError> {
+    let date_time: DateTime = parse(property, value)?;
+    if date_time.time.is_utc() {
+        return Err(invalid(property));
+    }
+    Ok(date_time)
+}
+
+/// Convert a `VLOCATION` component.
+fn read_location(
+    node: &ComponentNode,
+    owner_uid: &str,
+    index: usize,
+) -> Result<StructuredLocation, ReadError> {
+    let find = |property: &str| {
+        node.properties
+            .iter()
+            .find(|contentline| name(contentline) == property)
+    };
+    let location_name = find("NAME").ok_or(ReadError::MissingProperty {
+        component: "VLOCATION",
+        property: "NAME",
+    })?;
+    let geo = find("GEO")
+        .map(|property| {
+            let (latitude, longitude) = property
+                .value
+                .as_str()
+                .split_once(';')
+                .ok_or_else(|| invalid(property))?;
+            let latitude: f64 = parse(property, latitude)?;
+            let longitude: f64 = parse(property, longitude)?;
+            if (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude) {
+                Ok(Geo::new(latitude, longitude))
+            } else {
+                Err(invalid(property))
+            }
+        })
+        .transpose()?;
+    let mut location = StructuredLocation::try_new(
+        owner_uid,
+        index,
+        location_name.value.as_str().to_owned(),
+        find("DESCRIPTION").map(|property| property.value.as_str()),
+        geo,
+        find("URL").map(|property| property.value.as_str()),
+    )
+    .expect("Property values are valid values");
+    if let Some(uid) = find("UID") {
+        location.set_uid(uid.value.clone());
+    }
+    Ok(location)
+}
+
+/// Convert an `ATTENDEE` property.
+fn attendee(property: &Contentline) -> Attendee {
+    let mut attendee = Attendee::new(property.value.as_str());
+    if let Some(common_name) = param(property, "CN") {
+        attendee.set_common_name(common_name);
+    }
+    if let Some(role) = param(property, "ROLE").and_then(|role| find_keyword(role, &ROLES)) {
+        attendee.set_role(role);
+    }
+    if let Some(participation_status) =
+        param(property, "PARTSTAT").and_then(|participation_status| {
+            find_keyword(participation_status, &PARTICIPATION_STATUSES)
+        })
+    {
+        attendee.set_participation_status(participation_status);
+    }
+    attendee
+        .set_rsvp(param(property, "RSVP").is_some_and(|rsvp| rsvp.eq_ignore_ascii_case("TRUE")));
+    attendee
+}
+
+/// Convert a `CONFERENCE` property.
+fn conference(property: &Contentline) -> Conference {
+    let mut conference = Conference::new(property.value.as_str());
+    for feature in params(property, "FEATURE") {
+        if let Some(feature) = find_keyword(feature, &CONFERENCE_FEATURES) {
+            conference.add_feature(feature);
+        }
+    }
+    if let Some(label) = param(property, "LABEL") {
+        conference.set_label(label);
+    }
+    conference
+}
+
+/// Convert an `IMAGE` property.
+fn image(property: &Contentline) -> Image {
+    let display = param(property, "DISPLAY").and_then(|display| {
+        display
+            .split(',')
+            .find_map(|display| find_keyword(display, &IMAGE_DISPLAYS))
+    });
+    Image::try_new(property.value.as_str().to_owned(), display)
+        .expect("Property values are valid values")
+}
+
+/// The upper-case name of a property.
+fn name(property: &Contentline) -> String {
+    property.name.as_str().to_ascii_uppercase()
+}
+
+/// The values of the parameter `name` of `property`.
+fn params<'a>(property: &'a Contentline, name: &str) -> impl Iterator<Item = &'a str> {
+    property
+        .params
+        .iter()
+        .filter(move |param| param.name.as_str().eq_ignore_ascii_case(name))
+        .flat_map(|param| param.values.iter().map(ParamValue::as_str))
+}
+
+/// The first value of the parameter `name` of `property`.
+fn param<'a>(property: &'a Contentline, name: &str) -> Option<&'a str> {
+    params(property, name).next()
+}
+
+/// The error for a property with an invalid value.
+fn invalid(property: &Contentline) -> ReadError {
+    ReadError::InvalidProperty(name(property))
+}
+
+/// Parse `value`, which is (part of) the value of `property`.
+fn parse<T: FromStr>(property: &Contentline, value: &str) -> Result<T, ReadError> {
+    value.parse().map_err(|_| invalid(property))
+}
+
+/// Find the variant of `variants` that is written as `value`, ignoring case.
+pub(crate) fn find_keyword<T: Display + Copy>(value: &str, variants: &[T]) -> Option<T> {
+    variants
+        .iter()
+        .copied()
+        .find(|variant| variant.to_string().eq_ignore_ascii_case(value))
+}
+
+/// Parse the value of `property` as one of `variants`.
+fn keyword<T: Display + Copy>(property: &Contentline, variants: &[T]) -> Result<T, ReadError> {
+    find_keyword(property.value.as_str(), variants).ok_or_else(|| invalid(property))
+}
+
+/// Parse a single date or date-time, depending on the `VALUE` parameter of `property`.
+fn date_or_date_time(property: &Contentline) -> Result<DateOrDateTime, ReadError> {
+    date_list(property)?
+        .into_iter()
+        .next()
+        .filter(|_| !property.value.as_str().contains(','))
+        .ok_or_else(|| invalid(property))
+}
+
+/// Parse a comma-separated list of dates or date-times, depending on the `VALUE` parameter of
+/// `property`.
+fn date_list(property: &Contentline) -> Result<Vec<DateOrDateTime>, ReadError> {
+    let is_date = param(property, "VALUE").is_some_and(|value| value.eq_ignore_ascii_case("DATE"));
+    property
+        .value
+        .as_str()
+        .split(',')
+        .map(|value| {
+            Ok(if is_date {
+                DateOrDateTime::Date(parse::<Date>(property, value)?)
+            } else {
+                DateOrDateTime::DateTime(parse::<DateTime>(property, value)?)
+            })
+        })
+        .collect()
+}
@@ -1,46 +1,867 @@
 use {
-    crate::DateTime,
+    crate::{Date, DateOrDateTime, DateTime, StartDateTime, Weekday},
     std::{
+        error,
         fmt::{Display, Error, Formatter},
         str::FromStr,
     },
 };
 
+mod builder;
+pub use builder::RecurrenceRuleBuilder;
+
+mod expansion;
+pub(crate) use expansion::RuleInstances;
+
+mod human;
+
 /// Represents a recurrence rule as specified in
 /// [RFC 5545 section 3.3.10](https://tools.ietf.org/html/rfc5545#section-3.3.10).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RecurrenceRule {
     freq: RecurrenceFrequency,
-    until: Option<DateTime>,
+    interval: u32,
+    end: Option<RecurrenceEnd>,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<u8>,
+    by_set_pos: Vec<i16>,
+    week_start: Option<Weekday>,
+}
+
+/// How a recurrence rule ends.
+///
+/// RFC 5545 does not allow `UNTIL` and `COUNT` to occur in the same rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecurrenceEnd {
+    Until(DateOrDateTime),
+    Count(u32),
 }
 
 impl RecurrenceRule {
     /// Create a new recurrence rule with the specified frequency.
     #[must_use]
     pub fn new(freq: RecurrenceFrequency) -> Self {
-        RecurrenceRule { freq, until: None }
+        RecurrenceRule {
+            freq,
+            interval: 1,
+            end: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: None,
+        }
+    }
+
+    /// Create a [`RecurrenceRuleBuilder`] for a rule with the specified frequency.
+    ///
+    /// In contrast to the setters of [`RecurrenceRule`], the builder reports invalid rules as
+    /// errors instead of panicking.
+    #[must_use]
+    pub fn builder(freq: RecurrenceFrequency) -> RecurrenceRuleBuilder {
+        RecurrenceRuleBuilder::new(freq)
+    }
+
+    /// Get the frequency of the recurrence rule.
+    #[must_use]
+    pub fn freq(&self) -> RecurrenceFrequency {
+        self.freq
+    }
+
+    /// Set the frequency of the recurrence rule.
+    pub fn set_freq(&mut self, freq: RecurrenceFrequency) -> &mut Self {
+        self.freq = freq;
+        self
+    }
+
+    /// Get the end date (inclusive) of the recurrence rule, if any.
+    #[must_use]
+    pub fn until(&self) -> Option<DateOrDateTime> {
+        match self.end {
+            Some(RecurrenceEnd::Until(until)) => Some(until),
+            _ => None,
+        }
     }
 
     /// Set the end date (inclusive) of the recurrence rule.
+    ///
+    /// The end must be a date if the start of the recurrence set (`DTSTART`) is a date, as for
+    /// all-day events, and a date-time otherwise. See [`RecurrenceRule::validate_against`].
+    ///
+    /// This replaces a count previously set with [`RecurrenceRule::set_count`].
+    pub fn set_until<D: Into<DateOrDateTime>>(&mut self, until: D) -> &mut Self {
+        self.end = Some(RecurrenceEnd::Until(until.into()));
+        self
+    }
+
+    /// Remove the end date of the recurrence rule.
+    ///
+    /// A count set with [`RecurrenceRule::set_count`] is not affected.
+    pub fn clear_until(&mut self) -> &mut Self {
+        if let Some(RecurrenceEnd::Until(_)) = self.end {
+            self.end = None;
+        }
+        self
+    }
+
+    /// Get the number of occurrences of the recurrence rule, if any.
     #[must_use]
-    pub fn until(&self, until: DateTime) -> Self {
-        RecurrenceRule {
+    pub fn count(&self) -> Option<u32> {
+        match self.end {
+            Some(RecurrenceEnd::Count(count)) => Some(count),
+            _ => None,
+        }
+    }
+
+    /// Set the number of occurrences of the recurrence rule.
+    ///
+    /// This replaces an end date previously set with [`RecurrenceRule::set_until`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is 0.
+    pub fn set_count(&mut self, count: u32) -> &mut Self {
+        assert!(count >= 1, "Count must be at least 1");
+        self.end = Some(RecurrenceEnd::Count(count));
+        self
+    }
+
+    /// Remove the number of occurrences of the recurrence rule.
+    ///
+    /// An end date set with [`RecurrenceRule::set_until`] is not affected.
+    pub fn clear_count(&mut self) -> &mut Self {
+        if let Some(RecurrenceEnd::Count(_)) = self.end {
+            self.end = None;
+        }
+        self
+    }
+
+    /// Get the interval of the recurrence rule.
+    #[must_use]
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Set the interval of the recurrence rule.
+    ///
+    /// For example, a weekly rule with an interval of 2 repeats every other week. The default
+    /// interval is 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    pub fn set_interval(&mut self, interval: u32) -> &mut Self {
+        assert!(interval >= 1, "Interval must be at least 1");
+        self.interval = interval;
+        self
+    }
+
+    /// Get the days of the week on which the rule repeats (`BYDAY`).
+    #[must_use]
+    pub fn by_day(&self) -> &[WeekdayNum] {
+        &self.by_day
+    }
+
+    /// Set the days of the week on which the rule repeats (`BYDAY`).
+    ///
+    /// Both plain weekdays and ordinal weekdays can be used:
+    ///
+    /// ```
+    /// use ical::{RecurrenceFrequency, RecurrenceRule, Weekday, WeekdayNum};
+    ///
+    /// // Every Monday, Wednesday and Friday.
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+    /// rule.set_by_day([Weekday::Monday, Weekday::Wednesday, Weekday::Friday]);
+    /// assert_eq!(rule.to_string(), "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+    ///
+    /// // The second Sunday and the last Friday of every month.
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    /// rule.set_by_day([
+    ///     WeekdayNum::nth(2, Weekday::Sunday),
+    ///     WeekdayNum::nth(-1, Weekday::Friday),
+    /// ]);
+    /// assert_eq!(rule.to_string(), "FREQ=MONTHLY;BYDAY=2SU,-1FR");
+    /// ```
+    ///
+    /// This replaces the days set by previous calls.
+    pub fn set_by_day<I>(&mut self, days: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<WeekdayNum>,
+    {
+        self.by_day = days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Get the days of the month on which the rule repeats (`BYMONTHDAY`).
+    #[must_use]
+    pub fn by_month_day(&self) -> &[i8] {
+        &self.by_month_day
+    }
+
+    /// Set the days of the month on which the rule repeats (`BYMONTHDAY`).
+    ///
+    /// Negative values count from the end of the month, that is, -1 is the last day of the
+    /// month.
+    ///
+    /// This replaces the days set by previous calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the days is not in the range 1 to 31 or -31 to -1.
+    pub fn set_by_month_day<I: IntoIterator<Item = i8>>(&mut self, days: I) -> &mut Self {
+        let by_month_day: Vec<_> = days.into_iter().collect();
+        assert!(
+            by_month_day.iter().all(|&day| is_valid_month_day(day)),
+            "Month day must be between 1 and 31 or between -31 and -1"
+        );
+        self.by_month_day = by_month_day;
+        self
+    }
+
+    /// Get the days of the year on which the rule repeats (`BYYEARDAY`).
+    #[must_use]
+    pub fn by_year_day(&self) -> &[i16] {
+        &self.by_year_day
+    }
+
+    /// Set the days of the year on which the rule repeats (`BYYEARDAY`).
+    ///
+    /// Negative values count from the end of the year, that is, -1 is the last day of the year.
+    ///
+    /// This replaces the days set by previous calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the days is not in the range 1 to 366 or -366 to -1.
+    pub fn set_by_year_day<I: IntoIterator<Item = i16>>(&mut self, days: I) -> &mut Self {
+        let by_year_day: Vec<_> = days.into_iter().collect();
+        assert!(
+            by_year_day.iter().all(|&day| is_valid_year_day(day)),
+            "Year day must be between 1 and 366 or between -366 and -1"
+        );
+        self.by_year_day = by_year_day;
+        self
+    }
+
+    /// Get the week numbers in which the rule repeats (`BYWEEKNO`).
+    #[must_use]
+    pub fn by_week_no(&self) -> &[i8] {
+        &self.by_week_no
+    }
+
+    /// Set the ISO 8601 week numbers in which the rule repeats (`BYWEEKNO`).
+    ///
+    /// Week number 1 is the first week containing at least four days of the year, where weeks
+    /// start on the [week start](RecurrenceRule::set_week_start). Negative values count from the
+    /// end of the year, that is, -1 is the last week of the year.
+    ///
+    /// RFC 5545 only allows this for yearly rules.
+    ///
+    /// This replaces the week numbers set by previous calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the week numbers is not in the range 1 to 53 or -53 to -1.
+    pub fn set_by_week_no<I: IntoIterator<Item = i8>>(&mut self, weeks: I) -> &mut Self {
+        let by_week_no: Vec<_> = weeks.into_iter().collect();
+        assert!(
+            by_week_no.iter().all(|&week| is_valid_week_no(week)),
+            "Week number must be between 1 and 53 or between -53 and -1"
+        );
+        self.by_week_no = by_week_no;
+        self
+    }
+
+    /// Get the months in which the rule repeats (`BYMONTH`).
+    #[must_use]
+    pub fn by_month(&self) -> &[u8] {
+        &self.by_month
+    }
+
+    /// Set the months in which the rule repeats (`BYMONTH`).
+    ///
+    /// This replaces the months set by previous calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the months is not in the range 1 to 12.
+    pub fn set_by_month<I: IntoIterator<Item = u8>>(&mut self, months: I) -> &mut Self {
+        let by_month: Vec<_> = months.into_iter().collect();
+        assert!(
+            by_month.iter().all(|&month| is_valid_month(month)),
+            "Month must be between 1 and 12"
+        );
+        self.by_month = by_month;
+        self
+    }
+
+    /// Get the positions by which occurrences are selected (`BYSETPOS`).
+    #[must_use]
+    pub fn by_set_pos(&self) -> &[i16] {
+        &self.by_set_pos
+    }
+
+    /// Select occurrences by their position within the set of occurrences of each interval
+    /// (`BYSETPOS`).
+    ///
+    /// Negative values count from the end of the set, that is, -1 is the last occurrence.
+    ///
+    /// ```
+    /// use ical::{RecurrenceFrequency, RecurrenceRule, Weekday};
+    ///
+    /// // The last weekday of every month.
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    /// rule.set_by_day([
+    ///     Weekday::Monday,
+    ///     Weekday::Tuesday,
+    ///     Weekday::Wednesday,
+    ///     Weekday::Thursday,
+    ///     Weekday::Friday,
+    /// ])
+    /// .set_by_set_pos([-1]);
+    /// assert_eq!(
+    ///     rule.to_string(),
+    ///     "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"
+    /// );
+    /// ```
+    ///
+    /// This replaces the positions set by previous calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the positions is not in the range 1 to 366 or -366 to -1.
+    pub fn set_by_set_pos<I: IntoIterator<Item = i16>>(&mut self, positions: I) -> &mut Self {
+        let by_set_pos: Vec<_> = positions.into_iter().collect();
+        assert!(
+            by_set_pos
+                .iter()
+                .all(|&position| is_valid_set_pos(position)),
+            "Set position must be between 1 and 366 or between -366 and -1"
+        );
+        self.by_set_pos = by_set_pos;
+        self
+    }
+
+    /// Get the day on which the work week starts (`WKST`), if set.
+    #[must_use]
+    pub fn week_start(&self) -> Option<Weekday> {
+        self.week_start
+    }
+
+    /// Set the day on which the work week starts (`WKST`).
+    ///
+    /// This is significant for weekly rules with an interval greater than 1 and for rules using
+    /// `BYWEEKNO`. If no week start is set, RFC 5545 specifies Monday as the default.
+    pub fn set_week_start(&mut self, week_start: Weekday) -> &mut Self {
+        self.week_start = Some(week_start);
+        self
+    }
+
+    /// Check that the combination of the parts of the rule is valid.
+    ///
+    /// RFC 5545 restricts which parts may be used together:
+    /// - `BYWEEKNO` is only allowed in yearly rules.
+    /// - `BYYEARDAY` is not allowed in daily, weekly and monthly rules.
+    /// - `BYMONTHDAY` is not allowed in weekly rules.
+    /// - `BYDAY` must not contain ordinals (such as `2SU`) unless the rule is monthly or yearly,
+    ///   and not in yearly rules with `BYWEEKNO`.
+    /// - `BYSETPOS` must be used together with another `BYxxx` part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first violation that is found.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        let part_not_allowed = |part| RuleError::PartNotAllowed {
+            part,
             freq: self.freq,
-            until: Some(until),
+        };
+        if !self.by_week_no.is_empty() && self.freq != RecurrenceFrequency::Yearly {
+            return Err(part_not_allowed("BYWEEKNO"));
+        }
+        if !self.by_year_day.is_empty()
+            && matches!(
+                self.freq,
+                RecurrenceFrequency::Daily
+                    | RecurrenceFrequency::Weekly
+                    | RecurrenceFrequency::Monthly
+            )
+        {
+            return Err(part_not_allowed("BYYEARDAY"));
+        }
+        if !self.by_month_day.is_empty() && self.freq == RecurrenceFrequency::Weekly {
+            return Err(part_not_allowed("BYMONTHDAY"));
+        }
+        let ordinals_allowed = match self.freq {
+            RecurrenceFrequency::Monthly => true,
+            RecurrenceFrequency::Yearly => self.by_week_no.is_empty(),
+            _ => false,
+        };
+        if !ordinals_allowed && self.by_day.iter().any(|day| day.ordinal.is_some()) {
+            return Err(RuleError::OrdinalNotAllowed);
+        }
+        if !self.by_set_pos.is_empty()
+            && self.by_day.is_empty()
+            && self.by_month_day.is_empty()
+            && self.by_year_day.is_empty()
+            && self.by_week_no.is_empty()
+            && self.by_month.is_empty()
+        {
+            return Err(RuleError::SetPosWithoutOtherPart);
+        }
+        Ok(())
+    }
+
+    /// Check that the rule can be used for a recurrence set starting at `start`.
+    ///
+    /// RFC 5545 requires the `UNTIL` part of a rule to have the same value type as the
+    /// `DTSTART` property, that is, `UNTIL` must be a date if `DTSTART` is a date, and a
+    /// date-time if `DTSTART` is a date-time.
+    /// Furthermore, a date-time `UNTIL` must be in UTC time if `DTSTART` is in UTC time or has a
+    /// time zone, and in local time if `DTSTART` is in local time without a time zone.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, RecurrenceFrequency, RecurrenceRule, RuleError, Time};
+    ///
+    /// let until = DateTime {
+    ///     date: Date::new(2025, 12, 31),
+    ///     time: Time::new_utc(0, 0, 0),
+    /// };
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+    /// rule.set_until(until);
+    /// assert_eq!(rule.validate_against(&until.into()), Ok(()));
+    /// assert_eq!(
+    ///     rule.validate_against(&Date::new(2025, 1, 1).into()),
+    ///     Err(RuleError::UntilValueType),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RuleError::UntilValueType`] if the value types of `UNTIL` and `start` differ,
+    /// and [`RuleError::UntilTimeForm`] if `UNTIL` is in UTC time where it must be in local time
+    /// or vice versa.
+    pub fn validate_against(&self, start: &StartDateTime) -> Result<(), RuleError> {
+        let Some(RecurrenceEnd::Until(until)) = self.end else {
+            return Ok(());
+        };
+        match (until, start.value) {
+            (DateOrDateTime::Date(_), DateOrDateTime::Date(_)) => Ok(()),
+            (DateOrDateTime::DateTime(until), DateOrDateTime::DateTime(start_value)) => {
+                let utc_required = start_value.time.is_utc() || start.time_zone.is_some();
+                if until.time.is_utc() == utc_required {
+                    Ok(())
+                } else {
+                    Err(RuleError::UntilTimeForm)
+                }
+            }
+            _ => Err(RuleError::UntilValueType),
+        }
+    }
+
+    /// Bring the rule into a canonical form without changing its meaning, see
+    /// [`Calendar::normalize`](crate::Calendar::normalize).
+    ///
+    /// The values of the `BYxxx` parts are sorted and deduplicated, and `WKST=MO` is removed
+    /// because Monday is the default.
+    pub(crate) fn normalize(&mut self) {
+        self.by_day
+            .sort_by_key(|day| (day.weekday.days_from_monday(), day.ordinal));
+        self.by_day.dedup();
+        for values in [&mut self.by_month_day, &mut self.by_week_no] {
+            values.sort_unstable();
+            values.dedup();
+        }
+        for values in [&mut self.by_year_day, &mut self.by_set_pos] {
+            values.sort_unstable();
+            values.dedup();
+        }
+        self.by_month.sort_unstable();
+        self.by_month.dedup();
+        if self.week_start == Some(Weekday::Monday) {
+            self.week_start = None;
         }
     }
 }
 
 impl Display for RecurrenceRule {
+    // Rules are formatted for every event that is written, so the parts are written directly to
+    // the formatter instead of being collected into intermediate strings.
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         write!(f, "FREQ={}", self.freq)?;
-        if let Some(until) = self.until {
-            write!(f, ";UNTIL={until}")?;
+        if self.interval != 1 {
+            write_part(f, "INTERVAL", self.interval)?;
+        }
+        match self.end {
+            Some(RecurrenceEnd::Until(until)) => write_part(f, "UNTIL", until)?,
+            Some(RecurrenceEnd::Count(count)) => write_part(f, "COUNT", count)?,
+            None => (),
+        }
+        write_list_part(f, "BYDAY", &self.by_day)?;
+        write_list_part(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list_part(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list_part(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list_part(f, "BYMONTH", &self.by_month)?;
+        write_list_part(f, "BYSETPOS", &self.by_set_pos)?;
+        if let Some(week_start) = self.week_start {
+            write_part(f, "WKST", week_start)?;
         }
         Ok(())
     }
 }
 
+impl FromStr for RecurrenceRule {
+    type Err = ParseRecurrenceRuleError;
+
+    /// Parse a recurrence rule such as `FREQ=MONTHLY;INTERVAL=2;BYDAY=2SU`.
+    ///
+    /// Parsing is case-insensitive. The `BYSECOND`, `BYMINUTE` and `BYHOUR` parts are not
+    /// supported.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_ascii_uppercase();
+        let mut freq = None;
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        let mut seen: Vec<&str> = Vec::new();
+        for part in s.split(';') {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| ParseRecurrenceRuleError::InvalidPart(part.to_owned()))?;
+            if seen.contains(&name) {
+                return Err(ParseRecurrenceRuleError::DuplicatePart(name.to_owned()));
+            }
+            seen.push(name);
+            let invalid = || ParseRecurrenceRuleError::InvalidValue {
+                part: name.to_owned(),
+                value: value.to_owned(),
+            };
+            match name {
+                "FREQ" => freq = Some(value.parse().map_err(|()| invalid())?),
+                "UNTIL" => {
+                    if rule.end.is_some() {
+                        return Err(ParseRecurrenceRuleError::CountAndUntil);
+                    }
+                    rule.end = Some(RecurrenceEnd::Until(
+                        parse_date_or_date_time(value).ok_or_else(invalid)?,
+                    ));
+                }
+                "COUNT" => {
+                    if rule.end.is_some() {
+                        return Err(ParseRecurrenceRuleError::CountAndUntil);
+                    }
+                    let count = value.parse().map_err(|_| invalid())?;
+                    if count == 0 {
+                        return Err(invalid());
+                    }
+                    rule.end = Some(RecurrenceEnd::Count(count));
+                }
+                "INTERVAL" => {
+                    rule.interval = value.parse().map_err(|_| invalid())?;
+                    if rule.interval == 0 {
+                        return Err(invalid());
+                    }
+                }
+                "BYDAY" => {
+                    rule.by_day = parse_list(value, parse_weekday_num).ok_or_else(invalid)?;
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day =
+                        parse_list(value, parse_checked(is_valid_month_day)).ok_or_else(invalid)?;
+                }
+                "BYYEARDAY" => {
+                    rule.by_year_day =
+                        parse_list(value, parse_checked(is_valid_year_day)).ok_or_else(invalid)?;
+                }
+                "BYWEEKNO" => {
+                    rule.by_week_no =
+                        parse_list(value, parse_checked(is_valid_week_no)).ok_or_else(invalid)?;
+                }
+                "BYMONTH" => {
+                    rule.by_month =
+                        parse_list(value, parse_checked(is_valid_month)).ok_or_else(invalid)?;
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos =
+                        parse_list(value, parse_checked(is_valid_set_pos)).ok_or_else(invalid)?;
+                }
+                "WKST" => rule.week_start = Some(value.parse().map_err(|()| invalid())?),
+                "BYSECOND" | "BYMINUTE" | "BYHOUR" => {
+                    return Err(ParseRecurrenceRuleError::UnsupportedPart(name.to_owned()));
+                }
+                _ => return Err(ParseRecurrenceRuleError::InvalidPart(part.to_owned())),
+            }
+        }
+        rule.freq = freq.ok_or(ParseRecurrenceRuleError::MissingFrequency)?;
+        Ok(rule)
+    }
+}
+
+/// Parse a comma-separated list of values.
+///
+/// Returns `None` if any of the values is invalid.
+fn parse_list<T, F>(list: &str, parse: F) -> Option<Vec<T>>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    list.split(',').map(parse).collect()
+}
+
+/// Create a parser for values of type `T` that also checks the validity of the parsed value.
+fn parse_checked<T: FromStr + Copy>(is_valid: fn(T) -> bool) -> impl Fn(&str) -> Option<T> {
+    move |s| s.parse().ok().filter(|&value| is_valid(value))
+}
+
+/// Parse a date such as `20251231` or a date-time such as `20251231T235959Z`.
+fn parse_date_or_date_time(s: &str) -> Option<DateOrDateTime> {
+    if s.contains('T') {
+        s.parse::<DateTime>().ok().map(DateOrDateTime::DateTime)
+    } else {
+        s.parse::<Date>().ok().map(DateOrDateTime::Date)
+    }
+}
+
+/// Parse a `weekdaynum` such as `MO`, `2SU` or `-1FR`.
+fn parse_weekday_num(s: &str) -> Option<WeekdayNum> {
+    let split = s.len().checked_sub(2)?;
+    let weekday = s.get(split..)?.parse().ok()?;
+    let ordinal = s.get(..split)?;
+    if ordinal.is_empty() {
+        Some(WeekdayNum::every(weekday))
+    } else {
+        let ordinal = ordinal.parse().ok().filter(|&n| is_valid_ordinal(n))?;
+        Some(WeekdayNum::nth(ordinal, weekday))
+    }
+}
+
+fn is_valid_month_day(day: i8) -> bool {
+    (1..=31).contains(&day.unsigned_abs())
+}
+
+fn is_valid_year_day(day: i16) -> bool {
+    (1..=366).contains(&day.unsigned_abs())
+}
+
+fn is_valid_week_no(week: i8) -> bool {
+    (1..=53).contains(&week.unsigned_abs())
+}
+
+fn is_valid_month(month: u8) -> bool {
+    (1..=12).contains(&month)
+}
+
+fn is_valid_set_pos(position: i16) -> bool {
+    (1..=366).contains(&position.unsigned_abs())
+}
+
+fn is_valid_ordinal(ordinal: i8) -> bool {
+    (1..=53).contains(&ordinal.unsigned_abs())
+}
+
+/// Error type for parsing a [`RecurrenceRule`].
+///
+/// Parsing only checks the syntax and the ranges of the values. Use
+/// [`RecurrenceRule::validate`] to check the combination of the parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRecurrenceRuleError {
+    /// The rule does not contain a `FREQ` part.
+    MissingFrequency,
+    /// The part is not a `NAME=VALUE` pair or the name is unknown.
+    InvalidPart(String),
+    /// The part is valid, but not supported by this crate.
+    UnsupportedPart(String),
+    /// The part occurs more than once.
+    DuplicatePart(String),
+    /// The value of a part is invalid.
+    InvalidValue {
+        /// The name of the part.
+        part: String,
+        /// The invalid value.
+        value: String,
+    },
+    /// The rule contains both a `COUNT` and an `UNTIL` part.
+    CountAndUntil,
+}
+
+impl Display for ParseRecurrenceRuleError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self {
+            ParseRecurrenceRuleError::MissingFrequency => write!(f, "Missing FREQ"),
+            ParseRecurrenceRuleError::InvalidPart(part) => write!(f, "Invalid rule part: {part}"),
+            ParseRecurrenceRuleError::UnsupportedPart(part) => {
+                write!(f, "Unsupported rule part: {part}")
+            }
+            ParseRecurrenceRuleError::DuplicatePart(part) => {
+                write!(f, "Duplicate rule part: {part}")
+            }
+            ParseRecurrenceRuleError::InvalidValue { part, value } => {
+                write!(f, "Invalid value for {part}: {value}")
+            }
+            ParseRecurrenceRuleError::CountAndUntil => {
+                write!(f, "COUNT and UNTIL must not occur in the same rule")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseRecurrenceRuleError {}
+
+/// Error type for a [`RecurrenceRule`] that violates RFC 5545.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    /// A value of a part is out of range.
+    InvalidValue {
+        /// The name of the part.
+        part: &'static str,
+        /// The invalid value.
+        value: i64,
+    },
+    /// The rule contains both a `COUNT` and an `UNTIL` part.
+    CountAndUntil,
+    /// The part is not allowed for the frequency of the rule.
+    PartNotAllowed {
+        /// The name of the part.
+        part: &'static str,
+        /// The frequency of the rule.
+        freq: RecurrenceFrequency,
+    },
+    /// `BYDAY` contains an ordinal, but ordinals are not allowed in the rule.
+    OrdinalNotAllowed,
+    /// `BYSETPOS` is used without any other `BYxxx` part.
+    SetPosWithoutOtherPart,
+    /// The value type of `UNTIL` differs from the value type of `DTSTART`.
+    UntilValueType,
+    /// `UNTIL` is in local time, but `DTSTART` is in UTC time or has a time zone, or `UNTIL` is
+    /// in UTC time, but `DTSTART` is in local time without a time zone.
+    UntilTimeForm,
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self {
+            RuleError::InvalidValue { part, value } => {
+                write!(f, "Invalid value for {part}: {value}")
+            }
+            RuleError::CountAndUntil => {
+                write!(f, "COUNT and UNTIL must not occur in the same rule")
+            }
+            RuleError::PartNotAllowed { part, freq } => {
+                write!(f, "{part} is not allowed in {freq} rules")
+            }
+            RuleError::OrdinalNotAllowed => {
+                write!(f, "BYDAY must not contain ordinals in this rule")
+            }
+            RuleError::SetPosWithoutOtherPart => {
+                write!(f, "BYSETPOS must be used together with another BYxxx part")
+            }
+            RuleError::UntilValueType => {
+                write!(f, "UNTIL must have the same value type as DTSTART")
+            }
+            RuleError::UntilTimeForm => write!(
+                f,
+                "UNTIL must be in UTC time if and only if DTSTART is in UTC time or has a time zone"
+            ),
+        }
+    }
+}
+
+impl error::Error for RuleError {}
+
+/// Write a `;NAME=VALUE` rule part to the formatter.
+fn write_part<T: Display>(f: &mut Formatter, name: &str, value: T) -> Result<(), Error> {
+    write!(f, ";{name}={value}")
+}
+
+/// Write a `;NAME=VALUE,VALUE,...` rule part to the formatter.
+///
+/// Nothing is written if `values` is empty.
+fn write_list_part<I>(f: &mut Formatter, name: &str, values: I) -> Result<(), Error>
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    for (index, value) in values.into_iter().enumerate() {
+        if index == 0 {
+            write!(f, ";{name}={value}")?;
+        } else {
+            write!(f, ",{value}")?;
+        }
+    }
+    Ok(())
+}
+
+/// A day of the week, optionally restricted to the n-th occurrence of that day within the month
+/// or year.
+///
+/// Corresponds to `weekdaynum` in
+/// [RFC 5545 section 3.3.10](https://tools.ietf.org/html/rfc5545#section-3.3.10). For example,
+/// `2SU` is the second Sunday and `-1FR` is the last Friday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekdayNum {
+    ordinal: Option<i8>,
+    weekday: Weekday,
+}
+
+impl WeekdayNum {
+    /// Every occurrence of the given weekday.
+    #[must_use]
+    pub fn every(weekday: Weekday) -> Self {
+        Self {
+            ordinal: None,
+            weekday,
+        }
+    }
+
+    /// The n-th occurrence of the given weekday within the month or year.
+    ///
+    /// Negative values count from the end, that is, -1 is the last occurrence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not in the range 1 to 53 or -53 to -1.
+    #[must_use]
+    pub fn nth(n: i8, weekday: Weekday) -> Self {
+        assert!(
+            is_valid_ordinal(n),
+            "Ordinal must be between 1 and 53 or between -53 and -1"
+        );
+        Self {
+            ordinal: Some(n),
+            weekday,
+        }
+    }
+
+    /// Get the ordinal, if any.
+    #[must_use]
+    pub fn ordinal(&self) -> Option<i8> {
+        self.ordinal
+    }
+
+    /// Get the weekday.
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+}
+
+impl From<Weekday> for WeekdayNum {
+    fn from(weekday: Weekday) -> Self {
+        Self::every(weekday)
+    }
+}
+
+impl Display for WeekdayNum {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "{ordinal}")?;
+        }
+        write!(f, "{}", self.weekday)
+    }
+}
+
 /// The frequency of a recurrence rule.
 ///
 /// This is used to specify how often a recurrence rule should repeat.
@@ -92,3 +913,335 @@ impl FromStr for RecurrenceFrequency {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            ParseRecurrenceRuleError, RecurrenceFrequency, RecurrenceRule, RuleError, WeekdayNum,
+        },
+        crate::{Calendar, Date, DateTime, Event, Time, Weekday, ZonedDateTime},
+        std::io,
+    };
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Daily).to_string(),
+            "FREQ=DAILY"
+        );
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Weekly)
+                .set_until(DateTime {
+                    date: Date::new(2025, 12, 31),
+                    time: Time::new_utc(23, 59, 59),
+                })
+                .to_string(),
+            "FREQ=WEEKLY;UNTIL=20251231T235959Z"
+        );
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Weekly)
+                .set_interval(2)
+                .set_count(10)
+                .to_string(),
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=10"
+        );
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Monthly)
+                .set_interval(1)
+                .to_string(),
+            "FREQ=MONTHLY"
+        );
+    }
+
+    #[test]
+    fn display_by_day() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Yearly)
+                .set_by_day([
+                    WeekdayNum::nth(1, Weekday::Monday),
+                    WeekdayNum::nth(-53, Weekday::Sunday),
+                    WeekdayNum::every(Weekday::Tuesday),
+                ])
+                .set_count(3)
+                .to_string(),
+            "FREQ=YEARLY;COUNT=3;BYDAY=1MO,-53SU,TU"
+        );
+    }
+
+    #[test]
+    fn display_week_start() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Weekly)
+                .set_interval(2)
+                .set_by_day([Weekday::Tuesday, Weekday::Sunday])
+                .set_week_start(Weekday::Sunday)
+                .to_string(),
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,SU;WKST=SU"
+        );
+    }
+
+    #[test]
+    fn display_by_month_and_days() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Yearly)
+                .set_by_month([1, 7])
+                .set_by_month_day([1, -1])
+                .to_string(),
+            "FREQ=YEARLY;BYMONTHDAY=1,-1;BYMONTH=1,7"
+        );
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Yearly)
+                .set_by_year_day([100, -366])
+                .to_string(),
+            "FREQ=YEARLY;BYYEARDAY=100,-366"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Month day must be between 1 and 31 or between -31 and -1")]
+    fn invalid_month_day() {
+        RecurrenceRule::new(RecurrenceFrequency::Monthly).set_by_month_day([32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Year day must be between 1 and 366 or between -366 and -1")]
+    fn invalid_year_day() {
+        RecurrenceRule::new(RecurrenceFrequency::Yearly).set_by_year_day([0]);
+    }
+
+    #[test]
+    fn display_by_week_no() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Yearly)
+                .set_by_week_no([20, -1])
+                .set_by_day([Weekday::Monday])
+                .to_string(),
+            "FREQ=YEARLY;BYDAY=MO;BYWEEKNO=20,-1"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Week number must be between 1 and 53 or between -53 and -1")]
+    fn invalid_week_no() {
+        RecurrenceRule::new(RecurrenceFrequency::Yearly).set_by_week_no([54]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Month must be between 1 and 12")]
+    fn invalid_month() {
+        RecurrenceRule::new(RecurrenceFrequency::Yearly).set_by_month([13]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Set position must be between 1 and 366 or between -366 and -1")]
+    fn invalid_set_pos() {
+        RecurrenceRule::new(RecurrenceFrequency::Monthly).set_by_set_pos([367]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ordinal must be between 1 and 53 or between -53 and -1")]
+    fn invalid_ordinal() {
+        let _ = WeekdayNum::nth(0, Weekday::Monday);
+    }
+
+    #[test]
+    fn parse() {
+        for rule in [
+            "FREQ=DAILY",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=10",
+            "FREQ=WEEKLY;UNTIL=20251231T235959Z",
+            "FREQ=WEEKLY;UNTIL=20251231",
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "FREQ=YEARLY;INTERVAL=4;BYDAY=-1SU,2MO;BYMONTHDAY=1,-31;BYYEARDAY=-366,100;BYWEEKNO=20;BYMONTH=2,3;WKST=SU",
+        ] {
+            assert_eq!(rule.parse::<RecurrenceRule>().unwrap().to_string(), rule);
+        }
+        assert_eq!(
+            "byday=2su;freq=monthly"
+                .parse::<RecurrenceRule>()
+                .unwrap()
+                .to_string(),
+            "FREQ=MONTHLY;BYDAY=2SU"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "COUNT=3".parse::<RecurrenceRule>(),
+            Err(ParseRecurrenceRuleError::MissingFrequency)
+        );
+        assert_eq!(
+            "FREQ=DAILY;COUNT=3;UNTIL=20251231T235959Z".parse::<RecurrenceRule>(),
+            Err(ParseRecurrenceRuleError::CountAndUntil)
+        );
+        assert_eq!(
+            "FREQ=DAILY;FREQ=WEEKLY".parse::<RecurrenceRule>(),
+            Err(ParseRecurrenceRuleError::DuplicatePart("FREQ".to_owned()))
+        );
+        assert_eq!(
+            "FREQ=DAILY;BYHOUR=9".parse::<RecurrenceRule>(),
+            Err(ParseRecurrenceRuleError::UnsupportedPart(
+                "BYHOUR".to_owned()
+            ))
+        );
+        assert_eq!(
+            "FREQ=DAILY;FOO=BAR".parse::<RecurrenceRule>(),
+            Err(ParseRecurrenceRuleError::InvalidPart("FOO=BAR".to_owned()))
+        );
+        for (rule, part, value) in [
+            ("FREQ=FORTNIGHTLY", "FREQ", "FORTNIGHTLY"),
+            ("FREQ=DAILY;INTERVAL=0", "INTERVAL", "0"),
+            ("FREQ=DAILY;COUNT=-1", "COUNT", "-1"),
+            ("FREQ=MONTHLY;BYDAY=0MO", "BYDAY", "0MO"),
+            ("FREQ=MONTHLY;BYDAY=+SU", "BYDAY", "+SU"),
+            ("FREQ=MONTHLY;BYDAY=MO,", "BYDAY", "MO,"),
+            ("FREQ=MONTHLY;BYDAY=ÉMO", "BYDAY", "ÉMO"),
+            ("FREQ=MONTHLY;BYMONTHDAY=32", "BYMONTHDAY", "32"),
+            ("FREQ=YEARLY;BYMONTH=0", "BYMONTH", "0"),
+            ("FREQ=WEEKLY;WKST=XX", "WKST", "XX"),
+        ] {
+            assert_eq!(
+                rule.parse::<RecurrenceRule>(),
+                Err(ParseRecurrenceRuleError::InvalidValue {
+                    part: part.to_owned(),
+                    value: value.to_owned(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn count_and_until_are_exclusive() {
+        let until = DateTime {
+            date: Date::new(2025, 12, 31),
+            time: Time::new_utc(0, 0, 0),
+        };
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        assert_eq!(
+            rule.set_count(5).set_until(until).to_string(),
+            "FREQ=DAILY;UNTIL=20251231T000000Z"
+        );
+        assert_eq!(
+            rule.set_until(until).set_count(5).to_string(),
+            "FREQ=DAILY;COUNT=5"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval must be at least 1")]
+    fn invalid_interval() {
+        RecurrenceRule::new(RecurrenceFrequency::Daily).set_interval(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Count must be at least 1")]
+    fn invalid_count() {
+        RecurrenceRule::new(RecurrenceFrequency::Daily).set_count(0);
+    }
+
+    #[test]
+    fn strict_write_rejects_until_value_type_mismatch() {
+        let date_time = "20250101T090000Z".parse::<DateTime>().unwrap();
+        let mut event = Event::with_uid("1@example.com", Date::new(2025, 1, 1).into(), date_time);
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        rule.set_until(date_time);
+        event.set_recurrence_rule(rule);
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+
+        assert!(calendar.write(Vec::new()).is_ok());
+
+        let mut output = Vec::new();
+        let err = calendar.set_strict(true).write(&mut output).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner().unwrap().downcast::<RuleError>().unwrap(),
+            Box::new(RuleError::UntilValueType)
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn until_value_type() {
+        let date = Date::new(2025, 12, 31);
+        let date_time = DateTime {
+            date,
+            time: Time::new_utc(23, 59, 59),
+        };
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        assert_eq!(rule.set_until(date).validate_against(&date.into()), Ok(()));
+        assert_eq!(
+            rule.set_until(date).validate_against(&date_time.into()),
+            Err(RuleError::UntilValueType)
+        );
+        assert_eq!(
+            rule.set_until(date_time)
+                .validate_against(&date_time.into()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn until_time_form() {
+        let date = Date::new(2025, 12, 31);
+        let utc = DateTime {
+            date,
+            time: Time::new_utc(23, 59, 59),
+        };
+        let local = DateTime {
+            date,
+            time: Time::new_local(23, 59, 59),
+        };
+        let zoned = ZonedDateTime::new(local, "Europe/Zurich");
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        rule.set_until(utc);
+        assert_eq!(rule.validate_against(&utc.into()), Ok(()));
+        assert_eq!(rule.validate_against(&zoned.clone().into()), Ok(()));
+        assert_eq!(
+            rule.validate_against(&local.into()),
+            Err(RuleError::UntilTimeForm)
+        );
+        rule.set_until(local);
+        assert_eq!(rule.validate_against(&local.into()), Ok(()));
+        assert_eq!(
+            rule.validate_against(&zoned.into()),
+            Err(RuleError::UntilTimeForm)
+        );
+    }
+
+    #[test]
+    fn getters() {
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+        assert_eq!(rule.freq(), RecurrenceFrequency::Monthly);
+        assert_eq!(rule.interval(), 1);
+        assert_eq!(rule.until(), None);
+        assert_eq!(rule.count(), None);
+        assert_eq!(rule.week_start(), None);
+
+        let until = Date::new(2025, 12, 31);
+        rule.set_freq(RecurrenceFrequency::Yearly)
+            .set_interval(2)
+            .set_until(until)
+            .set_by_day([WeekdayNum::nth(-1, Weekday::Friday)])
+            .set_by_month([12])
+            .set_by_set_pos([-1])
+            .set_week_start(Weekday::Sunday);
+        assert_eq!(rule.freq(), RecurrenceFrequency::Yearly);
+        assert_eq!(rule.interval(), 2);
+        assert_eq!(rule.until(), Some(until.into()));
+        assert_eq!(rule.by_day(), [WeekdayNum::nth(-1, Weekday::Friday)]);
+        assert_eq!(rule.by_month(), [12]);
+        assert_eq!(rule.by_set_pos(), [-1]);
+        assert_eq!(rule.week_start(), Some(Weekday::Sunday));
+
+        // Clearing the end date does not affect the count and vice versa.
+        rule.clear_count();
+        assert_eq!(rule.until(), Some(until.into()));
+        rule.clear_until();
+        assert_eq!(rule.until(), None);
+        rule.set_count(3).clear_until();
+        assert_eq!(rule.count(), Some(3));
+    }
+}
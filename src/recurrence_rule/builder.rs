@@ -0,0 +1,306 @@
+use {
+    super::{
+        RecurrenceEnd, RecurrenceFrequency, RecurrenceRule, RuleError, WeekdayNum, is_valid_month,
+        is_valid_month_day, is_valid_set_pos, is_valid_week_no, is_valid_year_day,
+    },
+    crate::{DateOrDateTime, Weekday},
+};
+
+/// A builder for [`RecurrenceRule`]s that reports invalid rules as errors instead of panicking.
+///
+/// Unlike the setters of [`RecurrenceRule`], the setters of this builder accept any
+/// value. All values and their combinations are checked by [`RecurrenceRuleBuilder::build`].
+///
+/// ```
+/// use ical::{RecurrenceFrequency, RecurrenceRule, RuleError};
+///
+/// let rule = RecurrenceRule::builder(RecurrenceFrequency::Yearly)
+///     .by_week_no([1, -1])
+///     .build()
+///     .unwrap();
+/// assert_eq!(rule.to_string(), "FREQ=YEARLY;BYWEEKNO=1,-1");
+///
+/// let err = RecurrenceRule::builder(RecurrenceFrequency::Monthly)
+///     .by_week_no([1])
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(
+///     err,
+///     RuleError::PartNotAllowed {
+///         part: "BYWEEKNO",
+///         freq: RecurrenceFrequency::Monthly,
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecurrenceRuleBuilder {
+    freq: RecurrenceFrequency,
+    interval: u32,
+    until: Option<DateOrDateTime>,
+    count: Option<u32>,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<u8>,
+    by_set_pos: Vec<i16>,
+    week_start: Option<Weekday>,
+}
+
+impl RecurrenceRuleBuilder {
+    pub(crate) fn new(freq: RecurrenceFrequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            until: None,
+            count: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: None,
+        }
+    }
+
+    /// Set the end date (inclusive) of the rule (`UNTIL`).
+    pub fn until<D: Into<DateOrDateTime>>(&mut self, until: D) -> &mut Self {
+        self.until = Some(until.into());
+        self
+    }
+
+    /// Set the number of occurrences of the rule (`COUNT`).
+    pub fn count(&mut self, count: u32) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the interval of the rule (`INTERVAL`).
+    pub fn interval(&mut self, interval: u32) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the days of the week on which the rule repeats (`BYDAY`).
+    pub fn by_day<I>(&mut self, days: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<WeekdayNum>,
+    {
+        self.by_day = days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the days of the month on which the rule repeats (`BYMONTHDAY`).
+    pub fn by_month_day<I: IntoIterator<Item = i8>>(&mut self, days: I) -> &mut Self {
+        self.by_month_day = days.into_iter().collect();
+        self
+    }
+
+    /// Set the days of the year on which the rule repeats (`BYYEARDAY`).
+    pub fn by_year_day<I: IntoIterator<Item = i16>>(&mut self, days: I) -> &mut Self {
+        self.by_year_day = days.into_iter().collect();
+        self
+    }
+
+    /// Set the week numbers in which the rule repeats (`BYWEEKNO`).
+    pub fn by_week_no<I: IntoIterator<Item = i8>>(&mut self, weeks: I) -> &mut Self {
+        self.by_week_no = weeks.into_iter().collect();
+        self
+    }
+
+    /// Set the months in which the rule repeats (`BYMONTH`).
+    pub fn by_month<I: IntoIterator<Item = u8>>(&mut self, months: I) -> &mut Self {
+        self.by_month = months.into_iter().collect();
+        self
+    }
+
+    /// Select occurrences by their position within each interval (`BYSETPOS`).
+    pub fn by_set_pos<I: IntoIterator<Item = i16>>(&mut self, positions: I) -> &mut Self {
+        self.by_set_pos = positions.into_iter().collect();
+        self
+    }
+
+    /// Set the day on which the work week starts (`WKST`).
+    pub fn week_start(&mut self, week_start: Weekday) -> &mut Self {
+        self.week_start = Some(week_start);
+        self
+    }
+
+    /// Build the [`RecurrenceRule`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values is out of range or if the rule is invalid as a
+    /// whole, see [`RecurrenceRule::validate`].
+    pub fn build(&self) -> Result<RecurrenceRule, RuleError> {
+        check_values("INTERVAL", [self.interval], |interval| interval >= 1)?;
+        check_values("COUNT", self.count, |count| count >= 1)?;
+        check_values(
+            "BYMONTHDAY",
+            self.by_month_day.iter().copied(),
+            is_valid_month_day,
+        )?;
+        check_values(
+            "BYYEARDAY",
+            self.by_year_day.iter().copied(),
+            is_valid_year_day,
+        )?;
+        check_values(
+            "BYWEEKNO",
+            self.by_week_no.iter().copied(),
+            is_valid_week_no,
+        )?;
+        check_values("BYMONTH", self.by_month.iter().copied(), is_valid_month)?;
+        check_values(
+            "BYSETPOS",
+            self.by_set_pos.iter().copied(),
+            is_valid_set_pos,
+        )?;
+        let end = match (self.until, self.count) {
+            (Some(_), Some(_)) => return Err(RuleError::CountAndUntil),
+            (Some(until), None) => Some(RecurrenceEnd::Until(until)),
+            (None, Some(count)) => Some(RecurrenceEnd::Count(count)),
+            (None, None) => None,
+        };
+        let rule = RecurrenceRule {
+            freq: self.freq,
+            interval: self.interval,
+            end,
+            by_day: self.by_day.clone(),
+            by_month_day: self.by_month_day.clone(),
+            by_year_day: self.by_year_day.clone(),
+            by_week_no: self.by_week_no.clone(),
+            by_month: self.by_month.clone(),
+            by_set_pos: self.by_set_pos.clone(),
+            week_start: self.week_start,
+        };
+        rule.validate()?;
+        Ok(rule)
+    }
+}
+
+/// Check that all `values` of the rule part `part` are valid.
+fn check_values<T, I>(
+    part: &'static str,
+    values: I,
+    is_valid: fn(T) -> bool,
+) -> Result<(), RuleError>
+where
+    T: Copy + Into<i64>,
+    I: IntoIterator<Item = T>,
+{
+    match values.into_iter().find(|&value| !is_valid(value)) {
+        Some(value) => Err(RuleError::InvalidValue {
+            part,
+            value: value.into(),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Date, DateTime, RecurrenceFrequency, RecurrenceRule, RuleError, Time, Weekday, WeekdayNum,
+    };
+
+    #[test]
+    fn build() {
+        let rule = RecurrenceRule::builder(RecurrenceFrequency::Monthly)
+            .interval(2)
+            .count(10)
+            .by_day([WeekdayNum::nth(-1, Weekday::Friday)])
+            .week_start(Weekday::Sunday)
+            .build()
+            .unwrap();
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=-1FR;WKST=SU"
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Daily)
+                .interval(0)
+                .build(),
+            Err(RuleError::InvalidValue {
+                part: "INTERVAL",
+                value: 0
+            })
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Monthly)
+                .by_month_day([1, 32])
+                .build(),
+            Err(RuleError::InvalidValue {
+                part: "BYMONTHDAY",
+                value: 32
+            })
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Yearly)
+                .by_month([0])
+                .build(),
+            Err(RuleError::InvalidValue {
+                part: "BYMONTH",
+                value: 0
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_combinations() {
+        let until = DateTime {
+            date: Date::new(2025, 12, 31),
+            time: Time::new_utc(0, 0, 0),
+        };
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Daily)
+                .count(3)
+                .until(until)
+                .build(),
+            Err(RuleError::CountAndUntil)
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Weekly)
+                .by_month_day([1])
+                .build(),
+            Err(RuleError::PartNotAllowed {
+                part: "BYMONTHDAY",
+                freq: RecurrenceFrequency::Weekly
+            })
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Monthly)
+                .by_year_day([1])
+                .build(),
+            Err(RuleError::PartNotAllowed {
+                part: "BYYEARDAY",
+                freq: RecurrenceFrequency::Monthly
+            })
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Weekly)
+                .by_day([WeekdayNum::nth(1, Weekday::Monday)])
+                .build(),
+            Err(RuleError::OrdinalNotAllowed)
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Yearly)
+                .by_week_no([1])
+                .by_day([WeekdayNum::nth(1, Weekday::Monday)])
+                .build(),
+            Err(RuleError::OrdinalNotAllowed)
+        );
+        assert_eq!(
+            RecurrenceRule::builder(RecurrenceFrequency::Monthly)
+                .by_set_pos([1])
+                .build(),
+            Err(RuleError::SetPosWithoutOtherPart)
+        );
+    }
+}
@@ -0,0 +1,617 @@
+use {
+    super::{RecurrenceEnd, RecurrenceFrequency, RecurrenceRule, WeekdayNum},
+    crate::{
+        Date, DateOrDateTime, DateTime, Time, Weekday,
+        date_and_time::{days_in_month, days_in_year},
+    },
+    std::collections::VecDeque,
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// An iterator over the instances of a recurrence rule, in chronological order.
+///
+/// The rule is evaluated one period at a time, where a period is a year, month, week, day, hour,
+/// minute or second depending on the frequency of the rule. For every period, the candidate
+/// dates are filtered by the `BYxxx` parts of the rule and `BYSETPOS` is applied to the remaining
+/// set, as described in
+/// [RFC 5545 section 3.3.10](https://tools.ietf.org/html/rfc5545#section-3.3.10).
+///
+/// Unless created with [`RuleInstances::without_start`], the first instance is always the start
+/// of the recurrence set, that is, the `DTSTART` value. It counts towards the `COUNT` of the
+/// rule.
+///
+/// At most `period_limit` periods are evaluated. This guarantees termination for rules that
+/// never produce another instance, such as `FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30`.
+#[derive(Debug, Clone)]
+pub(crate) struct RuleInstances {
+    expansion: Option<Expansion>,
+    start: DateOrDateTime,
+    /// Whether `start` is an instance regardless of whether it matches the rule.
+    start_is_instance: bool,
+    pending: VecDeque<DateOrDateTime>,
+    emitted: u32,
+    next_period: i64,
+    period_limit: usize,
+    periods: usize,
+    limit_reached: bool,
+    exhausted: bool,
+}
+
+impl RuleInstances {
+    /// Create a new iterator over the instances of `rule`, starting at `start`.
+    ///
+    /// If `rule` is `None`, only `start` is produced.
+    pub(crate) fn new(
+        rule: Option<&RecurrenceRule>,
+        start: DateOrDateTime,
+        period_limit: usize,
+    ) -> Self {
+        Self {
+            expansion: rule.map(|rule| Expansion::new(rule, start.date())),
+            start,
+            start_is_instance: true,
+            pending: VecDeque::from([start]),
+            emitted: 0,
+            next_period: 0,
+            period_limit,
+            periods: 0,
+            limit_reached: false,
+            exhausted: false,
+        }
+    }
+
+    /// Create a new iterator over the instances of `rule`, starting at `start`, where `start`
+    /// is only an instance if it matches the rule.
+    ///
+    /// This is used for exception rules, which should not exclude the start of the recurrence
+    /// set unless they explicitly match it.
+    pub(crate) fn without_start(
+        rule: &RecurrenceRule,
+        start: DateOrDateTime,
+        period_limit: usize,
+    ) -> Self {
+        Self {
+            start_is_instance: false,
+            pending: VecDeque::new(),
+            ..Self::new(Some(rule), start, period_limit)
+        }
+    }
+
+    /// Set the maximum number of periods to evaluate.
+    pub(crate) fn set_period_limit(&mut self, period_limit: usize) {
+        self.period_limit = period_limit;
+    }
+
+    /// Returns `true` if the iterator stopped because the period limit was reached.
+    pub(crate) fn limit_reached(&self) -> bool {
+        self.limit_reached
+    }
+
+    fn emit(&mut self, instance: DateOrDateTime) -> DateOrDateTime {
+        self.emitted += 1;
+        if let Some(RecurrenceEnd::Count(count)) =
+            self.expansion.as_ref().and_then(|expansion| expansion.end)
+            && self.emitted >= count
+        {
+            self.exhausted = true;
+            self.pending.clear();
+        }
+        instance
+    }
+}
+
+impl Iterator for RuleInstances {
+    type Item = DateOrDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(instance) = self.pending.pop_front() {
+                return Some(self.emit(instance));
+            }
+            if self.exhausted {
+                return None;
+            }
+            let expansion = self.expansion.as_ref()?;
+            if self.periods >= self.period_limit {
+                self.limit_reached = true;
+                self.exhausted = true;
+                return None;
+            }
+            self.periods += 1;
+            let Some(instances) = expansion.period_instances(self.start, self.next_period) else {
+                // The period is outside of the supported range of dates.
+                self.exhausted = true;
+                return None;
+            };
+            self.next_period += 1;
+            let start = self.start.chronological_key();
+            for instance in instances {
+                let key = instance.chronological_key();
+                if key < start || (key == start && self.start_is_instance) {
+                    continue;
+                }
+                if let Some(RecurrenceEnd::Until(until)) = expansion.end
+                    && is_after(instance, until)
+                {
+                    self.exhausted = true;
+                    break;
+                }
+                self.pending.push_back(instance);
+            }
+        }
+    }
+}
+
+/// A recurrence rule with the defaults derived from the start of the recurrence set filled in.
+#[derive(Debug, Clone)]
+struct Expansion {
+    freq: RecurrenceFrequency,
+    interval: i64,
+    end: Option<RecurrenceEnd>,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<u8>,
+    by_set_pos: Vec<i16>,
+    week_start: Weekday,
+}
+
+impl Expansion {
+    fn new(rule: &RecurrenceRule, start: Date) -> Self {
+        let mut expansion = Self {
+            freq: rule.freq,
+            interval: i64::from(rule.interval),
+            end: rule.end,
+            by_day: rule.by_day.clone(),
+            by_month_day: rule.by_month_day.clone(),
+            by_year_day: rule.by_year_day.clone(),
+            by_week_no: rule.by_week_no.clone(),
+            by_month: rule.by_month.clone(),
+            by_set_pos: rule.by_set_pos.clone(),
+            week_start: rule.week_start.unwrap_or(Weekday::Monday),
+        };
+        // If the rule does not specify which days of the period to use, the days are derived
+        // from the start of the recurrence set.
+        if expansion.by_day.is_empty()
+            && expansion.by_month_day.is_empty()
+            && expansion.by_year_day.is_empty()
+            && expansion.by_week_no.is_empty()
+        {
+            let day = i8::try_from(start.day()).expect("day is at most 31");
+            match expansion.freq {
+                RecurrenceFrequency::Yearly => {
+                    if expansion.by_month.is_empty() {
+                        expansion.by_month.push(start.month());
+                    }
+                    expansion.by_month_day.push(day);
+                }
+                RecurrenceFrequency::Monthly => expansion.by_month_day.push(day),
+                RecurrenceFrequency::Weekly => {
+                    expansion.by_day.push(WeekdayNum::every(start.weekday()));
+                }
+                _ => {}
+            }
+        }
+        expansion
+    }
+
+    /// The instances in the given period of the rule.
+    ///
+    /// Period 0 is the one containing `start`, and every following period lies `INTERVAL`
+    /// periods further.
+    ///
+    /// Returns `None` if the period is outside of the supported range of dates.
+    fn period_instances(&self, start: DateOrDateTime, period: i64) -> Option<Vec<DateOrDateTime>> {
+        let step = period.checked_mul(self.interval)?;
+        let start_date = start.date();
+        let start_day = i64::from(start_date.days_since_epoch());
+        let dates = match self.freq {
+            RecurrenceFrequency::Yearly => {
+                let year = u16::try_from(i64::from(start_date.year()).checked_add(step)?).ok()?;
+                if year > 9999 {
+                    return None;
+                }
+                let first = Date::new(year, 1, 1);
+                self.matching_dates(first, i64::from(days_in_year(year)))?
+            }
+            RecurrenceFrequency::Monthly => {
+                let index = (i64::from(start_date.year()) * 12 + i64::from(start_date.month()) - 1)
+                    .checked_add(step)?;
+                let year = u16::try_from(index.div_euclid(12)).ok()?;
+                if year > 9999 {
+                    return None;
+                }
+                let month = u8::try_from(index.rem_euclid(12) + 1).expect("month is in 1-12");
+                let first = Date::new(year, month, 1);
+                self.matching_dates(first, i64::from(days_in_month(year, month)))?
+            }
+            RecurrenceFrequency::Weekly => {
+                let offset = (i64::from(start_date.weekday().days_from_monday())
+                    - i64::from(self.week_start.days_from_monday()))
+                .rem_euclid(7);
+                let first = date_from_days(start_day - offset + step.checked_mul(7)?)?;
+                self.matching_dates(first, 7)?
+            }
+            RecurrenceFrequency::Daily => {
+                let date = date_from_days(start_day.checked_add(step)?)?;
+                self.matching_dates(date, 1)?
+            }
+            RecurrenceFrequency::Hourly
+            | RecurrenceFrequency::Minutely
+            | RecurrenceFrequency::Secondly => {
+                // Sub-daily rules require the start to have a time. For a date start, the
+                // recurrence set consists of the start only.
+                let DateOrDateTime::DateTime(start) = start else {
+                    return None;
+                };
+                let unit = match self.freq {
+                    RecurrenceFrequency::Hourly => 3600,
+                    RecurrenceFrequency::Minutely => 60,
+                    _ => 1,
+                };
+                let seconds = (start_day * SECONDS_PER_DAY
+                    + i64::from(start.time.seconds_of_day()))
+                .checked_add(step.checked_mul(unit)?)?;
+                let date = date_from_days(seconds.div_euclid(SECONDS_PER_DAY))?;
+                let instance = DateTime {
+                    date,
+                    time: time_from_seconds(
+                        seconds.rem_euclid(SECONDS_PER_DAY),
+                        start.time.is_utc(),
+                    ),
+                };
+                let instances = if self.matches(date) {
+                    vec![DateOrDateTime::DateTime(instance)]
+                } else {
+                    Vec::new()
+                };
+                return Some(self.select_positions(instances));
+            }
+        };
+        let instances = dates
+            .into_iter()
+            .map(|date| match start {
+                DateOrDateTime::Date(_) => DateOrDateTime::Date(date),
+                DateOrDateTime::DateTime(start) => DateOrDateTime::DateTime(DateTime {
+                    date,
+                    time: start.time,
+                }),
+            })
+            .collect();
+        Some(self.select_positions(instances))
+    }
+
+    /// The dates among the `count` days starting at `first` which match the rule.
+    ///
+    /// Returns `None` if the first date is outside of the supported range of dates.
+    fn matching_dates(&self, first: Date, count: i64) -> Option<Vec<Date>> {
+        let first = i64::from(first.days_since_epoch());
+        date_from_days(first)?;
+        Some(
+            (first..first + count)
+                .filter_map(date_from_days)
+                .filter(|&date| self.matches(date))
+                .collect(),
+        )
+    }
+
+    /// Apply `BYSETPOS` to the instances of a period.
+    fn select_positions(&self, instances: Vec<DateOrDateTime>) -> Vec<DateOrDateTime> {
+        if self.by_set_pos.is_empty() {
+            return instances;
+        }
+        let len = i64::try_from(instances.len()).expect("a period has few instances");
+        let mut selected: Vec<_> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&position| {
+                let position = i64::from(position);
+                let index = if position > 0 {
+                    position - 1
+                } else {
+                    len + position
+                };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| instances.get(index))
+                    .copied()
+            })
+            .collect();
+        selected.sort_by_key(|instance| instance.chronological_key());
+        selected.dedup();
+        selected
+    }
+
+    /// Returns `true` if `date` matches all the `BYxxx` parts of the rule.
+    fn matches(&self, date: Date) -> bool {
+        (self.by_month.is_empty() || self.by_month.contains(&date.month()))
+            && (self.by_week_no.is_empty() || {
+                let (week, weeks) = week_number(date, self.week_start);
+                self.by_week_no
+                    .iter()
+                    .any(|&n| i64::from(n) == week || i64::from(n) == week - weeks - 1)
+            })
+            && (self.by_year_day.is_empty() || {
+                let day = i64::from(date.day_of_year());
+                let days = i64::from(days_in_year(date.year()));
+                self.by_year_day
+                    .iter()
+                    .any(|&n| i64::from(n) == day || i64::from(n) == day - days - 1)
+            })
+            && (self.by_month_day.is_empty() || {
+                let day = i64::from(date.day());
+                let days = i64::from(days_in_month(date.year(), date.month()));
+                self.by_month_day
+                    .iter()
+                    .any(|&n| i64::from(n) == day || i64::from(n) == day - days - 1)
+            })
+            && (self.by_day.is_empty()
+                || self
+                    .by_day
+                    .iter()
+                    .any(|&weekday_num| self.matches_weekday_num(weekday_num, date)))
+    }
+
+    fn matches_weekday_num(&self, weekday_num: WeekdayNum, date: Date) -> bool {
+        if weekday_num.weekday != date.weekday() {
+            return false;
+        }
+        let Some(ordinal) = weekday_num.ordinal else {
+            return true;
+        };
+        // Ordinals count within the month for monthly rules and for yearly rules restricted to
+        // certain months, and within the year for other yearly rules. They are ignored for all
+        // other frequencies.
+        let within_month = match self.freq {
+            RecurrenceFrequency::Monthly => true,
+            RecurrenceFrequency::Yearly => !self.by_month.is_empty(),
+            _ => return true,
+        };
+        let (first, last) = if within_month {
+            (
+                Date::new(date.year(), date.month(), 1),
+                Date::new(
+                    date.year(),
+                    date.month(),
+                    days_in_month(date.year(), date.month()),
+                ),
+            )
+        } else {
+            (Date::new(date.year(), 1, 1), Date::new(date.year(), 12, 31))
+        };
+        let day = date.days_since_epoch();
+        let ordinal = i32::from(ordinal);
+        if ordinal > 0 {
+            (day - first.days_since_epoch()) / 7 + 1 == ordinal
+        } else {
+            (last.days_since_epoch() - day) / 7 + 1 == -ordinal
+        }
+    }
+}
+
+/// Returns `true` if `instance` is after `until`.
+///
+/// If `until` is a date, all instances on that day are included.
+fn is_after(instance: DateOrDateTime, until: DateOrDateTime) -> bool {
+    match until {
+        DateOrDateTime::Date(until) => instance.date() > until,
+        DateOrDateTime::DateTime(_) => instance.chronological_key() > until.chronological_key(),
+    }
+}
+
+/// The week number of `date` and the number of weeks in the year the week belongs to.
+///
+/// Week 1 is the first week containing at least four days of the year, as in ISO 8601. Days
+/// before week 1 belong to the last week of the previous year and days after the last week
+/// belong to week 1 of the next year.
+fn week_number(date: Date, week_start: Weekday) -> (i64, i64) {
+    let year = i64::from(date.year());
+    let january_first = i64::from(Date::new(date.year(), 1, 1).days_since_epoch());
+    let day = i64::from(date.days_since_epoch());
+    let this_year = week_one_start(january_first, week_start);
+    let next_january_first = january_first + days_in_any_year(year);
+    let next_year = week_one_start(next_january_first, week_start);
+    if day < this_year {
+        let previous_year = week_one_start(january_first - days_in_any_year(year - 1), week_start);
+        (
+            (day - previous_year) / 7 + 1,
+            (this_year - previous_year) / 7,
+        )
+    } else if day >= next_year {
+        let year_after_next =
+            week_one_start(next_january_first + days_in_any_year(year + 1), week_start);
+        (1, (year_after_next - next_year) / 7)
+    } else {
+        ((day - this_year) / 7 + 1, (next_year - this_year) / 7)
+    }
+}
+
+/// The first day of week 1 of the year starting on day `january_first`.
+fn week_one_start(january_first: i64, week_start: Weekday) -> i64 {
+    // 1970-01-01 was a Thursday.
+    let weekday = (january_first + 3).rem_euclid(7);
+    let days_until_week_start = (i64::from(week_start.days_from_monday()) - weekday).rem_euclid(7);
+    if days_until_week_start >= 4 {
+        january_first + days_until_week_start - 7
+    } else {
+        january_first + days_until_week_start
+    }
+}
+
+/// The number of days in `year`, which may be outside of the range supported by [`Date`].
+fn days_in_any_year(year: i64) -> i64 {
+    if year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0) {
+        366
+    } else {
+        365
+    }
+}
+
+fn date_from_days(days: i64) -> Option<Date> {
+    Date::from_days_since_epoch(i32::try_from(days).ok()?)
+}
+
+/// The time `seconds` after midnight, in UTC time if `utc` is `true` and in local time otherwise.
+fn time_from_seconds(seconds: i64, utc: bool) -> Time {
+    let component = |value: i64| u8::try_from(value).expect("time component fits in a u8");
+    let new = if utc { Time::new_utc } else { Time::new_local };
+    new(
+        component(seconds / 3600),
+        component(seconds / 60 % 60),
+        component(seconds % 60),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::RuleInstances,
+        crate::{Date, DateOrDateTime, DateTime, RecurrenceRule},
+    };
+
+    fn instances(rule: &str, start: &str, n: usize) -> Vec<String> {
+        let rule: RecurrenceRule = rule.parse().unwrap();
+        let start = if start.contains('T') {
+            DateOrDateTime::DateTime(start.parse::<DateTime>().unwrap())
+        } else {
+            DateOrDateTime::Date(start.parse::<Date>().unwrap())
+        };
+        RuleInstances::new(Some(&rule), start, 10_000)
+            .take(n)
+            .map(|instance| instance.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn daily() {
+        assert_eq!(
+            instances("FREQ=DAILY;COUNT=3", "20240228T090000Z", 10),
+            ["20240228T090000Z", "20240229T090000Z", "20240301T090000Z"]
+        );
+        assert_eq!(
+            instances(
+                "FREQ=DAILY;INTERVAL=10;UNTIL=20240121T000000Z",
+                "20240101",
+                10
+            ),
+            ["20240101", "20240111", "20240121"]
+        );
+    }
+
+    #[test]
+    fn weekly() {
+        // The instances in the week of the start are included.
+        assert_eq!(
+            instances("FREQ=WEEKLY;BYDAY=MO,WE,FR", "20240103T100000Z", 4),
+            [
+                "20240103T100000Z",
+                "20240105T100000Z",
+                "20240108T100000Z",
+                "20240110T100000Z"
+            ]
+        );
+        // RFC 5545: the week start changes the result of rules with an interval.
+        assert_eq!(
+            instances(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+                "19970805",
+                10
+            ),
+            ["19970805", "19970810", "19970819", "19970824"]
+        );
+        assert_eq!(
+            instances(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+                "19970805",
+                10
+            ),
+            ["19970805", "19970817", "19970819", "19970831"]
+        );
+    }
+
+    #[test]
+    fn monthly() {
+        assert_eq!(
+            instances("FREQ=MONTHLY", "20240131", 3),
+            ["20240131", "20240331", "20240531"]
+        );
+        assert_eq!(
+            instances("FREQ=MONTHLY;BYDAY=-1FR", "20240126", 3),
+            ["20240126", "20240223", "20240329"]
+        );
+        assert_eq!(
+            instances("FREQ=MONTHLY;BYMONTHDAY=-2", "20240130", 3),
+            ["20240130", "20240228", "20240330"]
+        );
+        // The last work day of the month.
+        assert_eq!(
+            instances(
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+                "20240131",
+                3
+            ),
+            ["20240131", "20240229", "20240329"]
+        );
+    }
+
+    #[test]
+    fn yearly() {
+        assert_eq!(
+            instances("FREQ=YEARLY", "20240229", 3),
+            ["20240229", "20280229", "20320229"]
+        );
+        assert_eq!(
+            instances("FREQ=YEARLY;BYMONTH=3;BYDAY=TH", "19970313", 4),
+            ["19970313", "19970320", "19970327", "19980305"]
+        );
+        assert_eq!(
+            instances("FREQ=YEARLY;BYDAY=20MO", "19970519", 3),
+            ["19970519", "19980518", "19990517"]
+        );
+        assert_eq!(
+            instances("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", "19970512", 3),
+            ["19970512", "19980511", "19990517"]
+        );
+        assert_eq!(
+            instances("FREQ=YEARLY;BYYEARDAY=1,100,200", "19970101", 4),
+            ["19970101", "19970410", "19970719", "19980101"]
+        );
+    }
+
+    #[test]
+    fn sub_daily() {
+        assert_eq!(
+            instances("FREQ=HOURLY;INTERVAL=3;COUNT=3", "20241231T210000Z", 10),
+            ["20241231T210000Z", "20250101T000000Z", "20250101T030000Z"]
+        );
+        assert_eq!(
+            instances("FREQ=MINUTELY;INTERVAL=90;BYDAY=SA", "20240106T220000Z", 3),
+            ["20240106T220000Z", "20240106T233000Z", "20240113T010000Z"]
+        );
+        // Local times stay local.
+        assert_eq!(
+            instances("FREQ=HOURLY;COUNT=2", "20241231T230000", 10),
+            ["20241231T230000", "20250101T000000"]
+        );
+        // Sub-daily rules cannot be applied to a date without a time.
+        assert_eq!(instances("FREQ=HOURLY", "20240101", 10), ["20240101"]);
+    }
+
+    #[test]
+    fn period_limit() {
+        let rule: RecurrenceRule = "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30".parse().unwrap();
+        let mut instances = RuleInstances::new(Some(&rule), Date::new(2024, 1, 1).into(), 100);
+        assert_eq!(instances.next(), Some(Date::new(2024, 1, 1).into()));
+        assert_eq!(instances.next(), None);
+        assert!(instances.limit_reached());
+    }
+
+    #[test]
+    fn end_of_supported_range() {
+        let rule: RecurrenceRule = "FREQ=YEARLY".parse().unwrap();
+        let instances = RuleInstances::new(Some(&rule), Date::new(9998, 1, 1).into(), 100);
+        assert_eq!(instances.count(), 2);
+    }
+}
@@ -0,0 +1,363 @@
+use {
+    super::{RecurrenceEnd, RecurrenceFrequency, RecurrenceRule, WeekdayNum},
+    crate::{Date, Locale, Weekday},
+};
+
+impl RecurrenceRule {
+    /// Describe the rule in human-readable text.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Date, DateTime, Locale, RecurrenceFrequency, RecurrenceRule, Time, Weekday, WeekdayNum,
+    /// };
+    ///
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+    /// rule.set_interval(2)
+    ///     .set_by_day([Weekday::Monday, Weekday::Wednesday])
+    ///     .set_until(DateTime {
+    ///         date: Date::new(2025, 12, 31),
+    ///         time: Time::new_utc(23, 59, 59),
+    ///     });
+    /// assert_eq!(
+    ///     rule.to_human_string(Locale::English),
+    ///     "every 2 weeks on Monday and Wednesday until 31 Dec 2025",
+    /// );
+    ///
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+    /// rule.set_by_day([WeekdayNum::nth(-1, Weekday::Friday)])
+    ///     .set_count(10);
+    /// assert_eq!(
+    ///     rule.to_human_string(Locale::German),
+    ///     "jeden Monat am letzten Freitag, 10 Mal",
+    /// );
+    /// ```
+    ///
+    /// Only the date of `UNTIL` is included in the text. The week start (`WKST`) is omitted.
+    #[must_use]
+    pub fn to_human_string(&self, locale: Locale) -> String {
+        let mut text = vec![frequency(locale, self.freq, self.interval)];
+        if !self.by_day.is_empty() {
+            let days = self.by_day.iter().map(|&day| weekday_num(locale, day));
+            text.push(match locale {
+                Locale::English => format!(" on {}", list(locale, days)),
+                Locale::German => format!(" am {}", list(locale, days)),
+            });
+        }
+        if !self.by_month_day.is_empty() {
+            let days = list(
+                locale,
+                self.by_month_day.iter().map(|&n| ordinal(locale, n)),
+            );
+            text.push(match locale {
+                Locale::English => format!(" on the {days} day of the month"),
+                Locale::German => format!(" am {days} Tag des Monats"),
+            });
+        }
+        if !self.by_year_day.is_empty() {
+            let days = list(locale, self.by_year_day.iter().map(|&n| ordinal(locale, n)));
+            text.push(match locale {
+                Locale::English => format!(" on the {days} day of the year"),
+                Locale::German => format!(" am {days} Tag des Jahres"),
+            });
+        }
+        if !self.by_week_no.is_empty() {
+            let weeks = list(locale, self.by_week_no.iter().map(|&n| ordinal(locale, n)));
+            text.push(match locale {
+                Locale::English => format!(" in the {weeks} week of the year"),
+                Locale::German => format!(" in der {weeks} Woche des Jahres"),
+            });
+        }
+        if !self.by_month.is_empty() {
+            let months = list(locale, self.by_month.iter().map(|&n| month(locale, n)));
+            text.push(match locale {
+                Locale::English => format!(" in {months}"),
+                Locale::German => format!(" im {months}"),
+            });
+        }
+        if !self.by_set_pos.is_empty() {
+            let positions = list(locale, self.by_set_pos.iter().map(|&n| ordinal(locale, n)));
+            text.push(match locale {
+                Locale::English => format!(
+                    ", limited to the {positions} occurrence {}",
+                    each_period(locale, self.freq),
+                ),
+                Locale::German => format!(
+                    ", beschränkt auf den {positions} Termin {}",
+                    each_period(locale, self.freq),
+                ),
+            });
+        }
+        match (self.end, locale) {
+            (Some(RecurrenceEnd::Until(until)), Locale::English) => {
+                text.push(format!(" until {}", date(locale, until.date())));
+            }
+            (Some(RecurrenceEnd::Until(until)), Locale::German) => {
+                text.push(format!(" bis {}", date(locale, until.date())));
+            }
+            (Some(RecurrenceEnd::Count(1)), Locale::English) => text.push(", once".to_owned()),
+            (Some(RecurrenceEnd::Count(1)), Locale::German) => text.push(", einmal".to_owned()),
+            (Some(RecurrenceEnd::Count(count)), Locale::English) => {
+                text.push(format!(", {count} times"));
+            }
+            (Some(RecurrenceEnd::Count(count)), Locale::German) => {
+                text.push(format!(", {count} Mal"));
+            }
+            (None, _) => {}
+        }
+        text.concat()
+    }
+}
+
+/// Describe how often the rule repeats, for example "every 2 weeks".
+fn frequency(locale: Locale, freq: RecurrenceFrequency, interval: u32) -> String {
+    match (locale, interval) {
+        (Locale::English, 1) => format!("every {}", unit(locale, freq, false)),
+        (Locale::English, _) => format!("every {interval} {}", unit(locale, freq, true)),
+        (Locale::German, 1) => {
+            let each = match freq {
+                RecurrenceFrequency::Yearly => "jedes",
+                RecurrenceFrequency::Monthly | RecurrenceFrequency::Daily => "jeden",
+                _ => "jede",
+            };
+            format!("{each} {}", unit(locale, freq, false))
+        }
+        (Locale::German, _) => format!("alle {interval} {}", unit(locale, freq, true)),
+    }
+}
+
+/// Refer to each period of the rule, for example "of each month".
+fn each_period(locale: Locale, freq: RecurrenceFrequency) -> String {
+    match locale {
+        Locale::English => format!("of each {}", unit(locale, freq, false)),
+        Locale::German => match freq {
+            RecurrenceFrequency::Yearly => "jedes Jahres",
+            RecurrenceFrequency::Monthly => "jedes Monats",
+            RecurrenceFrequency::Weekly => "jeder Woche",
+            RecurrenceFrequency::Daily => "jedes Tages",
+            RecurrenceFrequency::Hourly => "jeder Stunde",
+            RecurrenceFrequency::Minutely => "jeder Minute",
+            RecurrenceFrequency::Secondly => "jeder Sekunde",
+        }
+        .to_owned(),
+    }
+}
+
+/// The unit of time of a frequency.
+fn unit(locale: Locale, freq: RecurrenceFrequency, plural: bool) -> &'static str {
+    match (locale, freq, plural) {
+        (Locale::English, RecurrenceFrequency::Yearly, false) => "year",
+        (Locale::English, RecurrenceFrequency::Yearly, true) => "years",
+        (Locale::English, RecurrenceFrequency::Monthly, false) => "month",
+        (Locale::English, RecurrenceFrequency::Monthly, true) => "months",
+        (Locale::English, RecurrenceFrequency::Weekly, false) => "week",
+        (Locale::English, RecurrenceFrequency::Weekly, true) => "weeks",
+        (Locale::English, RecurrenceFrequency::Daily, false) => "day",
+        (Locale::English, RecurrenceFrequency::Daily, true) => "days",
+        (Locale::English, RecurrenceFrequency::Hourly, false) => "hour",
+        (Locale::English, RecurrenceFrequency::Hourly, true) => "hours",
+        (Locale::English, RecurrenceFrequency::Minutely, false) => "minute",
+        (Locale::English, RecurrenceFrequency::Minutely, true) => "minutes",
+        (Locale::English, RecurrenceFrequency::Secondly, false) => "second",
+        (Locale::English, RecurrenceFrequency::Secondly, true) => "seconds",
+        (Locale::German, RecurrenceFrequency::Yearly, false) => "Jahr",
+        (Locale::German, RecurrenceFrequency::Yearly, true) => "Jahre",
+        (Locale::German, RecurrenceFrequency::Monthly, false) => "Monat",
+        (Locale::German, RecurrenceFrequency::Monthly, true) => "Monate",
+        (Locale::German, RecurrenceFrequency::Weekly, false) => "Woche",
+        (Locale::German, RecurrenceFrequency::Weekly, true) => "Wochen",
+        (Locale::German, RecurrenceFrequency::Daily, false) => "Tag",
+        (Locale::German, RecurrenceFrequency::Daily, true) => "Tage",
+        (Locale::German, RecurrenceFrequency::Hourly, false) => "Stunde",
+        (Locale::German, RecurrenceFrequency::Hourly, true) => "Stunden",
+        (Locale::German, RecurrenceFrequency::Minutely, false) => "Minute",
+        (Locale::German, RecurrenceFrequency::Minutely, true) => "Minuten",
+        (Locale::German, RecurrenceFrequency::Secondly, false) => "Sekunde",
+        (Locale::German, RecurrenceFrequency::Secondly, true) => "Sekunden",
+    }
+}
+
+fn weekday_num(locale: Locale, day: WeekdayNum) -> String {
+    let weekday = weekday(locale, day.weekday);
+    match (day.ordinal, locale) {
+        (None, _) => weekday.to_owned(),
+        (Some(n), Locale::English) => format!("the {} {weekday}", ordinal(locale, n)),
+        (Some(n), Locale::German) => format!("{} {weekday}", ordinal(locale, n)),
+    }
+}
+
+/// An ordinal number, where negative numbers count from the end.
+///
+/// German ordinals are inflected for use after "am", "den" and "der".
+fn ordinal<N: Into<i32>>(locale: Locale, n: N) -> String {
+    let n: i32 = n.into();
+    match locale {
+        Locale::English => match n {
+            -1 => "last".to_owned(),
+            n if n < 0 => format!("{} to last", ordinal(locale, -n)),
+            n => {
+                let suffix = match (n % 10, n % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{n}{suffix}")
+            }
+        },
+        Locale::German => match n {
+            -1 => "letzten".to_owned(),
+            -2 => "vorletzten".to_owned(),
+            n if n < 0 => format!("{}.-letzten", -n),
+            n => format!("{n}."),
+        },
+    }
+}
+
+/// Join the items to a list such as "A, B and C".
+fn list<I: Iterator<Item = String>>(locale: Locale, items: I) -> String {
+    let items: Vec<_> = items.collect();
+    let conjunction = match locale {
+        Locale::English => " and ",
+        Locale::German => " und ",
+    };
+    match items.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{}{conjunction}{last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+fn weekday(locale: Locale, weekday: Weekday) -> &'static str {
+    match (locale, weekday) {
+        (Locale::English, Weekday::Monday) => "Monday",
+        (Locale::English, Weekday::Tuesday) => "Tuesday",
+        (Locale::English, Weekday::Wednesday) => "Wednesday",
+        (Locale::English, Weekday::Thursday) => "Thursday",
+        (Locale::English, Weekday::Friday) => "Friday",
+        (Locale::English, Weekday::Saturday) => "Saturday",
+        (Locale::English, Weekday::Sunday) => "Sunday",
+        (Locale::German, Weekday::Monday) => "Montag",
+        (Locale::German, Weekday::Tuesday) => "Dienstag",
+        (Locale::German, Weekday::Wednesday) => "Mittwoch",
+        (Locale::German, Weekday::Thursday) => "Donnerstag",
+        (Locale::German, Weekday::Friday) => "Freitag",
+        (Locale::German, Weekday::Saturday) => "Samstag",
+        (Locale::German, Weekday::Sunday) => "Sonntag",
+    }
+}
+
+fn month(locale: Locale, month: u8) -> String {
+    const ENGLISH: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    const GERMAN: [&str; 12] = [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ];
+    let names = match locale {
+        Locale::English => ENGLISH,
+        Locale::German => GERMAN,
+    };
+    names[usize::from(month - 1)].to_owned()
+}
+
+/// Format a date such as "31 Dec 2025" or "31. Dezember 2025".
+fn date(locale: Locale, date: Date) -> String {
+    match locale {
+        Locale::English => format!(
+            "{} {} {}",
+            date.day(),
+            &month(locale, date.month())[..3],
+            date.year()
+        ),
+        Locale::German => format!(
+            "{}. {} {}",
+            date.day(),
+            month(locale, date.month()),
+            date.year()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, RecurrenceFrequency, RecurrenceRule};
+
+    fn human(rule: &str, locale: Locale) -> String {
+        rule.parse::<RecurrenceRule>()
+            .unwrap()
+            .to_human_string(locale)
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(
+            RecurrenceRule::new(RecurrenceFrequency::Daily).to_human_string(Locale::English),
+            "every day"
+        );
+        assert_eq!(
+            human("FREQ=MONTHLY;BYDAY=2SU,-1FR,-2MO;COUNT=1", Locale::English),
+            "every month on the 2nd Sunday, the last Friday and the 2nd to last Monday, once"
+        );
+        assert_eq!(
+            human(
+                "FREQ=YEARLY;BYMONTH=1,3;BYMONTHDAY=1,12,-1",
+                Locale::English
+            ),
+            "every year on the 1st, 12th and last day of the month in January and March"
+        );
+        assert_eq!(
+            human("FREQ=YEARLY;BYWEEKNO=22;BYYEARDAY=23", Locale::English),
+            "every year on the 23rd day of the year in the 22nd week of the year"
+        );
+        assert_eq!(
+            human(
+                "FREQ=MONTHLY;INTERVAL=3;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=4",
+                Locale::English
+            ),
+            "every 3 months on Monday, Tuesday, Wednesday, Thursday and Friday, limited to the \
+             last occurrence of each month, 4 times"
+        );
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!(
+            human(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20250301T000000Z",
+                Locale::German
+            ),
+            "alle 2 Wochen am Montag und Mittwoch bis 1. März 2025"
+        );
+        assert_eq!(
+            human("FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=-2,-3", Locale::German),
+            "jedes Jahr am vorletzten und 3.-letzten Tag des Monats im Dezember"
+        );
+        assert_eq!(
+            human("FREQ=DAILY;BYSETPOS=1;COUNT=1", Locale::German),
+            "jeden Tag, beschränkt auf den 1. Termin jedes Tages, einmal"
+        );
+    }
+}
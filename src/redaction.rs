@@ -0,0 +1,103 @@
+use {
+    crate::{Calendar, Component, Event},
+    ical_vcard::Value,
+};
+
+/// What [`Calendar::redacted`] puts in place of the details of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RedactionPolicy {
+    /// Remove the details, so that events have no summary.
+    Strip,
+    /// Remove the details and set the summary of every event to `Busy`.
+    #[default]
+    Busy,
+}
+
+impl Calendar {
+    /// Create a copy of the calendar without the details of the events, for example to share
+    /// availability with someone outside of an organization.
+    ///
+    /// The summary (`SUMMARY`), description (`DESCRIPTION`), location (`LOCATION` and
+    /// `VLOCATION`), attendees (`ATTENDEE`), conferences (`CONFERENCE`) and alarms (`VALARM`)
+    /// of all events are removed. With [`RedactionPolicy::Busy`], the summary of every event is
+    /// set to `Busy` instead. The times of the events, including their recurrences, are kept.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RedactionPolicy, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event
+    ///     .set_summary("Job interview")
+    ///     .set_location("Example Corp.");
+    /// let calendar = Calendar::from_event(event);
+    ///
+    /// let redacted = calendar.redacted(RedactionPolicy::Busy);
+    /// let event = redacted.events().next().unwrap();
+    /// assert_eq!(event.summary(), Some("Busy"));
+    /// assert_eq!(event.location(), None);
+    /// assert_eq!(event.start(), calendar.events().next().unwrap().start());
+    ///
+    /// let redacted = calendar.redacted(RedactionPolicy::Strip);
+    /// assert_eq!(redacted.events().next().unwrap().summary(), None);
+    /// ```
+    #[must_use]
+    pub fn redacted(&self, policy: RedactionPolicy) -> Calendar {
+        let mut calendar = self.clone();
+        for component in &mut calendar.components {
+            match component {
+                Component::Event(event) => event.redact(policy),
+            }
+        }
+        calendar
+    }
+}
+
+impl Event {
+    /// Remove the details of the event, see [`Calendar::redacted`].
+    fn redact(&mut self, policy: RedactionPolicy) {
+        self.description = None;
+        self.location = None;
+        self.structured_location = None;
+        self.attendees.clear();
+        self.conferences.clear();
+        self.alarms.clear();
+        self.summary = match policy {
+            RedactionPolicy::Strip => None,
+            RedactionPolicy::Busy => {
+                Some(Value::new("Busy".to_owned()).expect("`Busy` is a valid value"))
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Calendar, Conference, Date, DateTime, Event, RedactionPolicy, Time};
+
+    #[test]
+    fn redacted() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+        event
+            .set_description("Salary negotiation")
+            .set_location_structured("Example Corp.", None, None, None)
+            .add_conference(Conference::new("https://chat.example.com/audio?id=123456"));
+        let mut calendar = Calendar::from_event(event);
+        calendar.add_property("X-WR-CALNAME", "Work", &[]);
+
+        let redacted = calendar.redacted(RedactionPolicy::Strip);
+        let output = redacted.to_string();
+        assert!(!output.contains("Salary"));
+        assert!(!output.contains("Example Corp."));
+        assert!(!output.contains("chat.example.com"));
+        assert!(output.contains("X-WR-CALNAME:Work"));
+        assert_eq!(redacted.events().next().unwrap().summary(), None);
+    }
+}
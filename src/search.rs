@@ -0,0 +1,106 @@
+use crate::{Calendar, Event, StructuredLocation};
+
+/// How [`Calendar::search`] matches the query against the text of an event.
+///
+/// Matching is always case-insensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SearchMode {
+    /// The query matches if it is contained in the text.
+    #[default]
+    Substring,
+    /// The query matches if it is equal to the whole text.
+    Exact,
+}
+
+impl SearchMode {
+    /// Returns `true` if `query` matches `text`, both already converted to lower case.
+    fn matches(self, query: &str, text: &str) -> bool {
+        match self {
+            SearchMode::Substring => text.contains(query),
+            SearchMode::Exact => text == query,
+        }
+    }
+}
+
+impl Calendar {
+    /// Find the events whose summary (`SUMMARY`), description (`DESCRIPTION`) or location
+    /// (`LOCATION`) matches `query`, ignoring case.
+    ///
+    /// The text of a [structured location](Event::set_location_structured) is searched as
+    /// well. The events are returned in the order in which they appear in the calendar.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, SearchMode, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = |uid: &str, summary: &str| {
+    ///     let mut event = Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+    ///     event.set_summary(summary);
+    ///     event
+    /// };
+    /// let calendar = Calendar::from_events([
+    ///     event("1@example.com", "Team lunch"),
+    ///     event("2@example.com", "Lunch"),
+    /// ]);
+    /// assert_eq!(calendar.search("LUNCH", SearchMode::Substring).len(), 2);
+    /// let exact = calendar.search("LUNCH", SearchMode::Exact);
+    /// assert_eq!(exact[0].uid(), "2@example.com");
+    /// ```
+    #[must_use]
+    pub fn search(&self, query: &str, mode: SearchMode) -> Vec<&Event> {
+        let query = query.to_lowercase();
+        self.events()
+            .filter(|event| {
+                let location = event.structured_location().map(StructuredLocation::text);
+                [
+                    event.summary(),
+                    event.description(),
+                    event.location(),
+                    location.as_deref(),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|text| mode.matches(&query, &text.to_lowercase()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Calendar, Date, DateTime, Event, SearchMode, Time};
+
+    #[test]
+    fn search() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let event = |uid: &str| Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+        let mut description = event("description");
+        description.set_description("Bring your own Müsli");
+        let mut location = event("location");
+        location.set_location("Café Müller");
+        let mut structured = event("structured");
+        structured.set_location_structured("Town Hall", Some("Main Street 1"), None, None);
+        let calendar = Calendar::from_events([description, location, structured, event("none")]);
+
+        let uids = |query, mode| {
+            calendar
+                .search(query, mode)
+                .into_iter()
+                .map(Event::uid)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            uids("MÜ", SearchMode::Substring),
+            ["description", "location"]
+        );
+        assert_eq!(uids("café müller", SearchMode::Exact), ["location"]);
+        assert_eq!(uids("café", SearchMode::Exact), [] as [&str; 0]);
+        assert_eq!(uids("main street", SearchMode::Substring), ["structured"]);
+    }
+}
@@ -0,0 +1,123 @@
+use {
+    crate::{Calendar, Component},
+    std::collections::HashMap,
+};
+
+impl Calendar {
+    /// Split the calendar into one calendar per `UID`.
+    ///
+    /// Each calendar contains an event and all of its modified instances (events with the same
+    /// `UID` and a `RECURRENCE-ID`), in the order in which they appear in this calendar. This is
+    /// the form required by `CalDAV` servers, where each calendar object resource must contain
+    /// exactly one recurrence set, see [RFC 4791 section 4.1 - Calendar Object
+    /// Resources](https://tools.ietf.org/html/rfc4791#section-4.1).
+    ///
+    /// The calendars are minimal, that is, they only keep the product identifier, the [strict
+    /// mode](Calendar::set_strict) and the [inclusion of time
+    /// zones](Calendar::set_include_time_zones) of this calendar, as well as the time zones that
+    /// their events refer to. They are returned in the order in which their `UID`s first appear
+    /// in this calendar.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+    ///
+    /// let at = |day| DateTime {
+    ///     date: Date::new(2024, 7, day),
+    ///     time: Time::new_utc(9, 0, 0),
+    /// };
+    /// let mut standup = Event::with_uid("standup@example.com", at(1).into(), at(1));
+    /// standup.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+    /// let lunch = Event::with_uid("lunch@example.com", at(2).into(), at(1));
+    /// let mut moved = Event::with_uid("standup@example.com", at(3).into(), at(1));
+    /// moved.set_recurrence_id(at(2));
+    /// let calendar = Calendar::from_events([standup, lunch, moved]);
+    ///
+    /// let calendars = calendar.split();
+    /// assert_eq!(calendars.len(), 2);
+    /// assert_eq!(calendars[0].events().count(), 2);
+    /// assert_eq!(calendars[1].events().next().unwrap().uid(), "lunch@example.com");
+    /// ```
+    #[must_use]
+    pub fn split(&self) -> Vec<Calendar> {
+        let mut calendars: Vec<Calendar> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for component in &self.components {
+            let uid = match component {
+                Component::Event(event) => event.uid(),
+            };
+            let position = *positions.entry(uid).or_insert_with(|| {
+                calendars.push(Calendar {
+                    product_identifier: self.product_identifier.clone(),
+                    strict: self.strict,
+                    include_time_zones: self.include_time_zones,
+                    ..Calendar::new()
+                });
+                calendars.len() - 1
+            });
+            calendars[position].add_component(component.clone());
+        }
+        for calendar in &mut calendars {
+            calendar.time_zones = self
+                .time_zones
+                .iter()
+                .filter(|time_zone| calendar.referenced_time_zones().contains(&time_zone.id()))
+                .cloned()
+                .collect();
+        }
+        calendars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Calendar, Date, DateTime, Event, Time, TimeZone, ZonedDateTime};
+
+    #[test]
+    fn keeps_product_identifier() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let event = |uid: &str| Event::with_uid(uid, Date::new(2024, 7, 5).into(), stamp);
+        let mut calendar = Calendar::from_events([event("a"), event("b"), event("a")]);
+        calendar
+            .set_product_identifier("-//Example//Test//EN")
+            .add_property("X-WR-CALNAME", "Team", &[]);
+
+        let calendars = calendar.split();
+        let uids: Vec<Vec<_>> = calendars
+            .iter()
+            .map(|calendar| calendar.events().map(Event::uid).collect())
+            .collect();
+        assert_eq!(uids, [vec!["a", "a"], vec!["b"]]);
+        for split in &calendars {
+            assert_eq!(split.product_identifier(), "-//Example//Test//EN");
+            assert!(!split.to_string().contains("X-WR-CALNAME"));
+        }
+    }
+
+    #[test]
+    fn keeps_referenced_time_zones() {
+        let at = |hour| DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(hour, 0, 0),
+        };
+        let event = |uid: &str, time_zone: &str| {
+            let start = ZonedDateTime::new(at(9), time_zone);
+            Event::with_uid(uid, start.into(), at(8))
+        };
+        let mut calendar =
+            Calendar::from_events([event("a", "Europe/Zurich"), event("b", "America/New_York")]);
+        calendar
+            .add_time_zone(TimeZone::new("America/New_York"))
+            .add_time_zone(TimeZone::new("Europe/Zurich"))
+            .add_time_zone(TimeZone::new("Asia/Tokyo"));
+
+        let calendars = calendar.split();
+        let time_zones: Vec<Vec<_>> = calendars
+            .iter()
+            .map(|calendar| calendar.time_zones().iter().map(TimeZone::id).collect())
+            .collect();
+        assert_eq!(time_zones, [["Europe/Zurich"], ["America/New_York"]]);
+    }
+}
@@ -1,30 +1,54 @@
 use {
-    crate::{Date, DateTime},
-    ical_vcard::Contentline,
-    std::{
-        fmt,
-        fmt::Display,
-        io::{self, Write},
-    },
+    crate::{Date, DateOrDateTime, DateTime, ZonedDateTime, write_date_or_date_time},
+    std::io::{self, Write},
 };
 
 /// Represents a Date-Time Start as specified in
 /// [RFC 5545 section 3.8.2.4](https://tools.ietf.org/html/rfc5545#section-3.8.2.4)
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// The start is either a date, a date-time in UTC or local time, or a date-time in the local
+/// time of a time zone ([`ZonedDateTime`]).
+///
+/// Starts are ordered by their value as described for [`DateOrDateTime`], and then by their time
+/// zone identifier. Time zones are not resolved, so starts with different time zones are not
+/// necessarily ordered chronologically.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct StartDateTime {
-    value: DateAndMaybeTime,
+    pub(crate) value: DateOrDateTime,
+    pub(crate) time_zone: Option<String>,
 }
 
 impl StartDateTime {
+    /// Get the start as a date or date-time.
+    ///
+    /// For a start with a time zone, this is the date-time in the local time of the time zone.
+    #[must_use]
+    pub fn value(&self) -> DateOrDateTime {
+        self.value
+    }
+
+    /// Get the identifier of the time zone of the start, if any.
+    #[must_use]
+    pub fn time_zone(&self) -> Option<&str> {
+        self.time_zone.as_deref()
+    }
+
+    /// Returns `true` if the start is a date without a time, that is, the event lasts all day.
+    #[must_use]
+    pub fn is_all_day(&self) -> bool {
+        self.value.is_date()
+    }
+
     pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
-        writer.write(&Contentline::new("DTSTART", self.value.to_string()))
+        write_date_or_date_time(writer, "DTSTART", self.value, self.time_zone())
     }
 }
 
 impl From<Date> for StartDateTime {
     fn from(date: Date) -> Self {
         Self {
-            value: DateAndMaybeTime::Date(date),
+            value: DateOrDateTime::Date(date),
+            time_zone: None,
         }
     }
 }
@@ -32,22 +56,67 @@ impl From<Date> for StartDateTime {
 impl From<DateTime> for StartDateTime {
     fn from(date_time: DateTime) -> Self {
         Self {
-            value: DateAndMaybeTime::DateTime(date_time),
+            value: DateOrDateTime::DateTime(date_time),
+            time_zone: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-enum DateAndMaybeTime {
-    Date(Date),
-    DateTime(DateTime),
+impl From<ZonedDateTime> for StartDateTime {
+    fn from(zoned: ZonedDateTime) -> Self {
+        Self {
+            value: DateOrDateTime::DateTime(zoned.date_time()),
+            time_zone: Some(zoned.time_zone().to_owned()),
+        }
+    }
 }
 
-impl Display for DateAndMaybeTime {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            DateAndMaybeTime::Date(date) => write!(f, "{date}"),
-            DateAndMaybeTime::DateTime(date_time) => write!(f, "{date_time}"),
-        }
+#[cfg(test)]
+mod tests {
+    use {
+        super::StartDateTime,
+        crate::{Date, DateTime, Time, ZonedDateTime},
+    };
+
+    fn write(start: &StartDateTime) -> String {
+        let mut output = Vec::new();
+        start
+            .write(&mut ical_vcard::Writer::new(&mut output))
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn write_time_zone() {
+        let date_time = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        assert_eq!(write(&date_time.into()), "DTSTART:20240705T090000\r\n");
+        assert_eq!(
+            write(&ZonedDateTime::new(date_time, "Europe/Zurich").into()),
+            "DTSTART;TZID=Europe/Zurich:20240705T090000\r\n"
+        );
+    }
+
+    #[test]
+    fn order() {
+        let date_time = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let all_day = StartDateTime::from(Date::new(2024, 7, 5));
+        let timed = StartDateTime::from(date_time);
+        let zoned = StartDateTime::from(ZonedDateTime::new(date_time, "Europe/Zurich"));
+        assert!(all_day < timed);
+        assert!(timed < zoned);
+        assert!(StartDateTime::from(Date::new(2024, 7, 6)) > zoned);
+    }
+
+    #[test]
+    fn write_date() {
+        let start = StartDateTime::from(Date::new(2024, 7, 5));
+        assert!(start.is_all_day());
+        assert_eq!(write(&start), "DTSTART;VALUE=DATE:20240705\r\n");
     }
 }
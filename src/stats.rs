@@ -0,0 +1,168 @@
+use {
+    crate::{Calendar, DateOrDateTime},
+    std::{
+        collections::BTreeMap,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+/// A summary of the contents of a [`Calendar`], as computed by [`Calendar::stats`] and
+/// [`Calendar::stats_between`].
+///
+/// The [`Display`] implementation writes one line per statistic, which is useful for command
+/// line tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarStats {
+    components: BTreeMap<&'static str, usize>,
+    earliest_start: Option<DateOrDateTime>,
+    latest_start: Option<DateOrDateTime>,
+    recurring_events: usize,
+    occurrences: Option<usize>,
+}
+
+impl CalendarStats {
+    /// Get the number of components of each type, by component name, such as `VEVENT`.
+    ///
+    /// Types without any components are not included.
+    #[must_use]
+    pub fn components(&self) -> &BTreeMap<&'static str, usize> {
+        &self.components
+    }
+
+    /// Get the number of events.
+    #[must_use]
+    pub fn events(&self) -> usize {
+        self.components.get("VEVENT").copied().unwrap_or(0)
+    }
+
+    /// Get the earliest start (`DTSTART`) of all events, if there are any events.
+    ///
+    /// Only the starts of the events themselves are considered, not the starts of their
+    /// recurrences.
+    #[must_use]
+    pub fn earliest_start(&self) -> Option<DateOrDateTime> {
+        self.earliest_start
+    }
+
+    /// Get the latest start (`DTSTART`) of all events, if there are any events.
+    ///
+    /// Only the starts of the events themselves are considered, not the starts of their
+    /// recurrences.
+    #[must_use]
+    pub fn latest_start(&self) -> Option<DateOrDateTime> {
+        self.latest_start
+    }
+
+    /// Get the number of events that recur, that is, that have a recurrence rule (`RRULE`) or
+    /// recurrence dates (`RDATE`).
+    #[must_use]
+    pub fn recurring_events(&self) -> usize {
+        self.recurring_events
+    }
+
+    /// Get the number of occurrences of all events in the window that was passed to
+    /// [`Calendar::stats_between`], or `None` for [`Calendar::stats`].
+    #[must_use]
+    pub fn occurrences(&self) -> Option<usize> {
+        self.occurrences
+    }
+}
+
+impl Display for CalendarStats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (name, count) in &self.components {
+            writeln!(f, "{name}: {count}")?;
+        }
+        writeln!(f, "recurring events: {}", self.recurring_events)?;
+        if let Some(earliest_start) = self.earliest_start {
+            writeln!(f, "earliest start: {earliest_start}")?;
+        }
+        if let Some(latest_start) = self.latest_start {
+            writeln!(f, "latest start: {latest_start}")?;
+        }
+        if let Some(occurrences) = self.occurrences {
+            writeln!(f, "occurrences: {occurrences}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Calendar {
+    /// Compute a summary of the contents of the calendar.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut standup = Event::with_uid("1@example.com", Date::new(2024, 7, 1).into(), stamp);
+    /// standup.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+    /// let lunch = Event::with_uid("2@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// let calendar = Calendar::from_events([standup, lunch]);
+    ///
+    /// let stats = calendar.stats();
+    /// assert_eq!(stats.events(), 2);
+    /// assert_eq!(stats.recurring_events(), 1);
+    /// assert_eq!(stats.latest_start(), Some(Date::new(2024, 7, 5).into()));
+    /// assert_eq!(
+    ///     stats.to_string(),
+    ///     "VEVENT: 2\nrecurring events: 1\nearliest start: 20240701\nlatest start: 20240705\n"
+    /// );
+    ///
+    /// let stats = calendar.stats_between(Date::new(2024, 7, 1), Date::new(2024, 7, 8));
+    /// assert_eq!(stats.occurrences(), Some(8));
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> CalendarStats {
+        let mut components = BTreeMap::new();
+        for component in &self.components {
+            *components.entry(component.name()).or_insert(0) += 1;
+        }
+        let starts = self.events().map(|event| event.start().value());
+        CalendarStats {
+            components,
+            earliest_start: starts.clone().min(),
+            latest_start: starts.max(),
+            recurring_events: self
+                .events()
+                .filter(|event| {
+                    event.recurrence_rule.is_some() || !event.recurrence_dates.is_empty()
+                })
+                .count(),
+            occurrences: None,
+        }
+    }
+
+    /// Compute a summary of the contents of the calendar, including the number of occurrences
+    /// of all events that start in the range from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// The occurrences are counted as described for [`Calendar::events_between`].
+    #[must_use]
+    pub fn stats_between<S, E>(&self, start: S, end: E) -> CalendarStats
+    where
+        S: Into<DateOrDateTime>,
+        E: Into<DateOrDateTime>,
+    {
+        CalendarStats {
+            occurrences: Some(self.events_between(start, end).count()),
+            ..self.stats()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Calendar;
+
+    #[test]
+    fn empty() {
+        let stats = Calendar::new().stats();
+        assert_eq!(stats.events(), 0);
+        assert!(stats.components().is_empty());
+        assert_eq!(stats.earliest_start(), None);
+        assert_eq!(stats.occurrences(), None);
+        assert_eq!(stats.to_string(), "recurring events: 0\n");
+    }
+}
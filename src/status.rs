@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The status of an event, corresponding to the values of the `STATUS` property.
+///
+/// See [RFC 5545 section 3.8.1.11 - Status](https://tools.ietf.org/html/rfc5545#section-3.8.1.11)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventStatus {
+    /// The event is tentative.
+    Tentative,
+    /// The event is definite.
+    Confirmed,
+    /// The event was cancelled.
+    Cancelled,
+}
+
+impl Display for EventStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            EventStatus::Tentative => write!(f, "TENTATIVE"),
+            EventStatus::Confirmed => write!(f, "CONFIRMED"),
+            EventStatus::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
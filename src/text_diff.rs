@@ -0,0 +1,555 @@
+use {
+    ical_vcard::{Contentline, Parser},
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter, Write},
+        iter,
+    },
+};
+
+/// Number of unchanged lines shown around every change.
+const CONTEXT_LINES: usize = 3;
+
+/// Produce a unified, property-level diff of two iCalendar files.
+///
+/// Both inputs are parsed and normalized before they are compared:
+/// - `DTSTAMP` properties are ignored, since they usually change every time a file is
+///   generated.
+/// - Property and parameter names are upper-cased and parameters are sorted by name.
+/// - The properties of every component are sorted, so property order does not matter.
+/// - Components are sorted by their name, `UID` and `RECURRENCE-ID`, so component order does not
+///   matter either.
+///
+/// Every property of the normalized files is a single, unfolded line, indented according to its
+/// nesting depth. The result is empty if the two files are equal after normalization. Otherwise,
+/// it is a unified diff with `a` and `b` as file names.
+///
+/// This is mainly useful for debugging interoperability issues and for snapshot tests.
+///
+/// # Errors
+///
+/// Returns an error if either of the inputs is not a valid iCalendar file.
+pub fn diff_text(a: &str, b: &str) -> Result<String, DiffTextError> {
+    let a = normalize(a)?;
+    let b = normalize(b)?;
+    Ok(unified_diff(&a, &b))
+}
+
+/// A parsed component that is used for normalization.
+#[derive(Debug)]
+struct Node {
+    name: String,
+    properties: Vec<String>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// The key by which sibling components are sorted.
+    fn sort_key(&self) -> (&str, Option<&str>, Option<&str>) {
+        let property = |name: &str| {
+            self.properties.iter().find_map(|property| {
+                property
+                    .strip_prefix(name)
+                    .filter(|rest| rest.starts_with([':', ';']))
+            })
+        };
+        (&self.name, property("UID"), property("RECURRENCE-ID"))
+    }
+
+    fn sort(&mut self) {
+        self.properties.sort();
+        for child in &mut self.children {
+            child.sort();
+        }
+        self.children.sort_by(|a, b| {
+            a.sort_key()
+                .cmp(&b.sort_key())
+                .then_with(|| a.lines(0).cmp(&b.lines(0)))
+        });
+    }
+
+    fn lines(&self, depth: usize) -> Vec<String> {
+        let indent = "  ".repeat(depth);
+        let mut lines = vec![format!("{indent}BEGIN:{}", self.name)];
+        for property in &self.properties {
+            lines.push(format!("{indent}  {property}"));
+        }
+        for child in &self.children {
+            lines.extend(child.lines(depth + 1));
+        }
+        lines.push(format!("{indent}END:{}", self.name));
+        lines
+    }
+}
+
+/// Parse and normalize an iCalendar file into a list of lines.
+fn normalize(text: &str) -> Result<Vec<String>, DiffTextError> {
+    let mut stack: Vec<Node> = Vec::new();
+    let mut roots = Vec::new();
+    for (index, contentline) in Parser::new(text.as_bytes()).enumerate() {
+        let line = index + 1;
+        let contentline = contentline.map_err(|_| DiffTextError::InvalidContentline { line })?;
+        let name = contentline.name.as_str().to_ascii_uppercase();
+        if name == "BEGIN" {
+            stack.push(Node {
+                name: contentline.value.as_str().to_ascii_uppercase(),
+                properties: Vec::new(),
+                children: Vec::new(),
+            });
+        } else if name == "END" {
+            let node = stack
+                .pop()
+                .filter(|node| node.name.eq_ignore_ascii_case(contentline.value.as_str()))
+                .ok_or(DiffTextError::UnbalancedComponent { line })?;
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        } else if name != "DTSTAMP" {
+            stack
+                .last_mut()
+                .ok_or(DiffTextError::PropertyOutsideComponent { line })?
+                .properties
+                .push(render(&name, &contentline));
+        }
+    }
+    if !stack.is_empty() {
+        return Err(DiffTextError::UnbalancedComponent {
+            line: text.lines().count(),
+        });
+    }
+    let mut lines = Vec::new();
+    for mut root in roots {
+        root.sort();
+        lines.extend(root.lines(0));
+    }
+    Ok(lines)
+}
+
+/// Render a property in normalized form.
+fn render(name: &str, contentline: &Contentline) -> String {
+    let mut params: Vec<_> = contentline
+        .params
+        .iter()
+        .map(|param| {
+            let values: Vec<_> = param
+                .values
+                .iter()
+                .map(|value| {
+                    let value = value.as_str();
+                    if value.contains([':', ';', ',']) {
+                        format!("\"{value}\"")
+                    } else {
+                        value.to_owned()
+                    }
+                })
+                .collect();
+            format!(
+                "{}={}",
+                param.name.as_str().to_ascii_uppercase(),
+                values.join(",")
+            )
+        })
+        .collect();
+    params.sort();
+    let mut rendered = name.to_owned();
+    for param in params {
+        rendered.push(';');
+        rendered.push_str(&param);
+    }
+    rendered.push(':');
+    rendered.push_str(contentline.value.as_str());
+    rendered
+}
+
+/// A single line of an edit script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Keep,
+    Remove,
+    Insert,
+}
+
+/// Compute a shortest edit script turning `a` into `b`.
+///
+/// This is the linear space variant of the algorithm described in "An O(ND) Difference
+/// Algorithm and Its Variations" by Eugene W. Myers, which takes O((N + M) D) time for inputs of
+/// N and M lines that differ in D lines.
+fn edit_script(a: &[String], b: &[String]) -> Vec<Edit> {
+    let mut edits = Vec::with_capacity(a.len().max(b.len()));
+    push_edit_script(a, b, &mut edits);
+    edits
+}
+
+/// Append a shortest edit script turning `a` into `b` to `edits`.
+fn push_edit_script(a: &[String], b: &[String], edits: &mut Vec<Edit>) {
+    let prefix = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    edits.extend(iter::repeat_n(Edit::Keep, prefix));
+    if let Some((x, y)) = split_point(a, b) {
+        push_edit_script(&a[..x], &b[..y], edits);
+        push_edit_script(&a[x..], &b[y..], edits);
+    } else {
+        edits.extend(iter::repeat_n(Edit::Remove, a.len()));
+        edits.extend(iter::repeat_n(Edit::Insert, b.len()));
+    }
+    edits.extend(iter::repeat_n(Edit::Keep, suffix));
+}
+
+/// Find a point `(x, y)` on a shortest edit script turning `a` into `b` where the script can be
+/// split into one for `a[..x]` and `b[..y]` and one for `a[x..]` and `b[y..]`, both shorter
+/// than the whole.
+///
+/// The point is found by searching for shortest paths from the start and from the end at the
+/// same time until they meet in the middle. Returns `None` if `a` and `b` have no line in common,
+/// and expects them not to start or end with the same line.
+fn split_point(a: &[String], b: &[String]) -> Option<(usize, usize)> {
+    let (a_len, b_len) = (signed(a.len()), signed(b.len()));
+    let max_distance = (a_len + b_len + 1) / 2;
+    let delta = a_len - b_len;
+    let forward_overlaps = delta % 2 != 0;
+    // forward[offset + diagonal] is the furthest `x` reached from the start on the diagonal
+    // `x - y`, backward[offset + diagonal] the same for the reversed inputs, or -1 if the diagonal
+    // was not reached yet.
+    let offset = max_distance;
+    let mut forward = vec![-1; unsigned(2 * max_distance + 2)];
+    forward[unsigned(offset + 1)] = 0;
+    let mut backward = forward.clone();
+    // Diagonals that leave the edit graph at the bottom or on the right are no longer searched.
+    let (mut forward_start, mut forward_end) = (0, 0);
+    let (mut backward_start, mut backward_end) = (0, 0);
+    for distance in 0..max_distance {
+        for diagonal in (-distance + forward_start..=distance - forward_end).step_by(2) {
+            let index = offset + diagonal;
+            let mut x = if diagonal == -distance
+                || (diagonal != distance
+                    && forward[unsigned(index - 1)] < forward[unsigned(index + 1)])
+            {
+                forward[unsigned(index + 1)]
+            } else {
+                forward[unsigned(index - 1)] + 1
+            };
+            let mut y = x - diagonal;
+            while x < a_len && y < b_len && a[unsigned(x)] == b[unsigned(y)] {
+                x += 1;
+                y += 1;
+            }
+            forward[unsigned(index)] = x;
+            if x > a_len {
+                forward_end += 2;
+            } else if y > b_len {
+                forward_start += 2;
+            } else if forward_overlaps {
+                let backward_index = offset + delta - diagonal;
+                if (0..2 * max_distance + 2).contains(&backward_index)
+                    && backward[unsigned(backward_index)] != -1
+                    && x >= a_len - backward[unsigned(backward_index)]
+                {
+                    return Some((unsigned(x), unsigned(y)));
+                }
+            }
+        }
+        for diagonal in (-distance + backward_start..=distance - backward_end).step_by(2) {
+            let index = offset + diagonal;
+            let mut x = if diagonal == -distance
+                || (diagonal != distance
+                    && backward[unsigned(index - 1)] < backward[unsigned(index + 1)])
+            {
+                backward[unsigned(index + 1)]
+            } else {
+                backward[unsigned(index - 1)] + 1
+            };
+            let mut y = x - diagonal;
+            while x < a_len && y < b_len && a[unsigned(a_len - x - 1)] == b[unsigned(b_len - y - 1)]
+            {
+                x += 1;
+                y += 1;
+            }
+            backward[unsigned(index)] = x;
+            if x > a_len {
+                backward_end += 2;
+            } else if y > b_len {
+                backward_start += 2;
+            } else if !forward_overlaps {
+                let forward_index = offset + delta - diagonal;
+                if (0..2 * max_distance + 2).contains(&forward_index)
+                    && forward[unsigned(forward_index)] != -1
+                {
+                    let forward_x = forward[unsigned(forward_index)];
+                    if forward_x >= a_len - x {
+                        return Some((
+                            unsigned(forward_x),
+                            unsigned(forward_x - (delta - diagonal)),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+fn signed(length: usize) -> isize {
+    isize::try_from(length).expect("Slices have at most isize::MAX elements")
+}
+
+fn unsigned(index: isize) -> usize {
+    usize::try_from(index).expect("Indices are not negative")
+}
+
+/// Format the differences between `a` and `b` as a unified diff.
+fn unified_diff(a: &[String], b: &[String]) -> String {
+    let edits = edit_script(a, b);
+    let mut output = String::new();
+    if edits.iter().all(|&edit| edit == Edit::Keep) {
+        return output;
+    }
+    output.push_str("--- a\n+++ b\n");
+
+    // Group the edits into hunks, merging changes that are close to each other.
+    let changes: Vec<usize> = (0..edits.len())
+        .filter(|&index| edits[index] != Edit::Keep)
+        .collect();
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &index in &changes {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.1 => hunk.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        // Line numbers (0-based) in `a` and `b` at the start of the hunk.
+        let a_start = edits[..start]
+            .iter()
+            .filter(|&&edit| edit != Edit::Insert)
+            .count();
+        let b_start = edits[..start]
+            .iter()
+            .filter(|&&edit| edit != Edit::Remove)
+            .count();
+        let a_len = edits[start..end]
+            .iter()
+            .filter(|&&edit| edit != Edit::Insert)
+            .count();
+        let b_len = edits[start..end]
+            .iter()
+            .filter(|&&edit| edit != Edit::Remove)
+            .count();
+        writeln!(
+            output,
+            "@@ -{},{a_len} +{},{b_len} @@",
+            a_start + usize::from(a_len > 0),
+            b_start + usize::from(b_len > 0),
+        )
+        .expect("Writing to a String never fails");
+        let (mut i, mut j) = (a_start, b_start);
+        for edit in &edits[start..end] {
+            let line = match edit {
+                Edit::Keep => {
+                    i += 1;
+                    j += 1;
+                    format!(" {}", a[i - 1])
+                }
+                Edit::Remove => {
+                    i += 1;
+                    format!("-{}", a[i - 1])
+                }
+                Edit::Insert => {
+                    j += 1;
+                    format!("+{}", b[j - 1])
+                }
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Error type for [`diff_text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffTextError {
+    /// A line could not be parsed as a content line.
+    InvalidContentline {
+        /// The (1-based) index of the offending content line.
+        line: usize,
+    },
+    /// A `BEGIN` line has no matching `END` line or vice versa.
+    UnbalancedComponent {
+        /// The (1-based) index of the offending content line.
+        line: usize,
+    },
+    /// A property appears outside of any component.
+    PropertyOutsideComponent {
+        /// The (1-based) index of the offending content line.
+        line: usize,
+    },
+}
+
+impl Display for DiffTextError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DiffTextError::InvalidContentline { line } => {
+                write!(f, "Invalid content line (content line {line})")
+            }
+            DiffTextError::UnbalancedComponent { line } => {
+                write!(f, "Unbalanced BEGIN/END (content line {line})")
+            }
+            DiffTextError::PropertyOutsideComponent { line } => {
+                write!(f, "Property outside of a component (content line {line})")
+            }
+        }
+    }
+}
+
+impl Error for DiffTextError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffTextError, Edit, diff_text, edit_script};
+
+    const A: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:a\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTAMP:20250101T000000Z\r
+SUMMARY:First\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:2\r
+DTSTAMP:20250101T000000Z\r
+SUMMARY:Second\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn ignores_order_and_dtstamp() {
+        let b = "BEGIN:VCALENDAR\r
+PRODID:a\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+SUMMARY:Second\r
+UID:2\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTAMP:20240101T000000Z\r
+uid:1\r
+SUMMARY:First\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+        assert_eq!(diff_text(A, b).unwrap(), "");
+    }
+
+    #[test]
+    fn changed_property() {
+        let b = A.replace("SUMMARY:Second", "SUMMARY:Changed");
+        assert_eq!(
+            diff_text(A, &b).unwrap(),
+            "--- a
++++ b
+@@ -6,7 +6,7 @@
+     UID:1
+   END:VEVENT
+   BEGIN:VEVENT
+-    SUMMARY:Second
++    SUMMARY:Changed
+     UID:2
+   END:VEVENT
+ END:VCALENDAR
+"
+        );
+    }
+
+    #[test]
+    fn unbalanced() {
+        assert_eq!(
+            diff_text("BEGIN:VCALENDAR\r\nEND:VEVENT\r\n", A),
+            Err(DiffTextError::UnbalancedComponent { line: 2 })
+        );
+        assert!(diff_text(A, "BEGIN:VCALENDAR\r\n").is_err());
+    }
+
+    #[test]
+    fn shortest_edit_script() {
+        // The length of the longest common subsequence, computed with a full table.
+        fn lcs(a: &[String], b: &[String]) -> usize {
+            let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+            for i in (0..a.len()).rev() {
+                for j in (0..b.len()).rev() {
+                    table[i][j] = if a[i] == b[j] {
+                        table[i + 1][j + 1] + 1
+                    } else {
+                        table[i + 1][j].max(table[i][j + 1])
+                    };
+                }
+            }
+            table[0][0]
+        }
+
+        let mut state = 1_u32;
+        let mut lines = |count| -> Vec<String> {
+            (0..count)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    ((state >> 16) % 4).to_string()
+                })
+                .collect()
+        };
+        for (a_len, b_len) in [(0, 3), (3, 0), (1, 1), (5, 8), (9, 4), (12, 12), (20, 17)] {
+            for _ in 0..20 {
+                let (a, b) = (lines(a_len), lines(b_len));
+                let edits = edit_script(&a, &b);
+                let (mut i, mut j) = (0, 0);
+                let mut result = Vec::new();
+                for edit in &edits {
+                    match edit {
+                        Edit::Keep => {
+                            assert_eq!(a[i], b[j]);
+                            result.push(a[i].clone());
+                            i += 1;
+                            j += 1;
+                        }
+                        Edit::Remove => i += 1,
+                        Edit::Insert => {
+                            result.push(b[j].clone());
+                            j += 1;
+                        }
+                    }
+                }
+                assert_eq!((i, result), (a.len(), b.clone()));
+                let kept = edits.iter().filter(|&&edit| edit == Edit::Keep).count();
+                assert_eq!(kept, lcs(&a, &b), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn large_inputs() {
+        // A full table for these inputs would take tens of gigabytes.
+        let a: Vec<String> = (0..100_000).map(|line| format!("LINE:{line}")).collect();
+        let mut b = a.clone();
+        b[10] = "LINE:changed".to_owned();
+        b.remove(50_000);
+        b.insert(90_000, "LINE:inserted".to_owned());
+        let edits = edit_script(&a, &b);
+        assert_eq!(
+            edits.iter().filter(|&&edit| edit == Edit::Keep).count(),
+            99_998
+        );
+        assert_eq!(edits.len(), 100_002);
+    }
+}
@@ -0,0 +1,642 @@
+use {
+    crate::{
+        Calendar, Date, DateOrDateTime, DateTime, Event, InvalidValueError, Occurrences,
+        RecurrenceRule, UtcOffset, recurrence_rule::RuleInstances, value,
+    },
+    ical_vcard::{Contentline, ParamValue, Value},
+    std::{
+        error,
+        fmt::{self, Display, Formatter},
+        io::{self, Write},
+    },
+};
+
+#[cfg(feature = "tzdata")]
+use jiff::tz;
+
+#[cfg(feature = "tzdata")]
+mod conversion;
+#[cfg(feature = "tzdata")]
+pub use conversion::Disambiguation;
+#[cfg(feature = "tzdata")]
+mod display;
+#[cfg(feature = "tzdata")]
+pub use display::ZonedOccurrences;
+#[cfg(feature = "tzdata")]
+mod iana;
+mod transitions;
+mod tzif;
+
+pub use tzif::ParseTzifError;
+
+/// Represents a `VTIMEZONE` component as specified in [RFC 5545 section 3.6.5 - Time Zone
+/// Component](https://tools.ietf.org/html/rfc5545#section-3.6.5).
+///
+/// A time zone defines the UTC offsets of the local times that refer to it by its identifier,
+/// that is, the date-times with a `TZID` parameter, such as the start of an event created with
+/// [`ZonedDateTime`](crate::ZonedDateTime). It consists of observances, each of which defines
+/// the offset from a certain point in time on, either once or repeatedly.
+///
+/// ```
+/// use ical::{
+///     Calendar, Date, DateTime, Observance, ObservanceKind, RecurrenceFrequency,
+///     RecurrenceRule, Time, TimeZone, Weekday, WeekdayNum,
+/// };
+///
+/// let start = DateTime {
+///     date: Date::new(1996, 10, 27),
+///     time: Time::new_local(3, 0, 0),
+/// };
+/// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Yearly);
+/// rule.set_by_month([10])
+///     .set_by_day([WeekdayNum::nth(-1, Weekday::Sunday)]);
+/// let mut standard = Observance::new(
+///     ObservanceKind::Standard,
+///     start,
+///     "+0200".parse().unwrap(),
+///     "+0100".parse().unwrap(),
+/// );
+/// standard.set_name("CET").set_recurrence_rule(rule);
+/// let mut time_zone = TimeZone::new("Europe/Zurich");
+/// time_zone.add_observance(standard);
+///
+/// let mut calendar = Calendar::new();
+/// calendar.add_time_zone(time_zone);
+/// assert!(calendar.to_ics_string().contains(
+///     "BEGIN:VTIMEZONE\r\n\
+///      TZID:Europe/Zurich\r\n\
+///      BEGIN:STANDARD\r\n\
+///      DTSTART:19961027T030000\r\n\
+///      RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r\n\
+///      TZOFFSETFROM:+0200\r\n\
+///      TZOFFSETTO:+0100\r\n\
+///      TZNAME:CET\r\n\
+///      END:STANDARD\r\n\
+///      END:VTIMEZONE\r\n"
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeZone {
+    /// Corresponds to the `TZID` property. It is a valid [`Value`] and a valid [`ParamValue`],
+    /// because it is also written as the `TZID` parameter of date-times.
+    id: String,
+    observances: Vec<Observance>,
+}
+
+impl TimeZone {
+    /// Create a time zone with the identifier `id` and without observances.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is neither a valid [`Value`] nor a valid [`ParamValue`].
+    #[must_use]
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        let id = id.into();
+        if let Err(err) = Value::new(id.as_str()).and(ParamValue::new(id.as_str())) {
+            panic!("Invalid time zone identifier: {err}");
+        }
+        Self {
+            id,
+            observances: Vec::new(),
+        }
+    }
+
+    /// Get the identifier of the time zone, which is referenced by the `TZID` parameter of
+    /// date-times.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Add an observance to the time zone.
+    pub fn add_observance(&mut self, observance: Observance) -> &mut Self {
+        self.observances.push(observance);
+        self
+    }
+
+    /// Get the observances of the time zone.
+    #[must_use]
+    pub fn observances(&self) -> &[Observance] {
+        &self.observances
+    }
+
+    /// Remove the observances that are not needed for the local times from `start` to `end`
+    /// (inclusive), or from `start` on if `end` is `None`.
+    ///
+    /// The observance that is in effect at `start` is kept, starting at its last switch before
+    /// `start`, together with the switches between `start` and `end`. Observances whose
+    /// switches are all outside of this range are removed. The UTC offsets of the local times in
+    /// the range stay the same, but the output is much smaller for time zones with a long
+    /// history, such as those created with `TimeZone::from_iana`. See also
+    /// [`Calendar::minimize_time_zones`].
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Observance, ObservanceKind, Time, TimeZone};
+    ///
+    /// let at = |year, month, day| DateTime {
+    ///     date: Date::new(year, month, day),
+    ///     time: Time::new_local(2, 0, 0),
+    /// };
+    /// let mut daylight = Observance::new(
+    ///     ObservanceKind::Daylight,
+    ///     at(1981, 3, 29),
+    ///     "+0100".parse().unwrap(),
+    ///     "+0200".parse().unwrap(),
+    /// );
+    /// daylight.add_recurrence_date(at(1982, 3, 28));
+    /// let mut time_zone = TimeZone::new("Europe/Zurich");
+    /// time_zone.add_observance(daylight);
+    ///
+    /// time_zone.restrict_to(at(1982, 7, 1), None);
+    /// assert_eq!(time_zone.observances()[0].start(), at(1982, 3, 28));
+    /// assert!(time_zone.observances()[0].recurrence_dates().is_empty());
+    /// ```
+    pub fn restrict_to(&mut self, start: DateTime, end: Option<DateTime>) -> &mut Self {
+        let in_effect = self
+            .observances
+            .iter()
+            .filter_map(|observance| observance.last_switch_until(start))
+            .max();
+        self.observances
+            .retain_mut(|observance| observance.restrict_to(in_effect, end));
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        writer.write(&Contentline::new("BEGIN", "VTIMEZONE"))?;
+        writer.write(&Contentline::new("TZID", self.id.as_str()))?;
+        for observance in &self.observances {
+            observance.write(writer)?;
+        }
+        writer.write(&Contentline::new("END", "VTIMEZONE"))
+    }
+}
+
+/// Represents a `STANDARD` or `DAYLIGHT` sub-component of a [`TimeZone`].
+///
+/// An observance switches the UTC offset of the time zone from
+/// [`offset_from`](Observance::offset_from) to [`offset_to`](Observance::offset_to) at its
+/// start and at each of its recurrences. The start and the recurrences are in local time, with
+/// the offset that was in effect before the switch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observance {
+    kind: ObservanceKind,
+    /// Corresponds to the `DTSTART` property. It is in local time.
+    start: DateTime,
+    /// Corresponds to the `TZOFFSETFROM` property.
+    offset_from: UtcOffset,
+    /// Corresponds to the `TZOFFSETTO` property.
+    offset_to: UtcOffset,
+    /// Corresponds to the `TZNAME` property.
+    name: Option<Value<String>>,
+    /// Corresponds to the `RRULE` property.
+    recurrence_rule: Option<RecurrenceRule>,
+    /// Corresponds to the `RDATE` properties. They are in local time.
+    recurrence_dates: Vec<DateTime>,
+}
+
+impl Observance {
+    /// Create an observance that switches from `offset_from` to `offset_to` at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is in UTC time.
+    #[must_use]
+    pub fn new(
+        kind: ObservanceKind,
+        start: DateTime,
+        offset_from: UtcOffset,
+        offset_to: UtcOffset,
+    ) -> Self {
+        assert!(
+            !start.time.is_utc(),
+            "The start of an observance must be in local time"
+        );
+        Self {
+            kind,
+            start,
+            offset_from,
+            offset_to,
+            name: None,
+            recurrence_rule: None,
+            recurrence_dates: Vec::new(),
+        }
+    }
+
+    /// Get whether this is standard time or daylight saving time.
+    #[must_use]
+    pub fn kind(&self) -> ObservanceKind {
+        self.kind
+    }
+
+    /// Get the first switch to this observance, in local time before the switch.
+    #[must_use]
+    pub fn start(&self) -> DateTime {
+        self.start
+    }
+
+    /// Get the UTC offset before the switch.
+    #[must_use]
+    pub fn offset_from(&self) -> UtcOffset {
+        self.offset_from
+    }
+
+    /// Get the UTC offset after the switch.
+    #[must_use]
+    pub fn offset_to(&self) -> UtcOffset {
+        self.offset_to
+    }
+
+    /// Set the customary name of the observance, such as `CEST`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid [`Value`]. Use [`Observance::try_set_name`] if the name
+    /// is not known to be valid.
+    pub fn set_name<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        self.try_set_name(name)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the customary name of the observance, or return an error if it is not a valid
+    /// [`Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases in which [`Observance::set_name`] panics.
+    pub fn try_set_name<S: Into<String>>(
+        &mut self,
+        name: S,
+    ) -> Result<&mut Self, InvalidValueError> {
+        self.name = Some(value("observance name", name.into())?);
+        Ok(self)
+    }
+
+    /// Get the customary name of the observance.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Value::as_str)
+    }
+
+    /// Set the rule by which the switch to this observance recurs.
+    pub fn set_recurrence_rule(&mut self, recurrence_rule: RecurrenceRule) -> &mut Self {
+        self.recurrence_rule = Some(recurrence_rule);
+        self
+    }
+
+    /// Get the rule by which the switch to this observance recurs.
+    #[must_use]
+    pub fn recurrence_rule(&self) -> Option<&RecurrenceRule> {
+        self.recurrence_rule.as_ref()
+    }
+
+    /// Add a single recurrence of the switch to this observance, in local time before the
+    /// switch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date_time` is in UTC time.
+    pub fn add_recurrence_date(&mut self, date_time: DateTime) -> &mut Self {
+        assert!(
+            !date_time.time.is_utc(),
+            "The recurrence dates of an observance must be in local time"
+        );
+        self.recurrence_dates.push(date_time);
+        self
+    }
+
+    /// Get the single recurrences of the switch to this observance.
+    #[must_use]
+    pub fn recurrence_dates(&self) -> &[DateTime] {
+        &self.recurrence_dates
+    }
+
+    /// The switches to this observance by its start and its recurrence rule, in chronological
+    /// order.
+    fn rule_switches(&self) -> impl Iterator<Item = DateTime> {
+        RuleInstances::new(
+            self.recurrence_rule.as_ref(),
+            self.start.into(),
+            Occurrences::DEFAULT_LIMIT,
+        )
+        .filter_map(DateOrDateTime::date_time)
+    }
+
+    /// The last switch to this observance at or before `date_time`, if any.
+    fn last_switch_until(&self, date_time: DateTime) -> Option<DateTime> {
+        let from_rule = self
+            .rule_switches()
+            .take_while(|&switch| switch <= date_time)
+            .last();
+        let from_dates = self
+            .recurrence_dates
+            .iter()
+            .copied()
+            .filter(|&switch| switch <= date_time)
+            .max();
+        from_rule.max(from_dates)
+    }
+
+    /// Keep only the switches from `start` to `end` (inclusive), see [`TimeZone::restrict_to`].
+    ///
+    /// Returns `false` if no switch is left.
+    fn restrict_to(&mut self, start: Option<DateTime>, end: Option<DateTime>) -> bool {
+        let in_range = |switch: &DateTime| {
+            start.is_none_or(|start| start <= *switch) && end.is_none_or(|end| *switch <= end)
+        };
+        self.recurrence_dates.retain(in_range);
+        let mut skipped = 0;
+        let first = self
+            .rule_switches()
+            .find(|&switch| {
+                let is_before = start.is_some_and(|start| switch < start);
+                skipped += u32::from(is_before);
+                !is_before
+            })
+            .filter(in_range);
+        if let Some(first) = first {
+            self.start = first;
+            if let Some(rule) = &mut self.recurrence_rule
+                && let Some(count) = rule.count()
+            {
+                rule.set_count(count - skipped);
+            }
+            return true;
+        }
+        self.recurrence_rule = None;
+        let Some((index, _)) = self
+            .recurrence_dates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, switch)| **switch)
+        else {
+            return false;
+        };
+        self.start = self.recurrence_dates.remove(index);
+        true
+    }
+
+    fn write<W: Write>(&self, writer: &mut ical_vcard::Writer<W>) -> io::Result<()> {
+        writer.write(&Contentline::new("BEGIN", self.kind.to_string()))?;
+        writer.write(&Contentline::new("DTSTART", self.start.to_string()))?;
+        if let Some(recurrence_rule) = &self.recurrence_rule {
+            writer.write(&Contentline::new("RRULE", recurrence_rule.to_string()))?;
+        }
+        for date_time in &self.recurrence_dates {
+            writer.write(&Contentline::new("RDATE", date_time.to_string()))?;
+        }
+        writer.write(&Contentline::new(
+            "TZOFFSETFROM",
+            self.offset_from.to_string(),
+        ))?;
+        writer.write(&Contentline::new("TZOFFSETTO", self.offset_to.to_string()))?;
+        if let Some(name) = &self.name {
+            writer.write(&Contentline::new("TZNAME", name.as_str()))?;
+        }
+        writer.write(&Contentline::new("END", self.kind.to_string()))
+    }
+}
+
+/// Whether an [`Observance`] is standard time or daylight saving time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObservanceKind {
+    /// Standard time, written as a `STANDARD` component.
+    Standard,
+    /// Daylight saving time, written as a `DAYLIGHT` component.
+    Daylight,
+}
+
+impl Display for ObservanceKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ObservanceKind::Standard => write!(f, "STANDARD"),
+            ObservanceKind::Daylight => write!(f, "DAYLIGHT"),
+        }
+    }
+}
+
+impl Calendar {
+    /// Enable or disable the inclusion of the time zones that the events refer to.
+    ///
+    /// If enabled, [`Calendar::write`] checks that there is a time zone definition for every
+    /// time zone identifier that the events refer to (see [`Calendar::referenced_time_zones`])
+    /// before anything is written, and fails with a [`MissingTimeZoneError`] otherwise. With
+    /// the `tzdata` feature, the missing definitions are created with `TimeZone::from_iana`
+    /// instead and written after the time zones of the calendar. The calendar itself is not
+    /// changed. This is disabled by default.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, TimeZone, ZonedDateTime};
+    ///
+    /// let start = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_local(9, 0, 0),
+    /// };
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let start = ZonedDateTime::new(start, "Custom/Office");
+    /// let event = Event::with_uid("1@example.com", start.into(), stamp);
+    /// let mut calendar = Calendar::from_event(event);
+    /// calendar.set_include_time_zones(true);
+    ///
+    /// let err = calendar.write(Vec::new()).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "The time zone Custom/Office is referred to by an event but not defined"
+    /// );
+    ///
+    /// calendar.add_time_zone(TimeZone::new("Custom/Office"));
+    /// assert!(calendar.write(Vec::new()).is_ok());
+    /// ```
+    pub fn set_include_time_zones(&mut self, include: bool) -> &mut Self {
+        self.include_time_zones = include;
+        self
+    }
+
+    /// Get the identifiers of the time zones that the events refer to, in the order in which
+    /// they first appear.
+    #[must_use]
+    pub fn referenced_time_zones(&self) -> Vec<&str> {
+        let mut ids = Vec::new();
+        for event in self.events() {
+            if let Some(id) = event.start().time_zone()
+                && !ids.contains(&id)
+            {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// The identifiers of the time zones that the events refer to, but which are not defined.
+    fn undefined_time_zones(&self) -> impl Iterator<Item = &str> {
+        self.referenced_time_zones()
+            .into_iter()
+            .filter(|id| self.time_zone(id).is_none())
+    }
+
+    /// Check that all time zones that the events refer to can be included.
+    pub(crate) fn check_time_zones(&self) -> Result<(), MissingTimeZoneError> {
+        match self.undefined_time_zones().find(|id| !is_in_database(id)) {
+            Some(id) => Err(MissingTimeZoneError { id: id.to_owned() }),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the time zones that the events refer to but which are not defined, if time zones
+    /// are included. Time zones that are not in the database are skipped, see
+    /// [`Calendar::check_time_zones`].
+    #[cfg(feature = "tzdata")]
+    pub(crate) fn write_referenced_time_zones<W: Write>(
+        &self,
+        writer: &mut ical_vcard::Writer<W>,
+    ) -> io::Result<()> {
+        if self.include_time_zones {
+            for id in self.undefined_time_zones() {
+                if let Ok(time_zone) = TimeZone::from_iana(id) {
+                    time_zone.write(writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the observances of the time zones of the calendar that are not needed for its
+    /// events, like the time zones published by many calendar services.
+    ///
+    /// Each time zone is restricted with [`TimeZone::restrict_to`] to the range from the
+    /// earliest to the latest date-time of the events that refer to it, including their
+    /// occurrences and ends. The range of an event that recurs forever has no end. Time zones
+    /// that are not referred to by any event are not changed.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Calendar, Date, DateTime, Event, Observance, ObservanceKind, Time, TimeZone,
+    ///     ZonedDateTime,
+    /// };
+    ///
+    /// let at = |year, month, day| DateTime {
+    ///     date: Date::new(year, month, day),
+    ///     time: Time::new_local(2, 0, 0),
+    /// };
+    /// let mut standard = Observance::new(
+    ///     ObservanceKind::Standard,
+    ///     at(1981, 9, 27),
+    ///     "+0200".parse().unwrap(),
+    ///     "+0100".parse().unwrap(),
+    /// );
+    /// standard.add_recurrence_date(at(1982, 9, 26));
+    /// standard.add_recurrence_date(at(1983, 9, 25));
+    /// let mut time_zone = TimeZone::new("Europe/Zurich");
+    /// time_zone.add_observance(standard);
+    ///
+    /// let start = ZonedDateTime::new(at(1982, 12, 24), "Europe/Zurich");
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let event = Event::with_uid("1@example.com", start.into(), stamp);
+    /// let mut calendar = Calendar::from_event(event);
+    /// calendar.add_time_zone(time_zone).minimize_time_zones();
+    /// let standard = &calendar.time_zones()[0].observances()[0];
+    /// assert_eq!(standard.start(), at(1982, 9, 26));
+    /// assert!(standard.recurrence_dates().is_empty());
+    /// ```
+    pub fn minimize_time_zones(&mut self) -> &mut Self {
+        for time_zone in &mut self.time_zones {
+            let mut ranges = self
+                .components
+                .iter()
+                .filter_map(|component| component.as_event())
+                .filter(|event| event.start().time_zone() == Some(time_zone.id()))
+                .filter_map(event_range);
+            let Some(mut range) = ranges.next() else {
+                continue;
+            };
+            for (start, end) in ranges {
+                range.0 = range.0.min(start);
+                range.1 = range.1.zip(end).map(|(a, b)| a.max(b));
+            }
+            time_zone.restrict_to(range.0, range.1);
+        }
+        self
+    }
+}
+
+/// The range of the local date-times of `event`, from its earliest start to its latest end, or
+/// without an end if it recurs forever.
+///
+/// Returns `None` if the event starts on a date without a time.
+fn event_range(event: &Event) -> Option<(DateTime, Option<DateTime>)> {
+    let start = event.start_date_time.value.date_time()?;
+    let date_times = || {
+        event
+            .recurrence_dates
+            .iter()
+            .chain(&event.end)
+            .chain(&event.recurrence_id)
+            .filter_map(|value| value.date_time())
+    };
+    let earliest = date_times().fold(start, DateTime::min);
+    if event
+        .recurrence_rule
+        .as_ref()
+        .is_some_and(|rule| rule.until().is_none() && rule.count().is_none())
+    {
+        return Some((earliest, None));
+    }
+    let duration = event
+        .end
+        .and_then(DateOrDateTime::date_time)
+        .and_then(|end| end.duration_since(start));
+    let last_end = event
+        .occurrences_between(start, Date::new(9999, 12, 31))
+        .filter_map(DateOrDateTime::date_time)
+        .last()
+        .and_then(|last| last.checked_add(duration.unwrap_or_default()))
+        .unwrap_or(start);
+    let latest = date_times().fold(last_end, DateTime::max);
+    Some((earliest, Some(latest)))
+}
+
+/// Returns `true` if a definition of the time zone `id` can be created with
+/// `TimeZone::from_iana`.
+#[cfg(feature = "tzdata")]
+fn is_in_database(id: &str) -> bool {
+    tz::TimeZone::get(id).is_ok()
+}
+
+/// Without the `tzdata` feature, no time zone database is available.
+#[cfg(not(feature = "tzdata"))]
+fn is_in_database(_id: &str) -> bool {
+    false
+}
+
+/// Error type for an event that refers to a time zone without a definition, see
+/// [`Calendar::set_include_time_zones`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTimeZoneError {
+    id: String,
+}
+
+impl MissingTimeZoneError {
+    /// The identifier of the missing time zone.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Display for MissingTimeZoneError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The time zone {} is referred to by an event but not defined",
+            self.id
+        )
+    }
+}
+
+impl error::Error for MissingTimeZoneError {}
@@ -0,0 +1,182 @@
+use {
+    crate::{ConversionError, DateTime, ZonedDateTime},
+    jiff::{
+        Timestamp,
+        tz::{self, TimeZone},
+    },
+};
+
+/// How a local time that is skipped or repeated because of a daylight saving time transition is
+/// converted to UTC, see [`ZonedDateTime::to_utc`].
+///
+/// When the clocks are set forward, the local times in the gap do not exist. When the clocks are
+/// set back, the local times in the fold exist twice, with different UTC offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// Use the offset before the transition, as required by [RFC 5545 section
+    /// 3.3.5](https://tools.ietf.org/html/rfc5545#section-3.3.5). A skipped time is moved
+    /// forward by the length of the gap, and a repeated time refers to its first occurrence.
+    Compatible,
+    /// Use the earlier of the two points in time. A skipped time is moved backward by the
+    /// length of the gap.
+    Earlier,
+    /// Use the later of the two points in time. A skipped time is moved forward by the length of
+    /// the gap.
+    Later,
+    /// Fail with [`ConversionError::Ambiguous`].
+    Reject,
+}
+
+impl ZonedDateTime {
+    /// Convert the date-time to UTC time, using the time zone database of the `jiff` crate.
+    ///
+    /// Local times that are skipped or repeated because of a daylight saving time transition
+    /// are resolved as specified by `disambiguation`. Requires the `tzdata` feature.
+    ///
+    /// ```
+    /// use ical::{ConversionError, Date, DateTime, Disambiguation, Time, ZonedDateTime};
+    ///
+    /// let at = |day, hour, utc| DateTime {
+    ///     date: Date::new(2024, 10, day),
+    ///     time: if utc {
+    ///         Time::new_utc(hour, 30, 0)
+    ///     } else {
+    ///         Time::new_local(hour, 30, 0)
+    ///     },
+    /// };
+    /// let summer = ZonedDateTime::new(at(1, 9, false), "Europe/Zurich");
+    /// assert_eq!(summer.to_utc(Disambiguation::Reject), Ok(at(1, 7, true)));
+    ///
+    /// // The clocks are set back from 03:00 to 02:00 on 2024-10-27.
+    /// let repeated = ZonedDateTime::new(at(27, 2, false), "Europe/Zurich");
+    /// assert_eq!(repeated.to_utc(Disambiguation::Compatible), Ok(at(27, 0, true)));
+    /// assert_eq!(repeated.to_utc(Disambiguation::Later), Ok(at(27, 1, true)));
+    /// assert_eq!(
+    ///     repeated.to_utc(Disambiguation::Reject),
+    ///     Err(ConversionError::Ambiguous)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::UnknownTimeZone`] if the time zone is not in the database,
+    /// [`ConversionError::Ambiguous`] if the local time is skipped or repeated and
+    /// `disambiguation` is [`Disambiguation::Reject`], and [`ConversionError::OutOfRange`] if
+    /// the result is outside of the supported range of dates.
+    pub fn to_utc(&self, disambiguation: Disambiguation) -> Result<DateTime, ConversionError> {
+        let time_zone = database_time_zone(self.time_zone())?;
+        let ambiguous = time_zone.to_ambiguous_timestamp(self.date_time().into());
+        let timestamp = match disambiguation {
+            Disambiguation::Compatible => ambiguous.compatible(),
+            Disambiguation::Earlier => ambiguous.earlier(),
+            Disambiguation::Later => ambiguous.later(),
+            Disambiguation::Reject if ambiguous.is_ambiguous() => {
+                return Err(ConversionError::Ambiguous);
+            }
+            Disambiguation::Reject => ambiguous.unambiguous(),
+        }
+        .map_err(|_| ConversionError::OutOfRange)?;
+        let mut date_time = DateTime::try_from(TimeZone::UTC.to_datetime(timestamp))?;
+        date_time.time.set_utc(true);
+        Ok(date_time)
+    }
+}
+
+impl DateTime {
+    /// Convert the date-time from UTC time to the local time of the time zone `time_zone`, such
+    /// as `Europe/Zurich`, using the time zone database of the `jiff` crate.
+    ///
+    /// Requires the `tzdata` feature. See [`ZonedDateTime::to_utc`] for the reverse
+    /// conversion.
+    ///
+    /// ```
+    /// use ical::{Date, DateTime, Time, ZonedDateTime};
+    ///
+    /// let utc = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_utc(7, 0, 0),
+    /// };
+    /// let local = DateTime {
+    ///     date: Date::new(2024, 7, 5),
+    ///     time: Time::new_local(9, 0, 0),
+    /// };
+    /// assert_eq!(
+    ///     utc.in_zone("Europe/Zurich"),
+    ///     Ok(ZonedDateTime::new(local, "Europe/Zurich"))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::LocalTime`] if the date-time is in local time,
+    /// [`ConversionError::UnknownTimeZone`] if the time zone is not in the database and
+    /// [`ConversionError::OutOfRange`] if the result is outside of the supported range of dates.
+    pub fn in_zone(self, time_zone: &str) -> Result<ZonedDateTime, ConversionError> {
+        let seconds = self.to_unix_timestamp().ok_or(ConversionError::LocalTime)?;
+        let timestamp = Timestamp::from_second(seconds).map_err(|_| ConversionError::OutOfRange)?;
+        let local = DateTime::try_from(database_time_zone(time_zone)?.to_datetime(timestamp))?;
+        Ok(ZonedDateTime::new(local, time_zone))
+    }
+}
+
+/// Look up `id` in the time zone database.
+pub(super) fn database_time_zone(id: &str) -> Result<TimeZone, ConversionError> {
+    tz::db()
+        .get(id)
+        .map_err(|_| ConversionError::UnknownTimeZone)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Disambiguation,
+        crate::{ConversionError, Date, DateTime, Time, ZonedDateTime},
+    };
+
+    #[test]
+    fn skipped() {
+        // The clocks are set forward from 02:00 to 03:00 on 2024-03-31.
+        let at = |hour, minute, utc| DateTime {
+            date: Date::new(2024, 3, 31),
+            time: if utc {
+                Time::new_utc(hour, minute, 0)
+            } else {
+                Time::new_local(hour, minute, 0)
+            },
+        };
+        let skipped = ZonedDateTime::new(at(2, 30, false), "Europe/Zurich");
+        assert_eq!(
+            skipped.to_utc(Disambiguation::Compatible),
+            Ok(at(1, 30, true))
+        );
+        assert_eq!(skipped.to_utc(Disambiguation::Later), Ok(at(1, 30, true)));
+        assert_eq!(skipped.to_utc(Disambiguation::Earlier), Ok(at(0, 30, true)));
+        assert_eq!(
+            skipped.to_utc(Disambiguation::Reject),
+            Err(ConversionError::Ambiguous)
+        );
+
+        // Converting back yields the time after the gap.
+        let utc = skipped.to_utc(Disambiguation::Compatible).unwrap();
+        assert_eq!(
+            utc.in_zone("Europe/Zurich"),
+            Ok(ZonedDateTime::new(at(3, 30, false), "Europe/Zurich"))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let local = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        assert_eq!(
+            ZonedDateTime::new(local, "Mars/Olympus_Mons").to_utc(Disambiguation::Compatible),
+            Err(ConversionError::UnknownTimeZone)
+        );
+        assert_eq!(
+            local.in_zone("Europe/Zurich"),
+            Err(ConversionError::LocalTime)
+        );
+    }
+}
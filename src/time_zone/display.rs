@@ -0,0 +1,266 @@
+use {
+    super::conversion::database_time_zone,
+    crate::{ConversionError, DateOrDateTime, DateTime, Event, Occurrences},
+    jiff::{Timestamp, tz::TimeZone},
+};
+
+/// The occurrences of the source range are searched in a range that is extended by this number of
+/// days on both sides, which covers any difference between two UTC offsets.
+const MARGIN_DAYS: i64 = 2;
+
+impl Event {
+    /// Get the occurrences of the event that start in the range from `start` (inclusive) to
+    /// `end` (exclusive), converted to the local time of the time zone `time_zone`, such as
+    /// `Europe/Zurich`.
+    ///
+    /// The occurrences are computed as described for [`Event::occurrences_between`], in the
+    /// time zone of the start of the event, so that an event at 09:00 in `Europe/Zurich` stays
+    /// at 09:00 local time across daylight saving time transitions. Each occurrence is then
+    /// converted to `time_zone`, using the time zone database of the `jiff` crate. Local times
+    /// that are skipped or repeated in the time zone of the event are resolved with
+    /// [`Disambiguation::Compatible`](crate::Disambiguation::Compatible).
+    ///
+    /// Occurrences in floating time and all-day occurrences are not converted because they are
+    /// the same in every time zone. The range is in the local time of `time_zone`, where a date
+    /// refers to midnight and a UTC date-time is converted to `time_zone` first. The occurrences
+    /// are in chronological order, except that occurrences in a repeated hour of `time_zone` may
+    /// be out of order. Requires the `tzdata` feature.
+    ///
+    /// ```
+    /// use ical::{
+    ///     Date, DateOrDateTime, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time,
+    ///     ZonedDateTime,
+    /// };
+    ///
+    /// let at = |month, day, hour| DateTime {
+    ///     date: Date::new(2024, month, day),
+    ///     time: Time::new_local(hour, 0, 0),
+    /// };
+    /// let start = ZonedDateTime::new(at(3, 25, 9), "Europe/Zurich");
+    /// let mut event = Event::new(start.into(), at(3, 1, 0));
+    /// event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+    ///
+    /// // Daylight saving time starts on 2024-03-31 in Zurich.
+    /// let occurrences: Vec<_> = event
+    ///     .occurrences_in_zone(Date::new(2024, 3, 25), Date::new(2024, 4, 2), "Etc/UTC")
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(
+    ///     occurrences,
+    ///     [DateOrDateTime::from(at(3, 25, 8)), at(4, 1, 7).into()]
+    /// );
+    /// ```
+    ///
+    /// Unbounded recurrence rules are only expanded up to a limit, see [`Occurrences`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::UnknownTimeZone`] if `time_zone` or the time zone of the event
+    /// is not in the database, and [`ConversionError::OutOfRange`] if the range cannot be
+    /// converted.
+    pub fn occurrences_in_zone<S, E>(
+        &self,
+        start: S,
+        end: E,
+        time_zone: &str,
+    ) -> Result<ZonedOccurrences<'_>, ConversionError>
+    where
+        S: Into<DateOrDateTime>,
+        E: Into<DateOrDateTime>,
+    {
+        let display = database_time_zone(time_zone)?;
+        let source = self
+            .start()
+            .time_zone()
+            .map(database_time_zone)
+            .transpose()?;
+        let start = in_display_zone(start.into(), &display)?;
+        let end = in_display_zone(end.into(), &display)?;
+        let extend = |value: DateOrDateTime, days| {
+            value
+                .date()
+                .checked_add_days(days)
+                .ok_or(ConversionError::OutOfRange)
+        };
+        Ok(ZonedOccurrences {
+            occurrences: self
+                .occurrences_between(extend(start, -MARGIN_DAYS)?, extend(end, MARGIN_DAYS)?),
+            source,
+            display,
+            start,
+            end,
+        })
+    }
+}
+
+/// An iterator over the occurrences of an [`Event`] within a time range, in the local time of a
+/// time zone.
+///
+/// Created by [`Event::occurrences_in_zone`].
+#[derive(Debug, Clone)]
+pub struct ZonedOccurrences<'a> {
+    occurrences: Occurrences<'a>,
+    /// The time zone of the event, or `None` if it is in UTC or floating time.
+    source: Option<TimeZone>,
+    display: TimeZone,
+    start: DateOrDateTime,
+    end: DateOrDateTime,
+}
+
+impl ZonedOccurrences<'_> {
+    /// Set the maximum number of periods of the recurrence rule that are evaluated, see
+    /// [`Occurrences::limit`].
+    ///
+    /// This must be called before the iterator is advanced.
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.occurrences = self.occurrences.limit(limit);
+        self
+    }
+
+    /// Returns `true` if the expansion stopped because the limit was reached, see
+    /// [`Occurrences::limit_reached`].
+    #[must_use]
+    pub fn limit_reached(&self) -> bool {
+        self.occurrences.limit_reached()
+    }
+
+    /// Convert `occurrence` from the time zone of the event to the display time zone.
+    fn convert(&self, occurrence: DateOrDateTime) -> Option<DateOrDateTime> {
+        let DateOrDateTime::DateTime(date_time) = occurrence else {
+            return Some(occurrence);
+        };
+        let timestamp = if date_time.time.is_utc() {
+            Timestamp::from_second(date_time.to_unix_timestamp()?).ok()?
+        } else if let Some(source) = &self.source {
+            source
+                .to_ambiguous_timestamp(date_time.into())
+                .compatible()
+                .ok()?
+        } else {
+            return Some(occurrence);
+        };
+        let local = DateTime::try_from(self.display.to_datetime(timestamp)).ok()?;
+        Some(local.into())
+    }
+}
+
+impl Iterator for ZonedOccurrences<'_> {
+    type Item = DateOrDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let occurrence = self.occurrences.next()?;
+            // Occurrences that cannot be converted are at the limits of the supported range of
+            // dates, so there are no further occurrences either.
+            let occurrence = self.convert(occurrence)?;
+            let key = occurrence.chronological_key();
+            if self.start.chronological_key() <= key && key < self.end.chronological_key() {
+                return Some(occurrence);
+            }
+        }
+    }
+}
+
+/// Convert a boundary of a range to the local time of `time_zone`.
+fn in_display_zone(
+    value: DateOrDateTime,
+    time_zone: &TimeZone,
+) -> Result<DateOrDateTime, ConversionError> {
+    match value {
+        DateOrDateTime::DateTime(date_time) if date_time.time.is_utc() => {
+            let seconds = date_time
+                .to_unix_timestamp()
+                .ok_or(ConversionError::OutOfRange)?;
+            let timestamp =
+                Timestamp::from_second(seconds).map_err(|_| ConversionError::OutOfRange)?;
+            Ok(DateTime::try_from(time_zone.to_datetime(timestamp))?.into())
+        }
+        _ => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ConversionError, Date, DateOrDateTime, DateTime, Event, RecurrenceFrequency,
+        RecurrenceRule, Time, ZonedDateTime,
+    };
+
+    fn local(month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+        DateTime {
+            date: Date::new(2024, month, day),
+            time: Time::new_local(hour, minute, 0),
+        }
+    }
+
+    #[test]
+    fn weekly_in_zurich() {
+        let start = ZonedDateTime::new(local(10, 14, 9, 0), "Europe/Zurich");
+        let mut event = Event::new(start.into(), local(10, 1, 0, 0));
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+
+        // Daylight saving time ends on 2024-10-27 in Zurich and on 2024-11-03 in New York.
+        let occurrences: Vec<_> = event
+            .occurrences_in_zone(
+                Date::new(2024, 10, 14),
+                Date::new(2024, 11, 12),
+                "America/New_York",
+            )
+            .unwrap()
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                local(10, 14, 3, 0),
+                local(10, 21, 3, 0),
+                local(10, 28, 4, 0),
+                local(11, 4, 3, 0),
+                local(11, 11, 3, 0),
+            ]
+            .map(DateOrDateTime::from)
+        );
+
+        // The range is in the display time zone: 03:00 in New York is 09:00 in Zurich.
+        let occurrences: Vec<_> = event
+            .occurrences_in_zone(local(10, 21, 3, 0), local(10, 28, 4, 0), "America/New_York")
+            .unwrap()
+            .collect();
+        assert_eq!(occurrences, [local(10, 21, 3, 0).into()]);
+    }
+
+    #[test]
+    fn skipped_and_floating() {
+        // 02:30 does not exist on 2024-03-31 in Zurich.
+        let start = ZonedDateTime::new(local(3, 30, 2, 30), "Europe/Zurich");
+        let mut event = Event::new(start.into(), local(3, 1, 0, 0));
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        let occurrences: Vec<_> = event
+            .occurrences_in_zone(Date::new(2024, 3, 30), Date::new(2024, 4, 2), "Etc/UTC")
+            .unwrap()
+            .collect();
+        assert_eq!(
+            occurrences,
+            [local(3, 30, 1, 30), local(3, 31, 1, 30), local(4, 1, 0, 30)]
+                .map(DateOrDateTime::from)
+        );
+
+        let mut floating = Event::new(local(3, 30, 2, 30).into(), local(3, 1, 0, 0));
+        floating.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Daily));
+        let occurrences: Vec<_> = floating
+            .occurrences_in_zone(Date::new(2024, 3, 30), Date::new(2024, 4, 1), "Etc/UTC")
+            .unwrap()
+            .collect();
+        assert_eq!(
+            occurrences,
+            [local(3, 30, 2, 30), local(3, 31, 2, 30)].map(DateOrDateTime::from)
+        );
+
+        assert_eq!(
+            floating
+                .occurrences_in_zone(Date::new(2024, 3, 30), Date::new(2024, 4, 1), "Mars/Base")
+                .map(Iterator::count),
+            Err(ConversionError::UnknownTimeZone)
+        );
+    }
+}
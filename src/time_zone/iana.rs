@@ -0,0 +1,217 @@
+use {
+    super::{
+        ObservanceKind, TimeZone,
+        transitions::{self, LAST_YEAR, Transition},
+    },
+    crate::{ConversionError, DateTime, UtcOffset},
+    jiff::{Timestamp, tz},
+};
+
+impl TimeZone {
+    /// Create the time zone with the given identifier from the IANA time zone database, such as
+    /// `Europe/Zurich`.
+    ///
+    /// The observances are synthesized from the transitions of the time zone: the transitions
+    /// that follow a yearly rule until today, such as "the last Sunday in October", are written
+    /// as one observance with a recurrence rule, and all earlier transitions are written as
+    /// recurrence dates of one observance per combination of offsets and name. A time zone
+    /// without transitions has a single standard observance.
+    ///
+    /// Requires the `tzdata` feature. The time zone database of the operating system is used,
+    /// or a bundled copy on platforms without one.
+    ///
+    /// ```
+    /// use ical::{ObservanceKind, TimeZone};
+    ///
+    /// let time_zone = TimeZone::from_iana("Europe/Zurich").unwrap();
+    /// assert_eq!(time_zone.id(), "Europe/Zurich");
+    ///
+    /// let daylight = time_zone
+    ///     .observances()
+    ///     .iter()
+    ///     .find(|observance| observance.recurrence_rule().is_some())
+    ///     .unwrap();
+    /// assert_eq!(daylight.kind(), ObservanceKind::Daylight);
+    /// assert_eq!(daylight.name(), Some("CEST"));
+    /// assert_eq!(
+    ///     daylight.recurrence_rule().unwrap().to_string(),
+    ///     "FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::UnknownTimeZone`] if the time zone is not in the database.
+    pub fn from_iana(id: &str) -> Result<TimeZone, ConversionError> {
+        let database_time_zone =
+            tz::TimeZone::get(id).map_err(|_| ConversionError::UnknownTimeZone)?;
+        let mut transitions = database_transitions(&database_time_zone)?;
+        if transitions.is_empty() {
+            let info = database_time_zone.to_offset_info(Timestamp::UNIX_EPOCH);
+            transitions.push(Transition::fixed(
+                kind(info.dst()),
+                utc_offset(info.offset())?,
+                info.abbreviation().to_owned(),
+            ));
+        }
+        let mut time_zone = TimeZone::new(id);
+        time_zone.observances = transitions::observances(&transitions);
+        Ok(time_zone)
+    }
+}
+
+/// All transitions of `time_zone` up to [`LAST_YEAR`].
+fn database_transitions(time_zone: &tz::TimeZone) -> Result<Vec<Transition>, ConversionError> {
+    let mut transitions = Vec::new();
+    for transition in time_zone.following(Timestamp::MIN) {
+        let before = Timestamp::from_second(transition.timestamp().as_second() - 1)
+            .map_err(|_| ConversionError::OutOfRange)?;
+        let offset_from = time_zone.to_offset(before);
+        let local = DateTime::try_from(offset_from.to_datetime(transition.timestamp()))?;
+        if local.date.year() >= LAST_YEAR {
+            break;
+        }
+        transitions.push(Transition {
+            kind: kind(transition.dst()),
+            local,
+            offset_from: utc_offset(offset_from)?,
+            offset_to: utc_offset(transition.offset())?,
+            name: transition.abbreviation().to_owned(),
+        });
+    }
+    Ok(transitions)
+}
+
+fn utc_offset(offset: tz::Offset) -> Result<UtcOffset, ConversionError> {
+    UtcOffset::from_seconds(offset.seconds()).ok_or(ConversionError::OutOfRange)
+}
+
+fn kind(dst: tz::Dst) -> ObservanceKind {
+    if dst.is_dst() {
+        ObservanceKind::Daylight
+    } else {
+        ObservanceKind::Standard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, Date, DateTime, Event, MissingTimeZoneError, ObservanceKind, RecurrenceFrequency,
+        RecurrenceRule, Time, TimeZone, UtcOffset, ZonedDateTime,
+    };
+
+    #[test]
+    fn new_york() {
+        let time_zone = TimeZone::from_iana("America/New_York").unwrap();
+        let rules: Vec<_> = time_zone
+            .observances()
+            .iter()
+            .filter_map(|observance| {
+                let rule = observance.recurrence_rule()?;
+                Some((observance.kind(), observance.start(), rule.to_string()))
+            })
+            .collect();
+        assert_eq!(rules.len(), 2);
+        for (kind, start, rule) in rules {
+            assert_eq!(start.date.year(), 2007);
+            match kind {
+                ObservanceKind::Daylight => assert_eq!(rule, "FREQ=YEARLY;BYDAY=2SU;BYMONTH=3"),
+                ObservanceKind::Standard => assert_eq!(rule, "FREQ=YEARLY;BYDAY=1SU;BYMONTH=11"),
+            }
+        }
+        // Transitions before 2007 are recurrence dates.
+        assert!(
+            time_zone
+                .observances()
+                .iter()
+                .any(|observance| observance.recurrence_dates().len() > 10)
+        );
+    }
+
+    #[test]
+    fn without_daylight_saving_time() {
+        let time_zone = TimeZone::from_iana("Asia/Tokyo").unwrap();
+        assert!(
+            time_zone
+                .observances()
+                .iter()
+                .all(|observance| observance.recurrence_rule().is_none())
+        );
+        let utc = TimeZone::from_iana("Etc/UTC").unwrap();
+        assert_eq!(utc.observances().len(), 1);
+        assert_eq!(utc.observances()[0].offset_to(), UtcOffset::UTC);
+        assert!(TimeZone::from_iana("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn minimal() {
+        let at = |year, month, day| DateTime {
+            date: Date::new(year, month, day),
+            time: Time::new_local(9, 0, 0),
+        };
+        let start = ZonedDateTime::new(at(2024, 7, 5), "Europe/Zurich");
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", start.into(), stamp);
+        let mut calendar = Calendar::new();
+        calendar.add_time_zone(TimeZone::from_iana("Europe/Zurich").unwrap());
+        calendar.add_component(event.clone()).minimize_time_zones();
+        let observances = calendar.time_zones()[0].observances();
+        assert_eq!(observances.len(), 1);
+        assert_eq!(observances[0].kind(), ObservanceKind::Daylight);
+        assert_eq!(observances[0].start().date, Date::new(2024, 3, 31));
+
+        event.set_recurrence_rule(RecurrenceRule::new(RecurrenceFrequency::Weekly));
+        let mut calendar = Calendar::from_event(event);
+        calendar.add_time_zone(TimeZone::from_iana("Europe/Zurich").unwrap());
+        calendar.minimize_time_zones();
+        let starts: Vec<_> = calendar.time_zones()[0]
+            .observances()
+            .iter()
+            .map(|observance| (observance.kind(), observance.start().date))
+            .collect();
+        assert_eq!(
+            starts,
+            [
+                (ObservanceKind::Daylight, Date::new(2024, 3, 31)),
+                (ObservanceKind::Standard, Date::new(2024, 10, 27)),
+            ]
+        );
+    }
+
+    #[test]
+    fn included() {
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_local(9, 0, 0),
+        };
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let event = |uid: &str, time_zone: &str| {
+            Event::with_uid(uid, ZonedDateTime::new(start, time_zone).into(), stamp)
+        };
+        let mut calendar = Calendar::from_events([
+            event("1@example.com", "Europe/Zurich"),
+            event("2@example.com", "Europe/Zurich"),
+        ]);
+        assert!(!calendar.to_ics_string().contains("BEGIN:VTIMEZONE"));
+        calendar.set_include_time_zones(true);
+        let output = calendar.to_ics_string();
+        assert_eq!(output.matches("BEGIN:VTIMEZONE").count(), 1);
+        assert!(output.contains("TZID:Europe/Zurich\r\n"));
+
+        calendar.add_component(event("3@example.com", "Mars/Olympus_Mons"));
+        let err = calendar.write(Vec::new()).unwrap_err();
+        let err = err.into_inner().unwrap();
+        assert_eq!(
+            err.downcast_ref::<MissingTimeZoneError>()
+                .map(MissingTimeZoneError::id),
+            Some("Mars/Olympus_Mons")
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use {
+    super::{Observance, ObservanceKind},
+    crate::{
+        Date, DateTime, RecurrenceFrequency, RecurrenceRule, Time, UtcOffset, Weekday, WeekdayNum,
+        date_and_time::days_in_month,
+    },
+};
+
+/// Transitions are synthesized up to this year. Rules that are still in effect at that time are
+/// assumed to continue forever.
+pub(super) const LAST_YEAR: u16 = 2100;
+
+/// A change of the UTC offset or the name of a time zone.
+#[derive(Debug, Clone)]
+pub(super) struct Transition {
+    pub(super) kind: ObservanceKind,
+    /// The time of the transition, in local time before the transition.
+    pub(super) local: DateTime,
+    pub(super) offset_from: UtcOffset,
+    pub(super) offset_to: UtcOffset,
+    pub(super) name: String,
+}
+
+impl Transition {
+    /// A transition that does not change anything, which defines the offset of a time zone
+    /// without transitions.
+    pub(super) fn fixed(kind: ObservanceKind, offset: UtcOffset, name: String) -> Self {
+        Self {
+            kind,
+            local: DateTime {
+                date: Date::new(1970, 1, 1),
+                time: Time::new_local(0, 0, 0),
+            },
+            offset_from: offset,
+            offset_to: offset,
+            name,
+        }
+    }
+
+    /// Whether `self` and `other` belong to the same observance.
+    fn same_observance(&self, other: &Transition) -> bool {
+        self.kind == other.kind
+            && self.offset_from == other.offset_from
+            && self.offset_to == other.offset_to
+            && self.name == other.name
+    }
+
+    fn observance(&self) -> Observance {
+        let mut observance =
+            Observance::new(self.kind, self.local, self.offset_from, self.offset_to);
+        if !self.name.is_empty() {
+            observance.set_name(self.name.as_str());
+        }
+        observance
+    }
+}
+
+/// Synthesize the observances of a time zone from its `transitions`.
+///
+/// The transitions that follow yearly rules until [`LAST_YEAR`], such as "the last Sunday in
+/// October", become one observance with a recurrence rule each. All earlier transitions are
+/// grouped into one observance per combination of offsets and name, with recurrence dates.
+pub(super) fn observances(transitions: &[Transition]) -> Vec<Observance> {
+    let (rules, first_rule_transition) = yearly_rules(transitions);
+    let mut observances: Vec<(Transition, Observance)> = Vec::new();
+    for transition in &transitions[..first_rule_transition] {
+        match observances
+            .iter_mut()
+            .find(|(first, _)| first.same_observance(transition))
+        {
+            Some((_, observance)) => {
+                observance.add_recurrence_date(transition.local);
+            }
+            None => observances.push((transition.clone(), transition.observance())),
+        }
+    }
+    let mut observances: Vec<_> = observances
+        .into_iter()
+        .map(|(_, observance)| observance)
+        .collect();
+    for rule in rules {
+        let mut observance = transitions[rule.first].observance();
+        let mut recurrence_rule = RecurrenceRule::new(RecurrenceFrequency::Yearly);
+        recurrence_rule
+            .set_by_month([rule.month])
+            .set_by_day([WeekdayNum::nth(rule.ordinals[0], rule.weekday)]);
+        observance.set_recurrence_rule(recurrence_rule);
+        observances.push(observance);
+    }
+    observances
+}
+
+/// A yearly rule such as "the last Sunday in March at 02:00", derived from the last transitions
+/// of a time zone.
+struct YearlyRule {
+    /// The index of the first transition that follows the rule.
+    first: usize,
+    month: u8,
+    weekday: Weekday,
+    /// The ordinals of the weekday within the month that match all transitions so far, such as
+    /// `4` and `-1` for the 25th of a month with 31 days.
+    ordinals: Vec<i8>,
+}
+
+/// Find the yearly rules that the last transitions of `transitions` follow until
+/// [`LAST_YEAR`], if any.
+///
+/// Returns the rules and the index of the first transition that follows one of them.
+fn yearly_rules(transitions: &[Transition]) -> (Vec<YearlyRule>, usize) {
+    let [.., second_to_last, last] = transitions else {
+        return (Vec::new(), transitions.len());
+    };
+    if last.local.date.year() < LAST_YEAR - 1 || second_to_last.same_observance(last) {
+        return (Vec::new(), transitions.len());
+    }
+    let mut rules: Vec<YearlyRule> = Vec::new();
+    let mut first_rule_transition = transitions.len();
+    for (index, transition) in transitions.iter().enumerate().rev() {
+        let date = transition.local.date;
+        let rule_index = rules
+            .iter()
+            .position(|rule| transitions[rule.first].same_observance(transition));
+        let Some(rule_index) = rule_index else {
+            if rules.len() == 2 {
+                break;
+            }
+            let last_day = days_in_month(date.year(), date.month());
+            let mut ordinals = vec![i8::try_from((date.day() - 1) / 7 + 1).expect("At most 5")];
+            if date.day() + 7 > last_day {
+                ordinals.push(-1);
+            }
+            rules.push(YearlyRule {
+                first: index,
+                month: date.month(),
+                weekday: date.weekday(),
+                ordinals,
+            });
+            first_rule_transition = index;
+            continue;
+        };
+        let rule = &mut rules[rule_index];
+        let previous = &transitions[rule.first];
+        let is_previous_year = date.year() + 1 == previous.local.date.year();
+        let ordinals: Vec<_> = rule
+            .ordinals
+            .iter()
+            .copied()
+            .filter(|&ordinal| {
+                nth_weekday(date.year(), rule.month, rule.weekday, ordinal) == Some(date)
+            })
+            .collect();
+        if !is_previous_year || transition.local.time != previous.local.time || ordinals.is_empty()
+        {
+            break;
+        }
+        rule.first = index;
+        rule.ordinals = ordinals;
+        first_rule_transition = index;
+    }
+    // A rule that was only seen once is not a rule.
+    if rules
+        .iter()
+        .any(|rule| transitions[rule.first].local.date.year() + 1 >= LAST_YEAR)
+    {
+        return (Vec::new(), transitions.len());
+    }
+    rules.sort_by_key(|rule| rule.first);
+    (rules, first_rule_transition)
+}
+
+/// The `ordinal`-th `weekday` in the given month, counting from the end if `ordinal` is
+/// negative.
+pub(super) fn nth_weekday(year: u16, month: u8, weekday: Weekday, ordinal: i8) -> Option<Date> {
+    let last_day = days_in_month(year, month);
+    let day = if ordinal > 0 {
+        let first = Date::new(year, month, 1).weekday();
+        let offset = (7 + weekday.days_from_monday() - first.days_from_monday()) % 7;
+        1 + offset + 7 * (ordinal.unsigned_abs() - 1)
+    } else {
+        let last = Date::new(year, month, last_day).weekday();
+        let offset = (7 + last.days_from_monday() - weekday.days_from_monday()) % 7;
+        (last_day - offset).checked_sub(7 * (ordinal.unsigned_abs() - 1))?
+    };
+    (1..=last_day)
+        .contains(&day)
+        .then(|| Date::new(year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::nth_weekday,
+        crate::{Date, Weekday},
+    };
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(
+            nth_weekday(2024, 3, Weekday::Sunday, -1),
+            Some(Date::new(2024, 3, 31))
+        );
+        assert_eq!(
+            nth_weekday(2024, 3, Weekday::Sunday, 2),
+            Some(Date::new(2024, 3, 10))
+        );
+        assert_eq!(nth_weekday(2024, 2, Weekday::Monday, 5), None);
+    }
+}
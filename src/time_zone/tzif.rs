@@ -0,0 +1,781 @@
+use {
+    super::{
+        ObservanceKind, TimeZone,
+        transitions::{self, LAST_YEAR, Transition, nth_weekday},
+    },
+    crate::{Date, DateTime, Duration, Time, UtcOffset, Weekday},
+    ical_vcard::Value,
+    std::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+impl TimeZone {
+    /// Create the time zone with the identifier `id` from data in the Time Zone Information
+    /// Format, the binary format of the time zone database of most Unix-like systems, see [RFC
+    /// 8536](https://tools.ietf.org/html/rfc8536).
+    ///
+    /// The data is usually read from a file in `/usr/share/zoneinfo`. The transitions after the
+    /// last transition of the data are computed from the rule in the footer of the data, which
+    /// is only present from version 2 on. The observances are synthesized from the transitions
+    /// as described for `TimeZone::from_iana`, which does the same with the time zone database
+    /// of the `jiff` crate. Leap seconds are ignored.
+    ///
+    /// ```no_run
+    /// use ical::TimeZone;
+    ///
+    /// let data = std::fs::read("/usr/share/zoneinfo/Europe/Zurich").unwrap();
+    /// let time_zone = TimeZone::from_tzif("Europe/Zurich", &data).unwrap();
+    /// assert_eq!(time_zone.id(), "Europe/Zurich");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is not valid data in this format, see [`ParseTzifError`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not a valid time zone identifier, see [`TimeZone::new`].
+    pub fn from_tzif(id: &str, data: &[u8]) -> Result<TimeZone, ParseTzifError> {
+        let mut reader = Reader { data, position: 0 };
+        let mut header = read_header(&mut reader)?;
+        let mut time_size = 4;
+        if header.version >= 2 {
+            let size = header
+                .data_block_size(4)
+                .ok_or(ParseTzifError::UnexpectedEnd)?;
+            reader.take(size)?;
+            header = read_header(&mut reader)?;
+            time_size = 8;
+        }
+        let block = read_data_block(&mut reader, &header, time_size)?;
+        let footer = if header.version >= 2 {
+            Some(read_footer(&mut reader)?)
+        } else {
+            None
+        };
+
+        let mut transitions = block.transitions();
+        let last = transitions.last().map(|(seconds, _)| *seconds);
+        if let Some(PosixTimeZone {
+            standard,
+            daylight: Some(daylight),
+        }) = &footer
+        {
+            let first_year = last
+                .and_then(DateTime::from_unix_timestamp)
+                .map_or(1970, |last| last.date.year());
+            let current = transitions
+                .last()
+                .map_or(ObservanceKind::Standard, |(_, transition)| transition.kind);
+            transitions.extend(
+                daylight
+                    .transitions(standard, first_year)?
+                    .into_iter()
+                    .filter(|(seconds, _)| last.is_none_or(|last| *seconds > last))
+                    // The rule may continue with the time that is already in effect.
+                    .skip_while(|(_, transition)| transition.kind == current),
+            );
+        }
+        let mut transitions: Vec<_> = transitions
+            .into_iter()
+            .map(|(_, transition)| transition)
+            .collect();
+        if transitions.is_empty() {
+            let fixed = match footer {
+                Some(footer) => footer.standard,
+                None => block.types[0].clone(),
+            };
+            transitions.push(Transition::fixed(fixed.kind, fixed.offset, fixed.name));
+        }
+        let mut time_zone = TimeZone::new(id);
+        time_zone.observances = transitions::observances(&transitions);
+        Ok(time_zone)
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], ParseTzifError> {
+        let bytes = self
+            .data
+            .get(self.position..self.position.saturating_add(length))
+            .ok_or(ParseTzifError::UnexpectedEnd)?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], ParseTzifError> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("`take` returns exactly N bytes"))
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn count(&mut self) -> Result<usize, ParseTzifError> {
+        usize::try_from(u32::from_be_bytes(self.take_array()?))
+            .map_err(|_| ParseTzifError::InvalidData)
+    }
+}
+
+struct Header {
+    /// The version as a number, `1` for the version written as a NUL byte.
+    version: u8,
+    utc_indicators: usize,
+    standard_indicators: usize,
+    leap_seconds: usize,
+    times: usize,
+    types: usize,
+    designation_bytes: usize,
+}
+
+impl Header {
+    /// The size of the data block that follows the header, where times take `time_size` bytes.
+    ///
+    /// Returns `None` if the size overflows.
+    fn data_block_size(&self, time_size: usize) -> Option<usize> {
+        self.times
+            .checked_mul(time_size + 1)?
+            .checked_add(self.types.checked_mul(6)?)?
+            .checked_add(self.designation_bytes)?
+            .checked_add(self.leap_seconds.checked_mul(time_size + 4)?)?
+            .checked_add(self.standard_indicators)?
+            .checked_add(self.utc_indicators)
+    }
+}
+
+fn read_header(reader: &mut Reader) -> Result<Header, ParseTzifError> {
+    if reader.take(4)? != b"TZif" {
+        return Err(ParseTzifError::InvalidHeader);
+    }
+    let version = match reader.take_array::<1>()? {
+        [0] => 1,
+        [version @ b'2'..=b'4'] => version - b'0',
+        _ => return Err(ParseTzifError::InvalidHeader),
+    };
+    reader.take(15)?;
+    let header = Header {
+        version,
+        utc_indicators: reader.count()?,
+        standard_indicators: reader.count()?,
+        leap_seconds: reader.count()?,
+        times: reader.count()?,
+        types: reader.count()?,
+        designation_bytes: reader.count()?,
+    };
+    if header.types == 0 {
+        return Err(ParseTzifError::InvalidHeader);
+    }
+    Ok(header)
+}
+
+/// A local time type of the data block, or the standard or daylight saving time of a footer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalTimeType {
+    kind: ObservanceKind,
+    offset: UtcOffset,
+    name: String,
+}
+
+struct DataBlock {
+    /// The transition times as Unix timestamps, together with the index of the local time type
+    /// after the transition.
+    times: Vec<(i64, usize)>,
+    types: Vec<LocalTimeType>,
+}
+
+impl DataBlock {
+    /// The transitions with their Unix timestamps, up to [`LAST_YEAR`].
+    fn transitions(&self) -> Vec<(i64, Transition)> {
+        // The first local time type is in effect before the first transition.
+        let mut previous = &self.types[0];
+        let mut transitions = Vec::new();
+        for &(seconds, index) in &self.times {
+            let next = &self.types[index];
+            if next == previous {
+                continue;
+            }
+            let local = seconds
+                .checked_add(i64::from(previous.offset.as_seconds()))
+                .and_then(DateTime::from_unix_timestamp);
+            match local {
+                Some(mut local) if local.date.year() < LAST_YEAR => {
+                    local.time.set_utc(false);
+                    transitions.push((
+                        seconds,
+                        Transition {
+                            kind: next.kind,
+                            local,
+                            offset_from: previous.offset,
+                            offset_to: next.offset,
+                            name: next.name.clone(),
+                        },
+                    ));
+                }
+                // Transitions before the year 0 are placeholders for the beginning of time.
+                None if seconds < 0 => (),
+                _ => break,
+            }
+            previous = next;
+        }
+        transitions
+    }
+}
+
+fn read_data_block(
+    reader: &mut Reader,
+    header: &Header,
+    time_size: usize,
+) -> Result<DataBlock, ParseTzifError> {
+    // The counts of the header are checked against the length of the data before anything is
+    // allocated for them.
+    if header
+        .data_block_size(time_size)
+        .is_none_or(|size| size > reader.remaining())
+    {
+        return Err(ParseTzifError::UnexpectedEnd);
+    }
+    let mut times = Vec::with_capacity(header.times);
+    for _ in 0..header.times {
+        times.push(if time_size == 4 {
+            i64::from(i32::from_be_bytes(reader.take_array()?))
+        } else {
+            i64::from_be_bytes(reader.take_array()?)
+        });
+    }
+    let indices = reader.take(header.times)?;
+    if indices
+        .iter()
+        .any(|&index| usize::from(index) >= header.types)
+    {
+        return Err(ParseTzifError::InvalidData);
+    }
+    let mut raw_types = Vec::with_capacity(header.types);
+    for _ in 0..header.types {
+        let offset = i32::from_be_bytes(reader.take_array()?);
+        let [is_dst, designation] = reader.take_array()?;
+        raw_types.push((offset, is_dst, designation));
+    }
+    let designations = reader.take(header.designation_bytes)?;
+    // The size of the whole data block was checked above, so this cannot overflow.
+    reader.take(
+        header.leap_seconds * (time_size + 4) + header.standard_indicators + header.utc_indicators,
+    )?;
+
+    let types = raw_types
+        .into_iter()
+        .map(|(offset, is_dst, designation)| {
+            let name = designations
+                .get(usize::from(designation)..)
+                .and_then(|rest| rest.split(|&byte| byte == 0).next())
+                .and_then(|name| str::from_utf8(name).ok())
+                .filter(|name| Value::new(*name).is_ok())
+                .ok_or(ParseTzifError::InvalidData)?;
+            Ok(LocalTimeType {
+                kind: kind(is_dst != 0),
+                offset: UtcOffset::from_seconds(offset).ok_or(ParseTzifError::InvalidData)?,
+                name: name.to_owned(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(DataBlock {
+        times: times
+            .into_iter()
+            .zip(indices.iter().map(|&index| usize::from(index)))
+            .collect(),
+        types,
+    })
+}
+
+/// Read the footer, which is a POSIX TZ string between two newlines.
+fn read_footer(reader: &mut Reader) -> Result<PosixTimeZone, ParseTzifError> {
+    let rest = reader.take(reader.remaining())?;
+    let footer = rest
+        .strip_prefix(b"\n")
+        .and_then(|rest| rest.split(|&byte| byte == b'\n').next())
+        .filter(|footer| rest.len() > footer.len() + 1)
+        .ok_or(ParseTzifError::InvalidFooter)?;
+    let footer = str::from_utf8(footer).map_err(|_| ParseTzifError::InvalidFooter)?;
+    PosixTimeZone::parse(footer).ok_or(ParseTzifError::InvalidFooter)
+}
+
+fn kind(is_dst: bool) -> ObservanceKind {
+    if is_dst {
+        ObservanceKind::Daylight
+    } else {
+        ObservanceKind::Standard
+    }
+}
+
+/// A time zone described by a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Debug, PartialEq, Eq)]
+struct PosixTimeZone {
+    standard: LocalTimeType,
+    daylight: Option<PosixDaylight>,
+}
+
+/// The daylight saving time of a [`PosixTimeZone`].
+#[derive(Debug, PartialEq, Eq)]
+struct PosixDaylight {
+    time: LocalTimeType,
+    /// The start, in standard time.
+    start: PosixRule,
+    /// The end, in daylight saving time.
+    end: PosixRule,
+}
+
+/// The day and time of a transition of a [`PosixTimeZone`].
+#[derive(Debug, PartialEq, Eq)]
+struct PosixRule {
+    date: PosixDate,
+    /// The seconds after midnight, which can be negative or more than a day.
+    time: i64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum PosixDate {
+    /// `Jn`: the day of the year from 1 to 365, where February 29 is never counted.
+    Julian(u16),
+    /// `n`: the day of the year from 0 to 365, where February 29 is counted.
+    Ordinal(u16),
+    /// `Mm.w.d`: the `week`-th `weekday` in `month`, where week 5 is the last week.
+    Weekday {
+        month: u8,
+        week: u8,
+        weekday: Weekday,
+    },
+}
+
+impl PosixTimeZone {
+    fn parse(s: &str) -> Option<Self> {
+        let mut parser = PosixParser { s, position: 0 };
+        let standard = LocalTimeType {
+            kind: ObservanceKind::Standard,
+            name: parser.name()?,
+            offset: parser.offset()?,
+        };
+        if parser.is_done() {
+            return Some(Self {
+                standard,
+                daylight: None,
+            });
+        }
+        let name = parser.name()?;
+        let offset = if parser.peek() == Some(b',') {
+            UtcOffset::from_seconds(standard.offset.as_seconds() + 3600)?
+        } else {
+            parser.offset()?
+        };
+        parser.expect(b',')?;
+        let start = parser.rule()?;
+        parser.expect(b',')?;
+        let end = parser.rule()?;
+        parser.is_done().then_some(Self {
+            standard,
+            daylight: Some(PosixDaylight {
+                time: LocalTimeType {
+                    kind: ObservanceKind::Daylight,
+                    offset,
+                    name,
+                },
+                start,
+                end,
+            }),
+        })
+    }
+}
+
+impl PosixDaylight {
+    /// The transitions with their Unix timestamps from the year `first_year` up to
+    /// [`LAST_YEAR`], in chronological order.
+    fn transitions(
+        &self,
+        standard: &LocalTimeType,
+        first_year: u16,
+    ) -> Result<Vec<(i64, Transition)>, ParseTzifError> {
+        let mut transitions = Vec::new();
+        for year in first_year..LAST_YEAR {
+            for (rule, from, to) in [
+                (&self.start, standard, &self.time),
+                (&self.end, &self.time, standard),
+            ] {
+                let local = rule.local(year).ok_or(ParseTzifError::InvalidFooter)?;
+                let seconds = local.seconds_since_epoch() - i64::from(from.offset.as_seconds());
+                transitions.push((
+                    seconds,
+                    Transition {
+                        kind: to.kind,
+                        local,
+                        offset_from: from.offset,
+                        offset_to: to.offset,
+                        name: to.name.clone(),
+                    },
+                ));
+            }
+        }
+        transitions.sort_by_key(|(seconds, _)| *seconds);
+        Ok(transitions)
+    }
+}
+
+impl PosixRule {
+    /// The local time of the transition in `year`.
+    fn local(&self, year: u16) -> Option<DateTime> {
+        let date = match self.date {
+            PosixDate::Julian(day) => {
+                // Skip February 29 by counting from March 1 for the days after February 28.
+                let day = i64::from(day);
+                if day <= 59 {
+                    Date::new(year, 1, 1).checked_add_days(day - 1)?
+                } else {
+                    Date::new(year, 3, 1).checked_add_days(day - 60)?
+                }
+            }
+            PosixDate::Ordinal(day) => Date::new(year, 1, 1).checked_add_days(i64::from(day))?,
+            PosixDate::Weekday {
+                month,
+                week,
+                weekday,
+            } => {
+                let ordinal = if week == 5 {
+                    -1
+                } else {
+                    i8::try_from(week).ok()?
+                };
+                nth_weekday(year, month, weekday, ordinal)?
+            }
+        };
+        let midnight = DateTime {
+            date,
+            time: Time::new_local(0, 0, 0),
+        };
+        midnight.checked_add(Duration::from_total_seconds(self.time)?)
+    }
+}
+
+struct PosixParser<'a> {
+    s: &'a str,
+    position: usize,
+}
+
+impl PosixParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.position).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.s.len()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        (self.peek() == Some(byte)).then(|| self.position += 1)
+    }
+
+    /// Take the longest prefix whose bytes match `predicate`.
+    fn take_while<F: Fn(u8) -> bool>(&mut self, predicate: F) -> &str {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+        &self.s[start..self.position]
+    }
+
+    /// A name of at least three letters, or of at least three letters, digits and signs in
+    /// angle brackets, such as `<+0330>`.
+    fn name(&mut self) -> Option<String> {
+        let name = if self.expect(b'<').is_some() {
+            let name = self
+                .take_while(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-');
+            let name = name.to_owned();
+            self.expect(b'>')?;
+            name
+        } else {
+            self.take_while(|byte| byte.is_ascii_alphabetic())
+                .to_owned()
+        };
+        (name.len() >= 3).then_some(name)
+    }
+
+    /// A number with at most `max_digits` digits.
+    fn number(&mut self, max_digits: usize) -> Option<u16> {
+        let digits = self.take_while(|byte| byte.is_ascii_digit());
+        if digits.is_empty() || digits.len() > max_digits {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    /// A duration in the form `[+|-]hh[:mm[:ss]]` in seconds, where the hours are at most
+    /// `max_hours`.
+    fn duration(&mut self, max_hours: u16) -> Option<i64> {
+        let sign = match self.peek() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            self.position += 1;
+        }
+        let hours = self.number(3).filter(|&hours| hours <= max_hours)?;
+        let mut seconds = i64::from(hours) * 3600;
+        for factor in [60, 1] {
+            if self.expect(b':').is_none() {
+                break;
+            }
+            seconds += i64::from(self.number(2).filter(|&value| value < 60)?) * factor;
+        }
+        Some(if sign < 0 { -seconds } else { seconds })
+    }
+
+    /// A UTC offset, which is positive west of UTC in POSIX TZ strings.
+    fn offset(&mut self) -> Option<UtcOffset> {
+        let seconds = self.duration(24)?;
+        UtcOffset::from_seconds(i32::try_from(-seconds).ok()?)
+    }
+
+    /// A rule in the form `date[/time]`, where the time defaults to 02:00.
+    fn rule(&mut self) -> Option<PosixRule> {
+        let date = if self.expect(b'J').is_some() {
+            PosixDate::Julian(self.number(3).filter(|day| (1..=365).contains(day))?)
+        } else if self.expect(b'M').is_some() {
+            let month = self.number(2).filter(|month| (1..=12).contains(month))?;
+            self.expect(b'.')?;
+            let week = self.number(1).filter(|week| (1..=5).contains(week))?;
+            self.expect(b'.')?;
+            let weekday = self.number(1).filter(|&weekday| weekday <= 6)?;
+            PosixDate::Weekday {
+                month: u8::try_from(month).ok()?,
+                week: u8::try_from(week).ok()?,
+                // Days are counted from Sunday.
+                weekday: Weekday::from_days_from_monday(u8::try_from((weekday + 6) % 7).ok()?),
+            }
+        } else {
+            PosixDate::Ordinal(self.number(3).filter(|&day| day <= 365)?)
+        };
+        let time = if self.expect(b'/').is_some() {
+            self.duration(167)?
+        } else {
+            2 * 3600
+        };
+        Some(PosixRule { date, time })
+    }
+}
+
+/// Error type for [`TimeZone::from_tzif`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTzifError {
+    /// The data does not start with a valid header of version 1 to 4.
+    InvalidHeader,
+    /// The data ends before the end of the data block or the footer.
+    UnexpectedEnd,
+    /// The data block contains an invalid value, such as a UTC offset of a day or more or an
+    /// invalid time zone abbreviation.
+    InvalidData,
+    /// The footer is not a valid POSIX TZ string with a rule for daylight saving time, if any.
+    InvalidFooter,
+}
+
+impl Display for ParseTzifError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseTzifError::InvalidHeader => write!(f, "Invalid TZif header"),
+            ParseTzifError::UnexpectedEnd => write!(f, "Unexpected end of TZif data"),
+            ParseTzifError::InvalidData => write!(f, "Invalid TZif data block"),
+            ParseTzifError::InvalidFooter => write!(f, "Invalid TZif footer"),
+        }
+    }
+}
+
+impl Error for ParseTzifError {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{PosixDate, PosixRule, PosixTimeZone},
+        crate::{Date, ObservanceKind, ParseTzifError, TimeZone, UtcOffset, Weekday},
+    };
+
+    /// Build data of version 2 with the given transitions, local time types and footer.
+    fn tzif(times: &[(i64, u8)], types: &[(i32, bool, &str)], footer: &str) -> Vec<u8> {
+        let mut designations = Vec::new();
+        let mut type_bytes = Vec::new();
+        for (offset, is_dst, name) in types {
+            type_bytes.extend(offset.to_be_bytes());
+            type_bytes.push(u8::from(*is_dst));
+            type_bytes.push(u8::try_from(designations.len()).unwrap());
+            designations.extend(name.bytes());
+            designations.push(0);
+        }
+        let header = |version: u8, times: usize| {
+            let mut header = b"TZif".to_vec();
+            header.push(version);
+            header.extend([0; 15]);
+            for count in [0, 0, 0, times, types.len(), designations.len()] {
+                header.extend(u32::try_from(count).unwrap().to_be_bytes());
+            }
+            header
+        };
+        // An empty version 1 data block, which is allowed if readers use version 2.
+        let mut data = header(b'2', 0);
+        data.extend(&type_bytes);
+        data.extend(&designations);
+        data.extend(header(b'2', times.len()));
+        for (time, _) in times {
+            data.extend(time.to_be_bytes());
+        }
+        data.extend(times.iter().map(|(_, index)| index));
+        data.extend(&type_bytes);
+        data.extend(&designations);
+        data.extend(format!("\n{footer}\n").bytes());
+        data
+    }
+
+    #[test]
+    fn zurich() {
+        // Switzerland observed daylight saving time in 1941 and 1942, and since 1981. In 1981,
+        // daylight saving time ended in September instead of October.
+        let data = tzif(
+            &[
+                (-904_435_200, 1),
+                (-891_129_600, 0),
+                (-872_985_600, 1),
+                (-859_680_000, 0),
+                (354_675_600, 1),
+                (370_400_400, 0),
+            ],
+            &[(3600, false, "CET"), (7200, true, "CEST")],
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+        );
+        let time_zone = TimeZone::from_tzif("Europe/Zurich", &data).unwrap();
+        let observances: Vec<_> = time_zone
+            .observances()
+            .iter()
+            .map(|observance| {
+                (
+                    observance.kind(),
+                    observance.start().to_string(),
+                    observance.recurrence_dates().len(),
+                    observance.recurrence_rule().map(ToString::to_string),
+                )
+            })
+            .collect();
+        assert_eq!(
+            observances,
+            [
+                (
+                    ObservanceKind::Daylight,
+                    "19410505T010000".to_owned(),
+                    2,
+                    None
+                ),
+                (
+                    ObservanceKind::Standard,
+                    "19411006T020000".to_owned(),
+                    2,
+                    None
+                ),
+                (
+                    ObservanceKind::Daylight,
+                    "19820328T020000".to_owned(),
+                    0,
+                    Some("FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3".to_owned())
+                ),
+                (
+                    ObservanceKind::Standard,
+                    "19821031T030000".to_owned(),
+                    0,
+                    Some("FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10".to_owned())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let data = tzif(&[], &[(19_800, false, "IST")], "IST-5:30");
+        let time_zone = TimeZone::from_tzif("Asia/Kolkata", &data).unwrap();
+        assert_eq!(time_zone.observances().len(), 1);
+        let observance = &time_zone.observances()[0];
+        assert_eq!(
+            observance.offset_to(),
+            "+0530".parse::<UtcOffset>().unwrap()
+        );
+        assert_eq!(observance.name(), Some("IST"));
+    }
+
+    #[test]
+    fn posix_time_zone() {
+        let time_zone = PosixTimeZone::parse("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1").unwrap();
+        assert_eq!(time_zone.standard.name, "-03");
+        assert_eq!(time_zone.standard.offset.as_seconds(), -3 * 3600);
+        let daylight = time_zone.daylight.unwrap();
+        assert_eq!(daylight.time.offset.as_seconds(), -2 * 3600);
+        assert_eq!(
+            daylight.start,
+            PosixRule {
+                date: PosixDate::Weekday {
+                    month: 3,
+                    week: 5,
+                    weekday: Weekday::Sunday
+                },
+                time: -2 * 3600
+            }
+        );
+        let julian = PosixRule {
+            date: PosixDate::Julian(60),
+            time: 0,
+        };
+        assert_eq!(julian.local(2024).unwrap().date, Date::new(2024, 3, 1));
+        assert!(PosixTimeZone::parse("CET-1CEST").is_none());
+        assert!(PosixTimeZone::parse("XY-1").is_none());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Zurich", b"TZjf"),
+            Err(ParseTzifError::InvalidHeader)
+        );
+        let data = tzif(&[], &[(3600, false, "CET")], "CET-1");
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Zurich", &data[..data.len() - 1]),
+            Err(ParseTzifError::InvalidFooter)
+        );
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Zurich", &data[..50]),
+            Err(ParseTzifError::UnexpectedEnd)
+        );
+        let data = tzif(&[(0, 1)], &[(3600, false, "CET")], "CET-1");
+        assert_eq!(
+            TimeZone::from_tzif("Europe/Zurich", &data),
+            Err(ParseTzifError::InvalidData)
+        );
+    }
+
+    #[test]
+    fn huge_counts() {
+        // A header without a data block that claims the maximum number of transitions.
+        for version in [0, b'2'] {
+            let mut data = b"TZif".to_vec();
+            data.push(version);
+            data.extend([0; 15]);
+            for count in [0, 0, 0, u32::MAX, 1, 4] {
+                data.extend(count.to_be_bytes());
+            }
+            assert_eq!(data.len(), 44);
+            assert_eq!(
+                TimeZone::from_tzif("Europe/Zurich", &data),
+                Err(ParseTzifError::UnexpectedEnd)
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Whether an event blocks time in free/busy searches, corresponding to the values of the
+/// `TRANSP` property.
+///
+/// See [RFC 5545 section 3.8.2.7 - Time
+/// Transparency](https://tools.ietf.org/html/rfc5545#section-3.8.2.7)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transparency {
+    /// The event blocks time, which is the default.
+    Opaque,
+    /// The event does not block time, for example a reminder or a holiday.
+    Transparent,
+}
+
+impl Display for Transparency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Transparency::Opaque => write!(f, "OPAQUE"),
+            Transparency::Transparent => write!(f, "TRANSPARENT"),
+        }
+    }
+}
@@ -0,0 +1,748 @@
+use {
+    crate::{Calendar, Component, Date, DateOrDateTime, Error, Event, Method, RuleError},
+    std::{
+        collections::HashSet,
+        error,
+        fmt::{self, Display, Formatter},
+        io::Write,
+    },
+};
+
+#[cfg(feature = "jiff")]
+use jiff::tz;
+
+impl Calendar {
+    /// Check the calendar for violations of RFC 5545 that are not prevented by the types of this
+    /// crate.
+    ///
+    /// The following is checked:
+    /// - Every combination of `UID` and `RECURRENCE-ID` is unique.
+    /// - The recurrence and exception rules of every event are valid and match its start, see
+    ///   [`RecurrenceRule::validate`](crate::RecurrenceRule::validate) and
+    ///   [`RecurrenceRule::validate_against`](crate::RecurrenceRule::validate_against).
+    /// - The end of every event has the same value type as its start and is later than its
+    ///   start.
+    /// - If the calendar has a `METHOD`, every event has the properties that
+    ///   [RFC 5546 section 3.2](https://tools.ietf.org/html/rfc5546#section-3.2) requires for
+    ///   that method. For example, a [`Method::Request`] requires an `ORGANIZER`, at least one
+    ///   `ATTENDEE` and a `SUMMARY`, and a [`Method::Reply`] requires exactly one `ATTENDEE`.
+    ///   A `SEQUENCE` of 0 counts as missing, because it is not written.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, ValidationIssue};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event.set_end(Date::new(2024, 7, 4));
+    /// let mut calendar = Calendar::new();
+    /// calendar.add_component(event.clone());
+    /// calendar.add_component(event);
+    /// assert_eq!(
+    ///     calendar.validate(),
+    ///     Err(vec![
+    ///         ValidationIssue::EndNotAfterStart {
+    ///             uid: "1@example.com".to_owned()
+    ///         },
+    ///         ValidationIssue::EndNotAfterStart {
+    ///             uid: "1@example.com".to_owned()
+    ///         },
+    ///         ValidationIssue::DuplicateUid {
+    ///             uid: "1@example.com".to_owned()
+    ///         },
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns all issues that were found, in the order of the components.
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        let mut identifiers = HashSet::new();
+        for component in &self.components {
+            match component {
+                Component::Event(event) => {
+                    event.find_issues(&mut issues);
+                    if let Some(method) = self.method {
+                        event.find_method_issues(method, &mut issues);
+                    }
+                    if !identifiers.insert((event.uid(), event.recurrence_id)) {
+                        issues.push(ValidationIssue::DuplicateUid {
+                            uid: event.uid().to_owned(),
+                        });
+                    }
+                }
+            }
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Write the calendar to the given writer if it passes [`Calendar::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if the calendar is invalid, in which case nothing is
+    /// written, and [`Error::Io`] if writing to the writer fails.
+    pub fn write_validated<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.validate().map_err(Error::Validation)?;
+        Ok(self.write(writer)?)
+    }
+
+    /// Check the calendar for violations of RFC 5545, see [`Calendar::validate`], and for
+    /// interoperability hazards, see [`ValidationWarning`].
+    ///
+    /// `today` is used to find events that are far in the future.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time, ValidationWarning};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// calendar.add_component(Event::with_uid(
+    ///     "1@example.com",
+    ///     Date::new(2024, 7, 5).into(),
+    ///     stamp,
+    /// ));
+    /// let report = calendar.validation_report(stamp.date);
+    /// assert!(report.is_valid());
+    /// assert_eq!(
+    ///     report.warnings(),
+    ///     [ValidationWarning::MissingSummary {
+    ///         uid: "1@example.com".to_owned()
+    ///     }]
+    /// );
+    /// assert_eq!(report.to_string(), "warning: Event 1@example.com: no SUMMARY\n");
+    /// ```
+    #[must_use]
+    pub fn validation_report(&self, today: Date) -> ValidationReport {
+        let mut warnings = Vec::new();
+        let far_future = today
+            .checked_add_years(ValidationReport::FAR_FUTURE_YEARS)
+            .unwrap_or(today);
+        for component in &self.components {
+            match component {
+                Component::Event(event) => event.find_warnings(far_future, &mut warnings),
+            }
+        }
+        ValidationReport {
+            errors: self.validate().err().unwrap_or_default(),
+            warnings,
+        }
+    }
+}
+
+impl Event {
+    /// Add all issues of the event to `issues`, see [`Calendar::validate`].
+    fn find_issues(&self, issues: &mut Vec<ValidationIssue>) {
+        let uid = || self.uid().to_owned();
+        if let Err(error) = self.validate() {
+            issues.push(ValidationIssue::Rule { uid: uid(), error });
+        }
+        let start = self.start_date_time.value();
+        if let Some(end) = self.end {
+            if end.is_date() != start.is_date() {
+                issues.push(ValidationIssue::EndValueType { uid: uid() });
+            } else if is_comparable(start, end)
+                && end.chronological_key() <= start.chronological_key()
+            {
+                issues.push(ValidationIssue::EndNotAfterStart { uid: uid() });
+            }
+        }
+    }
+}
+
+impl Event {
+    /// Add all issues of the event that violate the requirements of `method` to `issues`, see
+    /// [RFC 5546 section 3.2](https://tools.ietf.org/html/rfc5546#section-3.2).
+    ///
+    /// `DTSTAMP`, `DTSTART` and `UID`, which every method requires, are always present.
+    fn find_method_issues(&self, method: Method, issues: &mut Vec<ValidationIssue>) {
+        let missing = |property| ValidationIssue::MissingProperty {
+            uid: self.uid().to_owned(),
+            method,
+            property,
+        };
+        if self.organizer.is_none() {
+            issues.push(missing("ORGANIZER"));
+        }
+        let requires_summary = matches!(
+            method,
+            Method::Publish | Method::Request | Method::Add | Method::Counter
+        );
+        if requires_summary && self.summary.is_none() {
+            issues.push(missing("SUMMARY"));
+        }
+        if matches!(method, Method::Add | Method::Cancel) && self.sequence == 0 {
+            issues.push(missing("SEQUENCE"));
+        }
+        let count = self.attendees.len();
+        let allowed = match method {
+            Method::Publish => count == 0,
+            Method::Request | Method::Add => count >= 1,
+            Method::Reply | Method::Refresh => count == 1,
+            Method::Cancel | Method::Counter | Method::DeclineCounter => true,
+        };
+        if !allowed {
+            issues.push(ValidationIssue::AttendeeCount {
+                uid: self.uid().to_owned(),
+                method,
+                count,
+            });
+        }
+    }
+}
+
+impl Event {
+    /// Add all warnings for the event to `warnings`, see [`Calendar::validation_report`].
+    fn find_warnings(&self, far_future: Date, warnings: &mut Vec<ValidationWarning>) {
+        let uid = || self.uid().to_owned();
+        if self.summary.is_none() {
+            warnings.push(ValidationWarning::MissingSummary { uid: uid() });
+        }
+        if let Some(description) = &self.description {
+            let length = description.as_str().chars().count();
+            if length > ValidationReport::DESCRIPTION_LIMIT {
+                warnings.push(ValidationWarning::LongDescription { uid: uid(), length });
+            }
+        }
+        if let Some(time_zone) = self.start_date_time.time_zone()
+            && !is_known_time_zone(time_zone)
+        {
+            warnings.push(ValidationWarning::UnknownTimeZone {
+                uid: uid(),
+                time_zone: time_zone.to_owned(),
+            });
+        }
+        let start = self.start_date_time.value().date();
+        if start > far_future {
+            warnings.push(ValidationWarning::FarFuture { uid: uid(), start });
+        }
+    }
+}
+
+/// Returns `true` if `time_zone` is in the IANA time zone database.
+#[cfg(feature = "jiff")]
+fn is_known_time_zone(time_zone: &str) -> bool {
+    tz::db().get(time_zone).is_ok()
+}
+
+/// Returns `true` if `time_zone` looks like an identifier of the IANA time zone database.
+///
+/// Without the `jiff` feature, no time zone database is available, so only the area of the
+/// identifier is checked.
+#[cfg(not(feature = "jiff"))]
+fn is_known_time_zone(time_zone: &str) -> bool {
+    const AREAS: [&str; 11] = [
+        "Africa",
+        "America",
+        "Antarctica",
+        "Arctic",
+        "Asia",
+        "Atlantic",
+        "Australia",
+        "Etc",
+        "Europe",
+        "Indian",
+        "Pacific",
+    ];
+    match time_zone.split_once('/') {
+        Some((area, location)) => AREAS.contains(&area) && !location.is_empty(),
+        None => time_zone == "UTC",
+    }
+}
+
+/// Returns `true` if `a` and `b` can be compared without knowing the time zone, that is, if both
+/// are dates, both are in UTC time or both are in local time.
+fn is_comparable(a: DateOrDateTime, b: DateOrDateTime) -> bool {
+    match (a.date_time(), b.date_time()) {
+        (Some(a), Some(b)) => a.time.is_utc() == b.time.is_utc(),
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+/// A violation of RFC 5545 found by [`Calendar::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// Several events have the same `UID` and the same `RECURRENCE-ID`, or no `RECURRENCE-ID`.
+    DuplicateUid {
+        /// The `UID` of the events.
+        uid: String,
+    },
+    /// The recurrence or exception rule of an event is invalid.
+    Rule {
+        /// The `UID` of the event.
+        uid: String,
+        /// The reason why the rule is invalid.
+        error: RuleError,
+    },
+    /// The end of an event is a date while its start is a date-time, or vice versa.
+    EndValueType {
+        /// The `UID` of the event.
+        uid: String,
+    },
+    /// The end of an event is not later than its start.
+    EndNotAfterStart {
+        /// The `UID` of the event.
+        uid: String,
+    },
+    /// An event lacks a property that RFC 5546 requires for the `METHOD` of the calendar.
+    MissingProperty {
+        /// The `UID` of the event.
+        uid: String,
+        /// The method of the calendar.
+        method: Method,
+        /// The name of the missing property, such as `ORGANIZER`.
+        property: &'static str,
+    },
+    /// The number of `ATTENDEE` properties of an event is not allowed for the `METHOD` of the
+    /// calendar, for example because a reply does not have exactly one attendee.
+    AttendeeCount {
+        /// The `UID` of the event.
+        uid: String,
+        /// The method of the calendar.
+        method: Method,
+        /// The number of attendees of the event.
+        count: usize,
+    },
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ValidationIssue::DuplicateUid { uid } => {
+                write!(f, "Several events have the UID {uid}")
+            }
+            ValidationIssue::Rule { uid, error } => write!(f, "Event {uid}: {error}"),
+            ValidationIssue::EndValueType { uid } => write!(
+                f,
+                "Event {uid}: DTEND must have the same value type as DTSTART"
+            ),
+            ValidationIssue::EndNotAfterStart { uid } => {
+                write!(f, "Event {uid}: DTEND must be later than DTSTART")
+            }
+            ValidationIssue::MissingProperty {
+                uid,
+                method,
+                property,
+            } => write!(f, "Event {uid}: METHOD:{method} requires {property}"),
+            ValidationIssue::AttendeeCount { uid, method, count } => write!(
+                f,
+                "Event {uid}: METHOD:{method} does not allow {count} ATTENDEE properties"
+            ),
+        }
+    }
+}
+
+impl error::Error for ValidationIssue {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ValidationIssue::Rule { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The result of [`Calendar::validation_report`].
+///
+/// Errors are violations of RFC 5545, see [`Calendar::validate`]. Warnings are
+/// interoperability hazards, that is, properties that are valid but that some clients do not
+/// handle well.
+///
+/// The [`Display`] implementation writes one line per error and warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    errors: Vec<ValidationIssue>,
+    warnings: Vec<ValidationWarning>,
+}
+
+impl ValidationReport {
+    /// The number of characters in a description above which
+    /// [`ValidationWarning::LongDescription`] is reported.
+    pub const DESCRIPTION_LIMIT: usize = 8_000;
+
+    /// The number of years after today after which [`ValidationWarning::FarFuture`] is reported.
+    pub const FAR_FUTURE_YEARS: i32 = 10;
+
+    /// Get the violations of RFC 5545.
+    #[must_use]
+    pub fn errors(&self) -> &[ValidationIssue] {
+        &self.errors
+    }
+
+    /// Get the interoperability hazards.
+    #[must_use]
+    pub fn warnings(&self) -> &[ValidationWarning] {
+        &self.warnings
+    }
+
+    /// Returns `true` if there are no errors.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for error in &self.errors {
+            writeln!(f, "error: {error}")?;
+        }
+        for warning in &self.warnings {
+            writeln!(f, "warning: {warning}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An interoperability hazard found by [`Calendar::validation_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// An event has no `SUMMARY`, so many clients show it without a title.
+    MissingSummary {
+        /// The `UID` of the event.
+        uid: String,
+    },
+    /// The description of an event is longer than [`ValidationReport::DESCRIPTION_LIMIT`], so
+    /// some clients truncate it.
+    LongDescription {
+        /// The `UID` of the event.
+        uid: String,
+        /// The number of characters in the description.
+        length: usize,
+    },
+    /// The time zone of the start of an event is not in the IANA time zone database, so most
+    /// clients only understand it if the calendar contains a matching `VTIMEZONE` component.
+    ///
+    /// Without the `jiff` feature, only the area of the identifier, such as `Europe` in
+    /// `Europe/Zurich`, is checked.
+    UnknownTimeZone {
+        /// The `UID` of the event.
+        uid: String,
+        /// The time zone identifier.
+        time_zone: String,
+    },
+    /// An event starts more than [`ValidationReport::FAR_FUTURE_YEARS`] years in the future,
+    /// which is often caused by a wrong year.
+    FarFuture {
+        /// The `UID` of the event.
+        uid: String,
+        /// The start date of the event.
+        start: Date,
+    },
+}
+
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ValidationWarning::MissingSummary { uid } => write!(f, "Event {uid}: no SUMMARY"),
+            ValidationWarning::LongDescription { uid, length } => write!(
+                f,
+                "Event {uid}: the DESCRIPTION is long ({length} characters)"
+            ),
+            ValidationWarning::UnknownTimeZone { uid, time_zone } => {
+                write!(f, "Event {uid}: unknown time zone {time_zone}")
+            }
+            ValidationWarning::FarFuture { uid, start } => write!(
+                f,
+                "Event {uid}: starts far in the future ({})",
+                start.to_iso8601()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{ValidationIssue, ValidationWarning},
+        crate::{
+            Attendee, Calendar, Date, DateTime, Error, Event, Method, Organizer, Time,
+            ZonedDateTime,
+        },
+    };
+
+    fn date_time(hour: u8, utc: bool) -> DateTime {
+        DateTime {
+            date: Date::new(2024, 7, 5),
+            time: if utc {
+                Time::new_utc(hour, 0, 0)
+            } else {
+                Time::new_local(hour, 0, 0)
+            },
+        }
+    }
+
+    #[test]
+    fn end() {
+        let mut event = Event::with_uid("1", date_time(9, true).into(), date_time(8, true));
+        event.set_end(date_time(10, true));
+        let mut calendar = Calendar::new();
+        calendar.add_component(event.clone());
+        assert_eq!(calendar.validate(), Ok(()));
+
+        event.set_end(Date::new(2024, 7, 6));
+        let mut calendar = Calendar::new();
+        calendar.add_component(event.clone());
+        assert_eq!(
+            calendar.validate(),
+            Err(vec![ValidationIssue::EndValueType {
+                uid: "1".to_owned()
+            }])
+        );
+
+        // A local end with a zoned start is compared in the same time zone.
+        let start = ZonedDateTime::new(date_time(9, false), "Europe/Zurich");
+        let mut event = Event::with_uid("2", start.clone().into(), date_time(8, true));
+        event.set_end(date_time(9, false));
+        // A UTC end cannot be compared with a zoned start without the time zone.
+        let mut other = Event::with_uid("3", start.into(), date_time(8, true));
+        other.set_end(date_time(8, true));
+        let mut calendar = Calendar::new();
+        calendar.add_component(event).add_component(other);
+        assert_eq!(
+            calendar.validate(),
+            Err(vec![ValidationIssue::EndNotAfterStart {
+                uid: "2".to_owned()
+            }])
+        );
+    }
+
+    /// An event without organizer, summary, sequence number and attendees.
+    fn bare_event() -> Event {
+        Event::with_uid("1", date_time(9, true).into(), date_time(8, true))
+    }
+
+    /// An event with an organizer, a summary, a sequence number of 1 and `attendees` attendees.
+    fn full_event(attendees: usize) -> Event {
+        let mut event = bare_event();
+        event
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .set_summary("Team lunch")
+            .set_sequence(1);
+        for index in 0..attendees {
+            event.add_attendee(Attendee::new(format!("mailto:{index}@example.com")));
+        }
+        event
+    }
+
+    fn validate(method: Method, event: Event) -> Result<(), Vec<ValidationIssue>> {
+        let mut calendar = Calendar::from_event(event);
+        calendar.set_method(method);
+        calendar.validate()
+    }
+
+    fn missing(method: Method, property: &'static str) -> ValidationIssue {
+        ValidationIssue::MissingProperty {
+            uid: "1".to_owned(),
+            method,
+            property,
+        }
+    }
+
+    fn attendee_count(method: Method, count: usize) -> ValidationIssue {
+        ValidationIssue::AttendeeCount {
+            uid: "1".to_owned(),
+            method,
+            count,
+        }
+    }
+
+    #[test]
+    fn method_publish() {
+        let method = Method::Publish;
+        assert_eq!(validate(method, full_event(0)), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                missing(method, "SUMMARY")
+            ])
+        );
+        assert_eq!(
+            validate(method, full_event(1)),
+            Err(vec![attendee_count(method, 1)])
+        );
+    }
+
+    #[test]
+    fn method_request() {
+        let method = Method::Request;
+        assert_eq!(validate(method, full_event(2)), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                missing(method, "SUMMARY"),
+                attendee_count(method, 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn method_reply() {
+        let method = Method::Reply;
+        let mut event = full_event(1);
+        event.summary = None;
+        event.set_sequence(0);
+        assert_eq!(validate(method, event), Ok(()));
+        assert_eq!(
+            validate(method, full_event(2)),
+            Err(vec![attendee_count(method, 2)])
+        );
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                attendee_count(method, 0)
+            ])
+        );
+    }
+
+    #[test]
+    fn method_add() {
+        let method = Method::Add;
+        assert_eq!(validate(method, full_event(1)), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                missing(method, "SUMMARY"),
+                missing(method, "SEQUENCE"),
+                attendee_count(method, 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn method_cancel() {
+        let method = Method::Cancel;
+        let mut event = full_event(0);
+        event.summary = None;
+        assert_eq!(validate(method, event), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                missing(method, "SEQUENCE")
+            ])
+        );
+    }
+
+    #[test]
+    fn method_refresh() {
+        let method = Method::Refresh;
+        let mut event = full_event(1);
+        event.summary = None;
+        assert_eq!(validate(method, event), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                attendee_count(method, 0)
+            ])
+        );
+    }
+
+    #[test]
+    fn method_counter() {
+        let method = Method::Counter;
+        assert_eq!(validate(method, full_event(1)), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![
+                missing(method, "ORGANIZER"),
+                missing(method, "SUMMARY")
+            ])
+        );
+    }
+
+    #[test]
+    fn method_decline_counter() {
+        let method = Method::DeclineCounter;
+        let mut event = full_event(1);
+        event.summary = None;
+        assert_eq!(validate(method, event), Ok(()));
+        assert_eq!(
+            validate(method, bare_event()),
+            Err(vec![missing(method, "ORGANIZER")])
+        );
+        assert_eq!(
+            missing(method, "ORGANIZER").to_string(),
+            "Event 1: METHOD:DECLINECOUNTER requires ORGANIZER"
+        );
+    }
+
+    #[test]
+    fn recurrence_id() {
+        let start = date_time(9, true);
+        let mut modified = Event::with_uid("1", start.into(), start);
+        modified.set_recurrence_id(start);
+        let mut calendar = Calendar::new();
+        calendar
+            .add_component(Event::with_uid("1", start.into(), start))
+            .add_component(modified);
+        assert_eq!(calendar.validate(), Ok(()));
+    }
+
+    #[test]
+    fn write_validated() {
+        let start = date_time(9, true);
+        let mut calendar = Calendar::new();
+        calendar
+            .add_component(Event::with_uid("1", start.into(), start))
+            .add_component(Event::with_uid("1", start.into(), start));
+        let mut output = Vec::new();
+        assert!(matches!(
+            calendar.write_validated(&mut output),
+            Err(Error::Validation(issues)) if issues.len() == 1
+        ));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn warnings() {
+        let stamp = date_time(8, true);
+        let start = ZonedDateTime::new(date_time(9, false), "Mars/Olympus_Mons");
+        let mut event = Event::with_uid("1", start.into(), stamp);
+        event.set_summary("Launch");
+        event.set_description("x".repeat(8_001));
+        let far = Date::new(2040, 1, 1);
+        let mut calendar = Calendar::new();
+        calendar
+            .add_component(event)
+            .add_component(Event::with_uid("2", far.into(), stamp));
+        let report = calendar.validation_report(stamp.date);
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings(),
+            [
+                ValidationWarning::LongDescription {
+                    uid: "1".to_owned(),
+                    length: 8_001
+                },
+                ValidationWarning::UnknownTimeZone {
+                    uid: "1".to_owned(),
+                    time_zone: "Mars/Olympus_Mons".to_owned()
+                },
+                ValidationWarning::MissingSummary {
+                    uid: "2".to_owned()
+                },
+                ValidationWarning::FarFuture {
+                    uid: "2".to_owned(),
+                    start: far
+                },
+            ]
+        );
+        assert_eq!(report.to_string().lines().count(), 4);
+    }
+}
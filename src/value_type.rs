@@ -0,0 +1,126 @@
+/// The value type of a property without a `VALUE` parameter.
+///
+/// Properties that are not listed here, including additional properties, are text.
+pub(crate) fn default_value_type(name: &str) -> &'static str {
+    match name {
+        "dtstamp" | "dtstart" | "dtend" | "due" | "recurrence-id" | "rdate" | "exdate"
+        | "created" | "last-modified" | "completed" => "date-time",
+        "rrule" | "exrule" => "recur",
+        "sequence" | "priority" | "percent-complete" | "repeat" => "integer",
+        "organizer" | "attendee" => "cal-address",
+        "url" | "tzurl" | "source" | "image" | "conference" => "uri",
+        "duration" | "trigger" => "duration",
+        "geo" => "float",
+        "tzoffsetfrom" | "tzoffsetto" => "utc-offset",
+        _ => "text",
+    }
+}
+
+/// Convert a date (`20240705`) or date-time (`20240705T120000Z`) to the extended format
+/// (`2024-07-05`, `2024-07-05T12:00:00Z`).
+///
+/// Values in any other format, such as durations, are returned unchanged.
+pub(crate) fn extended_date_time(value: &str) -> String {
+    let digits = |text: &str| text.bytes().all(|byte| byte.is_ascii_digit());
+    let (date, time) = value.split_once('T').unwrap_or((value, ""));
+    let date = match (date.get(..4), date.get(4..6), date.get(6..)) {
+        (Some(year), Some(month), Some(day)) if date.len() == 8 && digits(date) => {
+            format!("{year}-{month}-{day}")
+        }
+        _ => return value.to_owned(),
+    };
+    if time.is_empty() {
+        return date;
+    }
+    match (time.get(..2), time.get(2..4), time.get(4..6), time.get(6..)) {
+        (Some(hour), Some(minute), Some(second), Some(rest)) if digits(&time[..6]) => {
+            format!("{date}T{hour}:{minute}:{second}{rest}")
+        }
+        _ => value.to_owned(),
+    }
+}
+
+/// Convert a UTC offset (`+0100`) to the extended format (`+01:00`).
+pub(crate) fn extended_utc_offset(value: &str) -> String {
+    match (value.get(..3), value.get(3..5), value.get(5..)) {
+        (Some(hours), Some(minutes), Some("")) => format!("{hours}:{minutes}"),
+        (Some(hours), Some(minutes), Some(seconds)) => format!("{hours}:{minutes}:{seconds}"),
+        _ => value.to_owned(),
+    }
+}
+
+/// Escape `text` as a `TEXT` value, see [RFC 5545 section 3.3.11 -
+/// Text](https://tools.ietf.org/html/rfc5545#section-3.3.11).
+///
+/// Line breaks (`CRLF`, `CR` or `LF`) are escaped as `\n`.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.replace("\r\n", "\n").chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\r' | '\n' => escaped.push_str("\\n"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// Unescape a `TEXT` value, see [RFC 5545 section 3.3.11 -
+/// Text](https://tools.ietf.org/html/rfc5545#section-3.3.11).
+///
+/// `\n` and `\N` become line breaks. Backslashes that do not start an escape sequence are kept.
+pub(crate) fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            text.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(escaped @ ('\\' | ';' | ',')) => text.push(escaped),
+            Some(other) => {
+                text.push('\\');
+                text.push(other);
+            }
+            None => text.push('\\'),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_text, extended_date_time, extended_utc_offset, unescape_text};
+
+    #[test]
+    fn formats() {
+        assert_eq!(extended_date_time("20240705"), "2024-07-05");
+        assert_eq!(
+            extended_date_time("20240705T123000Z"),
+            "2024-07-05T12:30:00Z"
+        );
+        assert_eq!(extended_date_time("PT1H"), "PT1H");
+        assert_eq!(extended_utc_offset("+0100"), "+01:00");
+        assert_eq!(extended_utc_offset("-053015"), "-05:30:15");
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            unescape_text("Line 1\\nLine 2\\NLine 3"),
+            "Line 1\nLine 2\nLine 3"
+        );
+        assert_eq!(unescape_text("a\\, b\\; c\\\\d"), "a, b; c\\d");
+        assert_eq!(unescape_text("C:\\Users\\"), "C:\\Users\\");
+        let text = "Line 1\r\nLine 2, with a comma; and a semicolon\\";
+        assert_eq!(
+            escape_text(text),
+            "Line 1\\nLine 2\\, with a comma\\; and a semicolon\\\\"
+        );
+        assert_eq!(unescape_text(&escape_text(text)), text.replace('\r', ""));
+    }
+}
@@ -0,0 +1,432 @@
+use {
+    crate::{Calendar, RecurrenceFrequency, RecurrenceRule},
+    ical_vcard::{Contentline, ParamValue, Parser},
+    std::io::{self, Write},
+};
+
+/// The maximum length of an encoded line of a quoted-printable value, excluding the `=` of the
+/// soft line break.
+const QUOTED_PRINTABLE_LINE_LENGTH: usize = 75;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+impl Calendar {
+    /// Write the calendar in the legacy vCalendar 1.0 format to the given writer, as specified in
+    /// [vCalendar - The Electronic Calendaring and Scheduling Exchange Format, Version
+    /// 1.0](https://web.archive.org/web/20040214042002/http://www.imc.org/pdi/vcal-10.txt).
+    ///
+    /// This is only useful for old phones and embedded devices that do not understand iCalendar.
+    /// The calendar is translated into the 1.0 dialect:
+    ///
+    /// - The version is `1.0` and there is no `METHOD`.
+    /// - Recurrence rules use the 1.0 syntax, such as `W1 MO FR #10`.
+    /// - Dates are written as date-times at midnight, and lists of dates are separated by `;`.
+    /// - Date-times with a time zone are written as local time, and `VTIMEZONE` components are
+    ///   omitted.
+    /// - The organizer is written as an `ATTENDEE` with the role `ORGANIZER`, and `DTSTAMP` as
+    ///   `LAST-MODIFIED`.
+    /// - Values that are not ASCII are encoded as quoted-printable UTF-8.
+    /// - Lines are not folded.
+    ///
+    /// Properties and components that do not exist in vCalendar 1.0, such as `RECURRENCE-ID`,
+    /// `CONFERENCE`, `IMAGE`, `COLOR` and `VLOCATION`, are omitted.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// calendar.set_product_identifier("-//Example//Example Calendar//EN");
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+    /// rule.set_count(3);
+    /// event.set_summary("Café").set_recurrence_rule(rule);
+    /// calendar.add_component(event);
+    ///
+    /// let mut output = Vec::new();
+    /// calendar.to_vcalendar(&mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "BEGIN:VCALENDAR\r\n\
+    ///      PRODID:-//Example//Example Calendar//EN\r\n\
+    ///      VERSION:1.0\r\n\
+    ///      BEGIN:VEVENT\r\n\
+    ///      UID:1@example.com\r\n\
+    ///      LAST-MODIFIED:20240701T120000Z\r\n\
+    ///      DTSTART:20240705T000000\r\n\
+    ///      SUMMARY;ENCODING=QUOTED-PRINTABLE;CHARSET=UTF-8:Caf=C3=A9\r\n\
+    ///      RRULE:W1 #3\r\n\
+    ///      END:VEVENT\r\n\
+    ///      END:VCALENDAR\r\n"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`]. Additionally, an error of kind
+    /// [`io::ErrorKind::InvalidData`] is returned if a recurrence rule cannot be expressed in
+    /// vCalendar 1.0, for example because it repeats hourly or uses `BYSETPOS`.
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn to_vcalendar<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.validate_before_write()?;
+        let text = self.to_ics_string();
+        // The depth of the component that is currently being omitted, if any.
+        let mut omitted: Option<usize> = None;
+        let mut depth = 0;
+        for contentline in Parser::new(text.as_bytes()) {
+            let contentline = contentline.expect("Calendars are written as valid content lines");
+            let name = contentline.name.as_str().to_ascii_uppercase();
+            let value = contentline.value.as_str();
+            match name.as_str() {
+                "BEGIN" => {
+                    depth += 1;
+                    if omitted.is_none() && !["VCALENDAR", "VEVENT"].contains(&value) {
+                        omitted = Some(depth);
+                    }
+                    if omitted.is_none() {
+                        write_line(&mut writer, &name, &[], value)?;
+                    }
+                }
+                "END" => {
+                    if omitted.is_none() {
+                        write_line(&mut writer, &name, &[], value)?;
+                    }
+                    if omitted == Some(depth) {
+                        omitted = None;
+                    }
+                    depth -= 1;
+                }
+                _ if omitted.is_some() => (),
+                _ => write_property(&mut writer, &name, &contentline)?,
+            }
+        }
+        writer.flush()
+    }
+}
+
+/// Translate a property into the vCalendar 1.0 dialect and write it.
+fn write_property<W: Write>(
+    writer: &mut W,
+    name: &str,
+    contentline: &Contentline,
+) -> io::Result<()> {
+    let value = contentline.value.as_str();
+    let param = |param_name: &str| {
+        contentline
+            .params
+            .iter()
+            .find(|param| param.name.as_str().eq_ignore_ascii_case(param_name))
+            .and_then(|param| param.values.first())
+            .map(|value| value.as_str().to_ascii_uppercase())
+    };
+    match name {
+        "VERSION" => write_line(writer, name, &[], "1.0"),
+        "METHOD" | "RECURRENCE-ID" | "CONFERENCE" | "IMAGE" | "COLOR" => Ok(()),
+        "DTSTAMP" => write_line(writer, "LAST-MODIFIED", &[], value),
+        "DTSTART" | "DTEND" | "RDATE" | "EXDATE" => {
+            let is_date = param("VALUE").as_deref() == Some("DATE");
+            let dates: Vec<_> = value
+                .split(',')
+                .map(|date| {
+                    if is_date {
+                        format!("{date}T000000")
+                    } else {
+                        date.to_owned()
+                    }
+                })
+                .collect();
+            write_line(writer, name, &[], &dates.join(";"))
+        }
+        "RRULE" | "EXRULE" => {
+            let rule: RecurrenceRule = value
+                .parse()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            let rule = legacy_rule(&rule).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("The recurrence rule {value} cannot be expressed in vCalendar 1.0"),
+                )
+            })?;
+            write_line(writer, name, &[], &rule)
+        }
+        "ORGANIZER" => write_line(
+            writer,
+            "ATTENDEE",
+            &[("ROLE", "ORGANIZER".to_owned())],
+            email_address(value),
+        ),
+        "ATTENDEE" => {
+            let mut params = vec![("ROLE", "ATTENDEE".to_owned())];
+            if let Some(participation_status) = param("PARTSTAT") {
+                params.push(("STATUS", participation_status.replace('-', " ")));
+            }
+            let rsvp = param("RSVP").as_deref() == Some("TRUE");
+            params.push(("RSVP", if rsvp { "YES" } else { "NO" }.to_owned()));
+            write_line(writer, name, &params, email_address(value))
+        }
+        _ => {
+            let params: Vec<_> = contentline
+                .params
+                .iter()
+                .map(|param| {
+                    let values: Vec<_> = param.values.iter().map(ParamValue::as_str).collect();
+                    (param.name.as_str(), values.join(","))
+                })
+                .collect();
+            write_line(writer, name, &params, value)
+        }
+    }
+}
+
+/// Write a content line without folding it. A value that is not ASCII is encoded as
+/// quoted-printable UTF-8.
+fn write_line<W: Write>(
+    writer: &mut W,
+    name: &str,
+    params: &[(&str, String)],
+    value: &str,
+) -> io::Result<()> {
+    write!(writer, "{name}")?;
+    for (param_name, param_value) in params {
+        write!(writer, ";{param_name}={param_value}")?;
+    }
+    if value.is_ascii() {
+        write!(writer, ":{value}\r\n")
+    } else {
+        write!(
+            writer,
+            ";ENCODING=QUOTED-PRINTABLE;CHARSET=UTF-8:{}\r\n",
+            quoted_printable(value)
+        )
+    }
+}
+
+/// Encode `value` as quoted-printable, as specified in [RFC 2045 section 6.7 - Quoted-Printable
+/// Content-Transfer-Encoding](https://tools.ietf.org/html/rfc2045#section-6.7).
+fn quoted_printable(value: &str) -> String {
+    let mut encoded = String::new();
+    let mut line_length = 0;
+    for byte in value.bytes() {
+        let is_literal = (b'!'..=b'~').contains(&byte) && byte != b'=' || byte == b' ';
+        let length = if is_literal { 1 } else { 3 };
+        if line_length + length > QUOTED_PRINTABLE_LINE_LENGTH {
+            encoded.push_str("=\r\n");
+            line_length = 0;
+        }
+        if is_literal {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('=');
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+        }
+        line_length += length;
+    }
+    encoded
+}
+
+/// The email address of a calendar user address such as `mailto:alice@example.com`.
+fn email_address(address: &str) -> &str {
+    address
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map_or(address, |_| &address[7..])
+}
+
+/// Translate a recurrence rule into the vCalendar 1.0 syntax, such as `MP1 1+ MO #0`.
+///
+/// Returns `None` if the rule cannot be expressed in vCalendar 1.0.
+fn legacy_rule(rule: &RecurrenceRule) -> Option<String> {
+    if !rule.by_week_no().is_empty() || !rule.by_set_pos().is_empty() {
+        return None;
+    }
+    let has_by_day = !rule.by_day().is_empty();
+    let has_by_month_day = !rule.by_month_day().is_empty();
+    let has_by_month = !rule.by_month().is_empty();
+    let has_by_year_day = !rule.by_year_day().is_empty();
+    let interval = rule.interval();
+    let mut parts = match rule.freq() {
+        RecurrenceFrequency::Minutely
+            if !has_by_day && !has_by_month_day && !has_by_month && !has_by_year_day =>
+        {
+            vec![format!("M{interval}")]
+        }
+        RecurrenceFrequency::Daily
+            if !has_by_day && !has_by_month_day && !has_by_month && !has_by_year_day =>
+        {
+            vec![format!("D{interval}")]
+        }
+        RecurrenceFrequency::Weekly if !has_by_month_day && !has_by_month && !has_by_year_day => {
+            let mut parts = vec![format!("W{interval}")];
+            for day in rule.by_day() {
+                if day.ordinal().is_some() {
+                    return None;
+                }
+                parts.push(day.weekday().to_string());
+            }
+            parts
+        }
+        RecurrenceFrequency::Monthly
+            if has_by_day && !has_by_month_day && !has_by_month && !has_by_year_day =>
+        {
+            let mut parts = vec![format!("MP{interval}")];
+            for day in rule.by_day() {
+                let n = day.ordinal()?;
+                parts.push(if n < 0 {
+                    format!("{}-", -n)
+                } else {
+                    format!("{n}+")
+                });
+                parts.push(day.weekday().to_string());
+            }
+            parts
+        }
+        RecurrenceFrequency::Monthly if !has_by_month && !has_by_year_day => {
+            let mut parts = vec![format!("MD{interval}")];
+            parts.extend(
+                rule.by_month_day()
+                    .iter()
+                    .map(|day| day_number((*day).into())),
+            );
+            parts
+        }
+        RecurrenceFrequency::Yearly if !has_by_day && !has_by_month_day && !has_by_year_day => {
+            let mut parts = vec![format!("YM{interval}")];
+            parts.extend(rule.by_month().iter().map(ToString::to_string));
+            parts
+        }
+        RecurrenceFrequency::Yearly if !has_by_day && !has_by_month_day && !has_by_month => {
+            let mut parts = vec![format!("YD{interval}")];
+            parts.extend(
+                rule.by_year_day()
+                    .iter()
+                    .map(|day| day_number((*day).into())),
+            );
+            parts
+        }
+        _ => return None,
+    };
+    parts.push(match (rule.count(), rule.until()) {
+        (Some(count), _) => format!("#{count}"),
+        (None, Some(until)) if until.is_date() => format!("{until}T235959"),
+        (None, Some(until)) => until.to_string(),
+        (None, None) => "#0".to_owned(),
+    });
+    Some(parts.join(" "))
+}
+
+/// Write a day of the month or of the year in the vCalendar 1.0 syntax, where days counted from
+/// the end are written as `1-`.
+fn day_number(n: i32) -> String {
+    if n < 0 {
+        format!("{}-", -n)
+    } else {
+        n.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{legacy_rule, quoted_printable},
+        crate::{
+            Attendee, Calendar, Date, DateTime, Event, Organizer, PartStat, RecurrenceFrequency,
+            RecurrenceRule, Time, Weekday, WeekdayNum,
+        },
+        std::io::ErrorKind,
+    };
+
+    fn rule(text: &str) -> Option<String> {
+        legacy_rule(&text.parse::<RecurrenceRule>().unwrap())
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(rule("FREQ=DAILY;COUNT=5").as_deref(), Some("D1 #5"));
+        assert_eq!(
+            rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR").as_deref(),
+            Some("W2 MO FR #0")
+        );
+        assert_eq!(
+            rule("FREQ=MONTHLY;BYDAY=1MO,-1FR;UNTIL=20241231").as_deref(),
+            Some("MP1 1+ MO 1- FR 20241231T235959")
+        );
+        assert_eq!(
+            rule("FREQ=MONTHLY;BYMONTHDAY=1,-1;UNTIL=20241231T120000Z").as_deref(),
+            Some("MD1 1 1- 20241231T120000Z")
+        );
+        assert_eq!(
+            rule("FREQ=YEARLY;BYMONTH=6,7").as_deref(),
+            Some("YM1 6 7 #0")
+        );
+        assert_eq!(
+            rule("FREQ=YEARLY;BYYEARDAY=100").as_deref(),
+            Some("YD1 100 #0")
+        );
+        assert_eq!(rule("FREQ=HOURLY"), None);
+        assert_eq!(rule("FREQ=MONTHLY;BYDAY=MO"), None);
+        assert_eq!(rule("FREQ=MONTHLY;BYDAY=MO;BYSETPOS=1"), None);
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(quoted_printable("a=b ä"), "a=3Db =C3=A4");
+        let encoded = quoted_printable(&"ä".repeat(20));
+        let first_line = encoded.lines().next().unwrap();
+        assert_eq!(first_line.len(), 76);
+        assert!(first_line.ends_with('='));
+    }
+
+    #[test]
+    fn participants() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(9, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", start.into(), stamp);
+        let mut attendee = Attendee::new("mailto:bob@example.com");
+        attendee
+            .set_participation_status(PartStat::NeedsAction)
+            .set_rsvp(true);
+        event
+            .set_organizer(Organizer::new("mailto:alice@example.com"))
+            .add_attendee(attendee)
+            .add_exception_date(Date::new(2024, 7, 12))
+            .add_exception_date(Date::new(2024, 7, 19));
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+
+        let mut output = Vec::new();
+        calendar.to_vcalendar(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("\r\nATTENDEE;ROLE=ORGANIZER:alice@example.com\r\n"));
+        assert!(output.contains(
+            "\r\nATTENDEE;ROLE=ATTENDEE;STATUS=NEEDS ACTION;RSVP=YES:bob@example.com\r\n"
+        ));
+        assert!(output.contains("\r\nEXDATE:20240712T000000\r\nEXDATE:20240719T000000\r\n"));
+    }
+
+    #[test]
+    fn unsupported_rule() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Monthly);
+        rule.set_by_day([WeekdayNum::every(Weekday::Monday)]);
+        event.set_recurrence_rule(rule);
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+
+        let err = calendar.to_vcalendar(Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,84 @@
+use {
+    crate::{Calendar, write_footer, write_to_string},
+    std::io,
+    tokio::io::{AsyncWrite, AsyncWriteExt},
+};
+
+impl Calendar {
+    /// Write the calendar to the given asynchronous writer, such as the body of an HTTP
+    /// response.
+    ///
+    /// The calendar is written one component at a time, so only a single component is held in
+    /// memory as text at any time.
+    ///
+    /// Requires the `tokio` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`].
+    pub async fn write_async<W: AsyncWrite + Unpin>(&self, mut writer: W) -> io::Result<()> {
+        self.validate_before_write()?;
+        let header = write_to_string(|writer| self.write_header(writer));
+        writer.write_all(header.as_bytes()).await?;
+        for component in &self.components {
+            let component = write_to_string(|writer| component.write(writer));
+            writer.write_all(component.as_bytes()).await?;
+        }
+        #[allow(
+            clippy::redundant_closure,
+            reason = "Passing the function directly does not satisfy the lifetime bounds"
+        )]
+        let footer = write_to_string(|writer| write_footer(writer));
+        writer.write_all(footer.as_bytes()).await?;
+        writer.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{Calendar, Date, DateTime, Event, RecurrenceFrequency, RecurrenceRule, Time},
+        std::io::ErrorKind,
+    };
+
+    fn calendar() -> Calendar {
+        let start = DateTime {
+            date: Date::new(2024, 7, 5),
+            time: Time::new_utc(9, 0, 0),
+        };
+        let mut calendar = Calendar::new();
+        calendar.add_component(Event::with_uid("1@example.com", start.into(), start));
+        calendar.add_component(Event::with_uid("2@example.com", start.into(), start));
+        calendar
+    }
+
+    #[tokio::test]
+    async fn same_as_write() {
+        let calendar = calendar();
+        let mut output = Vec::new();
+        calendar.write_async(&mut output).await.unwrap();
+        assert_eq!(output, calendar.to_bytes());
+    }
+
+    #[tokio::test]
+    async fn strict() {
+        let mut calendar = calendar();
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("3@example.com", Date::new(2024, 7, 5).into(), stamp);
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily);
+        rule.set_until(DateTime {
+            date: Date::new(2024, 7, 10),
+            time: Time::new_utc(0, 0, 0),
+        });
+        event.set_recurrence_rule(rule);
+        calendar.add_component(event);
+        calendar.set_strict(true);
+        let mut output = Vec::new();
+        let err = calendar.write_async(&mut output).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(output.is_empty());
+    }
+}
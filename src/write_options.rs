@@ -0,0 +1,276 @@
+use std::io::{self, Write};
+
+/// Options for writing a calendar with
+/// [`Calendar::write_with_options`](crate::Calendar::write_with_options).
+///
+/// By default, lines are folded after 75 octets as required by
+/// [RFC 5545 section 3.1](https://tools.ietf.org/html/rfc5545#section-3.1), continuation lines
+/// are indented with a space and lines end with CRLF. Some consumers that do not implement RFC
+/// 5545 correctly need other options.
+///
+/// ```
+/// use ical::{Calendar, LineEnding, WriteOptions};
+///
+/// let mut calendar = Calendar::new();
+/// calendar.add_property("X-WR-CALDESC", "A".repeat(100), &[]);
+/// let mut output = Vec::new();
+/// calendar
+///     .write_with_options(
+///         &mut output,
+///         WriteOptions::new()
+///             .fold_width(None)
+///             .line_ending(LineEnding::Lf),
+///     )
+///     .unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.contains(&format!("\nX-WR-CALDESC:{}\n", "A".repeat(100))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteOptions {
+    fold_width: Option<usize>,
+    fold_indentation: u8,
+    line_ending: LineEnding,
+}
+
+impl WriteOptions {
+    /// Create the default options, see [`WriteOptions`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            fold_width: Some(75),
+            fold_indentation: b' ',
+            line_ending: LineEnding::CrLf,
+        }
+    }
+
+    /// Set the maximum length of a line in octets, excluding the line ending, or disable line
+    /// folding with `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is less than 5, because a continuation line must fit the indentation
+    /// and at least one UTF-8 encoded character.
+    pub fn fold_width(&mut self, width: Option<usize>) -> &mut Self {
+        if let Some(width) = width {
+            assert!(width >= 5, "The fold width must be at least 5 octets");
+        }
+        self.fold_width = width;
+        self
+    }
+
+    /// Set the character with which continuation lines are indented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indentation` is neither a space nor a horizontal tab, which are the only
+    /// characters allowed by RFC 5545.
+    pub fn fold_indentation(&mut self, indentation: char) -> &mut Self {
+        assert!(
+            indentation == ' ' || indentation == '\t',
+            "Lines can only be folded with a space or a horizontal tab"
+        );
+        self.fold_indentation = if indentation == ' ' { b' ' } else { b'\t' };
+        self
+    }
+
+    /// Set the line ending.
+    pub fn line_ending(&mut self, line_ending: LineEnding) -> &mut Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Returns `true` if these are the default options.
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::new()
+    }
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The line ending used when writing a calendar, see [`WriteOptions::line_ending`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\r\n`, as required by RFC 5545.
+    CrLf,
+    /// `\n`.
+    Lf,
+}
+
+impl LineEnding {
+    fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::CrLf => b"\r\n",
+            LineEnding::Lf => b"\n",
+        }
+    }
+}
+
+/// A writer that unfolds the content lines written to it and folds them again according to the
+/// given [`WriteOptions`].
+///
+/// [`FoldingWriter::finish`] must be called after the last content line has been written.
+pub(crate) struct FoldingWriter<W: Write> {
+    inner: W,
+    options: WriteOptions,
+    /// The unfolded content line that is currently being written.
+    line: Vec<u8>,
+    /// Whether the last byte was a CR.
+    after_cr: bool,
+    /// Whether the last bytes were a CRLF. The line is only complete if the next byte is not
+    /// the indentation of a continuation line.
+    after_crlf: bool,
+}
+
+impl<W: Write> FoldingWriter<W> {
+    pub(crate) fn new(inner: W, options: WriteOptions) -> Self {
+        Self {
+            inner,
+            options,
+            line: Vec::new(),
+            after_cr: false,
+            after_crlf: false,
+        }
+    }
+
+    /// Write the last content line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the inner writer fails.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        if self.after_cr {
+            self.line.push(b'\r');
+        }
+        if self.after_crlf || !self.line.is_empty() {
+            self.write_line()?;
+        }
+        self.inner.flush()
+    }
+
+    /// Fold the current content line and write it to the inner writer.
+    fn write_line(&mut self) -> io::Result<()> {
+        let ending = self.options.line_ending.as_bytes();
+        let mut output = Vec::with_capacity(self.line.len() + ending.len());
+        let mut length = 0;
+        for &byte in &self.line {
+            let is_char_boundary = byte & 0xC0 != 0x80;
+            if let Some(width) = self.options.fold_width
+                && is_char_boundary
+            {
+                let char_length = if byte < 0x80 {
+                    1
+                } else {
+                    byte.leading_ones() as usize
+                };
+                if length + char_length > width {
+                    output.extend_from_slice(ending);
+                    output.push(self.options.fold_indentation);
+                    length = 1;
+                }
+            }
+            output.push(byte);
+            length += 1;
+        }
+        output.extend_from_slice(ending);
+        self.line.clear();
+        self.inner.write_all(&output)
+    }
+}
+
+impl<W: Write> Write for FoldingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &byte in buf {
+            if self.after_crlf {
+                self.after_crlf = false;
+                if byte == b' ' || byte == b'\t' {
+                    continue;
+                }
+                self.write_line()?;
+            }
+            if self.after_cr {
+                self.after_cr = false;
+                if byte == b'\n' {
+                    self.after_crlf = true;
+                    continue;
+                }
+                self.line.push(b'\r');
+            }
+            if byte == b'\r' {
+                self.after_cr = true;
+            } else {
+                self.line.push(byte);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{FoldingWriter, LineEnding, WriteOptions},
+        std::io::Write,
+    };
+
+    fn refold(input: &str, options: &WriteOptions) -> String {
+        let mut output = Vec::new();
+        let mut writer = FoldingWriter::new(&mut output, *options);
+        // Write byte by byte to make sure that folds are recognized across writes.
+        for byte in input.bytes() {
+            writer.write_all(&[byte]).unwrap();
+        }
+        writer.finish().unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn unfold() {
+        let input = "SUMMARY:abc\r\n def\r\n\tghi\r\nEND:VEVENT\r\n";
+        assert_eq!(
+            refold(input, WriteOptions::new().fold_width(None)),
+            "SUMMARY:abcdefghi\r\nEND:VEVENT\r\n"
+        );
+        assert_eq!(
+            refold(
+                input,
+                WriteOptions::new()
+                    .fold_width(None)
+                    .line_ending(LineEnding::Lf)
+            ),
+            "SUMMARY:abcdefghi\nEND:VEVENT\n"
+        );
+    }
+
+    #[test]
+    fn fold() {
+        let input = "SUMMARY:abcdefghi\r\n";
+        assert_eq!(
+            refold(
+                input,
+                WriteOptions::new()
+                    .fold_width(Some(10))
+                    .fold_indentation('\t')
+            ),
+            "SUMMARY:ab\r\n\tcdefghi\r\n"
+        );
+        // Multi-octet characters are never split.
+        assert_eq!(
+            refold("X:äöü\r\n", WriteOptions::new().fold_width(Some(5))),
+            "X:ä\r\n öü\r\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Lines can only be folded with a space or a horizontal tab")]
+    fn invalid_indentation() {
+        WriteOptions::new().fold_indentation('x');
+    }
+}
@@ -0,0 +1,251 @@
+use {
+    crate::{
+        Calendar,
+        value_type::{default_value_type, extended_date_time, extended_utc_offset, unescape_text},
+    },
+    ical_vcard::{Contentline, Parser},
+    std::io::{self, Write},
+};
+
+/// The XML namespace of xCal, see [RFC 6321 section 3.1 -
+/// Namespace](https://tools.ietf.org/html/rfc6321#section-3.1).
+const NAMESPACE: &str = "urn:ietf:params:xml:ns:icalendar-2.0";
+
+impl Calendar {
+    /// Write the calendar in its XML representation to the given writer, as specified in
+    /// [RFC 6321 - xCal](https://tools.ietf.org/html/rfc6321).
+    ///
+    /// The output contains the same properties and components as [`Calendar::write`], without
+    /// any whitespace between the elements. Dates and date-times are written in the extended
+    /// ISO 8601 format, such as `2024-07-05T12:00:00Z`, recurrence rules are written as `recur`
+    /// elements with one element per rule part, and text is written without the escape sequences
+    /// of iCalendar, such as `\,` and `\n`.
+    ///
+    /// ```
+    /// use ical::{Calendar, Date, DateTime, Event, Time};
+    ///
+    /// let stamp = DateTime {
+    ///     date: Date::new(2024, 7, 1),
+    ///     time: Time::new_utc(12, 0, 0),
+    /// };
+    /// let mut calendar = Calendar::new();
+    /// calendar.set_product_identifier("-//Example//Example Calendar//EN");
+    /// let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+    /// event.set_summary("Fish & chips");
+    /// calendar.add_component(event);
+    ///
+    /// let mut xml = Vec::new();
+    /// calendar.to_xcal(&mut xml).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(xml).unwrap(),
+    ///     "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+    ///      <icalendar xmlns=\"urn:ietf:params:xml:ns:icalendar-2.0\">\
+    ///      <vcalendar><properties>\
+    ///      <prodid><text>-//Example//Example Calendar//EN</text></prodid>\
+    ///      <version><text>2.0</text></version>\
+    ///      </properties><components>\
+    ///      <vevent><properties>\
+    ///      <uid><text>1@example.com</text></uid>\
+    ///      <dtstamp><date-time>2024-07-01T12:00:00Z</date-time></dtstamp>\
+    ///      <dtstart><date>2024-07-05</date></dtstart>\
+    ///      <summary><text>Fish &amp; chips</text></summary>\
+    ///      </properties></vevent>\
+    ///      </components></vcalendar>\
+    ///      </icalendar>\n"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Calendar::write`].
+    #[allow(clippy::missing_panics_doc, reason = "This will never panic")]
+    pub fn to_xcal<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.validate_before_write()?;
+        let text = self.to_ics_string();
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+        write!(writer, "<icalendar xmlns=\"{NAMESPACE}\">")?;
+        // For every open component, whether its `components` element has been opened.
+        let mut stack: Vec<bool> = Vec::new();
+        for contentline in Parser::new(text.as_bytes()) {
+            let contentline = contentline.expect("Calendars are written as valid content lines");
+            let name = contentline.name.as_str().to_ascii_lowercase();
+            if name == "begin" {
+                if let Some(has_components) = stack.last_mut()
+                    && !*has_components
+                {
+                    write!(writer, "</properties><components>")?;
+                    *has_components = true;
+                }
+                let component = contentline.value.as_str().to_ascii_lowercase();
+                write!(writer, "<{component}><properties>")?;
+                stack.push(false);
+            } else if name == "end" {
+                if stack.pop().expect("Components are balanced") {
+                    write!(writer, "</components>")?;
+                } else {
+                    write!(writer, "</properties>")?;
+                }
+                let component = contentline.value.as_str().to_ascii_lowercase();
+                write!(writer, "</{component}>")?;
+            } else {
+                write_property(&mut writer, &name, &contentline)?;
+            }
+        }
+        writeln!(writer, "</icalendar>")?;
+        writer.flush()
+    }
+}
+
+/// Write a property as an XML element, see [RFC 6321 section 3.4 -
+/// Properties](https://tools.ietf.org/html/rfc6321#section-3.4).
+fn write_property<W: Write>(
+    writer: &mut W,
+    name: &str,
+    contentline: &Contentline,
+) -> io::Result<()> {
+    let mut value_type = default_value_type(name).to_owned();
+    let mut params = Vec::new();
+    for param in &contentline.params {
+        let param_name = param.name.as_str().to_ascii_lowercase();
+        if param_name == "value" {
+            if let Some(value) = param.values.first() {
+                value_type = value.as_str().to_ascii_lowercase();
+            }
+        } else {
+            params.push((param_name, param));
+        }
+    }
+    write!(writer, "<{name}>")?;
+    if !params.is_empty() {
+        write!(writer, "<parameters>")?;
+        for (param_name, param) in params {
+            let param_type = param_value_type(&param_name);
+            write!(writer, "<{param_name}>")?;
+            for value in &param.values {
+                write_element(writer, param_type, value.as_str())?;
+            }
+            write!(writer, "</{param_name}>")?;
+        }
+        write!(writer, "</parameters>")?;
+    }
+    let value = contentline.value.as_str();
+    match value_type.as_str() {
+        "date" | "date-time" => {
+            for value in value.split(',') {
+                write_element(writer, &value_type, &extended_date_time(value))?;
+            }
+        }
+        "period" => {
+            for period in value.split(',') {
+                let (start, end) = period.split_once('/').unwrap_or((period, ""));
+                write!(writer, "<period>")?;
+                write_element(writer, "start", &extended_date_time(start))?;
+                if end.trim_start_matches(['+', '-']).starts_with('P') {
+                    write_element(writer, "duration", end)?;
+                } else {
+                    write_element(writer, "end", &extended_date_time(end))?;
+                }
+                write!(writer, "</period>")?;
+            }
+        }
+        "float" if name == "geo" => {
+            let (latitude, longitude) = value.split_once(';').unwrap_or((value, ""));
+            write_element(writer, "latitude", latitude)?;
+            write_element(writer, "longitude", longitude)?;
+        }
+        "utc-offset" => write_element(writer, "utc-offset", &extended_utc_offset(value))?,
+        "recur" => {
+            write!(writer, "<recur>")?;
+            for part in value.split(';') {
+                let (part_name, values) = part.split_once('=').unwrap_or((part, ""));
+                let part_name = part_name.to_ascii_lowercase();
+                for value in values.split(',') {
+                    if part_name == "until" {
+                        write_element(writer, &part_name, &extended_date_time(value))?;
+                    } else {
+                        write_element(writer, &part_name, value)?;
+                    }
+                }
+            }
+            write!(writer, "</recur>")?;
+        }
+        "text" => write_element(writer, "text", &unescape_text(value))?,
+        _ => write_element(writer, &value_type, value)?,
+    }
+    write!(writer, "</{name}>")
+}
+
+/// The value type of a parameter, see [RFC 6321 section 3.5 -
+/// Parameters](https://tools.ietf.org/html/rfc6321#section-3.5).
+fn param_value_type(name: &str) -> &'static str {
+    match name {
+        "altrep" | "dir" => "uri",
+        "delegated-from" | "delegated-to" | "member" | "sent-by" => "cal-address",
+        _ => "text",
+    }
+}
+
+/// Write the element `<name>text</name>`, escaping the special characters of `text`.
+fn write_element<W: Write>(writer: &mut W, name: &str, text: &str) -> io::Result<()> {
+    write!(writer, "<{name}>")?;
+    let mut rest = text;
+    while let Some(index) = rest.find(['&', '<', '>']) {
+        let escaped = match rest.as_bytes()[index] {
+            b'&' => "&amp;",
+            b'<' => "&lt;",
+            _ => "&gt;",
+        };
+        write!(writer, "{}{escaped}", &rest[..index])?;
+        rest = &rest[index + 1..];
+    }
+    write!(writer, "{rest}</{name}>")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Attendee, Calendar, Date, DateTime, Event, PartStat, RecurrenceFrequency, RecurrenceRule,
+        Time, Weekday,
+    };
+
+    #[test]
+    fn properties() {
+        let stamp = DateTime {
+            date: Date::new(2024, 7, 1),
+            time: Time::new_utc(12, 0, 0),
+        };
+        let mut event = Event::with_uid("1@example.com", Date::new(2024, 7, 5).into(), stamp);
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Weekly);
+        rule.set_until(Date::new(2024, 8, 30))
+            .set_by_day([Weekday::Monday, Weekday::Friday]);
+        let mut attendee = Attendee::new("mailto:bob@example.com");
+        attendee
+            .set_common_name("Bob <Builder>")
+            .set_participation_status(PartStat::Accepted);
+        event
+            .set_sequence(1)
+            .set_description("Line 1\\nLine 2\\, with a comma\\; and a semicolon")
+            .add_attendee(attendee)
+            .set_recurrence_rule(rule);
+        let mut calendar = Calendar::new();
+        calendar.add_component(event);
+
+        let mut xml = Vec::new();
+        calendar.to_xcal(&mut xml).unwrap();
+        let xml = String::from_utf8(xml).unwrap();
+        assert!(xml.contains("<sequence><integer>1</integer></sequence>"));
+        assert!(xml.contains(
+            "<description><text>Line 1\nLine 2, with a comma; and a semicolon</text></description>"
+        ));
+        assert!(xml.contains(
+            "<attendee><parameters>\
+             <cn><text>Bob &lt;Builder&gt;</text></cn>\
+             <partstat><text>ACCEPTED</text></partstat>\
+             </parameters><cal-address>mailto:bob@example.com</cal-address></attendee>"
+        ));
+        assert!(xml.contains(
+            "<rrule><recur><freq>WEEKLY</freq><until>2024-08-30</until>\
+             <byday>MO</byday><byday>FR</byday></recur></rrule>"
+        ));
+    }
+}